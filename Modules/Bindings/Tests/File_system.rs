@@ -24,7 +24,7 @@ fn Integration_test() {
     let _ = Virtual_file_system.Mount(Box::new(Native_file_system), Path_type::Get_root());
 
     let (_Runtime, _Module, Instance) =
-        Instantiate_test_environment(Binary_buffer, File_system_bindings, &Data_type::New());
+        Instantiate_test_environment(Binary_buffer, File_system_bindings, Data_type::New());
 
     assert_eq!(
         Instance
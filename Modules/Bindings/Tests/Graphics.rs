@@ -48,11 +48,8 @@ fn Integration_test() {
     let mut Display_object = Display_object.unwrap();
 
     thread::spawn(|| {
-        let (_Runtime, _Module, Instance) = Instantiate_test_environment(
-            Binary_buffer,
-            Graphics_bindings::New(),
-            &Data_type::New(),
-        );
+        let (_Runtime, _Module, Instance) =
+            Instantiate_test_environment(Binary_buffer, Graphics_bindings::New(), Data_type::New());
 
         assert_eq!(
             Instance
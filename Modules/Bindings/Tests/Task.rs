@@ -2,8 +2,8 @@
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
 
-use Bindings::Task_bindings;
-use Virtual_machine::{Data_type, Instantiate_test_environment, WasmValue};
+use Bindings::{New_instance, Task_bindings};
+use Virtual_machine::{Module_type, Runtime_type, WasmValue};
 
 #[test]
 fn Integration_test() {
@@ -11,13 +11,60 @@ fn Integration_test() {
         "../Tests/WASM_test/target/wasm32-unknown-unknown/release/File_system_bindings_WASM_test.wasm"
     );
 
-    Task::Initialize().expect("Failed to initialize task manager");
+    let Task_manager = Task::Initialize().expect("Failed to initialize task manager");
 
-    let (_Runtime, _Module, Instance) =
-        Instantiate_test_environment(Binary_buffer, Task_bindings::New(), &Data_type::New());
+    let Runtime = Runtime_type::Builder()
+        .Register(Task_bindings::New())
+        .Build()
+        .expect("Failed to build runtime");
+
+    let Module =
+        Module_type::From_buffer(&Runtime, Binary_buffer, "main").expect("Failed to load module");
+
+    let Instance = New_instance(
+        &Runtime,
+        &Module,
+        1024 * 4,
+        Task_manager.Get_current_task_identifier().unwrap(),
+    )
+    .expect("Failed to instantiate module");
 
     assert_eq!(
         Instance.Call_export_function("Test_task", &vec![]).unwrap(),
         WasmValue::I32(42)
-    )
+    );
+
+    // - Periodic timer, deleted after at least three expirations
+    match Instance
+        .Call_export_function("Test_timer", &vec![])
+        .unwrap()
+    {
+        WasmValue::I32(Count) => assert!(Count >= 3),
+        Value => panic!("Unexpected return value : {:?}", Value),
+    }
+
+    // - The handler of the exit of a child runs when the child is waited for
+    assert_eq!(
+        Instance
+            .Call_export_function("Test_signal", &vec![])
+            .unwrap(),
+        WasmValue::I32(1)
+    );
+
+    // - At least the root task is listed
+    match Instance
+        .Call_export_function("Test_task_listing", &vec![])
+        .unwrap()
+    {
+        WasmValue::I32(Count) => assert!(Count >= 1),
+        Value => panic!("Unexpected return value : {:?}", Value),
+    }
+
+    // - The priority of a child is lowered while it runs
+    assert_eq!(
+        Instance
+            .Call_export_function("Test_priority", &vec![])
+            .unwrap(),
+        WasmValue::I32(1)
+    );
 }
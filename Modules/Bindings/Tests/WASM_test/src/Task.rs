@@ -5,22 +5,262 @@ use std::{num::NonZeroU32, sync::RwLock};
 use Binding_tool::Bind_function_WASM;
 
 #[Bind_function_WASM]
-fn New_task(Name: &str, Stack_size: u32, Function: u32) -> Result<(), NonZeroU32> {}
+fn New_task(
+    Name: &str,
+    Stack_size: u32,
+    Priority: u8,
+    Affinity: u32,
+    Function: u32,
+    Task: &mut u32,
+) -> Result<(), NonZeroU32> {
+}
+
+/// Inherit the priority of the parent task.
+const Inherit_priority: u8 = u8::MAX;
+
+/// Inherit the affinity of the parent task.
+const Inherit_affinity: u32 = 0;
+
+#[Bind_function_WASM]
+fn Wait_child(Child: u32, Kind: &mut u8, Code: &mut i32) -> Result<(), NonZeroU32> {}
+
+#[Bind_function_WASM]
+fn Set_exit_code(Code: i32) -> Result<(), NonZeroU32> {}
 
 #[Bind_function_WASM]
 fn Sleep(Duration: u64) {}
 
 fn Test_function() {
     *Test_variable.write().unwrap() = 42;
+
+    let _ = Set_exit_code(7);
 }
 
 static Test_variable: RwLock<u32> = RwLock::new(0);
 
 #[no_mangle]
 fn Test_task() -> u32 {
-    let _ = New_task("Test", 4096, Test_function as usize as u32);
+    let mut Task = 0;
+
+    if New_task(
+        "Test",
+        4096,
+        Inherit_priority,
+        Inherit_affinity,
+        Test_function as usize as u32,
+        &mut Task,
+    )
+    .is_err()
+    {
+        return 0;
+    }
+
+    let (mut Kind, mut Code) = (0, 0);
 
-    Sleep(1);
+    // - The child exited with its own code
+    if Wait_child(Task, &mut Kind, &mut Code).is_err() || Kind != 0 || Code != 7 {
+        return 0;
+    }
 
     *Test_variable.read().unwrap()
 }
+
+#[Bind_function_WASM]
+fn New_timer(Function: u32, Timer: &mut u32) -> Result<(), NonZeroU32> {}
+
+#[Bind_function_WASM]
+fn Start_timer(Timer: u32, Delay: u64, Period: u64) -> Result<(), NonZeroU32> {}
+
+#[Bind_function_WASM]
+fn Delete_timer(Timer: u32) -> Result<(), NonZeroU32> {}
+
+fn Timer_function() {
+    *Timer_count.write().unwrap() += 1;
+}
+
+static Timer_count: RwLock<u32> = RwLock::new(0);
+
+#[no_mangle]
+fn Test_timer() -> u32 {
+    let mut Timer = 0;
+
+    if New_timer(Timer_function as usize as u32, &mut Timer).is_err() {
+        return 0;
+    }
+
+    if Start_timer(Timer, 1, 1).is_err() {
+        return 0;
+    }
+
+    while *Timer_count.read().unwrap() < 3 {
+        Sleep(1);
+    }
+
+    if Delete_timer(Timer).is_err() {
+        return 0;
+    }
+
+    *Timer_count.read().unwrap()
+}
+
+#[Bind_function_WASM]
+fn Set_signal_handler(Signal: u8, Function: u32) -> Result<(), NonZeroU32> {}
+
+const Child_exited_signal: u8 = 17;
+
+fn Signal_handler(Signal: u32) {
+    if Signal == Child_exited_signal as u32 {
+        *Signal_count.write().unwrap() += 1;
+    }
+}
+
+static Signal_count: RwLock<u32> = RwLock::new(0);
+
+fn Empty_function() {}
+
+#[no_mangle]
+fn Test_signal() -> u32 {
+    if Set_signal_handler(Child_exited_signal, Signal_handler as usize as u32).is_err() {
+        return 0;
+    }
+
+    let mut Task = 0;
+
+    if New_task(
+        "Child",
+        4096,
+        Inherit_priority,
+        Inherit_affinity,
+        Empty_function as usize as u32,
+        &mut Task,
+    )
+    .is_err()
+    {
+        return 0;
+    }
+
+    // - The handler is invoked once the exit is waited for
+    let (mut Kind, mut Code) = (0, 0);
+
+    if Wait_child(Task, &mut Kind, &mut Code).is_err() {
+        return 0;
+    }
+
+    if Set_signal_handler(Child_exited_signal, 0).is_err() {
+        return 0;
+    }
+
+    *Signal_count.read().unwrap()
+}
+
+#[Bind_function_WASM]
+fn Get_task_identifiers(Identifiers: &mut [u8], Count: &mut u32) -> Result<(), NonZeroU32> {}
+
+#[Bind_function_WASM]
+fn Get_task_information(
+    Task: u32,
+    Parent: &mut u32,
+    Owner: &mut u16,
+    State: &mut u8,
+    Start_time: &mut u64,
+    CPU_time: &mut u64,
+    Stack_size: &mut u64,
+) -> Result<(), NonZeroU32> {
+}
+
+#[no_mangle]
+fn Test_task_listing() -> u32 {
+    let mut Identifiers = [0_u8; 64];
+    let mut Count = 0;
+
+    if Get_task_identifiers(&mut Identifiers, &mut Count).is_err() || Count == 0 {
+        return 0;
+    }
+
+    // - Every listed task can be inspected
+    for Chunk in Identifiers.chunks_exact(4).take(Count as usize) {
+        let Task = u32::from_le_bytes([Chunk[0], Chunk[1], Chunk[2], Chunk[3]]);
+
+        let (mut Parent, mut Owner, mut State) = (0, 0, 0);
+        let (mut Start_time, mut CPU_time, mut Stack_size) = (0, 0, 0);
+
+        if Get_task_information(
+            Task,
+            &mut Parent,
+            &mut Owner,
+            &mut State,
+            &mut Start_time,
+            &mut CPU_time,
+            &mut Stack_size,
+        )
+        .is_err()
+            || State > 3
+        {
+            return 0;
+        }
+    }
+
+    Count
+}
+
+#[Bind_function_WASM]
+fn Get_priority(Task: u32, Priority: &mut u8) -> Result<(), NonZeroU32> {}
+
+#[Bind_function_WASM]
+fn Set_priority(Task: u32, Priority: u8) -> Result<(), NonZeroU32> {}
+
+fn Sleeping_function() {
+    Sleep(10);
+}
+
+#[no_mangle]
+fn Test_priority() -> u32 {
+    let mut Task = 0;
+
+    if New_task(
+        "Low priority",
+        4096,
+        Inherit_priority,
+        Inherit_affinity,
+        Sleeping_function as usize as u32,
+        &mut Task,
+    )
+    .is_err()
+    {
+        return 0;
+    }
+
+    // - Lower the priority of the child while it sleeps
+    let mut Priority = 0;
+
+    if Set_priority(Task, 1).is_err() || Get_priority(Task, &mut Priority).is_err() {
+        return 0;
+    }
+
+    let (mut Kind, mut Code) = (0, 0);
+
+    if Wait_child(Task, &mut Kind, &mut Code).is_err() {
+        return 0;
+    }
+
+    // - The priority and the affinity can be chosen at creation
+    let mut Child_priority = Inherit_priority;
+
+    if New_task(
+        "Lowest priority",
+        4096,
+        0,
+        1,
+        Sleeping_function as usize as u32,
+        &mut Task,
+    )
+    .is_err()
+        || Get_priority(Task, &mut Child_priority).is_err()
+        || Child_priority != 0
+        || Wait_child(Task, &mut Kind, &mut Code).is_err()
+    {
+        return 0;
+    }
+
+    Priority as u32
+}
@@ -19,7 +19,7 @@ impl Registrable_trait for File_system_bindings {
     }
 }
 
-const File_system_bindings_functions: [Function_descriptor_type; 8] = Function_descriptors!(
+const File_system_bindings_functions: [Function_descriptor_type; 9] = Function_descriptors!(
     Open_binding,
     Close_file_binding,
     Read_binding,
@@ -27,7 +27,8 @@ const File_system_bindings_functions: [Function_descriptor_type; 8] = Function_d
     Create_file_binding,
     Exists_binding,
     Set_position_binding,
-    Delete_binding
+    Delete_binding,
+    Control_binding
 );
 
 fn New_path(Path: &str) -> Result_type<&Path_type> {
@@ -64,6 +65,8 @@ fn Read(
 ) -> Result_type<()> {
     *Read_size = Get_virtual_file_system().Read(File_identifier, Buffer)?;
 
+    let _ = crate::Task::Deliver_pending_signals(&Environment);
+
     Ok(())
 }
 
@@ -158,3 +161,16 @@ fn Exists(Path: &str, Exists: &mut bool) -> Result_type<()> {
 
     Ok(())
 }
+
+#[Bind_function_native(Prefix = "File_system")]
+fn Control(
+    File_identifier: Unique_file_identifier_type,
+    Command: u32,
+    Argument: &mut [u8],
+) -> Result_type<()> {
+    let Task = Task::Get_instance()
+        .map_err(|_| Error_type::Failed_to_get_task_informations)?
+        .Get_current_task_identifier()?;
+
+    Get_virtual_file_system().Control(File_identifier, Command.into(), Argument, Task)
+}
@@ -0,0 +1,176 @@
+use Binding_tool::Bind_function_native;
+use File_system::{
+    Error_type, Protocol_type, Result_type, Size_type, Socket_address_type, Status_type,
+    Unique_file_identifier_type, Virtual_file_system_type,
+};
+use Task::Task_identifier_type;
+use Virtual_machine::{Function_descriptor_type, Function_descriptors, Registrable_trait};
+
+pub struct Network_bindings;
+
+impl Network_bindings {
+    pub fn New() -> Self {
+        Self {}
+    }
+}
+
+impl Registrable_trait for Network_bindings {
+    fn Get_functions(&self) -> &[Function_descriptor_type] {
+        &Network_bindings_functions
+    }
+}
+
+const Network_bindings_functions: [Function_descriptor_type; 11] = Function_descriptors!(
+    New_socket_binding,
+    Bind_binding,
+    Listen_binding,
+    Accept_binding,
+    Connect_binding,
+    Send_binding,
+    Receive_binding,
+    Send_to_binding,
+    Receive_from_binding,
+    Get_local_address_binding,
+    Get_peer_address_binding
+);
+
+fn Get_virtual_file_system() -> &'static Virtual_file_system_type {
+    File_system::Get_instance().expect("File system not initialized")
+}
+
+fn Get_current_task() -> Result_type<Task_identifier_type> {
+    Ok(Task::Get_instance()
+        .map_err(|_| Error_type::Failed_to_get_task_informations)?
+        .Get_current_task_identifier()?)
+}
+
+/// Check an address coming from the WASM app.
+fn Check_address(Address: &Socket_address_type) -> Result_type<Socket_address_type> {
+    if !Address.Is_valid() {
+        return Err(Error_type::Invalid_input);
+    }
+
+    Ok(*Address)
+}
+
+/// Create a socket (`Protocol` is `0` for TCP and `1` for UDP), it is closed like any other file.
+#[Bind_function_native(Prefix = "Network")]
+fn New_socket(
+    Protocol: u8,
+    Non_blocking: bool,
+    Socket: &mut Unique_file_identifier_type,
+) -> Result_type<()> {
+    let Status = Status_type::default().Set_non_blocking(Non_blocking);
+
+    *Socket = Get_virtual_file_system().New_socket(
+        Protocol_type::try_from(Protocol)?,
+        Status,
+        Get_current_task()?,
+    )?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Bind(Socket: Unique_file_identifier_type, Address: &Socket_address_type) -> Result_type<()> {
+    Get_virtual_file_system().Bind(Socket, Check_address(Address)?, Get_current_task()?)
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Listen(Socket: Unique_file_identifier_type, Backlog: u32) -> Result_type<()> {
+    Get_virtual_file_system().Listen(Socket, Backlog as usize, Get_current_task()?)
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Accept(
+    Socket: Unique_file_identifier_type,
+    New_socket: &mut Unique_file_identifier_type,
+    Address: &mut Socket_address_type,
+) -> Result_type<()> {
+    (*New_socket, *Address) = Get_virtual_file_system().Accept(Socket, Get_current_task()?)?;
+
+    let _ = crate::Task::Deliver_pending_signals(&Environment);
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Connect(Socket: Unique_file_identifier_type, Address: &Socket_address_type) -> Result_type<()> {
+    Get_virtual_file_system().Connect(Socket, Check_address(Address)?, Get_current_task()?)
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Send(
+    Socket: Unique_file_identifier_type,
+    Buffer: &[u8],
+    Sent_size: &mut Size_type,
+) -> Result_type<()> {
+    *Sent_size = Get_virtual_file_system().Send(Socket, Buffer, Get_current_task()?)?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Receive(
+    Socket: Unique_file_identifier_type,
+    Buffer: &mut [u8],
+    Received_size: &mut Size_type,
+) -> Result_type<()> {
+    *Received_size = Get_virtual_file_system().Receive(Socket, Buffer, Get_current_task()?)?;
+
+    let _ = crate::Task::Deliver_pending_signals(&Environment);
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Send_to(
+    Socket: Unique_file_identifier_type,
+    Buffer: &[u8],
+    Address: &Socket_address_type,
+    Sent_size: &mut Size_type,
+) -> Result_type<()> {
+    *Sent_size = Get_virtual_file_system().Send_to(
+        Socket,
+        Buffer,
+        Check_address(Address)?,
+        Get_current_task()?,
+    )?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Receive_from(
+    Socket: Unique_file_identifier_type,
+    Buffer: &mut [u8],
+    Received_size: &mut Size_type,
+    Address: &mut Socket_address_type,
+) -> Result_type<()> {
+    (*Received_size, *Address) =
+        Get_virtual_file_system().Receive_from(Socket, Buffer, Get_current_task()?)?;
+
+    let _ = crate::Task::Deliver_pending_signals(&Environment);
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Get_local_address(
+    Socket: Unique_file_identifier_type,
+    Address: &mut Socket_address_type,
+) -> Result_type<()> {
+    *Address = Get_virtual_file_system().Get_local_address(Socket, Get_current_task()?)?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Network")]
+fn Get_peer_address(
+    Socket: Unique_file_identifier_type,
+    Address: &mut Socket_address_type,
+) -> Result_type<()> {
+    *Address = Get_virtual_file_system().Get_peer_address(Socket, Get_current_task()?)?;
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::mem::size_of;
+
+use Binding_tool::Bind_function_native;
+use File_system::{
+    Control_command_type, Error_type, Result_type, Unique_file_identifier_type,
+    Virtual_file_system_type,
+};
+use Peripherals::{
+    Pin_event_type, Pin_get_event_command, Pin_get_overflow_count_command,
+    Pin_set_interrupt_command, Pin_wait_event_command, Pin_wait_event_type,
+};
+use Virtual_machine::{Function_descriptor_type, Function_descriptors, Registrable_trait};
+
+pub struct Peripherals_bindings;
+
+impl Peripherals_bindings {
+    pub fn New() -> Self {
+        Self {}
+    }
+}
+
+impl Registrable_trait for Peripherals_bindings {
+    fn Get_functions(&self) -> &[Function_descriptor_type] {
+        &Peripherals_bindings_functions
+    }
+}
+
+const Peripherals_bindings_functions: [Function_descriptor_type; 4] = Function_descriptors!(
+    Set_pin_interrupt_binding,
+    Get_pin_event_binding,
+    Wait_pin_event_binding,
+    Get_pin_overflow_count_binding
+);
+
+fn Get_virtual_file_system() -> &'static Virtual_file_system_type {
+    File_system::Get_instance().expect("File system not initialized")
+}
+
+fn Control(
+    File_identifier: Unique_file_identifier_type,
+    Command: Control_command_type,
+    Argument: &mut [u8],
+) -> Result_type<()> {
+    let Task = Task::Get_instance()
+        .map_err(|_| Error_type::Failed_to_get_task_informations)?
+        .Get_current_task_identifier()?;
+
+    Get_virtual_file_system().Control(File_identifier, Command, Argument, Task)
+}
+
+#[Bind_function_native(Prefix = "Peripherals")]
+fn Set_pin_interrupt(
+    File_identifier: Unique_file_identifier_type,
+    Interrupt: u8,
+) -> Result_type<()> {
+    Control(File_identifier, Pin_set_interrupt_command, &mut [Interrupt])
+}
+
+#[Bind_function_native(Prefix = "Peripherals")]
+fn Get_pin_event(
+    File_identifier: Unique_file_identifier_type,
+    Event: &mut Pin_event_type,
+) -> Result_type<()> {
+    let mut Argument = [0_u8; size_of::<Pin_event_type>()];
+
+    Control(File_identifier, Pin_get_event_command, &mut Argument)?;
+
+    *Event = Pin_get_event_command.Get_argument(&Argument)?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Peripherals")]
+fn Wait_pin_event(
+    File_identifier: Unique_file_identifier_type,
+    Timeout: u64,
+    Event: &mut Pin_event_type,
+) -> Result_type<()> {
+    let mut Argument = [0_u8; size_of::<Pin_wait_event_type>()];
+
+    Pin_wait_event_command.Set_argument(
+        &mut Argument,
+        Pin_wait_event_type {
+            Timeout,
+            Event: *Event,
+        },
+    )?;
+
+    Control(File_identifier, Pin_wait_event_command, &mut Argument)?;
+
+    let _ = crate::Task::Deliver_pending_signals(&Environment);
+
+    *Event = Pin_wait_event_command
+        .Get_argument::<Pin_wait_event_type>(&Argument)?
+        .Event;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Peripherals")]
+fn Get_pin_overflow_count(
+    File_identifier: Unique_file_identifier_type,
+    Count: &mut u32,
+) -> Result_type<()> {
+    let mut Argument = [0_u8; size_of::<u32>()];
+
+    Control(
+        File_identifier,
+        Pin_get_overflow_count_command,
+        &mut Argument,
+    )?;
+
+    *Count = Pin_get_overflow_count_command.Get_argument(&Argument)?;
+
+    Ok(())
+}
@@ -1,6 +1,20 @@
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
 use Binding_tool::Bind_function_native;
-use Task::{Error_type, Result_type, Task_identifier_type, Task_type};
-use Virtual_machine::{Function_descriptors, Registrable_trait, Runtime_type};
+use Task::{
+    Affinity_type, Error_type, Exit_status_type, Limits_type, Priority_type, Result_type,
+    Signal_set_type, Signal_type, Task_identifier_type, Task_state_type, Task_type,
+    Timer_identifier_type,
+};
+use Virtual_machine::{
+    Data_type, Environment_type, Function_descriptors, Instance_type, Module_type,
+    Registrable_trait, Runtime_type, WasmValue,
+};
 
 pub struct Task_bindings {}
 
@@ -26,33 +40,187 @@ fn Get_task_manager() -> &'static Task::Manager_type {
     Task::Get_instance().expect("Task manager not initialized")
 }
 
-const Task_bindings_functions: [Virtual_machine::Function_descriptor_type; 5] = Function_descriptors!(
+/// Instantiate a module run by a task, this is how the applications must be loaded.
+///
+/// The linear memory of the instance is capped by the memory limit of the task.
+///
+/// # Errors
+/// Returns `Memory_limit_exceeded` if the module needs more memory than the task is allowed.
+pub fn New_instance(
+    Runtime: &Runtime_type,
+    Module: &Module_type,
+    Stack_size: usize,
+    Task_identifier: Task_identifier_type,
+) -> Virtual_machine::Result_type<Instance_type> {
+    let Limits = Get_task_manager()
+        .Get_limits(Task_identifier)
+        .map_err(|_| Virtual_machine::Error_type::Initialization_failure)?;
+
+    Instance_type::New(
+        Runtime,
+        Module,
+        Stack_size,
+        Data_type::New().Set_memory_limit(Limits.Get_memory()),
+    )
+}
+
+/// Stack size of the execution environments running the timer callbacks.
+const Timer_stack_size: usize = 8 * 1024;
+
+thread_local! {
+    /// Whether the runtime environment of the current thread (i.e. the timer service) is initialized.
+    static Thread_environment_initialized: Cell<bool> = const { Cell::new(false) };
+    /// Whether a signal handler is running on the current thread, the handlers aren't nested.
+    ///
+    /// It stays set on the timer threads, whose callbacks already hold the callback lock of the instance.
+    static Delivering_signals: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Clear `Delivering_signals` when dropped, even if a signal handler panics.
+struct Delivering_guard_type;
+
+impl Drop for Delivering_guard_type {
+    fn drop(&mut self) {
+        Delivering_signals.set(false);
+    }
+}
+
+/// Guest functions handling the signals of the tasks.
+///
+/// The entries of a task are removed when it exits (see `Remove_signal_handlers`).
+static Signal_handlers: Mutex<BTreeMap<(Task_identifier_type, Signal_type), u32>> =
+    Mutex::new(BTreeMap::new());
+
+/// Remove the signal handlers of a task, this must be called when a task running a guest exits.
+pub fn Remove_signal_handlers(Task_identifier: Task_identifier_type) {
+    Signal_handlers
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|(Task, _), _| *Task != Task_identifier);
+}
+
+/// Enforce the CPU time limit of the current task and invoke the handlers of its pending signals.
+///
+/// This is called at the safe points of the task : the blocking calls and `Deliver_signals`.
+/// A task exceeding its CPU time limit is sent a `Terminate` signal.
+pub(crate) fn Deliver_pending_signals(Environment: &Environment_type) -> Result_type<()> {
+    let Task_identifier = Get_task_manager().Get_current_task_identifier()?;
+
+    if let Err(Error_type::CPU_time_limit_exceeded) =
+        Get_task_manager().Check_CPU_time_limit(Task_identifier)
+    {
+        Get_task_manager().Raise_signal(Task_identifier, Signal_type::Terminate)?;
+    }
+
+    if Delivering_signals.replace(true) {
+        return Ok(());
+    }
+
+    let _Guard = Delivering_guard_type;
+
+    while let Some(Signal) = Get_task_manager().Take_signal(Task_identifier)? {
+        let Function = Signal_handlers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(Task_identifier, Signal))
+            .copied();
+
+        if let Some(Function) = Function {
+            // - The handlers are serialized with the other callbacks of the instance (e.g. timers)
+            Environment
+                .Call_callback(Function, &vec![WasmValue::I32(Signal as i32)])
+                .map_err(|_| Error_type::Signal_handler_failed)?;
+        }
+    }
+
+    Ok(())
+}
+
+const Task_bindings_functions: [Virtual_machine::Function_descriptor_type; 30] = Function_descriptors!(
     New_task_binding,
     Get_environment_variable_binding,
     Set_environment_variable_binding,
     Remove_environment_variable_binding,
-    Sleep_binding
+    Sleep_binding,
+    Get_working_directory_binding,
+    Set_working_directory_binding,
+    Get_limits_binding,
+    Set_limits_binding,
+    New_timer_binding,
+    Start_timer_binding,
+    Stop_timer_binding,
+    Is_timer_active_binding,
+    Delete_timer_binding,
+    Wait_child_binding,
+    Wait_any_child_binding,
+    Set_exit_code_binding,
+    Request_termination_binding,
+    Is_termination_requested_binding,
+    Send_signal_binding,
+    Set_signal_handler_binding,
+    Get_signal_mask_binding,
+    Set_signal_mask_binding,
+    Deliver_signals_binding,
+    Get_task_identifiers_binding,
+    Get_task_name_binding,
+    Get_task_information_binding,
+    Get_task_children_binding,
+    Get_priority_binding,
+    Set_priority_binding
 );
 
 #[Bind_function_native(Prefix = "Task")]
 fn Sleep(Duration: u64) {
     Task_type::Sleep(std::time::Duration::from_millis(Duration));
+
+    let _ = Deliver_pending_signals(&Environment);
 }
 
+/// Create a task running the guest function `Function`.
+///
+/// `Priority` is a `Priority_type` (`u8::MAX` to inherit the priority of the parent) and `Affinity` a mask of the cores
+/// on which the task can run (`0` to inherit the affinity of the parent).
 #[Bind_function_native(Prefix = "Task")]
-fn New_task(Name: &str, Stack_size: u32, Function: u32) -> Result_type<()> {
+fn New_task(
+    Name: &str,
+    Stack_size: u32,
+    Priority: u8,
+    Affinity: u32,
+    Function: u32,
+    Task: &mut Task_identifier_type,
+) -> Result_type<()> {
+    Get_task_manager().Check_CPU_time_limit(Get_task_manager().Get_current_task_identifier()?)?;
+
+    let Priority = match Priority {
+        u8::MAX => None,
+        Priority => {
+            Some(Priority_type::try_from(Priority).map_err(|_| Error_type::Invalid_priority)?)
+        }
+    };
+
     let New_environment = Environment
         .Create_environment(Stack_size as usize)
-        .map_err(|_| Error_type::Failed_to_spawn_thread)
-        .unwrap();
+        .map_err(|_| Error_type::Failed_to_spawn_thread)?;
 
-    Get_task_manager().New_task(None, None, Name, Some(Stack_size as usize), move || {
-        Runtime_type::Initialize_thread_environment().unwrap();
+    (*Task, _) = Get_task_manager().New_task(
+        None,
+        None,
+        Name,
+        Some(Stack_size as usize),
+        Priority,
+        Affinity_type::New(Affinity),
+        move || {
+            Runtime_type::Initialize_thread_environment().unwrap();
 
-        let _ = New_environment.Call_indirect_function(Function, &vec![]);
+            let _ = New_environment.Call_indirect_function(Function, &vec![]);
 
-        Runtime_type::Deinitialize_thread_environment();
-    })?;
+            Runtime_type::Deinitialize_thread_environment();
+
+            if let Ok(Task_identifier) = Get_task_manager().Get_current_task_identifier() {
+                Remove_signal_handlers(Task_identifier);
+            }
+        },
+    )?;
 
     Ok(())
 }
@@ -92,3 +260,352 @@ fn Remove_environment_variable(
 
     Ok(())
 }
+
+/// Get the working directory of a task.
+///
+/// If `Value` is too small, `Length` is set to the required length and `Buffer_too_small` is returned.
+#[Bind_function_native(Prefix = "Task")]
+fn Get_working_directory(
+    Task_identifier: Task_identifier_type,
+    Value: &mut [u8],
+    Length: &mut u32,
+) -> Result_type<()> {
+    let Working_directory = Get_task_manager().Get_working_directory(Task_identifier)?;
+
+    let Working_directory = Working_directory.as_bytes();
+
+    *Length = Working_directory.len() as u32;
+
+    if Working_directory.len() > Value.len() {
+        return Err(Error_type::Buffer_too_small);
+    }
+
+    Value[..Working_directory.len()].copy_from_slice(Working_directory);
+
+    Ok(())
+}
+
+/// Change the working directory of a task owned by the owner of the current task (or any task for root).
+///
+/// The errors of the file system other than `Permission_denied` are reported as `Invalid_working_directory`.
+#[Bind_function_native(Prefix = "Task")]
+fn Set_working_directory(Task_identifier: Task_identifier_type, Path: &str) -> Result_type<()> {
+    let Current_task_owner =
+        Get_task_manager().Get_owner(Get_task_manager().Get_current_task_identifier()?)?;
+
+    if !Users::Manager_type::Is_root(Current_task_owner)
+        && Get_task_manager().Get_owner(Task_identifier)? != Current_task_owner
+    {
+        return Err(Error_type::Permission_denied);
+    }
+
+    let Path = File_system::Path_type::New(Path).ok_or(Error_type::Invalid_working_directory)?;
+
+    File_system::Get_instance()
+        .and_then(|File_system| File_system.Set_working_directory(Path, Task_identifier))
+        .map_err(|Error| match Error {
+            File_system::Error_type::Permission_denied => Error_type::Permission_denied,
+            _ => Error_type::Invalid_working_directory,
+        })
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Get_limits(Task_identifier: Task_identifier_type, Limits: &mut Limits_type) -> Result_type<()> {
+    *Limits = Get_task_manager().Get_limits(Task_identifier)?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Set_limits(Task_identifier: Task_identifier_type, Limits: &Limits_type) -> Result_type<()> {
+    Get_task_manager().Set_limits(Task_identifier, *Limits)?;
+
+    Ok(())
+}
+
+/// Create a stopped timer owned by the current task, which calls the guest function `Function` when it expires.
+///
+/// The callback is serialized with the other callbacks of the instance.
+#[Bind_function_native(Prefix = "Task")]
+fn New_timer(Function: u32, Timer: &mut Timer_identifier_type) -> Result_type<()> {
+    let Task_identifier = Get_task_manager().Get_current_task_identifier()?;
+
+    let Timer_environment = Environment
+        .Create_environment(Timer_stack_size)
+        .map_err(|_| Error_type::Failed_to_create_thread)?;
+
+    *Timer = Get_task_manager().New_timer(Task_identifier, move || {
+        if !Thread_environment_initialized.replace(true) {
+            Runtime_type::Initialize_thread_environment().unwrap();
+
+            Delivering_signals.set(true);
+        }
+
+        let _ = Timer_environment.Call_callback(Function, &vec![]);
+    })?;
+
+    Ok(())
+}
+
+/// Start or reschedule a timer, `Delay` and `Period` are in milliseconds (a `Period` of `0` makes a one-shot timer).
+#[Bind_function_native(Prefix = "Task")]
+fn Start_timer(Timer: Timer_identifier_type, Delay: u64, Period: u64) -> Result_type<()> {
+    let Period = (Period != 0).then(|| Duration::from_millis(Period));
+
+    Get_task_manager().Start_timer(
+        Get_task_manager().Get_current_task_identifier()?,
+        Timer,
+        Duration::from_millis(Delay),
+        Period,
+    )
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Stop_timer(Timer: Timer_identifier_type) -> Result_type<()> {
+    Get_task_manager().Stop_timer(Get_task_manager().Get_current_task_identifier()?, Timer)
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Is_timer_active(Timer: Timer_identifier_type, Active: &mut bool) -> Result_type<()> {
+    *Active = Get_task_manager()
+        .Is_timer_active(Get_task_manager().Get_current_task_identifier()?, Timer)?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Delete_timer(Timer: Timer_identifier_type) -> Result_type<()> {
+    Get_task_manager().Delete_timer(Get_task_manager().Get_current_task_identifier()?, Timer)
+}
+
+/// Split an exit status into a kind (`0` : exited, `1` : terminated, `2` : panicked) and an exit code.
+fn Split_exit_status(Exit_status: Exit_status_type) -> (u8, i32) {
+    match Exit_status {
+        Exit_status_type::Exited(Code) => (0, Code),
+        Exit_status_type::Terminated => (1, 0),
+        Exit_status_type::Panicked => (2, 0),
+    }
+}
+
+/// Wait for a child of the current task to exit, see `Split_exit_status` for the meaning of `Kind` and `Code`.
+///
+/// The wait is interrupted by the signals handled by the task, which are delivered before returning.
+#[Bind_function_native(Prefix = "Task")]
+fn Wait_child(Child: Task_identifier_type, Kind: &mut u8, Code: &mut i32) -> Result_type<()> {
+    let Result = Get_task_manager().Wait_child(
+        Get_task_manager().Get_current_task_identifier()?,
+        Some(Child),
+    );
+
+    Deliver_pending_signals(&Environment)?;
+
+    let (_, Exit_status) = Result?;
+
+    (*Kind, *Code) = Split_exit_status(Exit_status);
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Wait_any_child(
+    Child: &mut Task_identifier_type,
+    Kind: &mut u8,
+    Code: &mut i32,
+) -> Result_type<()> {
+    let Result =
+        Get_task_manager().Wait_child(Get_task_manager().Get_current_task_identifier()?, None);
+
+    Deliver_pending_signals(&Environment)?;
+
+    let Exit_status;
+
+    (*Child, Exit_status) = Result?;
+
+    (*Kind, *Code) = Split_exit_status(Exit_status);
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Set_exit_code(Code: i32) -> Result_type<()> {
+    Get_task_manager().Set_exit_code(Get_task_manager().Get_current_task_identifier()?, Code)
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Request_termination(Task_identifier: Task_identifier_type) -> Result_type<()> {
+    Get_task_manager().Request_termination(Task_identifier)
+}
+
+/// Check whether the current task was asked to terminate, it should then return from its function.
+#[Bind_function_native(Prefix = "Task")]
+fn Is_termination_requested(Requested: &mut bool) -> Result_type<()> {
+    *Requested = Get_task_manager()
+        .Is_termination_requested(Get_task_manager().Get_current_task_identifier()?)?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Send_signal(Task_identifier: Task_identifier_type, Signal: u8) -> Result_type<()> {
+    let Signal = Signal_type::try_from(Signal).map_err(|_| Error_type::Invalid_signal)?;
+
+    Get_task_manager().Send_signal(Task_identifier, Signal)
+}
+
+/// Register the guest function `Function` as the handler of a signal for the current task (`0` restores the default action).
+///
+/// The handler receives the signal number and runs on the thread of the task, at its next safe point.
+#[Bind_function_native(Prefix = "Task")]
+fn Set_signal_handler(Signal: u8, Function: u32) -> Result_type<()> {
+    let Signal = Signal_type::try_from(Signal).map_err(|_| Error_type::Invalid_signal)?;
+
+    let Task_identifier = Get_task_manager().Get_current_task_identifier()?;
+
+    let mut Signal_handlers = Signal_handlers
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if Function == 0 {
+        Signal_handlers.remove(&(Task_identifier, Signal));
+    } else {
+        Signal_handlers.insert((Task_identifier, Signal), Function);
+    }
+
+    Get_task_manager().Set_signal_handled(Task_identifier, Signal, Function != 0)
+}
+
+/// Get the signals blocked by the current task, as a bit mask indexed by the signal numbers.
+#[Bind_function_native(Prefix = "Task")]
+fn Get_signal_mask(Mask: &mut u32) -> Result_type<()> {
+    *Mask = Get_task_manager()
+        .Get_signal_mask(Get_task_manager().Get_current_task_identifier()?)?
+        .Get_raw();
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Task")]
+fn Set_signal_mask(Mask: u32) -> Result_type<()> {
+    Get_task_manager().Set_signal_mask(
+        Get_task_manager().Get_current_task_identifier()?,
+        Signal_set_type::New(Mask),
+    )?;
+
+    Deliver_pending_signals(&Environment)
+}
+
+/// Safe point : invoke the handlers of the pending signals of the current task.
+#[Bind_function_native(Prefix = "Task")]
+fn Deliver_signals() -> Result_type<()> {
+    Deliver_pending_signals(&Environment)
+}
+
+/// Copy as many task identifiers as possible into `Buffer` (as little-endian `u32`), `Count` is set to the total number of identifiers.
+fn Copy_task_identifiers(Identifiers: &[Task_identifier_type], Buffer: &mut [u8], Count: &mut u32) {
+    for (Identifier, Chunk) in Identifiers.iter().zip(Buffer.chunks_exact_mut(4)) {
+        Chunk.copy_from_slice(&u32::from(*Identifier).to_le_bytes());
+    }
+
+    *Count = Identifiers.len() as u32;
+}
+
+/// Get the identifiers of all the tasks, see `Copy_task_identifiers`.
+#[Bind_function_native(Prefix = "Task")]
+fn Get_task_identifiers(Identifiers: &mut [u8], Count: &mut u32) -> Result_type<()> {
+    Copy_task_identifiers(
+        &Get_task_manager().Get_task_identifiers()?,
+        Identifiers,
+        Count,
+    );
+
+    Ok(())
+}
+
+/// Get the name of a task.
+///
+/// If `Name` is too small, `Length` is set to the required length and `Buffer_too_small` is returned.
+#[Bind_function_native(Prefix = "Task")]
+fn Get_task_name(
+    Task_identifier: Task_identifier_type,
+    Name: &mut [u8],
+    Length: &mut u32,
+) -> Result_type<()> {
+    let Task_name = Get_task_manager().Get_task_name(Task_identifier)?;
+
+    let Task_name = Task_name.as_bytes();
+
+    *Length = Task_name.len() as u32;
+
+    if Task_name.len() > Name.len() {
+        return Err(Error_type::Buffer_too_small);
+    }
+
+    Name[..Task_name.len()].copy_from_slice(Task_name);
+
+    Ok(())
+}
+
+/// Get information about a task.
+///
+/// `State` is `0` : running, `1` : sleeping, `2` : blocked, `3` : exited.
+/// The times are in milliseconds and the stack size in bytes, `u64::MAX` when unknown.
+#[Bind_function_native(Prefix = "Task")]
+fn Get_task_information(
+    Task_identifier: Task_identifier_type,
+    Parent: &mut Task_identifier_type,
+    Owner: &mut Users::User_identifier_type,
+    State: &mut u8,
+    Start_time: &mut u64,
+    CPU_time: &mut u64,
+    Stack_size: &mut u64,
+) -> Result_type<()> {
+    let Snapshot = Get_task_manager().Get_task_snapshot(Task_identifier)?;
+
+    *Parent = Snapshot.Get_parent();
+    *Owner = Snapshot.Get_owner();
+    *State = match Snapshot.Get_state() {
+        Task_state_type::Running => 0,
+        Task_state_type::Sleeping => 1,
+        Task_state_type::Blocked => 2,
+        Task_state_type::Exited => 3,
+    };
+    *Start_time = Snapshot.Get_start_time().as_millis() as u64;
+    *CPU_time = Snapshot
+        .Get_CPU_time()
+        .map_or(u64::MAX, |CPU_time| CPU_time.as_millis() as u64);
+    *Stack_size = Snapshot
+        .Get_stack_size()
+        .map_or(u64::MAX, |Stack_size| Stack_size as u64);
+
+    Ok(())
+}
+
+/// Get the identifiers of the children of a task, see `Copy_task_identifiers`.
+#[Bind_function_native(Prefix = "Task")]
+fn Get_task_children(
+    Task_identifier: Task_identifier_type,
+    Identifiers: &mut [u8],
+    Count: &mut u32,
+) -> Result_type<()> {
+    let Snapshot = Get_task_manager().Get_task_snapshot(Task_identifier)?;
+
+    Copy_task_identifiers(Snapshot.Get_children(), Identifiers, Count);
+
+    Ok(())
+}
+
+/// Get the priority of a task, from `0` (lowest) to `4` (highest).
+#[Bind_function_native(Prefix = "Task")]
+fn Get_priority(Task_identifier: Task_identifier_type, Priority: &mut u8) -> Result_type<()> {
+    *Priority = Get_task_manager().Get_priority(Task_identifier)? as u8;
+
+    Ok(())
+}
+
+/// Change the priority of a task, see `Get_priority`. Only the root user can raise priorities.
+#[Bind_function_native(Prefix = "Task")]
+fn Set_priority(Task_identifier: Task_identifier_type, Priority: u8) -> Result_type<()> {
+    let Priority = Priority_type::try_from(Priority).map_err(|_| Error_type::Invalid_priority)?;
+
+    Get_task_manager().Set_priority(Task_identifier, Priority)
+}
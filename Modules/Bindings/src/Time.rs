@@ -0,0 +1,104 @@
+use Binding_tool::Bind_function_native;
+use Time::{Date_time_type, Error_type, Result_type, Time_type, Time_zone_type};
+use Virtual_machine::{Function_descriptor_type, Function_descriptors, Registrable_trait};
+
+pub struct Time_bindings;
+
+impl Time_bindings {
+    pub fn New() -> Self {
+        Self {}
+    }
+}
+
+impl Registrable_trait for Time_bindings {
+    fn Get_functions(&self) -> &[Function_descriptor_type] {
+        &Time_bindings_functions
+    }
+}
+
+const Time_bindings_functions: [Function_descriptor_type; 7] = Function_descriptors!(
+    Get_monotonic_time_binding,
+    Get_current_time_binding,
+    Set_current_time_binding,
+    Get_time_zone_binding,
+    Set_time_zone_binding,
+    Get_local_date_time_binding,
+    Get_time_from_date_time_binding
+);
+
+fn Get_time_manager() -> &'static Time::Manager_type {
+    Time::Get_instance().expect("Time manager not initialized")
+}
+
+/// Only the root user can change the time or the time zone of the system.
+fn Check_root_user() -> Result_type<()> {
+    let Task_manager = Task::Get_instance().map_err(|_| Error_type::Not_initialized)?;
+
+    let Owner = Task_manager
+        .Get_current_task_identifier()
+        .and_then(|Task| Task_manager.Get_owner(Task))
+        .map_err(|_| Error_type::Permission_denied)?;
+
+    if Owner != Users::Root_user_identifier {
+        return Err(Error_type::Permission_denied);
+    }
+
+    Ok(())
+}
+
+/// Get the time elapsed since the boot, in microseconds.
+#[Bind_function_native(Prefix = "Time")]
+fn Get_monotonic_time(Time: &mut u64) -> Result_type<()> {
+    *Time = Get_time_manager().Get_monotonic_time().as_micros() as u64;
+
+    Ok(())
+}
+
+/// Get the wall clock time, in microseconds since the Unix epoch.
+#[Bind_function_native(Prefix = "Time")]
+fn Get_current_time(Time: &mut u64) -> Result_type<()> {
+    *Time = Get_time_manager().Get_current_time()?.Get_microseconds();
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Time")]
+fn Set_current_time(Time: u64) -> Result_type<()> {
+    Check_root_user()?;
+
+    Get_time_manager().Set_current_time(Time_type::New(Time))
+}
+
+#[Bind_function_native(Prefix = "Time")]
+fn Get_time_zone(Time_zone: &mut Time_zone_type) -> Result_type<()> {
+    *Time_zone = Get_time_manager().Get_time_zone()?;
+
+    Ok(())
+}
+
+#[Bind_function_native(Prefix = "Time")]
+fn Set_time_zone(Time_zone: &Time_zone_type) -> Result_type<()> {
+    Check_root_user()?;
+
+    Get_time_manager().Set_time_zone(*Time_zone)
+}
+
+/// Get the local date time of a time (in microseconds since the Unix epoch).
+#[Bind_function_native(Prefix = "Time")]
+fn Get_local_date_time(Time: u64, Date_time: &mut Date_time_type) -> Result_type<()> {
+    *Date_time = Get_time_manager().Get_local_date_time(Time_type::New(Time))?;
+
+    Ok(())
+}
+
+/// Get the time (in microseconds since the Unix epoch) of a date time.
+#[Bind_function_native(Prefix = "Time")]
+fn Get_time_from_date_time(Date_time: &Date_time_type, Time: &mut u64) -> Result_type<()> {
+    if !Date_time.Is_valid() {
+        return Err(Error_type::Invalid_date_time);
+    }
+
+    *Time = Date_time.Get_time()?.Get_microseconds();
+
+    Ok(())
+}
@@ -8,5 +8,14 @@ pub use File_system::*;
 mod Task;
 pub use Task::*;
 
+mod Peripherals;
+pub use Peripherals::*;
+
+mod Time;
+pub use Time::*;
+
+mod Network;
+pub use Network::*;
+
 //mod Graphics;
 //pub use Graphics::*;
@@ -0,0 +1,1042 @@
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_standard_devices() {
+    use Drivers::Standard::{
+        Clock_device_path, Console_device_path, Null_device_path, Random_device_path,
+        Zero_device_path,
+    };
+    use File_system::{
+        Clock_get_date_time_command, Clock_get_monotonic_time_command, Clock_get_time_zone_command,
+        Clock_set_time_zone_command, Device_class_type, File_type, Mode_type,
+    };
+    use Time::{Date_time_type, Time_zone_type};
+
+    let Task_instance = Task::Initialize().expect("Failed to initialize task manager");
+
+    Users::Initialize().expect("Failed to initialize users manager");
+
+    Time::Initialize(Some(Box::new(Drivers::Native::Host_clock_type)))
+        .expect("Failed to initialize time manager");
+
+    let Virtual_file_system = File_system::Initialize().expect("Failed to initialize file system");
+
+    Drivers::Standard::Mount_devices(Virtual_file_system).expect("Failed to mount devices");
+
+    let Task = Task_instance
+        .Get_current_task_identifier()
+        .expect("Failed to get task identifier");
+
+    for Path in [
+        Null_device_path,
+        Zero_device_path,
+        Random_device_path,
+        Console_device_path,
+        Clock_device_path,
+    ] {
+        assert_eq!(
+            Virtual_file_system.Get_device_class(Path),
+            Ok(Device_class_type::Character)
+        );
+    }
+
+    // - Null
+    let Null = File_type::Open(
+        Virtual_file_system,
+        Null_device_path,
+        Mode_type::Read_write().into(),
+        Task,
+    )
+    .expect("Failed to open null device");
+
+    let mut Buffer = [0xFF_u8; 16];
+
+    assert_eq!(Null.Write(&Buffer), Ok(16_usize.into()));
+    assert_eq!(Null.Read(&mut Buffer), Ok(0_usize.into()));
+
+    // - Zero
+    let Zero = File_type::Open(
+        Virtual_file_system,
+        Zero_device_path,
+        Mode_type::Read_only().into(),
+        Task,
+    )
+    .expect("Failed to open zero device");
+
+    assert_eq!(Zero.Read(&mut Buffer), Ok(16_usize.into()));
+    assert_eq!(Buffer, [0; 16]);
+
+    // - Random
+    let Random = File_type::Open(
+        Virtual_file_system,
+        Random_device_path,
+        Mode_type::Read_write().into(),
+        Task,
+    )
+    .expect("Failed to open random device");
+
+    let mut Buffer_2 = [0_u8; 16];
+
+    assert_eq!(Random.Read(&mut Buffer), Ok(16_usize.into()));
+    assert_eq!(Random.Read(&mut Buffer_2), Ok(16_usize.into()));
+    assert_ne!(Buffer, Buffer_2);
+
+    assert_eq!(Random.Write(b"Entropy"), Ok(7_usize.into()));
+    assert_eq!(Random.Read(&mut Buffer), Ok(16_usize.into()));
+    assert_ne!(Buffer, Buffer_2);
+
+    // - Console
+    let Console = File_type::Open(
+        Virtual_file_system,
+        Console_device_path,
+        Mode_type::Read_write().into(),
+        Task,
+    )
+    .expect("Failed to open console device");
+
+    assert_eq!(Console.Write_line(b"Hello, world!"), Ok(14_usize.into()));
+    assert_eq!(Console.Read(&mut Buffer), Ok(0_usize.into()));
+
+    // - Clock
+    let Clock = File_type::Open(
+        Virtual_file_system,
+        Clock_device_path,
+        Mode_type::Read_write().into(),
+        Task,
+    )
+    .expect("Failed to open clock device");
+
+    // - 2024-07-01T12:00:00Z
+    let Time = 1_719_835_200_000_000_u64;
+
+    assert_eq!(Clock.Write(&Time.to_ne_bytes()), Ok(8_usize.into()));
+
+    let mut Time_buffer = [0_u8; 8];
+
+    assert_eq!(Clock.Read(&mut Time_buffer), Ok(8_usize.into()));
+    assert!(u64::from_ne_bytes(Time_buffer) - Time < 60_000_000);
+
+    let Time_zone = Time_zone_type::From_POSIX_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+
+    let mut Argument = [0_u8; size_of::<Time_zone_type>()];
+
+    Clock_set_time_zone_command
+        .Set_argument(&mut Argument, Time_zone)
+        .unwrap();
+    Clock
+        .Control(Clock_set_time_zone_command, &mut Argument)
+        .expect("Failed to set time zone");
+
+    let mut Argument = [0_u8; size_of::<Time_zone_type>()];
+
+    Clock
+        .Control(Clock_get_time_zone_command, &mut Argument)
+        .expect("Failed to get time zone");
+
+    assert_eq!(Time_zone_type::try_from(Argument.as_slice()), Ok(Time_zone));
+
+    let mut Argument = [0_u8; size_of::<Date_time_type>()];
+
+    Clock
+        .Control(Clock_get_date_time_command, &mut Argument)
+        .expect("Failed to get date time");
+
+    let Date_time = Clock_get_date_time_command
+        .Get_argument::<Date_time_type>(&Argument)
+        .unwrap();
+
+    assert_eq!(
+        (
+            Date_time.Get_year(),
+            Date_time.Get_month(),
+            Date_time.Get_day()
+        ),
+        (2024, 7, 1)
+    );
+    assert_eq!(Date_time.Get_hour(), 14);
+    assert_eq!(Date_time.Get_offset(), 7200);
+
+    let mut Argument = [0_u8; size_of::<u64>()];
+
+    Clock
+        .Control(Clock_get_monotonic_time_command, &mut Argument)
+        .expect("Failed to get monotonic time");
+
+    assert!(u64::from_ne_bytes(Argument) > 0);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_serial_device() {
+    use std::io::{Read, Write};
+
+    use Drivers::Native::Serial_device_type;
+    use File_system::{Device_trait, Error_type};
+    use Peripherals::{
+        Flow_control_type, Parity_type, Serial_configuration_type,
+        Serial_get_configuration_command, Serial_set_configuration_command, Stop_bits_type,
+    };
+
+    let Device = Serial_device_type::New().expect("Failed to create serial device");
+
+    let mut Other_end = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(Device.Get_slave_path())
+        .expect("Failed to open the other end of the serial device");
+
+    // - Device to other end
+    assert_eq!(Device.Write(b"Hello"), Ok(5));
+    Device.Flush().unwrap();
+
+    let mut Buffer = [0_u8; 5];
+    Other_end.read_exact(&mut Buffer).unwrap();
+    assert_eq!(&Buffer, b"Hello");
+
+    // - Other end to device
+    Other_end.write_all(b"World").unwrap();
+    Other_end.flush().unwrap();
+
+    let mut Buffer = [0_u8; 5];
+    let mut Size = 0;
+
+    for _ in 0..100 {
+        Size += Device.Read(&mut Buffer[Size..]).unwrap();
+
+        if Size == Buffer.len() {
+            break;
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert_eq!(&Buffer, b"World");
+
+    // - Configuration
+    let mut Argument = [0_u8; size_of::<Serial_configuration_type>()];
+
+    Serial_get_configuration_command
+        .Set_argument(&mut Argument, Serial_configuration_type::default())
+        .unwrap();
+    Device
+        .Control(Serial_get_configuration_command, &mut Argument)
+        .unwrap();
+    assert_eq!(
+        Serial_configuration_type::try_from(Argument.as_slice()),
+        Ok(Serial_configuration_type::default())
+    );
+
+    let Configuration = Serial_configuration_type::default()
+        .Set_baud_rate(9600)
+        .Set_data_bits(7)
+        .Set_parity(Parity_type::Even)
+        .Set_stop_bits(Stop_bits_type::Two)
+        .Set_flow_control(Flow_control_type::Software);
+
+    Serial_set_configuration_command
+        .Set_argument(&mut Argument, Configuration)
+        .unwrap();
+    Device
+        .Control(Serial_set_configuration_command, &mut Argument)
+        .unwrap();
+
+    Device
+        .Control(Serial_get_configuration_command, &mut Argument)
+        .unwrap();
+    assert_eq!(
+        Serial_configuration_type::try_from(Argument.as_slice()),
+        Ok(Configuration)
+    );
+
+    // - Unsupported baud rate
+    Serial_set_configuration_command
+        .Set_argument(&mut Argument, Configuration.Set_baud_rate(12345))
+        .unwrap();
+    assert_eq!(
+        Device.Control(Serial_set_configuration_command, &mut Argument),
+        Err(Error_type::Invalid_input)
+    );
+
+    // - Invalid data bits
+    Serial_set_configuration_command
+        .Set_argument(&mut Argument, Configuration.Set_data_bits(9))
+        .unwrap();
+    assert_eq!(
+        Device.Control(Serial_set_configuration_command, &mut Argument),
+        Err(Error_type::Invalid_input)
+    );
+
+    Device
+        .Control(Serial_get_configuration_command, &mut Argument)
+        .unwrap();
+    assert_eq!(
+        Serial_configuration_type::try_from(Argument.as_slice()),
+        Ok(Configuration)
+    );
+}
+
+#[test]
+fn Test_record_replay() {
+    use std::{
+        sync::atomic::{AtomicU8, Ordering},
+        time::Duration,
+    };
+
+    use Drivers::Native::{Record_mode_type, Recording_device_type, Replay_device_type};
+    use File_system::{Device_trait, Error_type, Position_type, Result_type};
+
+    /// Device returning a state changed by the test (0 means no event in events mode).
+    struct Test_device_type(&'static AtomicU8);
+
+    impl Device_trait for Test_device_type {
+        fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+            match self.0.load(Ordering::Relaxed) {
+                0 => Ok(0),
+                Value => {
+                    Buffer.fill(Value);
+                    Ok(Buffer.len())
+                }
+            }
+        }
+
+        fn Write(&self, _: &[u8]) -> Result_type<usize> {
+            Err(Error_type::Unsupported_operation)
+        }
+
+        fn Get_size(&self) -> Result_type<usize> {
+            Ok(2)
+        }
+
+        fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+            Err(Error_type::Unsupported_operation)
+        }
+
+        fn Flush(&self) -> Result_type<()> {
+            Ok(())
+        }
+    }
+
+    static State: AtomicU8 = AtomicU8::new(0);
+
+    let Path = std::env::temp_dir().join("Xila_record_replay.bin");
+
+    // - Record
+    let Recording = Recording_device_type::Create(
+        Box::new(Test_device_type(&State)),
+        Record_mode_type::State,
+        &Path,
+    )
+    .unwrap();
+
+    let mut Buffer = [0_u8; 2];
+
+    for Value in [1, 1, 1, 2, 2, 3] {
+        State.store(Value, Ordering::Relaxed);
+        assert_eq!(Recording.Read(&mut Buffer), Ok(2));
+        assert_eq!(Buffer, [Value; 2]);
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    Recording.Flush().unwrap();
+    drop(Recording);
+
+    // - Replay (state)
+    let Replay = Replay_device_type::New_manual(std::fs::File::open(&Path).unwrap()).unwrap();
+
+    assert_eq!(Replay.Get_mode(), Record_mode_type::State);
+    assert_eq!(Replay.Read(&mut [0_u8; 3]), Err(Error_type::Invalid_input));
+
+    let mut Replayed = Vec::new();
+
+    while !Replay.Is_finished().unwrap() {
+        Replay.Advance(Duration::from_millis(1)).unwrap();
+
+        if Replay.Read(&mut Buffer) == Ok(2) && Replayed.last() != Some(&Buffer[0]) {
+            Replayed.push(Buffer[0]);
+        }
+    }
+
+    // - Only the changes are recorded, and the last state is kept
+    assert_eq!(Replayed, [1, 2, 3]);
+    assert_eq!(Replay.Read(&mut Buffer), Ok(2));
+    assert_eq!(Buffer, [3; 2]);
+
+    // - Replay in real time, faster
+    let Replay = Replay_device_type::Open(&Path, 100.0).unwrap();
+
+    assert!(Replay.Advance(Duration::from_millis(1)).is_err());
+
+    Replay.Read(&mut Buffer).unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+    assert_eq!(Replay.Read(&mut Buffer), Ok(2));
+    assert_eq!(Buffer, [3; 2]);
+    assert!(Replay.Is_finished().unwrap());
+
+    // - Events
+    let Recording = Recording_device_type::Create(
+        Box::new(Test_device_type(&State)),
+        Record_mode_type::Events,
+        &Path,
+    )
+    .unwrap();
+
+    for Value in [4, 4, 0, 5] {
+        State.store(Value, Ordering::Relaxed);
+        Recording.Read(&mut Buffer).unwrap();
+    }
+
+    Recording.Flush().unwrap();
+    drop(Recording);
+
+    let Replay = Replay_device_type::New_manual(std::fs::File::open(&Path).unwrap()).unwrap();
+
+    Replay.Advance(Duration::from_secs(1)).unwrap();
+
+    let mut Replayed = Vec::new();
+
+    while Replay.Read(&mut Buffer) == Ok(2) {
+        Replayed.push(Buffer[0]);
+    }
+
+    assert_eq!(Replayed, [4, 4, 5]);
+
+    let _ = std::fs::remove_file(Path);
+}
+
+#[test]
+fn Test_simulated_GPIO() {
+    use Drivers::Native::Simulated_GPIO_type;
+    use File_system::{Device_trait, Error_type};
+    use Peripherals::{
+        Direction_type, Level_type, Pin_get_direction_command, Pin_get_pull_command,
+        Pin_set_direction_command, Pin_set_pull_command, Pull_type,
+    };
+
+    let Controller = Simulated_GPIO_type::New(4);
+
+    assert!(Controller.New_pin_device(4).is_err());
+
+    let Pin_0 = Controller.New_pin_device(0).unwrap();
+    let Pin_1 = Controller.New_pin_device(1).unwrap();
+    let Pin_2 = Controller.New_pin_device(2).unwrap();
+
+    let mut Level = [0xFF_u8];
+
+    // - Input driven by the test
+    assert_eq!(Controller.Get_direction(0), Ok(Direction_type::Input));
+    Pin_0.Read(&mut Level).unwrap();
+    assert_eq!(Level, [Level_type::Low as u8]);
+
+    Controller.Drive(0, Some(Level_type::High)).unwrap();
+    Pin_0.Read(&mut Level).unwrap();
+    assert_eq!(Level, [Level_type::High as u8]);
+
+    Controller.Drive(0, None).unwrap();
+
+    // - Pull
+    Pin_0
+        .Control(Pin_set_pull_command, &mut [Pull_type::Up as u8])
+        .unwrap();
+    assert_eq!(Controller.Get_pull(0), Ok(Pull_type::Up));
+    let mut Pull = [0xFF_u8];
+    Pin_0.Control(Pin_get_pull_command, &mut Pull).unwrap();
+    assert_eq!(Pull, [Pull_type::Up as u8]);
+    Pin_0.Read(&mut Level).unwrap();
+    assert_eq!(Level, [Level_type::High as u8]);
+
+    assert_eq!(
+        Pin_0.Control(Pin_set_pull_command, &mut [4]),
+        Err(Error_type::Invalid_input)
+    );
+
+    // - Output observed by the test (the level is only driven once the pin is an output)
+    Pin_1.Write(&[Level_type::High as u8]).unwrap();
+    assert_eq!(Controller.Get_level(1), Ok(Level_type::Low));
+
+    Pin_1
+        .Control(
+            Pin_set_direction_command,
+            &mut [Direction_type::Output as u8],
+        )
+        .unwrap();
+    assert_eq!(Controller.Get_direction(1), Ok(Direction_type::Output));
+    let mut Direction = [0xFF_u8];
+    Pin_1
+        .Control(Pin_get_direction_command, &mut Direction)
+        .unwrap();
+    assert_eq!(Direction, [Direction_type::Output as u8]);
+    assert_eq!(Controller.Get_level(1), Ok(Level_type::High));
+
+    assert_eq!(Pin_1.Write(&[2]), Err(Error_type::Invalid_input));
+
+    // - Wiring : output 1 drives input 2 (through 3)
+    Controller.Connect(1, 3).unwrap();
+    Controller.Connect(3, 2).unwrap();
+
+    Pin_2.Read(&mut Level).unwrap();
+    assert_eq!(Level, [Level_type::High as u8]);
+
+    Pin_1.Write(&[Level_type::Low as u8]).unwrap();
+    Pin_2.Read(&mut Level).unwrap();
+    assert_eq!(Level, [Level_type::Low as u8]);
+
+    // - Wiring : the pull up of pin 0 applies to the pins wired to it
+    Controller.Disconnect(3, 1).unwrap();
+    Controller.Connect(0, 2).unwrap();
+
+    Pin_2.Read(&mut Level).unwrap();
+    assert_eq!(Level, [Level_type::High as u8]);
+
+    assert_eq!(Controller.Disconnect(1, 2), Err(Error_type::Not_found));
+}
+
+#[test]
+fn Test_simulated_GPIO_interrupts() {
+    use std::time::Duration;
+
+    use Drivers::Native::Simulated_GPIO_type;
+    use File_system::{Device_trait, Error_type};
+    use Peripherals::{
+        Interrupt_type, Level_type, Pin_event_type, Pin_events_queue_size, Pin_get_event_command,
+        Pin_get_overflow_count_command, Pin_set_interrupt_command, Pin_wait_event_command,
+        Pin_wait_event_type,
+    };
+
+    let Controller = Simulated_GPIO_type::New(2);
+
+    let Pin = Controller.New_pin_device(0).unwrap();
+
+    let Get_event = |Pin: &dyn Device_trait| {
+        let mut Argument = [0_u8; size_of::<Pin_event_type>()];
+
+        Pin.Control(Pin_get_event_command, &mut Argument).map(|_| {
+            Pin_get_event_command
+                .Get_argument::<Pin_event_type>(&Argument)
+                .unwrap()
+        })
+    };
+
+    // - No interrupt configured
+    Controller.Drive(0, Some(Level_type::High)).unwrap();
+    assert_eq!(Get_event(&Pin), Err(Error_type::Ressource_busy));
+
+    // - Rising edge
+    Pin.Control(
+        Pin_set_interrupt_command,
+        &mut [Interrupt_type::Rising_edge as u8],
+    )
+    .unwrap();
+
+    Controller.Drive(0, Some(Level_type::Low)).unwrap();
+    assert_eq!(Get_event(&Pin), Err(Error_type::Ressource_busy));
+
+    Controller.Drive(0, Some(Level_type::High)).unwrap();
+    let First = Get_event(&Pin).unwrap();
+    assert_eq!(First.Get_level(), Some(Level_type::High));
+
+    // - Both edges, through a wire
+    Pin.Control(
+        Pin_set_interrupt_command,
+        &mut [Interrupt_type::Both_edges as u8],
+    )
+    .unwrap();
+
+    Controller.Drive(0, None).unwrap();
+    Controller.Drive(1, Some(Level_type::High)).unwrap();
+    Controller.Connect(0, 1).unwrap();
+
+    let Events: Vec<_> = std::iter::from_fn(|| Get_event(&Pin).ok()).collect();
+    assert_eq!(
+        Events
+            .iter()
+            .map(|Event| Event.Get_level())
+            .collect::<Vec<_>>(),
+        [Some(Level_type::Low), Some(Level_type::High)]
+    );
+    assert!(Events[0].Get_timestamp() >= First.Get_timestamp());
+
+    // - Level interrupt triggered right away
+    Pin.Control(
+        Pin_set_interrupt_command,
+        &mut [Interrupt_type::High_level as u8],
+    )
+    .unwrap();
+    assert_eq!(Get_event(&Pin).unwrap().Get_level(), Some(Level_type::High));
+
+    // - Overflow
+    Pin.Control(
+        Pin_set_interrupt_command,
+        &mut [Interrupt_type::Both_edges as u8],
+    )
+    .unwrap();
+
+    for _ in 0..Pin_events_queue_size + 2 {
+        Controller.Disconnect(0, 1).unwrap();
+        Controller.Connect(0, 1).unwrap();
+    }
+
+    let mut Argument = [0_u8; 4];
+    Pin.Control(Pin_get_overflow_count_command, &mut Argument)
+        .unwrap();
+    assert_eq!(
+        u32::from_ne_bytes(Argument),
+        Pin_events_queue_size as u32 + 4
+    );
+
+    Pin.Control(
+        Pin_set_interrupt_command,
+        &mut [Interrupt_type::Falling_edge as u8],
+    )
+    .unwrap();
+
+    // - Wait with timeout
+    let mut Argument = [0_u8; size_of::<Pin_wait_event_type>()];
+
+    let Wait = Pin_wait_event_type {
+        Timeout: 10_000,
+        ..Default::default()
+    };
+
+    Pin_wait_event_command
+        .Set_argument(&mut Argument, Wait)
+        .unwrap();
+    assert_eq!(
+        Pin.Control(Pin_wait_event_command, &mut Argument),
+        Err(Error_type::Ressource_busy)
+    );
+
+    // - Wait for an event from another thread
+    let Driver = Controller.clone();
+
+    let Thread = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        Driver.Drive(1, Some(Level_type::Low)).unwrap();
+    });
+
+    Pin_wait_event_command
+        .Set_argument(
+            &mut Argument,
+            Pin_wait_event_type {
+                Timeout: u64::MAX,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    Pin.Control(Pin_wait_event_command, &mut Argument).unwrap();
+
+    let Wait = Pin_wait_event_command
+        .Get_argument::<Pin_wait_event_type>(&Argument)
+        .unwrap();
+    assert_eq!(Wait.Event.Get_level(), Some(Level_type::Low));
+
+    Thread.join().unwrap();
+}
+
+#[test]
+fn Test_simulated_I2C_bus() {
+    use Drivers::Native::{
+        EEPROM_model_type, I2C_expectation_type, Scripted_I2C_peripheral_type,
+        Simulated_I2C_bus_type, Temperature_sensor_model_type,
+    };
+    use File_system::{Device_trait, Error_type};
+    use Peripherals::{
+        I2C_address_type, I2C_fast_mode_frequency, I2C_get_frequency_command,
+        I2C_set_address_command, I2C_set_frequency_command, I2C_write_read_command,
+        I2C_write_read_type,
+    };
+
+    let Bus = Simulated_I2C_bus_type::New();
+
+    let Sensor = Temperature_sensor_model_type::New(21.5);
+
+    let Script = Scripted_I2C_peripheral_type::New()
+        .Expect_write(&[0xA0, 0x01])
+        .Expect_read(&[0x42]);
+
+    let EEPROM_address = I2C_address_type::New_seven_bits(0x50).unwrap();
+    let Sensor_address = I2C_address_type::New_seven_bits(0x48).unwrap();
+    let Script_address = I2C_address_type::New_ten_bits(0x2A5).unwrap();
+
+    Bus.Attach(EEPROM_address, EEPROM_model_type::New(128))
+        .unwrap();
+    Bus.Attach(Sensor_address, Sensor.clone()).unwrap();
+    Bus.Attach(Script_address, Script.clone()).unwrap();
+
+    assert_eq!(
+        Bus.Attach(Sensor_address, EEPROM_model_type::New(16)),
+        Err(Error_type::Already_exists)
+    );
+
+    let Select = |Address: I2C_address_type| {
+        let mut Argument = [0_u8; size_of::<I2C_address_type>()];
+
+        I2C_set_address_command
+            .Set_argument(&mut Argument, Address)
+            .unwrap();
+
+        Bus.Control(I2C_set_address_command, &mut Argument)
+    };
+
+    let Write_read = |Write: &[u8], Read_size: usize| {
+        let mut Argument = [0_u8; size_of::<I2C_write_read_type>()];
+
+        I2C_write_read_command
+            .Set_argument(
+                &mut Argument,
+                I2C_write_read_type::New(Write, Read_size).unwrap(),
+            )
+            .unwrap();
+
+        Bus.Control(I2C_write_read_command, &mut Argument)?;
+
+        Ok::<_, Error_type>(
+            I2C_write_read_command
+                .Get_argument::<I2C_write_read_type>(&Argument)?
+                .Get_read_data()
+                .unwrap()
+                .to_vec(),
+        )
+    };
+
+    // - Frequency
+    Bus.Control(
+        I2C_set_frequency_command,
+        &mut I2C_fast_mode_frequency.to_ne_bytes(),
+    )
+    .unwrap();
+
+    let mut Frequency = [0_u8; 4];
+    Bus.Control(I2C_get_frequency_command, &mut Frequency)
+        .unwrap();
+    assert_eq!(u32::from_ne_bytes(Frequency), I2C_fast_mode_frequency);
+
+    assert_eq!(
+        Bus.Control(I2C_set_frequency_command, &mut 0_u32.to_ne_bytes()),
+        Err(Error_type::Invalid_input)
+    );
+
+    // - EEPROM : write at an address, then read back sequentially
+    Select(EEPROM_address).unwrap();
+
+    assert_eq!(Bus.Write(&[0x10, 1, 2, 3]), Ok(4));
+    assert_eq!(Write_read(&[0x10], 3), Ok(vec![1, 2, 3]));
+
+    let mut Buffer = [0_u8; 2];
+    assert_eq!(Bus.Read(&mut Buffer), Ok(2));
+    assert_eq!(Buffer, [0xFF, 0xFF]);
+
+    // - Temperature sensor
+    Select(Sensor_address).unwrap();
+
+    assert_eq!(Write_read(&[0], 2), Ok(vec![21, 0x80]));
+
+    Sensor.Set_temperature(-0.5);
+    assert_eq!(Write_read(&[0], 2), Ok(vec![0xFF, 0x80]));
+
+    assert_eq!(Bus.Write(&[7]), Err(Error_type::No_acknowledge));
+
+    // - Scripted peripheral with a 10 bits address
+    Select(Script_address).unwrap();
+
+    assert_eq!(Bus.Write(&[0xA0, 0x02]), Err(Error_type::No_acknowledge));
+    assert_eq!(
+        Script.Get_remaining(),
+        Ok(vec![
+            I2C_expectation_type::Write(vec![0xA0, 0x01]),
+            I2C_expectation_type::Read(vec![0x42])
+        ])
+    );
+
+    assert_eq!(Write_read(&[0xA0, 0x01], 1), Ok(vec![0x42]));
+    assert_eq!(Script.Get_remaining(), Ok(vec![]));
+
+    // - No peripheral at this address
+    Select(I2C_address_type::New_seven_bits(0x51).unwrap()).unwrap();
+    assert_eq!(Bus.Write(&[0]), Err(Error_type::No_acknowledge));
+
+    Bus.Detach(Sensor_address).unwrap();
+    Select(Sensor_address).unwrap();
+    assert_eq!(Write_read(&[0], 2), Err(Error_type::No_acknowledge));
+}
+
+#[test]
+fn Test_simulated_SPI_bus() {
+    use Drivers::Native::{
+        Loopback_SPI_peripheral_type, Register_SPI_peripheral_type, Simulated_SPI_bus_type,
+    };
+    use File_system::{Device_trait, Error_type};
+    use Peripherals::{
+        SPI_begin_batch_command, SPI_end_batch_command, SPI_get_mode_command, SPI_mode_type,
+        SPI_set_mode_command, SPI_transfer_command, SPI_transfer_type,
+    };
+
+    let Bus = Simulated_SPI_bus_type::New();
+
+    let Sensor = Register_SPI_peripheral_type::New(SPI_mode_type::Mode_3);
+    Sensor.Set_register(0x0F, 0x33).unwrap();
+
+    Bus.Attach(0, Loopback_SPI_peripheral_type).unwrap();
+    Bus.Attach(1, Sensor.clone()).unwrap();
+
+    let Loopback = Bus.New_device(0);
+    let Sensor_device = Bus.New_device(1);
+    let Unconnected = Bus.New_device(2);
+
+    let Transfer = |Device: &dyn Device_trait, Data: &[u8]| {
+        let mut Argument = [0_u8; size_of::<SPI_transfer_type>()];
+
+        SPI_transfer_command
+            .Set_argument(&mut Argument, SPI_transfer_type::New(Data).unwrap())
+            .unwrap();
+
+        Device.Control(SPI_transfer_command, &mut Argument)?;
+
+        Ok::<_, Error_type>(
+            SPI_transfer_command
+                .Get_argument::<SPI_transfer_type>(&Argument)?
+                .Get_data()
+                .unwrap()
+                .to_vec(),
+        )
+    };
+
+    // - Loopback
+    assert_eq!(Transfer(&Loopback, &[1, 2, 3]), Ok(vec![1, 2, 3]));
+
+    // - Nothing on the chip select
+    let mut Buffer = [0_u8; 2];
+    assert_eq!(Unconnected.Read(&mut Buffer), Ok(2));
+    assert_eq!(Buffer, [0xFF, 0xFF]);
+
+    // - Wrong mode
+    assert_eq!(Transfer(&Sensor_device, &[0x8F, 0]), Ok(vec![0xFF, 0xFF]));
+
+    Sensor_device
+        .Control(SPI_set_mode_command, &mut [SPI_mode_type::Mode_3 as u8])
+        .unwrap();
+
+    let mut Mode = [0_u8];
+    Sensor_device
+        .Control(SPI_get_mode_command, &mut Mode)
+        .unwrap();
+    assert_eq!(Mode, [SPI_mode_type::Mode_3 as u8]);
+
+    assert_eq!(
+        Sensor_device.Control(SPI_set_mode_command, &mut [4]),
+        Err(Error_type::Invalid_input)
+    );
+
+    // - Full-duplex register read
+    assert_eq!(Transfer(&Sensor_device, &[0x8F, 0]), Ok(vec![0, 0x33]));
+
+    // - Batch : the chip select stays asserted between the transfers
+    Sensor_device
+        .Control(SPI_begin_batch_command, &mut [])
+        .unwrap();
+
+    assert_eq!(Sensor_device.Write(&[0x10]), Ok(1));
+    assert_eq!(Sensor_device.Write(&[0xAA, 0xBB]), Ok(2));
+
+    // - The bus is held by the batch
+    assert_eq!(Transfer(&Loopback, &[1]), Err(Error_type::Ressource_busy));
+    assert_eq!(
+        Loopback.Control(SPI_begin_batch_command, &mut []),
+        Err(Error_type::Ressource_busy)
+    );
+
+    Sensor_device
+        .Control(SPI_end_batch_command, &mut [])
+        .unwrap();
+
+    assert_eq!(Sensor.Get_register(0x10), Ok(0xAA));
+    assert_eq!(Sensor.Get_register(0x11), Ok(0xBB));
+
+    // - Without batch, each operation selects the peripheral again
+    assert_eq!(Sensor_device.Write(&[0x90]), Ok(1));
+    assert_eq!(Sensor_device.Read(&mut Buffer), Ok(2));
+    assert_eq!(Buffer, [0, 0]);
+
+    assert_eq!(Transfer(&Loopback, &[1]), Ok(vec![1]));
+}
+
+#[test]
+fn Test_simulated_PWM() {
+    use std::time::Duration;
+
+    use Drivers::Native::Simulated_PWM_type;
+    use File_system::{Device_trait, Error_type};
+    use Peripherals::{
+        PWM_configuration_type, PWM_fade_command, PWM_fade_type, PWM_set_configuration_command,
+        PWM_set_pin_command,
+    };
+
+    let Channel = Simulated_PWM_type::New();
+
+    let Device: &dyn Device_trait = &Channel;
+
+    // - Configuration
+    let Configuration = PWM_configuration_type::New(50, 8);
+
+    let mut Argument = [0_u8; size_of::<PWM_configuration_type>()];
+    PWM_set_configuration_command
+        .Set_argument(&mut Argument, Configuration)
+        .unwrap();
+    Device
+        .Control(PWM_set_configuration_command, &mut Argument)
+        .unwrap();
+    assert_eq!(Channel.Get_configuration(), Ok(Configuration));
+
+    assert_eq!(
+        Device.Write(&128_u32.to_ne_bytes()),
+        Err(Error_type::Unsupported_operation)
+    );
+
+    Device.Control(PWM_set_pin_command, &mut [12]).unwrap();
+    assert_eq!(Channel.Get_pin(), Ok(Some(12)));
+
+    // - Duty
+    assert_eq!(Device.Write(&128_u32.to_ne_bytes()), Ok(4));
+    assert_eq!(
+        Device.Write(&257_u32.to_ne_bytes()),
+        Err(Error_type::Invalid_input)
+    );
+
+    let mut Duty = [0_u8; 4];
+    assert_eq!(Device.Read(&mut Duty), Ok(4));
+    assert_eq!(u32::from_ne_bytes(Duty), 128);
+
+    // - Fade
+    let mut Argument = [0_u8; size_of::<PWM_fade_type>()];
+    PWM_fade_command
+        .Set_argument(
+            &mut Argument,
+            PWM_fade_type {
+                Duty: 256,
+                Duration: 10_000,
+            },
+        )
+        .unwrap();
+    Device.Control(PWM_fade_command, &mut Argument).unwrap();
+
+    let Timeline = Channel.Get_timeline().unwrap();
+    let Fade_start = Timeline[Timeline.len() - 2];
+    let Fade_end = Timeline[Timeline.len() - 1];
+
+    assert_eq!(Fade_start.Duty, 128);
+    assert_eq!(Fade_end.Duty, 256);
+    assert_eq!(Fade_end.Time - Fade_start.Time, Duration::from_secs(10));
+    assert_eq!(
+        Channel.Get_duty_at(Fade_start.Time + Duration::from_secs(5)),
+        Ok(192)
+    );
+
+    // - Writing the duty stops the fade
+    Device.Write(&0_u32.to_ne_bytes()).unwrap();
+
+    let Timeline = Channel.Get_timeline().unwrap();
+    let Stop = Timeline[Timeline.len() - 1];
+
+    assert_eq!(Stop.Duty, 0);
+    assert!(Stop.Time < Fade_end.Time);
+    assert!(Timeline.iter().all(|Point| Point.Time <= Stop.Time));
+    assert_eq!(Channel.Get_duty_at(Fade_end.Time), Ok(0));
+}
+
+#[test]
+fn Test_simulated_ADC() {
+    use std::time::Duration;
+
+    use Drivers::Native::{Simulated_ADC_type, Waveform_type};
+    use File_system::{Device_trait, Error_type};
+    use Peripherals::{
+        ADC_attenuation_type, ADC_convert_command, ADC_get_millivolts_command,
+        ADC_get_resolution_command, ADC_set_attenuation_command, ADC_start_sampling_command,
+        ADC_stop_sampling_command,
+    };
+
+    let Channel = Simulated_ADC_type::New(Waveform_type::Constant(1000.0));
+
+    let Device: &dyn Device_trait = &Channel;
+
+    let Get_millivolts = || {
+        let mut Argument = [0_u8; 2];
+
+        Device
+            .Control(ADC_get_millivolts_command, &mut Argument)
+            .unwrap();
+
+        u16::from_ne_bytes(Argument)
+    };
+
+    let mut Resolution = [0_u8];
+    Device
+        .Control(ADC_get_resolution_command, &mut Resolution)
+        .unwrap();
+    assert_eq!(Resolution, [12]);
+
+    // - Raw and calibrated readings
+    let mut Raw = [0_u8; 2];
+    assert_eq!(Device.Read(&mut Raw), Ok(2));
+    assert_eq!(u16::from_ne_bytes(Raw), 1671);
+
+    assert!(Get_millivolts().abs_diff(1000) <= 1);
+
+    let mut Argument = 4095_u16.to_ne_bytes();
+    Device.Control(ADC_convert_command, &mut Argument).unwrap();
+    assert_eq!(
+        u16::from_ne_bytes(Argument),
+        ADC_attenuation_type::Attenuation_11_dB.Get_full_scale_voltage()
+    );
+
+    // - Attenuation : the voltage is beyond the full scale
+    Device
+        .Control(
+            ADC_set_attenuation_command,
+            &mut [ADC_attenuation_type::Attenuation_0_dB as u8],
+        )
+        .unwrap();
+    assert_eq!(Get_millivolts(), 950);
+
+    assert_eq!(
+        Device.Control(ADC_set_attenuation_command, &mut [4]),
+        Err(Error_type::Invalid_input)
+    );
+
+    Device
+        .Control(
+            ADC_set_attenuation_command,
+            &mut [ADC_attenuation_type::Attenuation_11_dB as u8],
+        )
+        .unwrap();
+
+    // - Continuous sampling of a rising voltage (1 mV per millisecond)
+    Channel
+        .Set_source(|Time: Duration| Time.as_secs_f32() * 1000.0)
+        .unwrap();
+
+    Device
+        .Control(ADC_start_sampling_command, &mut 1000_u32.to_ne_bytes())
+        .unwrap();
+
+    std::thread::sleep(Duration::from_millis(50));
+
+    let mut Buffer = [0_u8; 256];
+    let Size = Device.Read(&mut Buffer).unwrap();
+
+    let Samples: Vec<u16> = Buffer[..Size]
+        .chunks_exact(2)
+        .map(|Sample| u16::from_ne_bytes([Sample[0], Sample[1]]))
+        .collect();
+
+    assert!(Samples.len() >= 40, "{} samples", Samples.len());
+    assert!(Samples.windows(2).all(|Pair| Pair[0] < Pair[1]));
+
+    // - The samples are consumed
+    assert!(Device.Read(&mut Buffer).unwrap() < Size);
+
+    Device.Control(ADC_stop_sampling_command, &mut []).unwrap();
+    assert_eq!(Device.Read(&mut Buffer), Ok(2));
+}
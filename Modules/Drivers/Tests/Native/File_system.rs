@@ -12,19 +12,24 @@ fn Test_file_system() {
     use std::sync::RwLock;
 
     use File_system::{
-        Device_trait, File_type, Mode_type, Path_type, Position_type, Result_type, Status_type,
+        Control_command_type, Control_direction_type, Device_class_type, Device_event_type,
+        Device_trait, Error_type, File_type, Mode_type, Path_type, Permissions_type, Position_type,
+        Quota_subject_type, Quota_type, Result_type, Status_type,
     };
 
     let Task_instance = Task::Initialize().expect("Failed to initialize task manager");
 
     Users::Initialize().expect("Failed to initialize users manager");
 
+    let Time_manager = Time::Initialize(Some(Box::new(Drivers::Native::Host_clock_type)))
+        .expect("Failed to initialize time manager");
+
     let File_system =
         Drivers::Native::File_system_type::New().expect("Failed to create file system");
 
     let Virtual_file_system = File_system::Initialize().expect("Failed to initialize file system");
 
-    Virtual_file_system
+    let File_system_identifier = Virtual_file_system
         .Mount(Box::new(File_system), Path_type::Get_root())
         .expect("Failed to mount file system");
 
@@ -57,8 +62,32 @@ fn Test_file_system() {
 
     let Data = b"Hello, world!";
 
+    // - The modification time comes from the time of the system
+    let Time = Time::Time_type::From_seconds(1_000_000_000);
+
+    Time_manager
+        .Set_current_time(Time)
+        .expect("Failed to set time");
+
     File.Write(Data).expect("Failed to write data");
 
+    let Modification_time = Virtual_file_system
+        .Get_modification_time(File_path)
+        .expect("Failed to get modification time");
+
+    assert!(Modification_time
+        .Duration_since(Time)
+        .is_some_and(|Duration| Duration.as_secs() < 60));
+
+    Virtual_file_system
+        .Set_modification_time(File_path, Time)
+        .expect("Failed to set modification time");
+
+    assert_eq!(
+        Virtual_file_system.Get_modification_time(File_path),
+        Ok(Time)
+    );
+
     File.Set_position(&Position_type::Start(0))
         .expect("Failed to set position");
 
@@ -70,10 +99,239 @@ fn Test_file_system() {
 
     std::mem::drop(File);
 
+    // - Working directory
+    let Directory_path = Path_type::New("/test_directory").expect("Failed to create path");
+
+    if !Virtual_file_system
+        .Exists(Directory_path)
+        .expect("Failed to check if directory exists")
+    {
+        Virtual_file_system
+            .Create_directory(Directory_path, false, Task)
+            .expect("Failed to create directory");
+    }
+
+    Virtual_file_system
+        .Set_working_directory(Directory_path, Task)
+        .expect("Failed to set working directory");
+
+    assert_eq!(
+        Virtual_file_system
+            .Get_working_directory(Task)
+            .expect("Failed to get working directory")
+            .As_str(),
+        "/test_directory"
+    );
+
+    assert!(Virtual_file_system
+        .Exists(Path_type::New("../test.txt").unwrap())
+        .expect("Failed to check if file exists"));
+
+    assert!(Virtual_file_system
+        .Set_working_directory(Path_type::New("../test.txt").unwrap(), Task)
+        .is_err());
+
+    Virtual_file_system
+        .Set_working_directory(Path_type::New("..").unwrap(), Task)
+        .expect("Failed to set working directory");
+
+    let File = File_type::Open(
+        Virtual_file_system,
+        Path_type::New("./test.txt").unwrap(),
+        Mode_type::Read_only().into(),
+        Task,
+    )
+    .expect("Failed to open file with relative path");
+
+    std::mem::drop(File);
+
     Virtual_file_system
         .Delete(File_path, false, Task)
         .expect("Failed to delete file");
 
+    // - Owner of the created files
+    let Owner_path = Path_type::New("/owner.txt").expect("Failed to create path");
+
+    if Virtual_file_system
+        .Exists(Owner_path)
+        .expect("Failed to check if file exists")
+    {
+        Virtual_file_system
+            .Delete(Owner_path, false, Task)
+            .expect("Failed to delete file");
+    }
+
+    Users::Get_instance()
+        .expect("Failed to get users manager")
+        .Create_group("Root", Some(Users::Root_group_identifier))
+        .expect("Failed to create group");
+
+    let (_, Join_handle) = Task_instance
+        .New_task(
+            None,
+            Some(1),
+            "Other user task",
+            None,
+            None,
+            None,
+            move || {
+                let Task = Task::Get_instance()
+                    .unwrap()
+                    .Get_current_task_identifier()
+                    .unwrap();
+
+                File_system::Get_instance()
+                    .unwrap()
+                    .Create_file(Owner_path, Task)
+                    .expect("Failed to create file");
+            },
+        )
+        .expect("Failed to create task");
+
+    Join_handle.Join().expect("Failed to join task");
+
+    assert_eq!(
+        Virtual_file_system.Get_owner(Owner_path),
+        Ok((1, Users::Root_group_identifier))
+    );
+
+    Virtual_file_system
+        .Set_owner(Owner_path, None, Some(2))
+        .expect("Failed to set owner");
+
+    assert_eq!(Virtual_file_system.Get_owner(Owner_path), Ok((1, 2)));
+
+    // - Only the owner of the file or the root user can change its owner
+    let (_, Join_handle) = Task_instance
+        .New_task(
+            None,
+            Some(2),
+            "Third user task",
+            None,
+            None,
+            None,
+            move || {
+                File_system::Get_instance()
+                    .unwrap()
+                    .Set_owner(Owner_path, Some(2), None)
+            },
+        )
+        .expect("Failed to create task");
+
+    assert_eq!(
+        Join_handle.Join().expect("Failed to join task"),
+        Err(Error_type::Permission_denied)
+    );
+    assert_eq!(Virtual_file_system.Get_owner(Owner_path), Ok((1, 2)));
+
+    Virtual_file_system
+        .Delete(Owner_path, false, Task)
+        .expect("Failed to delete file");
+
+    // - Quotas
+    let Subject = Quota_subject_type::User(Users::Root_user_identifier);
+
+    let Usage = Virtual_file_system
+        .Get_quota(File_system_identifier, Subject)
+        .expect("Failed to get quota")
+        .Get_usage();
+
+    Virtual_file_system
+        .Set_quota(
+            File_system_identifier,
+            Subject,
+            Quota_type::New_unlimited()
+                .Set_blocks(None, Some(Usage.Get_blocks() + 1))
+                .Set_files(Some(Usage.Get_files()), Some(Usage.Get_files() + 1)),
+            Task,
+        )
+        .expect("Failed to set quota");
+
+    let Quota_path = Path_type::New("/quota.txt").expect("Failed to create path");
+    let Quota_path_2 = Path_type::New("/quota_2.txt").expect("Failed to create path");
+
+    for Path in [Quota_path, Quota_path_2] {
+        if Virtual_file_system
+            .Exists(Path)
+            .expect("Failed to check if file exists")
+        {
+            Virtual_file_system
+                .Delete(Path, false, Task)
+                .expect("Failed to delete file");
+        }
+    }
+
+    Virtual_file_system
+        .Create_file(Quota_path, Task)
+        .expect("Failed to create file");
+
+    assert_eq!(
+        Virtual_file_system.Create_file(Quota_path_2, Task),
+        Err(Error_type::File_system_full)
+    );
+    assert!(!Virtual_file_system
+        .Exists(Quota_path_2)
+        .expect("Failed to check if file exists"));
+
+    let File = File_type::Open(
+        Virtual_file_system,
+        Quota_path,
+        Mode_type::Write_only().into(),
+        Task,
+    )
+    .expect("Failed to open file");
+
+    File.Write(&[0; 512]).expect("Failed to write data");
+
+    assert_eq!(File.Write(&[0; 1]), Err(Error_type::File_system_full));
+
+    std::mem::drop(File);
+
+    let Report = Virtual_file_system
+        .Get_quota(File_system_identifier, Subject)
+        .expect("Failed to get quota");
+
+    assert!(Report.Is_soft_limit_exceeded());
+    assert!(!Report.Is_hard_limit_exceeded());
+
+    // - The usage is rebuilt when the file system is mounted again
+    let File_system = Virtual_file_system
+        .Unmount(File_system_identifier)
+        .expect("Failed to unmount file system");
+
+    let File_system_identifier = Virtual_file_system
+        .Mount(File_system, Path_type::Get_root())
+        .expect("Failed to mount file system");
+
+    assert_eq!(
+        Virtual_file_system
+            .Get_quota(File_system_identifier, Subject)
+            .expect("Failed to get quota")
+            .Get_usage(),
+        Report.Get_usage()
+    );
+
+    Virtual_file_system
+        .Delete(Quota_path, false, Task)
+        .expect("Failed to delete file");
+
+    assert_eq!(
+        Virtual_file_system
+            .Get_quota(File_system_identifier, Subject)
+            .expect("Failed to get quota")
+            .Get_usage(),
+        Usage
+    );
+
+    Virtual_file_system
+        .Set_quota(
+            File_system_identifier,
+            Subject,
+            Quota_type::New_unlimited(),
+            Task,
+        )
+        .expect("Failed to set quota");
+
     let (Pipe_read, Pipe_write) = File_type::Create_unnamed_pipe(
         Virtual_file_system,
         512_usize.into(),
@@ -138,6 +396,9 @@ fn Test_file_system() {
 
     struct Dummy_device_type(RwLock<u64>);
 
+    const Dummy_get_value_command: Control_command_type =
+        Control_command_type::New::<u64>(Control_direction_type::Read, 0xFF, 0);
+
     impl Device_trait for Dummy_device_type {
         fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
             Buffer.copy_from_slice(&self.0.read()?.to_le_bytes());
@@ -162,14 +423,45 @@ fn Test_file_system() {
         fn Flush(&self) -> Result_type<()> {
             Ok(())
         }
+
+        fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+            match Command {
+                Dummy_get_value_command => Command.Set_argument(Argument, *self.0.read()?),
+                _ => Err(Error_type::Unsupported_operation),
+            }
+        }
     }
 
     let Device = Dummy_device_type(RwLock::new(0));
 
+    let Device_events = Virtual_file_system
+        .Subscribe_device_events()
+        .expect("Failed to subscribe to device events");
+
     Virtual_file_system
-        .Add_device(&Device_path, Box::new(Device))
+        .Add_device(
+            Device_path,
+            Device_class_type::Character,
+            Box::new(Device),
+            Users::Root_user_identifier,
+            Users::Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
         .expect("Failed to add device");
 
+    assert_eq!(
+        Device_events.try_recv(),
+        Ok(Device_event_type::Added(
+            Device_path.to_owned(),
+            Device_class_type::Character
+        ))
+    );
+
+    assert_eq!(
+        Virtual_file_system.Get_device_class(Device_path),
+        Ok(Device_class_type::Character)
+    );
+
     let Device_file = File_type::Open(
         Virtual_file_system,
         Device_path,
@@ -194,9 +486,118 @@ fn Test_file_system() {
 
     assert_eq!(Buffer, Data.to_le_bytes());
 
+    let mut Argument = [0; 8];
+
+    Device_file
+        .Control(Dummy_get_value_command, &mut Argument)
+        .expect("Failed to control device");
+
+    assert_eq!(
+        Dummy_get_value_command.Get_argument::<u64>(&Argument),
+        Ok(Data)
+    );
+
+    assert_eq!(
+        Device_file.Control(
+            Control_command_type::New::<u64>(Control_direction_type::Read, 0xFF, 1),
+            &mut Argument
+        ),
+        Err(Error_type::Unsupported_operation)
+    );
+
+    // - Replace the device while it is opened
+    Virtual_file_system
+        .Replace_device(
+            Device_path,
+            Device_class_type::Block,
+            Box::new(Dummy_device_type(RwLock::new(42))),
+        )
+        .expect("Failed to replace device");
+
+    assert_eq!(
+        Device_events.try_recv(),
+        Ok(Device_event_type::Replaced(
+            Device_path.to_owned(),
+            Device_class_type::Block
+        ))
+    );
+
+    assert_eq!(
+        Device_file.Read(&mut Buffer),
+        Err(Error_type::Device_removed)
+    );
+
     std::mem::drop(Device_file);
 
+    let Device_file = File_type::Open(
+        Virtual_file_system,
+        Device_path,
+        Mode_type::Read_write().into(),
+        Task,
+    )
+    .expect("Failed to open device");
+
+    Device_file.Read(&mut Buffer).expect("Failed to read data");
+
+    assert_eq!(Buffer, 42_u64.to_le_bytes());
+
+    // - Only the root user and the owner of a device can manage it
+    let (_, Join_handle) = Task_instance
+        .New_task(
+            None,
+            Some(1),
+            "Other user device task",
+            None,
+            None,
+            None,
+            move || {
+                let Virtual_file_system = File_system::Get_instance().unwrap();
+
+                assert_eq!(
+                    Virtual_file_system.Replace_device(
+                        Device_path,
+                        Device_class_type::Block,
+                        Box::new(Dummy_device_type(RwLock::new(0))),
+                    ),
+                    Err(Error_type::Permission_denied)
+                );
+                assert_eq!(
+                    Virtual_file_system.Remove_device(Device_path),
+                    Err(Error_type::Permission_denied)
+                );
+                assert_eq!(
+                    Virtual_file_system.Add_device(
+                        Path_type::New("/Other_device").unwrap(),
+                        Device_class_type::Character,
+                        Box::new(Dummy_device_type(RwLock::new(0))),
+                        Users::Root_user_identifier,
+                        Users::Root_group_identifier,
+                        Permissions_type::New_standard_file(),
+                    ),
+                    Err(Error_type::Permission_denied)
+                );
+            },
+        )
+        .expect("Failed to create task");
+
+    Join_handle.Join().expect("Failed to join task");
+
+    // - Remove the device while it is opened
     Virtual_file_system
-        .Delete(Device_path, false, Task)
-        .expect("Failed to delete device");
+        .Remove_device(Device_path)
+        .expect("Failed to remove device");
+
+    assert_eq!(
+        Device_events.try_recv(),
+        Ok(Device_event_type::Removed(Device_path.to_owned()))
+    );
+
+    assert_eq!(
+        Device_file.Read(&mut Buffer),
+        Err(Error_type::Device_removed)
+    );
+
+    assert!(!Virtual_file_system
+        .Exists(Device_path)
+        .expect("Failed to check if device exists"));
 }
@@ -6,7 +6,8 @@ use std::{
     thread::sleep,
     time::{Duration, Instant},
 };
-use File_system::{File_type, Mode_type, Path_type};
+use File_system::{Device_class_type, File_type, Mode_type, Path_type, Permissions_type};
+use Users::{Root_group_identifier, Root_user_identifier};
 
 const Pointer_device_path: &Path_type =
     unsafe { Path_type::New_unchecked_constant("/Device/Pointer") };
@@ -14,6 +15,9 @@ const Pointer_device_path: &Path_type =
 const Screen_device_path: &Path_type =
     unsafe { Path_type::New_unchecked_constant("/Device/Screen") };
 
+const Keyboard_device_path: &Path_type =
+    unsafe { Path_type::New_unchecked_constant("/Device/Keyboard") };
+
 #[cfg(target_os = "linux")]
 #[test]
 #[ignore]
@@ -31,6 +35,8 @@ fn main() {
     let (S, Pointer) =
         New_touchscreen::<Buffer_size>(Resolution).expect("Error creating touchscreen");
 
+    let Keyboard = Pointer.New_keyboard_device();
+
     Users::Initialize().expect("Error initializing users manager");
 
     let Task_instance = Task::Initialize().expect("Error initializing task manager");
@@ -38,11 +44,36 @@ fn main() {
     let Virtual_file_system = File_system::Initialize().expect("Error initializing file system");
 
     Virtual_file_system
-        .Add_device(&Pointer_device_path, Box::new(Pointer))
+        .Add_device(
+            Pointer_device_path,
+            Device_class_type::Input,
+            Box::new(Pointer),
+            Root_user_identifier,
+            Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
         .expect("Error adding pointer device");
 
     Virtual_file_system
-        .Add_device(&Screen_device_path, Box::new(S))
+        .Add_device(
+            Keyboard_device_path,
+            Device_class_type::Input,
+            Box::new(Keyboard),
+            Root_user_identifier,
+            Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
+        .expect("Error adding keyboard device");
+
+    Virtual_file_system
+        .Add_device(
+            Screen_device_path,
+            Device_class_type::Display,
+            Box::new(S),
+            Root_user_identifier,
+            Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
         .expect("Error adding screen device");
 
     let Task = Task_instance
@@ -73,6 +104,18 @@ fn main() {
         .Create_display::<Buffer_size>(Screen_file, Pointer_file)
         .expect("Error adding screen");
 
+    let Keyboard_file = File_type::Open(
+        Virtual_file_system,
+        Keyboard_device_path,
+        Mode_type::Read_only().into(),
+        Task,
+    )
+    .expect("Error opening keyboard file");
+
+    Graphics_manager
+        .Add_keyboard(Keyboard_file)
+        .expect("Error adding keyboard");
+
     let mut S = Display.Get_object().expect("Error getting screen");
 
     let _ = lvgl::widgets::Slider::create(&mut S);
@@ -94,3 +137,126 @@ fn main() {
         lvgl::tick_inc(Instant::now().duration_since(Start));
     }
 }
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_headless_screen() {
+    use Drivers::Native::New_headless_touchscreen;
+    use Graphics::{
+        lvgl::{self, Widget},
+        Get_recommended_buffer_size, Point_type,
+    };
+
+    const Resolution: Point_type = Point_type::New(320, 240);
+
+    const Buffer_size: usize = Get_recommended_buffer_size(&Resolution);
+
+    let (Screen, Pointer) = New_headless_touchscreen::<Buffer_size>(Resolution);
+
+    // - Keep a handle on the framebuffer
+    let Framebuffer = Screen.clone();
+
+    Users::Initialize().expect("Error initializing users manager");
+
+    let Task_instance = Task::Initialize().expect("Error initializing task manager");
+
+    let Virtual_file_system = File_system::Initialize().expect("Error initializing file system");
+
+    Virtual_file_system
+        .Add_device(
+            Pointer_device_path,
+            Device_class_type::Input,
+            Box::new(Pointer),
+            Root_user_identifier,
+            Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
+        .expect("Error adding pointer device");
+
+    Virtual_file_system
+        .Add_device(
+            Screen_device_path,
+            Device_class_type::Display,
+            Box::new(Screen),
+            Root_user_identifier,
+            Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
+        .expect("Error adding screen device");
+
+    let Task = Task_instance
+        .Get_current_task_identifier()
+        .expect("Error getting task identifier");
+
+    let Pointer_file = File_type::Open(
+        Virtual_file_system,
+        Pointer_device_path,
+        Mode_type::Read_only().into(),
+        Task,
+    )
+    .expect("Error opening pointer file");
+
+    let Screen_file = File_type::Open(
+        Virtual_file_system,
+        Screen_device_path,
+        Mode_type::Read_write().into(),
+        Task,
+    )
+    .expect("Error opening screen file");
+
+    Graphics::Initialize().expect("Error initializing manager");
+
+    let Graphics_manager = Graphics::Get_instance().expect("Error getting manager");
+
+    let Display = Graphics_manager
+        .Create_display::<Buffer_size>(Screen_file, Pointer_file)
+        .expect("Error adding screen");
+
+    let mut S = Display.Get_object().expect("Error getting screen");
+
+    let mut Style = lvgl::style::Style::default();
+    Style.set_bg_color(lvgl::Color::from_rgb((255, 0, 0)));
+    Style.set_bg_opa(lvgl::style::Opacity::OPA_COVER);
+
+    S.add_style(lvgl::obj::Part::Main, &mut Style);
+
+    let Start = Instant::now();
+
+    while Start.elapsed() < Duration::from_millis(200) {
+        let Iteration_start = Instant::now();
+        lvgl::task_handler();
+        sleep(Duration::from_millis(5));
+        lvgl::tick_inc(Instant::now().duration_since(Iteration_start));
+    }
+
+    let Pixel = Framebuffer
+        .Get_pixel(Point_type::New(160, 120))
+        .expect("Error getting pixel");
+
+    assert!(Pixel.Get_red() > 0xF0);
+    assert_eq!(Pixel.Get_green(), 0);
+    assert_eq!(Pixel.Get_blue(), 0);
+
+    assert!(Framebuffer.Get_pixel(Resolution).is_none());
+
+    // - Dumps
+    let Directory = std::env::temp_dir();
+
+    let PPM_path = Directory.join("Xila_headless_screen.ppm");
+    Framebuffer.Dump(&PPM_path).expect("Error dumping PPM");
+
+    let PPM = std::fs::read(&PPM_path).expect("Error reading PPM");
+    assert!(PPM.starts_with(b"P6\n320 240\n255\n"));
+    assert_eq!(PPM.len(), b"P6\n320 240\n255\n".len() + 320 * 240 * 3);
+
+    let PNG_path = Directory.join("Xila_headless_screen.png");
+    Framebuffer.Dump(&PNG_path).expect("Error dumping PNG");
+
+    let PNG = std::fs::read(&PNG_path).expect("Error reading PNG");
+    assert!(PNG.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+    assert!(Framebuffer.Dump(Directory.join("Screen.bmp")).is_err());
+
+    let _ = std::fs::remove_file(PPM_path);
+    let _ = std::fs::remove_file(PNG_path);
+}
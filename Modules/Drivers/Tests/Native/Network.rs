@@ -0,0 +1,196 @@
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+
+#[cfg(target_os = "linux")]
+#[test]
+fn Test_loopback_sockets() {
+    use File_system::{Error_type, Protocol_type, Socket_address_type, Status_type};
+
+    let Task_instance = Task::Initialize().expect("Failed to initialize task manager");
+
+    Users::Initialize().expect("Failed to initialize users manager");
+
+    let Virtual_file_system = File_system::Initialize().expect("Failed to initialize file system");
+
+    let Task = Task_instance
+        .Get_current_task_identifier()
+        .expect("Failed to get task identifier");
+
+    assert_eq!(
+        Virtual_file_system.New_socket(Protocol_type::TCP, Status_type::default(), Task),
+        Err(Error_type::Network_unavailable)
+    );
+
+    // - Only the root user can set the network backend
+    let (_, Join_handle) = Task_instance
+        .New_task(None, Some(1), "Other user task", None, None, None, || {
+            File_system::Get_instance()
+                .unwrap()
+                .Set_network(Box::new(Drivers::Native::Network_type))
+        })
+        .expect("Failed to create task");
+
+    assert_eq!(
+        Join_handle.Join().expect("Failed to join task"),
+        Err(Error_type::Permission_denied)
+    );
+
+    Virtual_file_system
+        .Set_network(Box::new(Drivers::Native::Network_type))
+        .expect("Failed to set network");
+
+    // - TCP
+    let Listener = Virtual_file_system
+        .New_socket(Protocol_type::TCP, Status_type::default(), Task)
+        .expect("Failed to create TCP socket");
+
+    Virtual_file_system
+        .Bind(Listener, Socket_address_type::New_localhost(0), Task)
+        .unwrap();
+    Virtual_file_system.Listen(Listener, 1, Task).unwrap();
+
+    let Server_address = Virtual_file_system
+        .Get_local_address(Listener, Task)
+        .unwrap();
+
+    assert_eq!(Server_address.Get_IPv4_address(), Some([127, 0, 0, 1]));
+    assert_ne!(Server_address.Get_port(), 0);
+
+    let (_, Client) = Task_instance
+        .New_task(None, None, "Client", None, None, None, move || {
+            let Virtual_file_system = File_system::Get_instance().unwrap();
+
+            let Task = Task::Get_instance()
+                .unwrap()
+                .Get_current_task_identifier()
+                .unwrap();
+
+            let Socket = Virtual_file_system
+                .New_socket(Protocol_type::TCP, Status_type::default(), Task)
+                .unwrap();
+
+            Virtual_file_system
+                .Connect(Socket, Server_address, Task)
+                .unwrap();
+
+            assert_eq!(
+                Virtual_file_system.Get_peer_address(Socket, Task),
+                Ok(Server_address)
+            );
+
+            // - Sockets are read and written like files
+            assert_eq!(
+                Virtual_file_system.Write(Socket, b"Ping", Task),
+                Ok(4_usize.into())
+            );
+
+            let mut Buffer = [0_u8; 4];
+
+            assert_eq!(
+                Virtual_file_system.Read(Socket, &mut Buffer, Task),
+                Ok(4_usize.into())
+            );
+            assert_eq!(&Buffer, b"Pong");
+
+            Virtual_file_system.Close(Socket, Task).unwrap();
+        })
+        .unwrap();
+
+    let (Connection, Peer_address) = Virtual_file_system.Accept(Listener, Task).unwrap();
+
+    assert_eq!(Peer_address.Get_IPv4_address(), Some([127, 0, 0, 1]));
+
+    let mut Buffer = [0_u8; 4];
+
+    assert_eq!(
+        Virtual_file_system.Receive(Connection, &mut Buffer, Task),
+        Ok(4_usize.into())
+    );
+    assert_eq!(&Buffer, b"Ping");
+    assert_eq!(
+        Virtual_file_system.Send(Connection, b"Pong", Task),
+        Ok(4_usize.into())
+    );
+
+    Client.Join().unwrap();
+
+    // - The peer closed the connection
+    assert_eq!(
+        Virtual_file_system.Receive(Connection, &mut Buffer, Task),
+        Ok(0_usize.into())
+    );
+
+    Virtual_file_system.Close(Connection, Task).unwrap();
+    Virtual_file_system.Close(Listener, Task).unwrap();
+
+    // - UDP
+    let First = Virtual_file_system
+        .New_socket(Protocol_type::UDP, Status_type::default(), Task)
+        .unwrap();
+    let Second = Virtual_file_system
+        .New_socket(
+            Protocol_type::UDP,
+            Status_type::default().Set_non_blocking(true),
+            Task,
+        )
+        .unwrap();
+
+    Virtual_file_system
+        .Bind(First, Socket_address_type::New_localhost(0), Task)
+        .unwrap();
+    Virtual_file_system
+        .Bind(Second, Socket_address_type::New_localhost(0), Task)
+        .unwrap();
+
+    let First_address = Virtual_file_system.Get_local_address(First, Task).unwrap();
+    let Second_address = Virtual_file_system.Get_local_address(Second, Task).unwrap();
+
+    assert_eq!(
+        Virtual_file_system.Receive_from(Second, &mut Buffer, Task),
+        Err(Error_type::Ressource_busy)
+    );
+
+    assert_eq!(
+        Virtual_file_system.Send_to(First, b"Data", Second_address, Task),
+        Ok(4_usize.into())
+    );
+
+    // - Wait for the datagram on the non-blocking socket
+    let Result = loop {
+        match Virtual_file_system.Receive_from(Second, &mut Buffer, Task) {
+            Err(Error_type::Ressource_busy) => {
+                Task::Task_type::Sleep(std::time::Duration::from_millis(1))
+            }
+            Result => break Result,
+        }
+    };
+
+    assert_eq!(Result, Ok((4_usize.into(), First_address)));
+    assert_eq!(&Buffer, b"Data");
+
+    // - Connected UDP socket
+    Virtual_file_system
+        .Connect(Second, First_address, Task)
+        .unwrap();
+
+    assert_eq!(
+        Virtual_file_system.Write(Second, b"Back", Task),
+        Ok(4_usize.into())
+    );
+    assert_eq!(
+        Virtual_file_system.Receive_from(First, &mut Buffer, Task),
+        Ok((4_usize.into(), Second_address))
+    );
+    assert_eq!(&Buffer, b"Back");
+
+    Virtual_file_system.Close(First, Task).unwrap();
+    Virtual_file_system.Close(Second, Task).unwrap();
+
+    assert_eq!(Virtual_file_system.Get_open_files_count(Task), Ok(0));
+
+    assert_eq!(
+        Virtual_file_system.Send(First, b"Closed", Task),
+        Err(Error_type::Invalid_identifier)
+    );
+}
@@ -0,0 +1,409 @@
+use std::{
+    collections::VecDeque,
+    ffi::c_void,
+    mem::size_of,
+    ptr::null_mut,
+    sync::{Arc, Mutex},
+};
+
+use esp_idf_sys::{
+    self, adc_cali_handle_t, adc_channel_t, adc_oneshot_unit_handle_t, adc_unit_t,
+    esp_timer_handle_t, ESP_OK,
+};
+use File_system::{Control_command_type, Device_trait};
+use Peripherals::{
+    ADC_attenuation_type, ADC_convert_command, ADC_get_attenuation_command,
+    ADC_get_millivolts_command, ADC_get_resolution_command, ADC_sampling_buffer_size,
+    ADC_set_attenuation_command, ADC_start_sampling_command, ADC_stop_sampling_command,
+};
+
+use super::{Error_type, Result_type};
+
+/// Resolution of the conversions, in bits.
+const Resolution: u8 = 12;
+
+/// Highest sampling frequency supported by the timer driving the continuous sampling, in Hz.
+const Maximum_sampling_frequency: u32 = 20_000;
+
+struct Unit_inner_type {
+    Unit: adc_unit_t,
+    /// The driver doesn't serialize the accesses to a unit.
+    Handle: Mutex<adc_oneshot_unit_handle_t>,
+}
+
+// - The accesses to the handle are serialized by the mutex
+unsafe impl Send for Unit_inner_type {}
+unsafe impl Sync for Unit_inner_type {}
+
+impl Unit_inner_type {
+    fn Read(&self, Channel: adc_channel_t) -> File_system::Result_type<u16> {
+        let Handle = self.Handle.lock()?;
+
+        let mut Raw = 0;
+
+        if unsafe { esp_idf_sys::adc_oneshot_read(*Handle, Channel, &mut Raw) } != ESP_OK {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        Ok(Raw as u16)
+    }
+}
+
+impl Drop for Unit_inner_type {
+    fn drop(&mut self) {
+        if let Ok(Handle) = self.Handle.lock() {
+            unsafe {
+                esp_idf_sys::adc_oneshot_del_unit(*Handle);
+            }
+        }
+    }
+}
+
+/// ADC unit, whose channels are read in oneshot mode.
+///
+/// The unit is released once it and all its channels are dropped.
+#[derive(Clone)]
+pub struct ADC_unit_type(Arc<Unit_inner_type>);
+
+impl ADC_unit_type {
+    /// Initialize an ADC unit (`0` for ADC1, `1` for ADC2).
+    pub fn New(Unit: u8) -> Result_type<Self> {
+        let Unit = Unit as adc_unit_t;
+
+        let Configuration = esp_idf_sys::adc_oneshot_unit_init_cfg_t {
+            unit_id: Unit,
+            ..Default::default()
+        };
+
+        let mut Handle: adc_oneshot_unit_handle_t = null_mut();
+
+        if unsafe { esp_idf_sys::adc_oneshot_new_unit(&Configuration, &mut Handle) } != ESP_OK {
+            return Err(Error_type::Failed_to_initialize_ADC_channel);
+        }
+
+        Ok(Self(Arc::new(Unit_inner_type {
+            Unit,
+            Handle: Mutex::new(Handle),
+        })))
+    }
+
+    /// Get a channel of the unit, with the default attenuation.
+    pub fn New_channel(&self, Channel: u8) -> Result_type<ADC_channel_type> {
+        let Channel = ADC_channel_type {
+            Unit: self.clone(),
+            Channel: Channel as adc_channel_t,
+            Inner: Mutex::new(Channel_inner_type {
+                Attenuation: ADC_attenuation_type::default(),
+                Calibration: None,
+                Sampling: None,
+            }),
+        };
+
+        Channel
+            .Configure(ADC_attenuation_type::default())
+            .map_err(|_| Error_type::Failed_to_initialize_ADC_channel)?;
+
+        Ok(Channel)
+    }
+}
+
+const fn Convert_attenuation(Attenuation: ADC_attenuation_type) -> esp_idf_sys::adc_atten_t {
+    match Attenuation {
+        ADC_attenuation_type::Attenuation_0_dB => esp_idf_sys::adc_atten_t_ADC_ATTEN_DB_0,
+        ADC_attenuation_type::Attenuation_2_5_dB => esp_idf_sys::adc_atten_t_ADC_ATTEN_DB_2_5,
+        ADC_attenuation_type::Attenuation_6_dB => esp_idf_sys::adc_atten_t_ADC_ATTEN_DB_6,
+        ADC_attenuation_type::Attenuation_11_dB => esp_idf_sys::adc_atten_t_ADC_ATTEN_DB_11,
+    }
+}
+
+/// Create the calibration scheme supported by the chip, if its eFuses hold the calibration data.
+fn Create_calibration(
+    Unit: adc_unit_t,
+    Channel: adc_channel_t,
+    Attenuation: esp_idf_sys::adc_atten_t,
+) -> Option<adc_cali_handle_t> {
+    let mut Handle: adc_cali_handle_t = null_mut();
+
+    #[cfg(esp32)]
+    let Result = {
+        let _ = Channel;
+
+        let Configuration = esp_idf_sys::adc_cali_line_fitting_config_t {
+            unit_id: Unit,
+            atten: Attenuation,
+            bitwidth: esp_idf_sys::adc_bitwidth_t_ADC_BITWIDTH_12,
+            ..Default::default()
+        };
+
+        unsafe { esp_idf_sys::adc_cali_create_scheme_line_fitting(&Configuration, &mut Handle) }
+    };
+
+    #[cfg(not(esp32))]
+    let Result = {
+        let Configuration = esp_idf_sys::adc_cali_curve_fitting_config_t {
+            unit_id: Unit,
+            chan: Channel,
+            atten: Attenuation,
+            bitwidth: esp_idf_sys::adc_bitwidth_t_ADC_BITWIDTH_12,
+        };
+
+        unsafe { esp_idf_sys::adc_cali_create_scheme_curve_fitting(&Configuration, &mut Handle) }
+    };
+
+    (Result == ESP_OK).then_some(Handle)
+}
+
+fn Delete_calibration(Handle: adc_cali_handle_t) {
+    unsafe {
+        #[cfg(esp32)]
+        esp_idf_sys::adc_cali_delete_scheme_line_fitting(Handle);
+
+        #[cfg(not(esp32))]
+        esp_idf_sys::adc_cali_delete_scheme_curve_fitting(Handle);
+    }
+}
+
+/// State shared with the timer callback during a continuous sampling.
+struct Sampling_context_type {
+    Unit: ADC_unit_type,
+    Channel: adc_channel_t,
+    Samples: Mutex<VecDeque<u16>>,
+}
+
+unsafe extern "C" fn Sampling_callback(Argument: *mut c_void) {
+    let Context = &*(Argument as *const Sampling_context_type);
+
+    let Ok(Sample) = Context.Unit.0.Read(Context.Channel) else {
+        return;
+    };
+
+    if let Ok(mut Samples) = Context.Samples.lock() {
+        if Samples.len() == ADC_sampling_buffer_size {
+            Samples.pop_front();
+        }
+
+        Samples.push_back(Sample);
+    }
+}
+
+struct Channel_inner_type {
+    Attenuation: ADC_attenuation_type,
+    /// Missing if the chip isn't calibrated, the conversion is then nominal.
+    Calibration: Option<adc_cali_handle_t>,
+    Sampling: Option<(esp_timer_handle_t, Box<Sampling_context_type>)>,
+}
+
+/// Channel of an ADC unit.
+pub struct ADC_channel_type {
+    Unit: ADC_unit_type,
+    Channel: adc_channel_t,
+    Inner: Mutex<Channel_inner_type>,
+}
+
+// - The handles are only used while holding the mutex (or by the timer, which is stopped before being deleted)
+unsafe impl Send for ADC_channel_type {}
+unsafe impl Sync for ADC_channel_type {}
+
+impl ADC_channel_type {
+    fn Configure(&self, Attenuation: ADC_attenuation_type) -> File_system::Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        let Configuration = esp_idf_sys::adc_oneshot_chan_cfg_t {
+            atten: Convert_attenuation(Attenuation),
+            bitwidth: esp_idf_sys::adc_bitwidth_t_ADC_BITWIDTH_12,
+        };
+
+        if unsafe {
+            esp_idf_sys::adc_oneshot_config_channel(
+                *self.Unit.0.Handle.lock()?,
+                self.Channel,
+                &Configuration,
+            )
+        } != ESP_OK
+        {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        if let Some(Calibration) = Inner.Calibration.take() {
+            Delete_calibration(Calibration);
+        }
+
+        Inner.Calibration = Create_calibration(
+            self.Unit.0.Unit,
+            self.Channel,
+            Convert_attenuation(Attenuation),
+        );
+        Inner.Attenuation = Attenuation;
+
+        Ok(())
+    }
+
+    fn Convert(&self, Raw: u16) -> File_system::Result_type<u16> {
+        let Inner = self.Inner.lock()?;
+
+        match Inner.Calibration {
+            Some(Calibration) => {
+                let mut Voltage = 0;
+
+                if unsafe {
+                    esp_idf_sys::adc_cali_raw_to_voltage(Calibration, Raw as i32, &mut Voltage)
+                } != ESP_OK
+                {
+                    return Err(File_system::Error_type::Internal_error);
+                }
+
+                Ok(Voltage as u16)
+            }
+            None => {
+                let Full_scale = Inner.Attenuation.Get_full_scale_voltage() as u32;
+                let Maximum = (1_u32 << Resolution) - 1;
+
+                Ok(((Raw as u32).min(Maximum) * Full_scale / Maximum) as u16)
+            }
+        }
+    }
+
+    fn Start_sampling(&self, Frequency: u32) -> File_system::Result_type<()> {
+        if Frequency == 0 || Frequency > Maximum_sampling_frequency {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        self.Stop_sampling()?;
+
+        let Context = Box::new(Sampling_context_type {
+            Unit: self.Unit.clone(),
+            Channel: self.Channel,
+            Samples: Mutex::new(VecDeque::with_capacity(ADC_sampling_buffer_size)),
+        });
+
+        let Arguments = esp_idf_sys::esp_timer_create_args_t {
+            callback: Some(Sampling_callback),
+            arg: &*Context as *const _ as *mut c_void,
+            dispatch_method: esp_idf_sys::esp_timer_dispatch_t_ESP_TIMER_TASK,
+            name: c"ADC sampling".as_ptr(),
+            skip_unhandled_events: true,
+        };
+
+        let mut Timer: esp_timer_handle_t = null_mut();
+
+        unsafe {
+            if esp_idf_sys::esp_timer_create(&Arguments, &mut Timer) != ESP_OK {
+                return Err(File_system::Error_type::Internal_error);
+            }
+
+            if esp_idf_sys::esp_timer_start_periodic(Timer, 1_000_000 / Frequency as u64) != ESP_OK
+            {
+                esp_idf_sys::esp_timer_delete(Timer);
+                return Err(File_system::Error_type::Internal_error);
+            }
+        }
+
+        self.Inner.lock()?.Sampling = Some((Timer, Context));
+
+        Ok(())
+    }
+
+    fn Stop_sampling(&self) -> File_system::Result_type<()> {
+        if let Some((Timer, _Context)) = self.Inner.lock()?.Sampling.take() {
+            unsafe {
+                esp_idf_sys::esp_timer_stop(Timer);
+                esp_idf_sys::esp_timer_delete(Timer);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for ADC_channel_type {
+    fn drop(&mut self) {
+        let _ = self.Stop_sampling();
+
+        if let Ok(mut Inner) = self.Inner.lock() {
+            if let Some(Calibration) = Inner.Calibration.take() {
+                Delete_calibration(Calibration);
+            }
+        }
+    }
+}
+
+impl Device_trait for ADC_channel_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        {
+            let Inner = self.Inner.lock()?;
+
+            if let Some((_, Context)) = &Inner.Sampling {
+                let mut Samples = Context.Samples.lock()?;
+
+                let mut Size = 0;
+
+                for Chunk in Buffer.chunks_exact_mut(size_of::<u16>()) {
+                    let Some(Sample) = Samples.pop_front() else {
+                        break;
+                    };
+
+                    Chunk.copy_from_slice(&Sample.to_ne_bytes());
+                    Size += size_of::<u16>();
+                }
+
+                return Ok(Size);
+            }
+        }
+
+        let Buffer = Buffer
+            .get_mut(..size_of::<u16>())
+            .ok_or(File_system::Error_type::Invalid_input)?;
+
+        Buffer.copy_from_slice(&self.Unit.0.Read(self.Channel)?.to_ne_bytes());
+
+        Ok(size_of::<u16>())
+    }
+
+    fn Write(&self, _: &[u8]) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(size_of::<u16>())
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            ADC_set_attenuation_command => self.Configure(
+                ADC_attenuation_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?,
+            ),
+            ADC_get_attenuation_command => {
+                Command.Set_argument(Argument, self.Inner.lock()?.Attenuation as u8)
+            }
+            ADC_get_resolution_command => Command.Set_argument(Argument, Resolution),
+            ADC_get_millivolts_command => {
+                let Raw = self.Unit.0.Read(self.Channel)?;
+
+                Command.Set_argument(Argument, self.Convert(Raw)?)
+            }
+            ADC_convert_command => {
+                let Raw = Command.Get_argument::<u16>(Argument)?;
+
+                Command.Set_argument(Argument, self.Convert(Raw)?)
+            }
+            ADC_start_sampling_command => {
+                self.Start_sampling(Command.Get_argument::<u32>(Argument)?)
+            }
+            ADC_stop_sampling_command => self.Stop_sampling(),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
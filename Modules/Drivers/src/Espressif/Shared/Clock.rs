@@ -0,0 +1,39 @@
+use core::ptr::{null, null_mut};
+
+use esp_idf_sys::{self, timeval};
+use Time::{Error_type, Real_time_clock_trait, Result_type, Time_type};
+
+/// Real time clock of the chip, driven by the RTC timer.
+///
+/// The time is kept across software resets and deep sleeps, but not across power losses.
+pub struct Real_time_clock_type;
+
+impl Real_time_clock_trait for Real_time_clock_type {
+    fn Get_time(&self) -> Result_type<Time_type> {
+        let mut Time = timeval {
+            tv_sec: 0,
+            tv_usec: 0,
+        };
+
+        if unsafe { esp_idf_sys::gettimeofday(&mut Time, null_mut()) } != 0 {
+            return Err(Error_type::Real_time_clock_error);
+        }
+
+        Ok(Time_type::New(
+            Time.tv_sec as u64 * 1_000_000 + Time.tv_usec as u64,
+        ))
+    }
+
+    fn Set_time(&self, Time: Time_type) -> Result_type<()> {
+        let Time = timeval {
+            tv_sec: Time.Get_seconds() as _,
+            tv_usec: (Time.Get_microseconds() % 1_000_000) as _,
+        };
+
+        if unsafe { esp_idf_sys::settimeofday(&Time, null()) } != 0 {
+            return Err(Error_type::Real_time_clock_error);
+        }
+
+        Ok(())
+    }
+}
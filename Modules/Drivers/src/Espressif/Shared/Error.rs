@@ -3,4 +3,9 @@ pub type Result_type<T> = core::result::Result<T, Error_type>;
 #[derive(Debug, Clone, Copy)]
 pub enum Error_type {
     Failed_to_register_pin_device,
+    Failed_to_initialize_serial_device,
+    Failed_to_initialize_I2C_device,
+    Failed_to_initialize_SPI_device,
+    Failed_to_initialize_PWM_channel,
+    Failed_to_initialize_ADC_channel,
 }
@@ -0,0 +1,254 @@
+use std::sync::Mutex;
+
+use esp_idf_sys::{self, i2c_cmd_handle_t, i2c_port_t, ESP_OK};
+use File_system::{Control_command_type, Device_trait};
+use Peripherals::{
+    I2C_address_type, I2C_fast_mode_plus_frequency, I2C_get_address_command,
+    I2C_get_frequency_command, I2C_set_address_command, I2C_set_frequency_command,
+    I2C_write_read_buffer_size, I2C_write_read_command, I2C_write_read_type,
+};
+
+use super::{Error_type, Result_type};
+
+/// Maximum duration of a transaction, in milliseconds.
+const Transaction_timeout: u32 = 1000;
+
+struct Inner_type {
+    Address: I2C_address_type,
+    Frequency: u32,
+}
+
+/// I2C bus backed by an I2C controller in master mode.
+pub struct I2C_device_type {
+    Port: i2c_port_t,
+    Data_pin: u8,
+    Clock_pin: u8,
+    Inner: Mutex<Inner_type>,
+}
+
+impl I2C_device_type {
+    /// Install the I2C driver on a port, with the internal pull-ups enabled on the data (SDA) and clock (SCL) pins.
+    pub fn New(Port: u8, Data_pin: u8, Clock_pin: u8, Frequency: u32) -> Result_type<Self> {
+        let Device = Self {
+            Port: Port as i2c_port_t,
+            Data_pin,
+            Clock_pin,
+            Inner: Mutex::new(Inner_type {
+                Address: I2C_address_type::default(),
+                Frequency,
+            }),
+        };
+
+        Device
+            .Configure(Frequency)
+            .map_err(|_| Error_type::Failed_to_initialize_I2C_device)?;
+
+        if unsafe {
+            esp_idf_sys::i2c_driver_install(
+                Device.Port,
+                esp_idf_sys::i2c_mode_t_I2C_MODE_MASTER,
+                0,
+                0,
+                0,
+            )
+        } != ESP_OK
+        {
+            return Err(Error_type::Failed_to_initialize_I2C_device);
+        }
+
+        Ok(Device)
+    }
+
+    fn Configure(&self, Frequency: u32) -> File_system::Result_type<()> {
+        if Frequency == 0 || Frequency > I2C_fast_mode_plus_frequency {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        let Configuration = esp_idf_sys::i2c_config_t {
+            mode: esp_idf_sys::i2c_mode_t_I2C_MODE_MASTER,
+            sda_io_num: self.Data_pin as i32,
+            scl_io_num: self.Clock_pin as i32,
+            sda_pullup_en: true,
+            scl_pullup_en: true,
+            __bindgen_anon_1: esp_idf_sys::i2c_config_t__bindgen_ty_1 {
+                master: esp_idf_sys::i2c_config_t__bindgen_ty_1__bindgen_ty_1 {
+                    clk_speed: Frequency,
+                },
+            },
+            ..Default::default()
+        };
+
+        if unsafe { esp_idf_sys::i2c_param_config(self.Port, &Configuration) } != ESP_OK {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        Ok(())
+    }
+
+    /// Queue the address header of a transfer.
+    ///
+    /// With a 10 bits address, the second byte is only sent in write direction : a read following a write to the same
+    /// peripheral only repeats the first byte.
+    unsafe fn Queue_address(Command: i2c_cmd_handle_t, Address: I2C_address_type, Read: bool) {
+        let Read_bit = Read as u8;
+
+        if Address.Is_ten_bits() {
+            let High = 0xF0 | ((Address.Get_address() >> 7) as u8 & 0x06);
+
+            esp_idf_sys::i2c_master_write_byte(Command, High | Read_bit, true);
+
+            if !Read {
+                esp_idf_sys::i2c_master_write_byte(Command, Address.Get_address() as u8, true);
+            }
+        } else {
+            esp_idf_sys::i2c_master_write_byte(
+                Command,
+                ((Address.Get_address() as u8) << 1) | Read_bit,
+                true,
+            );
+        }
+    }
+
+    /// Perform a transaction : an optional write, followed by an optional read with a repeated start.
+    fn Transfer(&self, Write: &[u8], Read: &mut [u8]) -> File_system::Result_type<()> {
+        let Address = self.Inner.lock()?.Address;
+
+        // - A read from a 10 bits address always starts by writing the full address
+        let Write_phase = !Write.is_empty() || Read.is_empty() || Address.Is_ten_bits();
+
+        let Result = unsafe {
+            let Command = esp_idf_sys::i2c_cmd_link_create();
+
+            if Command.is_null() {
+                return Err(File_system::Error_type::Internal_error);
+            }
+
+            if Write_phase {
+                esp_idf_sys::i2c_master_start(Command);
+                Self::Queue_address(Command, Address, false);
+
+                if !Write.is_empty() {
+                    esp_idf_sys::i2c_master_write(Command, Write.as_ptr(), Write.len(), true);
+                }
+            }
+
+            if !Read.is_empty() {
+                esp_idf_sys::i2c_master_start(Command);
+                Self::Queue_address(Command, Address, true);
+                esp_idf_sys::i2c_master_read(
+                    Command,
+                    Read.as_mut_ptr(),
+                    Read.len(),
+                    esp_idf_sys::i2c_ack_type_t_I2C_MASTER_LAST_NACK,
+                );
+            }
+
+            esp_idf_sys::i2c_master_stop(Command);
+
+            let Result = esp_idf_sys::i2c_master_cmd_begin(
+                self.Port,
+                Command,
+                Transaction_timeout * esp_idf_sys::configTICK_RATE_HZ / 1000,
+            );
+
+            esp_idf_sys::i2c_cmd_link_delete(Command);
+
+            Result
+        };
+
+        match Result {
+            ESP_OK => Ok(()),
+            esp_idf_sys::ESP_FAIL => Err(File_system::Error_type::No_acknowledge),
+            esp_idf_sys::ESP_ERR_TIMEOUT => Err(File_system::Error_type::Ressource_busy),
+            _ => Err(File_system::Error_type::Internal_error),
+        }
+    }
+}
+
+impl Drop for I2C_device_type {
+    fn drop(&mut self) {
+        unsafe {
+            esp_idf_sys::i2c_driver_delete(self.Port);
+        }
+    }
+}
+
+impl Device_trait for I2C_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        self.Transfer(&[], Buffer)?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
+        self.Transfer(Buffer, &mut [])?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            I2C_set_address_command => {
+                Command.Check_argument(Argument)?;
+
+                self.Inner.lock()?.Address = I2C_address_type::try_from(&*Argument)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+                Ok(())
+            }
+            I2C_get_address_command => Command.Set_argument(Argument, self.Inner.lock()?.Address),
+            I2C_set_frequency_command => {
+                let Frequency = Command.Get_argument::<u32>(Argument)?;
+
+                let mut Inner = self.Inner.lock()?;
+
+                self.Configure(Frequency)?;
+
+                Inner.Frequency = Frequency;
+
+                Ok(())
+            }
+            I2C_get_frequency_command => {
+                Command.Set_argument(Argument, self.Inner.lock()?.Frequency)
+            }
+            I2C_write_read_command => {
+                let mut Transfer = Command.Get_argument::<I2C_write_read_type>(Argument)?;
+
+                let mut Read = [0_u8; I2C_write_read_buffer_size];
+                let Read = Read
+                    .get_mut(..Transfer.Get_read_size())
+                    .ok_or(File_system::Error_type::Invalid_input)?;
+
+                self.Transfer(
+                    Transfer
+                        .Get_write_data()
+                        .ok_or(File_system::Error_type::Invalid_input)?,
+                    Read,
+                )?;
+
+                Transfer
+                    .Set_read_data(Read)
+                    .ok_or(File_system::Error_type::Invalid_input)?;
+
+                Command.Set_argument(Argument, Transfer)
+            }
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
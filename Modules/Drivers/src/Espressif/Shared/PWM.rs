@@ -0,0 +1,261 @@
+use std::{
+    mem::size_of,
+    sync::{Mutex, Once},
+};
+
+use esp_idf_sys::{self, ledc_channel_t, ledc_timer_t, ESP_OK};
+use File_system::{Control_command_type, Device_trait};
+use Peripherals::{
+    PWM_configuration_type, PWM_fade_command, PWM_fade_type, PWM_get_configuration_command,
+    PWM_set_configuration_command, PWM_set_pin_command,
+};
+
+use super::{Error_type, Result_type};
+
+/// The low speed mode is available on all the chips.
+const Speed_mode: esp_idf_sys::ledc_mode_t = esp_idf_sys::ledc_mode_t_LEDC_LOW_SPEED_MODE;
+
+/// The fade service is shared by all the channels and must be installed once.
+static Install_fade_service: Once = Once::new();
+
+struct Inner_type {
+    Configuration: PWM_configuration_type,
+    Pin: Option<u8>,
+}
+
+/// PWM channel backed by a LEDC channel and its own LEDC timer.
+pub struct PWM_channel_type {
+    Timer: ledc_timer_t,
+    Channel: ledc_channel_t,
+    Inner: Mutex<Inner_type>,
+}
+
+impl PWM_channel_type {
+    /// Configure a LEDC timer and channel with the default configuration, the output is assigned to a pin later.
+    pub fn New(Timer: u8, Channel: u8) -> Result_type<Self> {
+        let mut Result = ESP_OK;
+
+        Install_fade_service.call_once(|| unsafe {
+            Result = esp_idf_sys::ledc_fade_func_install(0);
+        });
+
+        if Result != ESP_OK {
+            return Err(Error_type::Failed_to_initialize_PWM_channel);
+        }
+
+        let Channel = Self {
+            Timer: Timer as ledc_timer_t,
+            Channel: Channel as ledc_channel_t,
+            Inner: Mutex::new(Inner_type {
+                Configuration: PWM_configuration_type::default(),
+                Pin: None,
+            }),
+        };
+
+        Channel
+            .Configure_timer(&PWM_configuration_type::default())
+            .map_err(|_| Error_type::Failed_to_initialize_PWM_channel)?;
+
+        Ok(Channel)
+    }
+
+    fn Configure_timer(
+        &self,
+        Configuration: &PWM_configuration_type,
+    ) -> File_system::Result_type<()> {
+        let Timer_configuration = esp_idf_sys::ledc_timer_config_t {
+            speed_mode: Speed_mode,
+            duty_resolution: Configuration.Get_resolution() as esp_idf_sys::ledc_timer_bit_t,
+            timer_num: self.Timer,
+            freq_hz: Configuration.Get_frequency(),
+            clk_cfg: esp_idf_sys::soc_periph_ledc_clk_src_legacy_t_LEDC_AUTO_CLK,
+            ..Default::default()
+        };
+
+        // - Fails if the source clock can't provide the frequency with this resolution
+        if unsafe { esp_idf_sys::ledc_timer_config(&Timer_configuration) } != ESP_OK {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        Ok(())
+    }
+
+    fn Configure_channel(&self, Pin: u8) -> File_system::Result_type<()> {
+        let Channel_configuration = esp_idf_sys::ledc_channel_config_t {
+            gpio_num: Pin as i32,
+            speed_mode: Speed_mode,
+            channel: self.Channel,
+            timer_sel: self.Timer,
+            duty: 0,
+            hpoint: 0,
+            ..Default::default()
+        };
+
+        if unsafe { esp_idf_sys::ledc_channel_config(&Channel_configuration) } != ESP_OK {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        Ok(())
+    }
+
+    fn Set_duty(&self, Inner: &Inner_type, Duty: u32) -> File_system::Result_type<()> {
+        if Duty > Inner.Configuration.Get_maximum_duty() {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        if Inner.Pin.is_none() {
+            return Err(File_system::Error_type::Unsupported_operation);
+        }
+
+        unsafe {
+            // - Stop any fade in progress
+            esp_idf_sys::ledc_fade_stop(Speed_mode, self.Channel);
+
+            if esp_idf_sys::ledc_set_duty(Speed_mode, self.Channel, Duty) != ESP_OK
+                || esp_idf_sys::ledc_update_duty(Speed_mode, self.Channel) != ESP_OK
+            {
+                return Err(File_system::Error_type::Internal_error);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn Fade(&self, Fade: &PWM_fade_type) -> File_system::Result_type<()> {
+        let Inner = self.Inner.lock()?;
+
+        if Fade.Duty > Inner.Configuration.Get_maximum_duty() {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        if Inner.Pin.is_none() {
+            return Err(File_system::Error_type::Unsupported_operation);
+        }
+
+        unsafe {
+            if esp_idf_sys::ledc_set_fade_with_time(
+                Speed_mode,
+                self.Channel,
+                Fade.Duty,
+                Fade.Duration as i32,
+            ) != ESP_OK
+                || esp_idf_sys::ledc_fade_start(
+                    Speed_mode,
+                    self.Channel,
+                    esp_idf_sys::ledc_fade_mode_t_LEDC_FADE_NO_WAIT,
+                ) != ESP_OK
+            {
+                return Err(File_system::Error_type::Internal_error);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for PWM_channel_type {
+    fn drop(&mut self) {
+        unsafe {
+            esp_idf_sys::ledc_fade_stop(Speed_mode, self.Channel);
+            esp_idf_sys::ledc_stop(Speed_mode, self.Channel, 0);
+        }
+    }
+}
+
+impl Device_trait for PWM_channel_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        let Buffer = Buffer
+            .get_mut(..size_of::<u32>())
+            .ok_or(File_system::Error_type::Invalid_input)?;
+
+        let Duty = unsafe { esp_idf_sys::ledc_get_duty(Speed_mode, self.Channel) };
+
+        Buffer.copy_from_slice(&Duty.to_ne_bytes());
+
+        Ok(size_of::<u32>())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
+        let Duty = u32::from_ne_bytes(
+            Buffer
+                .get(..size_of::<u32>())
+                .ok_or(File_system::Error_type::Invalid_input)?
+                .try_into()
+                .map_err(|_| File_system::Error_type::Invalid_input)?,
+        );
+
+        self.Set_duty(&*self.Inner.lock()?, Duty)?;
+
+        Ok(size_of::<u32>())
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(size_of::<u32>())
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            PWM_set_configuration_command => {
+                Command.Check_argument(Argument)?;
+
+                let Configuration = PWM_configuration_type::try_from(&*Argument)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+                let mut Inner = self.Inner.lock()?;
+
+                unsafe {
+                    esp_idf_sys::ledc_fade_stop(Speed_mode, self.Channel);
+                }
+
+                self.Configure_timer(&Configuration)?;
+
+                Inner.Configuration = Configuration;
+
+                if Inner.Pin.is_some() {
+                    self.Set_duty(&Inner, 0)?;
+                }
+
+                Ok(())
+            }
+            PWM_get_configuration_command => {
+                Command.Set_argument(Argument, self.Inner.lock()?.Configuration)
+            }
+            PWM_set_pin_command => {
+                let Pin = Command.Get_argument::<u8>(Argument)?;
+
+                let mut Inner = self.Inner.lock()?;
+
+                match Inner.Pin {
+                    // - Route the output of the channel to another pin, keeping the duty
+                    Some(_) => {
+                        if unsafe {
+                            esp_idf_sys::ledc_set_pin(Pin as i32, Speed_mode, self.Channel)
+                        } != ESP_OK
+                        {
+                            return Err(File_system::Error_type::Invalid_input);
+                        }
+                    }
+                    None => self.Configure_channel(Pin)?,
+                }
+
+                Inner.Pin = Some(Pin);
+
+                Ok(())
+            }
+            PWM_fade_command => self.Fade(&Command.Get_argument::<PWM_fade_type>(Argument)?),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
@@ -1,9 +1,21 @@
-use std::sync::RwLock;
+use std::{
+    ffi::c_void,
+    mem::size_of,
+    ptr::null_mut,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, OnceLock, RwLock,
+    },
+};
 
 use esp_idf_sys::{self, gpio_reset_pin};
-use File_system::Device_trait;
-use Graphics::lvgl::input_device::Data;
-use Peripherals::{Direction_type, Pin_data_type, Pull_type};
+use File_system::{Control_command_type, Device_class_type, Device_trait, Permissions_type};
+use Peripherals::{
+    Direction_type, Interrupt_type, Level_type, Pin_event_type, Pin_events_queue_size,
+    Pin_get_direction_command, Pin_get_event_command, Pin_get_overflow_count_command,
+    Pin_get_pull_command, Pin_set_direction_command, Pin_set_interrupt_command,
+    Pin_set_pull_command, Pin_wait_event_command, Pin_wait_event_type, Pull_type,
+};
 
 use super::{Error_type, Result_type};
 
@@ -23,19 +35,85 @@ macro_rules! Enumerate_pin_devices {
 
 pub fn Mount_pin_devices(
     Virtual_file_system: &File_system::Virtual_file_system_type,
-    Pin_devices: &'static [(u8, &'static File_system::Path_type)],
+    Pin_devices: &[(u8, &File_system::Path_type)],
 ) -> Result_type<()> {
     for (i, Path) in Pin_devices.iter() {
         Virtual_file_system
-            .Add_device(Path, Box::new(Pin_device_type::New(*i)))
+            .Add_device(
+                Path,
+                Device_class_type::GPIO,
+                Box::new(Pin_device_type::New(*i)),
+                Users::Root_user_identifier,
+                Users::Root_group_identifier,
+                Permissions_type::New_standard_file(),
+            )
             .map_err(|_| Error_type::Failed_to_register_pin_device)?;
     }
 
     Ok(())
 }
 
+/// The GPIO ISR service is shared by all the pins and must be installed once : the result of the installation is kept
+/// for the following calls.
+static ISR_service_result: OnceLock<esp_idf_sys::esp_err_t> = OnceLock::new();
+
+/// State shared with the interrupt handler of a pin.
+struct Interrupt_context_type {
+    Pin: u8,
+    Queue: esp_idf_sys::QueueHandle_t,
+    Overflow_count: AtomicU32,
+    /// Level interrupts are disabled by the handler until the event is taken, otherwise they would fire continuously.
+    Level_triggered: bool,
+}
+
+// - The FreeRTOS queue can be used from any task and from interrupts
+unsafe impl Send for Interrupt_context_type {}
+unsafe impl Sync for Interrupt_context_type {}
+
+impl Drop for Interrupt_context_type {
+    fn drop(&mut self) {
+        // - The context is shared with the waiting tasks, so the queue is only deleted once none of them uses it
+        unsafe {
+            esp_idf_sys::vQueueDelete(self.Queue);
+        }
+    }
+}
+
+unsafe extern "C" fn Interrupt_handler(Argument: *mut c_void) {
+    let Context = &*(Argument as *const Interrupt_context_type);
+
+    let Level = if esp_idf_sys::gpio_get_level(Context.Pin as i32) == 0 {
+        Level_type::Low
+    } else {
+        Level_type::High
+    };
+
+    let Event = Pin_event_type::New(esp_idf_sys::esp_timer_get_time() as u64, Level);
+
+    let mut Higher_priority_task_woken = 0;
+
+    let Result = esp_idf_sys::xQueueGenericSendFromISR(
+        Context.Queue,
+        &Event as *const _ as *const c_void,
+        &mut Higher_priority_task_woken,
+        0, // queueSEND_TO_BACK
+    );
+
+    if Result != 1 {
+        Context.Overflow_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    if Context.Level_triggered {
+        esp_idf_sys::gpio_intr_disable(Context.Pin as i32);
+    }
+}
+
 struct Inner_type {
     Pin: u8,
+    /// The configuration is kept since ESP-IDF doesn't provide a way to read it back.
+    Direction: Direction_type,
+    Pull: Pull_type,
+    Interrupt: Option<Arc<Interrupt_context_type>>,
 }
 
 #[repr(transparent)]
@@ -47,90 +125,213 @@ impl Pin_device_type {
             gpio_reset_pin(Pin as i32);
         }
 
-        Pin_device_type(RwLock::new(Inner_type { Pin }))
+        // - `gpio_reset_pin` leaves the pin as an input with the pull-up enabled
+        Pin_device_type(RwLock::new(Inner_type {
+            Pin,
+            Direction: Direction_type::Input,
+            Pull: Pull_type::Up,
+            Interrupt: None,
+        }))
     }
-}
-
-impl Device_trait for Pin_device_type {
-    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
-        let Data: &mut Pin_data_type = Buffer
-            .try_into()
-            .map_err(|_| File_system::Error_type::Invalid_input)?;
 
-        Data.Set_level(
-            Level_type::try_from(unsafe {
-                esp_idf_sys::gpio_get_level(self.0.read()?.Pin as i32) as u8
-            })
-            .map_err(|_| File_system::Error_type::Invalid_input)?,
-        );
+    fn Remove_interrupt(Inner: &mut Inner_type) {
+        if let Some(Context) = Inner.Interrupt.take() {
+            unsafe {
+                esp_idf_sys::gpio_set_intr_type(
+                    Inner.Pin as i32,
+                    esp_idf_sys::gpio_int_type_t_GPIO_INTR_DISABLE,
+                );
+                esp_idf_sys::gpio_isr_handler_remove(Inner.Pin as i32);
+            }
 
-        Ok(size_of::<Pin_data_type>())
+            // - The queue is deleted with the last reference to the context
+            drop(Context);
+        }
     }
 
-    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
-        let Data: &mut Pin_data_type = Buffer
-            .try_into()
-            .map_err(|_| File_system::Error_type::Invalid_input)?;
-
-        let Pin = self.0.read()? as i32;
-
-        if let Some(Pin) = Data.Get_direction() {
-            match Pin {
-                Direction_type::Input => unsafe {
-                    esp_idf_sys::gpio_set_direction(
-                        Pin,
-                        esp_idf_sys::gpio_mode_t_GPIO_MODE_INPUT as u32,
-                    );
-                },
-                Direction_type::Output => unsafe {
-                    esp_idf_sys::gpio_set_direction(
-                        Pin,
-                        esp_idf_sys::gpio_mode_t_GPIO_MODE_OUTPUT as u32,
-                    );
-                },
+    fn Set_interrupt(&self, Interrupt: Interrupt_type) -> File_system::Result_type<()> {
+        let mut Inner = self.0.write()?;
+
+        // - Removing the previous handler also clears the queued events
+        Self::Remove_interrupt(&mut Inner);
+
+        let Type = match Interrupt {
+            Interrupt_type::None => return Ok(()),
+            Interrupt_type::Rising_edge => esp_idf_sys::gpio_int_type_t_GPIO_INTR_POSEDGE,
+            Interrupt_type::Falling_edge => esp_idf_sys::gpio_int_type_t_GPIO_INTR_NEGEDGE,
+            Interrupt_type::Both_edges => esp_idf_sys::gpio_int_type_t_GPIO_INTR_ANYEDGE,
+            Interrupt_type::Low_level => esp_idf_sys::gpio_int_type_t_GPIO_INTR_LOW_LEVEL,
+            Interrupt_type::High_level => esp_idf_sys::gpio_int_type_t_GPIO_INTR_HIGH_LEVEL,
+        };
+
+        let Result =
+            *ISR_service_result.get_or_init(|| unsafe { esp_idf_sys::gpio_install_isr_service(0) });
+
+        if Result != esp_idf_sys::ESP_OK {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        let Queue = unsafe {
+            esp_idf_sys::xQueueGenericCreate(
+                Pin_events_queue_size as u32,
+                size_of::<Pin_event_type>() as u32,
+                0, // queueQUEUE_TYPE_BASE
+            )
+        };
+
+        if Queue.is_null() {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        let Context = Arc::new(Interrupt_context_type {
+            Pin: Inner.Pin,
+            Queue,
+            Overflow_count: AtomicU32::new(0),
+            Level_triggered: matches!(
+                Interrupt,
+                Interrupt_type::Low_level | Interrupt_type::High_level
+            ),
+        });
+
+        unsafe {
+            esp_idf_sys::gpio_set_intr_type(Inner.Pin as i32, Type);
+
+            if esp_idf_sys::gpio_isr_handler_add(
+                Inner.Pin as i32,
+                Some(Interrupt_handler),
+                Arc::as_ptr(&Context) as *mut c_void,
+            ) != esp_idf_sys::ESP_OK
+            {
+                // - Dropping the context deletes the queue
+                return Err(File_system::Error_type::Internal_error);
             }
+
+            esp_idf_sys::gpio_intr_enable(Inner.Pin as i32);
+        }
+
+        Inner.Interrupt = Some(Context);
+
+        Ok(())
+    }
+
+    /// Take an event from the queue, waiting at most `Ticks` (`0` to poll).
+    fn Receive_event(
+        &self,
+        Ticks: esp_idf_sys::TickType_t,
+    ) -> File_system::Result_type<Pin_event_type> {
+        // - Keep a reference to the context so that the lock isn't held while blocking and the queue outlives the wait
+        let Context = self
+            .0
+            .read()?
+            .Interrupt
+            .clone()
+            .ok_or(File_system::Error_type::Ressource_busy)?;
+
+        let mut Event = Pin_event_type::default();
+
+        if unsafe {
+            esp_idf_sys::xQueueReceive(Context.Queue, &mut Event as *mut _ as *mut c_void, Ticks)
+        } != 1
+        {
+            return Err(File_system::Error_type::Ressource_busy);
         }
 
-        if let Some(Level) = Data.Get_level() {
+        if Context.Level_triggered {
             unsafe {
-                esp_idf_sys::gpio_set_level(Pin, Level.into() as u32);
+                esp_idf_sys::gpio_intr_enable(Context.Pin as i32);
             }
         }
 
-        if let Some(Pull) = Data.Get_pull() {
-            match Pull {
-                Pull_type::None => unsafe {
-                    esp_idf_sys::gpio_set_pull_mode(
-                        Pin,
-                        esp_idf_sys::gpio_pull_mode_t_GPIO_FLOATING as u32,
-                    );
-                },
-                Pull_type::Up => unsafe {
-                    esp_idf_sys::gpio_set_pull_mode(
-                        Pin,
-                        esp_idf_sys::gpio_pull_mode_t_GPIO_PULLUP_ONLY as u32,
-                    );
-                },
-                Pull_type::Down => unsafe {
-                    esp_idf_sys::gpio_set_pull_mode(
-                        Pin,
-                        esp_idf_sys::gpio_pull_mode_t_GPIO_PULLDOWN_ONLY as u32,
-                    );
-                },
-                Pull_type::Up_down => unsafe {
-                    esp_idf_sys::gpio_set_pull_mode(
-                        Pin,
-                        esp_idf_sys::gpio_pull_mode_t_GPIO_PULLUP_PULLDOWN as u32,
-                    );
-                },
-            }
+        Ok(Event)
+    }
+
+    fn Wait_event(&self, Timeout: u64) -> File_system::Result_type<Pin_event_type> {
+        let Ticks = if Timeout == u64::MAX {
+            esp_idf_sys::TickType_t::MAX
+        } else {
+            (Timeout.div_ceil(1000) * esp_idf_sys::configTICK_RATE_HZ as u64 / 1000)
+                .min(esp_idf_sys::TickType_t::MAX as u64 - 1) as esp_idf_sys::TickType_t
+        };
+
+        self.Receive_event(Ticks)
+    }
+
+    fn Get_overflow_count(&self) -> File_system::Result_type<u32> {
+        Ok(self
+            .0
+            .read()?
+            .Interrupt
+            .as_ref()
+            .map_or(0, |Context| Context.Overflow_count.load(Ordering::Relaxed)))
+    }
+
+    fn Set_direction(&self, Direction: Direction_type) -> File_system::Result_type<()> {
+        let Mode = match Direction {
+            Direction_type::Input => esp_idf_sys::gpio_mode_t_GPIO_MODE_INPUT,
+            Direction_type::Output => esp_idf_sys::gpio_mode_t_GPIO_MODE_INPUT_OUTPUT,
+        };
+
+        let mut Inner = self.0.write()?;
+
+        if unsafe { esp_idf_sys::gpio_set_direction(Inner.Pin as i32, Mode) } != esp_idf_sys::ESP_OK
+        {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        Inner.Direction = Direction;
+
+        Ok(())
+    }
+
+    fn Set_pull(&self, Pull: Pull_type) -> File_system::Result_type<()> {
+        let Mode = match Pull {
+            Pull_type::None => esp_idf_sys::gpio_pull_mode_t_GPIO_FLOATING,
+            Pull_type::Up => esp_idf_sys::gpio_pull_mode_t_GPIO_PULLUP_ONLY,
+            Pull_type::Down => esp_idf_sys::gpio_pull_mode_t_GPIO_PULLDOWN_ONLY,
+            Pull_type::Up_down => esp_idf_sys::gpio_pull_mode_t_GPIO_PULLUP_PULLDOWN,
+        };
+
+        let mut Inner = self.0.write()?;
+
+        if unsafe { esp_idf_sys::gpio_set_pull_mode(Inner.Pin as i32, Mode) } != esp_idf_sys::ESP_OK
+        {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        Inner.Pull = Pull;
+
+        Ok(())
+    }
+}
+
+impl Device_trait for Pin_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        let Level = Buffer
+            .first_mut()
+            .ok_or(File_system::Error_type::Invalid_input)?;
+
+        *Level = unsafe { esp_idf_sys::gpio_get_level(self.0.read()?.Pin as i32) as u8 };
+
+        Ok(size_of::<Level_type>())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
+        let Level = Level_type::try_from(
+            *Buffer
+                .first()
+                .ok_or(File_system::Error_type::Invalid_input)?,
+        )
+        .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+        unsafe {
+            esp_idf_sys::gpio_set_level(self.0.read()?.Pin as i32, u8::from(Level) as u32);
         }
 
-        Ok(size_of::<Pin_data_type>())
+        Ok(size_of::<Level_type>())
     }
 
     fn Get_size(&self) -> File_system::Result_type<usize> {
-        Ok(size_of::<Pin_data_type>())
+        Ok(size_of::<Level_type>())
     }
 
     fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
@@ -140,4 +341,55 @@ impl Device_trait for Pin_device_type {
     fn Flush(&self) -> File_system::Result_type<()> {
         Ok(())
     }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            Pin_set_direction_command => self.Set_direction(
+                Direction_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?,
+            ),
+            Pin_set_pull_command => self.Set_pull(
+                Pull_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?,
+            ),
+            Pin_set_interrupt_command => self.Set_interrupt(
+                Interrupt_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?,
+            ),
+            Pin_get_event_command => Command.Set_argument(Argument, self.Receive_event(0)?),
+            Pin_wait_event_command => {
+                let Wait = Command.Get_argument::<Pin_wait_event_type>(Argument)?;
+
+                let Event = self.Wait_event(Wait.Timeout)?;
+
+                Command.Set_argument(
+                    Argument,
+                    Pin_wait_event_type {
+                        Timeout: Wait.Timeout,
+                        Event,
+                    },
+                )
+            }
+            Pin_get_overflow_count_command => {
+                Command.Set_argument(Argument, self.Get_overflow_count()?)
+            }
+            Pin_get_direction_command => {
+                Command.Set_argument(Argument, self.0.read()?.Direction as u8)
+            }
+            Pin_get_pull_command => Command.Set_argument(Argument, self.0.read()?.Pull as u8),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
+
+impl Drop for Pin_device_type {
+    fn drop(&mut self) {
+        if let Ok(mut Inner) = self.0.write() {
+            Self::Remove_interrupt(&mut Inner);
+        }
+    }
 }
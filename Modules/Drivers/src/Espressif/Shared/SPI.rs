@@ -0,0 +1,342 @@
+use std::{
+    ptr::null_mut,
+    sync::{Arc, Mutex},
+};
+
+use esp_idf_sys::{self, spi_device_handle_t, spi_host_device_t, ESP_OK};
+use File_system::{Control_command_type, Device_trait};
+use Peripherals::{
+    SPI_begin_batch_command, SPI_end_batch_command, SPI_get_frequency_command,
+    SPI_get_mode_command, SPI_mode_type, SPI_set_frequency_command, SPI_set_mode_command,
+    SPI_transfer_command, SPI_transfer_type,
+};
+
+use super::{Error_type, Result_type};
+
+struct Bus_inner_type {
+    Host: spi_host_device_t,
+}
+
+impl Drop for Bus_inner_type {
+    fn drop(&mut self) {
+        unsafe {
+            esp_idf_sys::spi_bus_free(self.Host);
+        }
+    }
+}
+
+/// SPI bus backed by a SPI controller in master mode.
+///
+/// The bus is freed once it and all its devices are dropped.
+#[derive(Clone)]
+pub struct SPI_bus_type(Arc<Bus_inner_type>);
+
+impl SPI_bus_type {
+    /// Initialize a SPI controller (`Host`) on the given pins, with DMA for transfers up to `Maximum_transfer_size`.
+    pub fn New(
+        Host: u8,
+        Clock_pin: u8,
+        MOSI_pin: u8,
+        MISO_pin: Option<u8>,
+        Maximum_transfer_size: usize,
+    ) -> Result_type<Self> {
+        let Host = Host as spi_host_device_t;
+
+        let Configuration = esp_idf_sys::spi_bus_config_t {
+            __bindgen_anon_1: esp_idf_sys::spi_bus_config_t__bindgen_ty_1 {
+                mosi_io_num: MOSI_pin as i32,
+            },
+            __bindgen_anon_2: esp_idf_sys::spi_bus_config_t__bindgen_ty_2 {
+                miso_io_num: MISO_pin.map_or(-1, i32::from),
+            },
+            sclk_io_num: Clock_pin as i32,
+            __bindgen_anon_3: esp_idf_sys::spi_bus_config_t__bindgen_ty_3 { quadwp_io_num: -1 },
+            __bindgen_anon_4: esp_idf_sys::spi_bus_config_t__bindgen_ty_4 { quadhd_io_num: -1 },
+            max_transfer_sz: Maximum_transfer_size as i32,
+            ..Default::default()
+        };
+
+        if unsafe {
+            esp_idf_sys::spi_bus_initialize(
+                Host,
+                &Configuration,
+                esp_idf_sys::spi_common_dma_t_SPI_DMA_CH_AUTO,
+            )
+        } != ESP_OK
+        {
+            return Err(Error_type::Failed_to_initialize_SPI_device);
+        }
+
+        Ok(Self(Arc::new(Bus_inner_type { Host })))
+    }
+
+    /// Add a device to the bus, whose chip select pin is driven by the device.
+    pub fn New_device(
+        &self,
+        Chip_select_pin: u8,
+        Mode: SPI_mode_type,
+        Frequency: u32,
+    ) -> Result_type<SPI_device_type> {
+        unsafe {
+            esp_idf_sys::gpio_reset_pin(Chip_select_pin as i32);
+            esp_idf_sys::gpio_set_direction(
+                Chip_select_pin as i32,
+                esp_idf_sys::gpio_mode_t_GPIO_MODE_OUTPUT,
+            );
+            esp_idf_sys::gpio_set_level(Chip_select_pin as i32, 1);
+        }
+
+        let Handle = Add_device(self.0.Host, Mode, Frequency)
+            .map_err(|_| Error_type::Failed_to_initialize_SPI_device)?;
+
+        Ok(SPI_device_type {
+            Bus: self.clone(),
+            Chip_select_pin,
+            Inner: Mutex::new(Device_inner_type {
+                Handle,
+                Mode,
+                Frequency,
+                In_batch: false,
+            }),
+        })
+    }
+}
+
+fn Add_device(
+    Host: spi_host_device_t,
+    Mode: SPI_mode_type,
+    Frequency: u32,
+) -> File_system::Result_type<spi_device_handle_t> {
+    if Frequency == 0 {
+        return Err(File_system::Error_type::Invalid_input);
+    }
+
+    // - The chip select is managed by the device to keep it asserted during batches
+    let Configuration = esp_idf_sys::spi_device_interface_config_t {
+        mode: Mode as u8,
+        clock_speed_hz: Frequency as i32,
+        spics_io_num: -1,
+        queue_size: 1,
+        ..Default::default()
+    };
+
+    let mut Handle: spi_device_handle_t = null_mut();
+
+    if unsafe { esp_idf_sys::spi_bus_add_device(Host, &Configuration, &mut Handle) } != ESP_OK {
+        return Err(File_system::Error_type::Invalid_input);
+    }
+
+    Ok(Handle)
+}
+
+struct Device_inner_type {
+    Handle: spi_device_handle_t,
+    Mode: SPI_mode_type,
+    Frequency: u32,
+    In_batch: bool,
+}
+
+/// Peripheral on a SPI bus.
+pub struct SPI_device_type {
+    Bus: SPI_bus_type,
+    Chip_select_pin: u8,
+    Inner: Mutex<Device_inner_type>,
+}
+
+// - The device handle can be used from any task, the accesses are serialized by the mutex
+unsafe impl Send for SPI_device_type {}
+unsafe impl Sync for SPI_device_type {}
+
+impl SPI_device_type {
+    fn Set_chip_select(&self, Asserted: bool) {
+        unsafe {
+            esp_idf_sys::gpio_set_level(self.Chip_select_pin as i32, u32::from(!Asserted));
+        }
+    }
+
+    /// Change the mode or the frequency, which requires to add the device again.
+    fn Reconfigure(&self, Mode: SPI_mode_type, Frequency: u32) -> File_system::Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        if Inner.In_batch {
+            return Err(File_system::Error_type::Ressource_busy);
+        }
+
+        let Handle = Add_device(self.Bus.0.Host, Mode, Frequency)?;
+
+        unsafe {
+            esp_idf_sys::spi_bus_remove_device(Inner.Handle);
+        }
+
+        Inner.Handle = Handle;
+        Inner.Mode = Mode;
+        Inner.Frequency = Frequency;
+
+        Ok(())
+    }
+
+    /// Full-duplex transfer, in place.
+    fn Transfer(&self, Buffer: &mut [u8]) -> File_system::Result_type<()> {
+        if Buffer.is_empty() {
+            return Ok(());
+        }
+
+        let Inner = self.Inner.lock()?;
+
+        let mut Transaction = esp_idf_sys::spi_transaction_t {
+            length: Buffer.len() * 8,
+            __bindgen_anon_1: esp_idf_sys::spi_transaction_t__bindgen_ty_1 {
+                tx_buffer: Buffer.as_ptr() as *const _,
+            },
+            __bindgen_anon_2: esp_idf_sys::spi_transaction_t__bindgen_ty_2 {
+                rx_buffer: Buffer.as_mut_ptr() as *mut _,
+            },
+            ..Default::default()
+        };
+
+        unsafe {
+            if !Inner.In_batch {
+                if esp_idf_sys::spi_device_acquire_bus(Inner.Handle, esp_idf_sys::portMAX_DELAY)
+                    != ESP_OK
+                {
+                    return Err(File_system::Error_type::Ressource_busy);
+                }
+
+                self.Set_chip_select(true);
+            }
+
+            let Result = esp_idf_sys::spi_device_polling_transmit(Inner.Handle, &mut Transaction);
+
+            if !Inner.In_batch {
+                self.Set_chip_select(false);
+                esp_idf_sys::spi_device_release_bus(Inner.Handle);
+            }
+
+            if Result != ESP_OK {
+                return Err(File_system::Error_type::Internal_error);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn Begin_batch(&self) -> File_system::Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        if Inner.In_batch {
+            return Err(File_system::Error_type::Ressource_busy);
+        }
+
+        // - The driver only supports waiting for the bus (until the batch of another device ends)
+        if unsafe { esp_idf_sys::spi_device_acquire_bus(Inner.Handle, esp_idf_sys::portMAX_DELAY) }
+            != ESP_OK
+        {
+            return Err(File_system::Error_type::Ressource_busy);
+        }
+
+        self.Set_chip_select(true);
+        Inner.In_batch = true;
+
+        Ok(())
+    }
+
+    fn End_batch(&self) -> File_system::Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        if !Inner.In_batch {
+            return Err(File_system::Error_type::Invalid_input);
+        }
+
+        self.Set_chip_select(false);
+
+        unsafe {
+            esp_idf_sys::spi_device_release_bus(Inner.Handle);
+        }
+
+        Inner.In_batch = false;
+
+        Ok(())
+    }
+}
+
+impl Drop for SPI_device_type {
+    fn drop(&mut self) {
+        let _ = self.End_batch();
+
+        if let Ok(Inner) = self.Inner.lock() {
+            unsafe {
+                esp_idf_sys::spi_bus_remove_device(Inner.Handle);
+            }
+        }
+    }
+}
+
+impl Device_trait for SPI_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        Buffer.fill(0);
+
+        self.Transfer(Buffer)?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
+        self.Transfer(&mut Buffer.to_vec())?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            SPI_set_mode_command => {
+                let Mode = SPI_mode_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+                let Frequency = self.Inner.lock()?.Frequency;
+
+                self.Reconfigure(Mode, Frequency)
+            }
+            SPI_get_mode_command => Command.Set_argument(Argument, self.Inner.lock()?.Mode as u8),
+            SPI_set_frequency_command => {
+                let Frequency = Command.Get_argument::<u32>(Argument)?;
+
+                let Mode = self.Inner.lock()?.Mode;
+
+                self.Reconfigure(Mode, Frequency)
+            }
+            SPI_get_frequency_command => {
+                Command.Set_argument(Argument, self.Inner.lock()?.Frequency)
+            }
+            SPI_transfer_command => {
+                let mut Transfer = Command.Get_argument::<SPI_transfer_type>(Argument)?;
+
+                self.Transfer(
+                    Transfer
+                        .Get_data_mutable()
+                        .ok_or(File_system::Error_type::Invalid_input)?,
+                )?;
+
+                Command.Set_argument(Argument, Transfer)
+            }
+            SPI_begin_batch_command => self.Begin_batch(),
+            SPI_end_batch_command => self.End_batch(),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::{ptr::null_mut, sync::Mutex};
+
+use esp_idf_sys::{self, uart_port_t, ESP_OK};
+use File_system::{Control_command_type, Device_trait};
+use Peripherals::{
+    Flow_control_type, Parity_type, Serial_configuration_type, Serial_get_configuration_command,
+    Serial_set_configuration_command, Stop_bits_type,
+};
+
+use super::{Error_type, Result_type};
+
+/// XON / XOFF thresholds of the receive FIFO used for software flow control.
+const Software_flow_control_thresholds: (u8, u8) = (100, 20);
+
+/// Serial port backed by an UART controller.
+pub struct Serial_device_type {
+    Port: uart_port_t,
+    Configuration: Mutex<Serial_configuration_type>,
+}
+
+impl Serial_device_type {
+    /// Install the UART driver on a port and route its signals to the given pins (`None` to leave a signal unrouted).
+    pub fn New(
+        Port: u8,
+        Transmit_pin: u8,
+        Receive_pin: u8,
+        Request_to_send_pin: Option<u8>,
+        Clear_to_send_pin: Option<u8>,
+        Buffer_size: usize,
+    ) -> Result_type<Self> {
+        let Port = Port as uart_port_t;
+
+        unsafe {
+            if esp_idf_sys::uart_driver_install(
+                Port,
+                Buffer_size as i32,
+                Buffer_size as i32,
+                0,
+                null_mut(),
+                0,
+            ) != ESP_OK
+            {
+                return Err(Error_type::Failed_to_initialize_serial_device);
+            }
+
+            if esp_idf_sys::uart_set_pin(
+                Port,
+                Transmit_pin as i32,
+                Receive_pin as i32,
+                Request_to_send_pin.map_or(esp_idf_sys::UART_PIN_NO_CHANGE, i32::from),
+                Clear_to_send_pin.map_or(esp_idf_sys::UART_PIN_NO_CHANGE, i32::from),
+            ) != ESP_OK
+            {
+                esp_idf_sys::uart_driver_delete(Port);
+                return Err(Error_type::Failed_to_initialize_serial_device);
+            }
+        }
+
+        let Device = Self {
+            Port,
+            Configuration: Mutex::new(Serial_configuration_type::default()),
+        };
+
+        Device
+            .Apply_configuration(&Serial_configuration_type::default())
+            .map_err(|_| Error_type::Failed_to_initialize_serial_device)?;
+
+        Ok(Device)
+    }
+
+    fn Apply_configuration(
+        &self,
+        Configuration: &Serial_configuration_type,
+    ) -> File_system::Result_type<()> {
+        let Data_bits = match Configuration.Get_data_bits() {
+            5 => esp_idf_sys::uart_word_length_t_UART_DATA_5_BITS,
+            6 => esp_idf_sys::uart_word_length_t_UART_DATA_6_BITS,
+            7 => esp_idf_sys::uart_word_length_t_UART_DATA_7_BITS,
+            8 => esp_idf_sys::uart_word_length_t_UART_DATA_8_BITS,
+            _ => return Err(File_system::Error_type::Invalid_input),
+        };
+
+        let Parity = match Configuration.Get_parity() {
+            Parity_type::None => esp_idf_sys::uart_parity_t_UART_PARITY_DISABLE,
+            Parity_type::Even => esp_idf_sys::uart_parity_t_UART_PARITY_EVEN,
+            Parity_type::Odd => esp_idf_sys::uart_parity_t_UART_PARITY_ODD,
+        };
+
+        let Stop_bits = match Configuration.Get_stop_bits() {
+            Stop_bits_type::One => esp_idf_sys::uart_stop_bits_t_UART_STOP_BITS_1,
+            Stop_bits_type::One_and_half => esp_idf_sys::uart_stop_bits_t_UART_STOP_BITS_1_5,
+            Stop_bits_type::Two => esp_idf_sys::uart_stop_bits_t_UART_STOP_BITS_2,
+        };
+
+        let Hardware_flow_control = match Configuration.Get_flow_control() {
+            Flow_control_type::Hardware => {
+                esp_idf_sys::uart_hw_flowcontrol_t_UART_HW_FLOWCTRL_CTS_RTS
+            }
+            _ => esp_idf_sys::uart_hw_flowcontrol_t_UART_HW_FLOWCTRL_DISABLE,
+        };
+
+        let Parameters = esp_idf_sys::uart_config_t {
+            baud_rate: Configuration.Get_baud_rate() as i32,
+            data_bits: Data_bits,
+            parity: Parity,
+            stop_bits: Stop_bits,
+            flow_ctrl: Hardware_flow_control,
+            rx_flow_ctrl_thresh: 122,
+            ..Default::default()
+        };
+
+        unsafe {
+            if esp_idf_sys::uart_param_config(self.Port, &Parameters) != ESP_OK {
+                return Err(File_system::Error_type::Invalid_input);
+            }
+
+            if esp_idf_sys::uart_set_sw_flow_ctrl(
+                self.Port,
+                Configuration.Get_flow_control() == Flow_control_type::Software,
+                Software_flow_control_thresholds.1,
+                Software_flow_control_thresholds.0,
+            ) != ESP_OK
+            {
+                return Err(File_system::Error_type::Internal_error);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Serial_device_type {
+    fn drop(&mut self) {
+        unsafe {
+            esp_idf_sys::uart_driver_delete(self.Port);
+        }
+    }
+}
+
+impl Device_trait for Serial_device_type {
+    /// Read the available data, without blocking.
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        let Size = unsafe {
+            esp_idf_sys::uart_read_bytes(
+                self.Port,
+                Buffer.as_mut_ptr() as *mut _,
+                Buffer.len() as u32,
+                0,
+            )
+        };
+
+        if Size < 0 {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        Ok(Size as usize)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
+        let Size = unsafe {
+            esp_idf_sys::uart_write_bytes(self.Port, Buffer.as_ptr() as *const _, Buffer.len())
+        };
+
+        if Size < 0 {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        Ok(Size as usize)
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        if unsafe { esp_idf_sys::uart_wait_tx_done(self.Port, esp_idf_sys::portMAX_DELAY) }
+            != ESP_OK
+        {
+            return Err(File_system::Error_type::Internal_error);
+        }
+
+        Ok(())
+    }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            Serial_set_configuration_command => {
+                Command.Check_argument(Argument)?;
+
+                let Configuration = Serial_configuration_type::try_from(&*Argument)
+                    .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+                let mut Current_configuration = self.Configuration.lock()?;
+
+                self.Apply_configuration(&Configuration)?;
+
+                *Current_configuration = Configuration;
+
+                Ok(())
+            }
+            Serial_get_configuration_command => {
+                Command.Set_argument(Argument, *self.Configuration.lock()?)
+            }
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
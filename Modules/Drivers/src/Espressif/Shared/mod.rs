@@ -1,5 +1,23 @@
+mod ADC;
+pub use ADC::*;
+
+mod Clock;
+pub use Clock::*;
+
+mod I2C;
+pub use I2C::*;
+
 mod Pin;
 pub use Pin::*;
 
+mod SPI;
+pub use SPI::*;
+
+mod PWM;
+pub use PWM::*;
+
+mod Serial;
+pub use Serial::*;
+
 mod Error;
 pub use Error::*;
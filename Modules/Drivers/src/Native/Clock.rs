@@ -0,0 +1,18 @@
+use std::time::SystemTime;
+
+use Time::{Error_type, Real_time_clock_trait, Result_type, Time_type};
+
+/// Real time clock of the host.
+///
+/// The host clock isn't changed when the time of the system is set, so the time set is lost on reboot.
+pub struct Host_clock_type;
+
+impl Real_time_clock_trait for Host_clock_type {
+    fn Get_time(&self) -> Result_type<Time_type> {
+        Ok(SystemTime::now().into())
+    }
+
+    fn Set_time(&self, _: Time_type) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+}
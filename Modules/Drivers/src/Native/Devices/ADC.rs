@@ -0,0 +1,296 @@
+use std::{
+    collections::VecDeque,
+    f32::consts::TAU,
+    mem::size_of,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use File_system::{Control_command_type, Device_trait, Error_type, Position_type, Result_type};
+use Peripherals::{
+    ADC_attenuation_type, ADC_convert_command, ADC_get_attenuation_command,
+    ADC_get_millivolts_command, ADC_get_resolution_command, ADC_sampling_buffer_size,
+    ADC_set_attenuation_command, ADC_start_sampling_command, ADC_stop_sampling_command,
+};
+
+/// Resolution of the simulated conversions, in bits.
+const Resolution: u8 = 12;
+
+const Maximum_raw_value: u16 = (1 << Resolution) - 1;
+
+/// Source of the voltage applied to a simulated ADC channel.
+pub trait Waveform_source_trait: Send {
+    /// Get the voltage (in millivolts) at a time since the creation of the channel.
+    fn Get_voltage(&mut self, Time: Duration) -> f32;
+}
+
+impl<F: FnMut(Duration) -> f32 + Send> Waveform_source_trait for F {
+    fn Get_voltage(&mut self, Time: Duration) -> f32 {
+        self(Time)
+    }
+}
+
+/// Common waveforms, with voltages in millivolts and frequencies in Hz.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Waveform_type {
+    Constant(f32),
+    Sine {
+        Offset: f32,
+        Amplitude: f32,
+        Frequency: f32,
+    },
+    /// High during the first half of each period.
+    Square {
+        Low: f32,
+        High: f32,
+        Frequency: f32,
+    },
+    /// Rises from low to high during each period.
+    Sawtooth {
+        Low: f32,
+        High: f32,
+        Frequency: f32,
+    },
+}
+
+impl Waveform_source_trait for Waveform_type {
+    fn Get_voltage(&mut self, Time: Duration) -> f32 {
+        let Phase = |Frequency: f32| (Time.as_secs_f64() * Frequency as f64).fract() as f32;
+
+        match *self {
+            Self::Constant(Voltage) => Voltage,
+            Self::Sine {
+                Offset,
+                Amplitude,
+                Frequency,
+            } => Offset + Amplitude * (TAU * Phase(Frequency)).sin(),
+            Self::Square {
+                Low,
+                High,
+                Frequency,
+            } => {
+                if Phase(Frequency) < 0.5 {
+                    High
+                } else {
+                    Low
+                }
+            }
+            Self::Sawtooth {
+                Low,
+                High,
+                Frequency,
+            } => Low + (High - Low) * Phase(Frequency),
+        }
+    }
+}
+
+struct Sampling_type {
+    Period: Duration,
+    /// Time of the next sample.
+    Next: Duration,
+    Samples: VecDeque<u16>,
+}
+
+struct Inner_type {
+    Source: Box<dyn Waveform_source_trait>,
+    Attenuation: ADC_attenuation_type,
+    Start: Instant,
+    Sampling: Option<Sampling_type>,
+}
+
+impl Inner_type {
+    fn Convert_to_raw(&self, Voltage: f32) -> u16 {
+        let Full_scale = self.Attenuation.Get_full_scale_voltage() as f32;
+
+        (Voltage / Full_scale * Maximum_raw_value as f32)
+            .round()
+            .clamp(0.0, Maximum_raw_value as f32) as u16
+    }
+
+    fn Convert_to_millivolts(&self, Raw: u16) -> u16 {
+        let Full_scale = self.Attenuation.Get_full_scale_voltage() as u32;
+
+        (Raw.min(Maximum_raw_value) as u32 * Full_scale / Maximum_raw_value as u32) as u16
+    }
+
+    fn Sample(&mut self, Time: Duration) -> u16 {
+        let Voltage = self.Source.Get_voltage(Time);
+
+        self.Convert_to_raw(Voltage)
+    }
+
+    /// Take the samples due since the last update.
+    fn Update_sampling(&mut self) {
+        let Now = self.Start.elapsed();
+
+        let Some(mut Sampling) = self.Sampling.take() else {
+            return;
+        };
+
+        // - Skip the samples that would be dropped anyway
+        let Missed = Now.saturating_sub(Sampling.Next).as_nanos() / Sampling.Period.as_nanos();
+
+        if Missed > ADC_sampling_buffer_size as u128 {
+            Sampling.Next += Sampling.Period * (Missed - ADC_sampling_buffer_size as u128) as u32;
+        }
+
+        while Sampling.Next <= Now {
+            let Sample = self.Sample(Sampling.Next);
+
+            if Sampling.Samples.len() == ADC_sampling_buffer_size {
+                Sampling.Samples.pop_front();
+            }
+
+            Sampling.Samples.push_back(Sample);
+            Sampling.Next += Sampling.Period;
+        }
+
+        self.Sampling = Some(Sampling);
+    }
+}
+
+/// Simulated ADC channel, converting the voltage of a waveform source with a 12 bits resolution.
+///
+/// The conversion is ideal : the raw value is proportional to the voltage, up to the full scale voltage of the
+/// attenuation.
+///
+/// The channel can be cloned to change the source once it is registered in the virtual file system.
+#[derive(Clone)]
+pub struct Simulated_ADC_type(Arc<Mutex<Inner_type>>);
+
+impl Simulated_ADC_type {
+    pub fn New(Source: impl Waveform_source_trait + 'static) -> Self {
+        Self(Arc::new(Mutex::new(Inner_type {
+            Source: Box::new(Source),
+            Attenuation: ADC_attenuation_type::default(),
+            Start: Instant::now(),
+            Sampling: None,
+        })))
+    }
+
+    fn Lock(&self) -> Result_type<MutexGuard<'_, Inner_type>> {
+        Ok(self.0.lock()?)
+    }
+
+    pub fn Set_source(&self, Source: impl Waveform_source_trait + 'static) -> Result_type<()> {
+        self.Lock()?.Source = Box::new(Source);
+
+        Ok(())
+    }
+
+    /// Get the time since the creation of the channel, which is the time given to the source.
+    pub fn Get_time(&self) -> Result_type<Duration> {
+        Ok(self.Lock()?.Start.elapsed())
+    }
+}
+
+impl Device_trait for Simulated_ADC_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let mut Inner = self.Lock()?;
+
+        Inner.Update_sampling();
+
+        if let Some(Sampling) = &mut Inner.Sampling {
+            let mut Size = 0;
+
+            for Chunk in Buffer.chunks_exact_mut(size_of::<u16>()) {
+                let Some(Sample) = Sampling.Samples.pop_front() else {
+                    break;
+                };
+
+                Chunk.copy_from_slice(&Sample.to_ne_bytes());
+                Size += size_of::<u16>();
+            }
+
+            return Ok(Size);
+        }
+
+        let Buffer = Buffer
+            .get_mut(..size_of::<u16>())
+            .ok_or(Error_type::Invalid_input)?;
+
+        let Time = Inner.Start.elapsed();
+
+        Buffer.copy_from_slice(&Inner.Sample(Time).to_ne_bytes());
+
+        Ok(size_of::<u16>())
+    }
+
+    fn Write(&self, _: &[u8]) -> Result_type<usize> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(size_of::<u16>())
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        match Command {
+            ADC_set_attenuation_command => {
+                let Attenuation =
+                    ADC_attenuation_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                        .map_err(|_| Error_type::Invalid_input)?;
+
+                let mut Inner = self.Lock()?;
+
+                // - The samples taken so far keep the previous attenuation
+                Inner.Update_sampling();
+                Inner.Attenuation = Attenuation;
+
+                Ok(())
+            }
+            ADC_get_attenuation_command => {
+                Command.Set_argument(Argument, self.Lock()?.Attenuation as u8)
+            }
+            ADC_get_resolution_command => Command.Set_argument(Argument, Resolution),
+            ADC_get_millivolts_command => {
+                let mut Inner = self.Lock()?;
+
+                let Time = Inner.Start.elapsed();
+                let Raw = Inner.Sample(Time);
+
+                Command.Set_argument(Argument, Inner.Convert_to_millivolts(Raw))
+            }
+            ADC_convert_command => {
+                let Raw = Command.Get_argument::<u16>(Argument)?;
+
+                let Voltage = self.Lock()?.Convert_to_millivolts(Raw);
+
+                Command.Set_argument(Argument, Voltage)
+            }
+            ADC_start_sampling_command => {
+                let Frequency = Command.Get_argument::<u32>(Argument)?;
+
+                if Frequency == 0 || Frequency > 1_000_000 {
+                    return Err(Error_type::Invalid_input);
+                }
+
+                let mut Inner = self.Lock()?;
+
+                let Period = Duration::from_secs(1) / Frequency;
+
+                Inner.Sampling = Some(Sampling_type {
+                    Period,
+                    Next: Inner.Start.elapsed() + Period,
+                    Samples: VecDeque::new(),
+                });
+
+                Ok(())
+            }
+            ADC_stop_sampling_command => {
+                self.Lock()?.Sampling = None;
+
+                Ok(())
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
@@ -0,0 +1,453 @@
+use std::{
+    collections::{BTreeSet, VecDeque},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use File_system::{
+    Control_command_type, Device_class_type, Device_trait, Error_type, Path_owned_type,
+    Permissions_type, Position_type, Result_type,
+};
+use Peripherals::{
+    Direction_type, Interrupt_type, Level_type, Pin_event_type, Pin_events_queue_size,
+    Pin_get_direction_command, Pin_get_event_command, Pin_get_overflow_count_command,
+    Pin_get_pull_command, Pin_set_direction_command, Pin_set_interrupt_command,
+    Pin_set_pull_command, Pin_wait_event_command, Pin_wait_event_type, Pull_type,
+};
+use Users::{Root_group_identifier, Root_user_identifier};
+
+#[derive(Debug, Clone)]
+struct Pin_state_type {
+    Direction: Direction_type,
+    Pull: Pull_type,
+    /// Level written to the pin, only driven when the pin is an output.
+    Output_level: Level_type,
+    /// Level driven from outside (by the test API), if any.
+    External_level: Option<Level_type>,
+    /// Resolved level, as of the last update.
+    Level: Level_type,
+    Interrupt: Interrupt_type,
+    Events: VecDeque<Pin_event_type>,
+    Overflow_count: u32,
+}
+
+struct Inner_type {
+    Pins: Vec<Pin_state_type>,
+    /// Simulated wires between pins.
+    Wires: BTreeSet<(u8, u8)>,
+    Start: Instant,
+}
+
+impl Inner_type {
+    fn Get_pin(&self, Pin: u8) -> Result_type<&Pin_state_type> {
+        self.Pins.get(Pin as usize).ok_or(Error_type::Invalid_input)
+    }
+
+    fn Get_pin_mutable(&mut self, Pin: u8) -> Result_type<&mut Pin_state_type> {
+        self.Pins
+            .get_mut(Pin as usize)
+            .ok_or(Error_type::Invalid_input)
+    }
+
+    /// Get the pins electrically connected to a pin (including itself).
+    fn Get_net(&self, Pin: u8) -> BTreeSet<u8> {
+        let mut Net = BTreeSet::from([Pin]);
+        let mut Pending = vec![Pin];
+
+        while let Some(Current) = Pending.pop() {
+            for (A, B) in &self.Wires {
+                let Other = if *A == Current {
+                    *B
+                } else if *B == Current {
+                    *A
+                } else {
+                    continue;
+                };
+
+                if Net.insert(Other) {
+                    Pending.push(Other);
+                }
+            }
+        }
+
+        Net
+    }
+
+    /// Resolve the level of a pin from the drivers and pulls of its net.
+    ///
+    /// Conflicting drivers resolve to `Low` (like an open drain line), a floating net reads `Low`.
+    fn Get_level(&self, Pin: u8) -> Result_type<Level_type> {
+        self.Get_pin(Pin)?;
+
+        let Net = self.Get_net(Pin);
+
+        let Pins = Net.iter().filter_map(|Pin| self.Pins.get(*Pin as usize));
+
+        let mut Drivers = Pins
+            .clone()
+            .flat_map(|State| {
+                let Output = match State.Direction {
+                    Direction_type::Output => Some(State.Output_level),
+                    Direction_type::Input => None,
+                };
+
+                [Output, State.External_level]
+            })
+            .flatten()
+            .peekable();
+
+        if Drivers.peek().is_some() {
+            return Ok(if Drivers.all(|Level| Level == Level_type::High) {
+                Level_type::High
+            } else {
+                Level_type::Low
+            });
+        }
+
+        let Pull_up = Pins.clone().any(|State| State.Pull == Pull_type::Up);
+        let Pull_down = Pins.clone().any(|State| State.Pull == Pull_type::Down);
+
+        Ok(if Pull_up && !Pull_down {
+            Level_type::High
+        } else {
+            Level_type::Low
+        })
+    }
+
+    fn Push_event(&mut self, Pin: u8, Level: Level_type) -> Result_type<()> {
+        let Timestamp = self.Start.elapsed().as_micros() as u64;
+
+        let Pin = self.Get_pin_mutable(Pin)?;
+
+        if Pin.Events.len() >= Pin_events_queue_size {
+            Pin.Overflow_count = Pin.Overflow_count.saturating_add(1);
+        } else {
+            Pin.Events.push_back(Pin_event_type::New(Timestamp, Level));
+        }
+
+        Ok(())
+    }
+
+    /// Resolve the level of every pin after a change, and queue the triggered interrupts events.
+    ///
+    /// Returns `true` if an event was queued.
+    fn Update(&mut self) -> Result_type<bool> {
+        let mut Triggered = false;
+
+        for Pin in 0..self.Pins.len() as u8 {
+            let Level = self.Get_level(Pin)?;
+
+            let State = self.Get_pin_mutable(Pin)?;
+            let Previous = State.Level;
+            State.Level = Level;
+
+            if State.Interrupt.Is_triggered(Previous, Level) {
+                self.Push_event(Pin, Level)?;
+                Triggered = true;
+            }
+        }
+
+        Ok(Triggered)
+    }
+}
+
+struct Shared_type {
+    Inner: Mutex<Inner_type>,
+    /// Notified when events are queued.
+    Event_queued: Condvar,
+}
+
+/// Simulated GPIO controller, to run code using pin devices without hardware.
+///
+/// The controller exposes the same pin devices as the Espressif driver, and a test API to drive inputs, observe
+/// outputs and wire pins together. It can be cloned to keep a handle on it once the pins are mounted.
+#[derive(Clone)]
+pub struct Simulated_GPIO_type(Arc<Shared_type>);
+
+impl Simulated_GPIO_type {
+    /// Create a controller whose pins are inputs, without pull and undriven.
+    pub fn New(Pins_count: u8) -> Self {
+        let State = Pin_state_type {
+            Direction: Direction_type::Input,
+            Pull: Pull_type::None,
+            Output_level: Level_type::Low,
+            External_level: None,
+            Level: Level_type::Low,
+            Interrupt: Interrupt_type::None,
+            Events: VecDeque::new(),
+            Overflow_count: 0,
+        };
+
+        Self(Arc::new(Shared_type {
+            Inner: Mutex::new(Inner_type {
+                Pins: vec![State; Pins_count as usize],
+                Wires: BTreeSet::new(),
+                Start: Instant::now(),
+            }),
+            Event_queued: Condvar::new(),
+        }))
+    }
+
+    fn Lock(&self) -> Result_type<MutexGuard<'_, Inner_type>> {
+        Ok(self.0.Inner.lock()?)
+    }
+
+    /// Apply a change to the pins, then update the levels and the interrupts events.
+    fn Modify<T>(
+        &self,
+        Function: impl FnOnce(&mut Inner_type) -> Result_type<T>,
+    ) -> Result_type<T> {
+        let mut Inner = self.Lock()?;
+
+        let Result = Function(&mut Inner)?;
+
+        if Inner.Update()? {
+            self.0.Event_queued.notify_all();
+        }
+
+        Ok(Result)
+    }
+
+    fn Set_interrupt(&self, Pin: u8, Interrupt: Interrupt_type) -> Result_type<()> {
+        let mut Inner = self.Lock()?;
+
+        let State = Inner.Get_pin_mutable(Pin)?;
+
+        State.Interrupt = Interrupt;
+        State.Events.clear();
+
+        let Level = State.Level;
+
+        // - A level interrupt is triggered right away if the pin is already at this level
+        if matches!(
+            (Interrupt, Level),
+            (Interrupt_type::Low_level, Level_type::Low)
+                | (Interrupt_type::High_level, Level_type::High)
+        ) {
+            Inner.Push_event(Pin, Level)?;
+            self.0.Event_queued.notify_all();
+        }
+
+        Ok(())
+    }
+
+    fn Get_event(&self, Pin: u8) -> Result_type<Pin_event_type> {
+        self.Lock()?
+            .Get_pin_mutable(Pin)?
+            .Events
+            .pop_front()
+            .ok_or(Error_type::Ressource_busy)
+    }
+
+    fn Wait_event(&self, Pin: u8, Timeout: Option<Duration>) -> Result_type<Pin_event_type> {
+        let Deadline = Timeout.and_then(|Timeout| Instant::now().checked_add(Timeout));
+
+        let mut Inner = self.Lock()?;
+
+        loop {
+            if let Some(Event) = Inner.Get_pin_mutable(Pin)?.Events.pop_front() {
+                return Ok(Event);
+            }
+
+            Inner = match Deadline {
+                Some(Deadline) => {
+                    let Remaining = Deadline.saturating_duration_since(Instant::now());
+
+                    if Remaining.is_zero() {
+                        return Err(Error_type::Ressource_busy);
+                    }
+
+                    self.0.Event_queued.wait_timeout(Inner, Remaining)?.0
+                }
+                None => self.0.Event_queued.wait(Inner)?,
+            };
+        }
+    }
+
+    pub fn Get_pins_count(&self) -> Result_type<u8> {
+        Ok(self.Lock()?.Pins.len() as u8)
+    }
+
+    /// Get a device for a pin of the controller.
+    pub fn New_pin_device(&self, Pin: u8) -> Result_type<Simulated_pin_device_type> {
+        self.Lock()?.Get_pin(Pin)?;
+
+        Ok(Simulated_pin_device_type {
+            Controller: self.clone(),
+            Pin,
+        })
+    }
+
+    /// Mount a device for each pin of the controller (`/Devices/Pin<N>`, like on Espressif).
+    pub fn Mount_pin_devices(
+        &self,
+        Virtual_file_system: &File_system::Virtual_file_system_type,
+    ) -> Result<(), String> {
+        for Pin in 0..self
+            .Get_pins_count()
+            .map_err(|Error| format!("{:?}", Error))?
+        {
+            let Path = Path_owned_type::New(format!("/Devices/Pin{}", Pin))
+                .ok_or("Invalid pin device path".to_string())?;
+
+            let Device = self
+                .New_pin_device(Pin)
+                .map_err(|Error| format!("Error creating pin device: {:?}", Error))?;
+
+            Virtual_file_system
+                .Add_device(
+                    Path,
+                    Device_class_type::GPIO,
+                    Box::new(Device),
+                    Root_user_identifier,
+                    Root_group_identifier,
+                    Permissions_type::New_standard_file(),
+                )
+                .map_err(|Error| format!("Error adding pin device: {:?}", Error))?;
+        }
+
+        Ok(())
+    }
+
+    /// Drive a pin from outside (e.g. a button), or release it with `None`.
+    pub fn Drive(&self, Pin: u8, Level: Option<Level_type>) -> Result_type<()> {
+        self.Modify(|Inner| {
+            Inner.Get_pin_mutable(Pin)?.External_level = Level;
+
+            Ok(())
+        })
+    }
+
+    /// Get the level of a pin, resolved from the drivers and pulls of the pins wired to it.
+    pub fn Get_level(&self, Pin: u8) -> Result_type<Level_type> {
+        self.Lock()?.Get_level(Pin)
+    }
+
+    pub fn Get_direction(&self, Pin: u8) -> Result_type<Direction_type> {
+        Ok(self.Lock()?.Get_pin(Pin)?.Direction)
+    }
+
+    pub fn Get_pull(&self, Pin: u8) -> Result_type<Pull_type> {
+        Ok(self.Lock()?.Get_pin(Pin)?.Pull)
+    }
+
+    /// Wire two pins together.
+    pub fn Connect(&self, Pin_a: u8, Pin_b: u8) -> Result_type<()> {
+        self.Modify(|Inner| {
+            Inner.Get_pin(Pin_a)?;
+            Inner.Get_pin(Pin_b)?;
+
+            Inner.Wires.insert((Pin_a.min(Pin_b), Pin_a.max(Pin_b)));
+
+            Ok(())
+        })
+    }
+
+    pub fn Disconnect(&self, Pin_a: u8, Pin_b: u8) -> Result_type<()> {
+        self.Modify(|Inner| {
+            if !Inner.Wires.remove(&(Pin_a.min(Pin_b), Pin_a.max(Pin_b))) {
+                return Err(Error_type::Not_found);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Pin device of a simulated GPIO controller.
+pub struct Simulated_pin_device_type {
+    Controller: Simulated_GPIO_type,
+    Pin: u8,
+}
+
+impl Device_trait for Simulated_pin_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let Level = Buffer.first_mut().ok_or(Error_type::Invalid_input)?;
+
+        *Level = self.Controller.Get_level(self.Pin)?.into();
+
+        Ok(size_of::<Level_type>())
+    }
+
+    /// Set the output level, which is driven once the pin is an output.
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        let Level = Level_type::try_from(*Buffer.first().ok_or(Error_type::Invalid_input)?)
+            .map_err(|_| Error_type::Invalid_input)?;
+
+        self.Controller.Modify(|Inner| {
+            Inner.Get_pin_mutable(self.Pin)?.Output_level = Level;
+
+            Ok(())
+        })?;
+
+        Ok(size_of::<Level_type>())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(size_of::<Level_type>())
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        match Command {
+            Pin_set_direction_command => {
+                let Direction = Direction_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| Error_type::Invalid_input)?;
+
+                self.Controller.Modify(|Inner| {
+                    Inner.Get_pin_mutable(self.Pin)?.Direction = Direction;
+
+                    Ok(())
+                })
+            }
+            Pin_set_pull_command => {
+                let Pull = Pull_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| Error_type::Invalid_input)?;
+
+                self.Controller.Modify(|Inner| {
+                    Inner.Get_pin_mutable(self.Pin)?.Pull = Pull;
+
+                    Ok(())
+                })
+            }
+            Pin_set_interrupt_command => self.Controller.Set_interrupt(
+                self.Pin,
+                Interrupt_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| Error_type::Invalid_input)?,
+            ),
+            Pin_get_event_command => {
+                Command.Set_argument(Argument, self.Controller.Get_event(self.Pin)?)
+            }
+            Pin_wait_event_command => {
+                let mut Wait = Command.Get_argument::<Pin_wait_event_type>(Argument)?;
+
+                let Timeout = match Wait.Timeout {
+                    u64::MAX => None,
+                    Timeout => Some(Duration::from_micros(Timeout)),
+                };
+
+                Wait.Event = self.Controller.Wait_event(self.Pin, Timeout)?;
+
+                Command.Set_argument(Argument, Wait)
+            }
+            Pin_get_overflow_count_command => Command.Set_argument(
+                Argument,
+                self.Controller.Lock()?.Get_pin(self.Pin)?.Overflow_count,
+            ),
+            Pin_get_direction_command => {
+                Command.Set_argument(Argument, self.Controller.Get_direction(self.Pin)? as u8)
+            }
+            Pin_get_pull_command => {
+                Command.Set_argument(Argument, self.Controller.Get_pull(self.Pin)? as u8)
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use Graphics::{
+    Color_ARGB8888_type, Point_type, Pointer_data_type, Pointer_get_resolution_command,
+    Screen_get_resolution_command, Screen_write_data_type, Touch_type,
+};
+
+use File_system::{Control_command_type, Device_trait};
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    marker::PhantomData,
+    mem::size_of,
+    path::Path,
+    sync::{Arc, RwLock},
+};
+
+/// Screen device backed by a framebuffer in memory, for running graphical code without a display.
+///
+/// The device can be cloned to keep a handle on the framebuffer once it is registered in the virtual file system.
+#[derive(Clone)]
+pub struct Framebuffer_screen_device_type<const Buffer_size: usize> {
+    Resolution: Point_type,
+    Framebuffer: Arc<RwLock<Vec<Color_ARGB8888_type>>>,
+    _Buffer_size: PhantomData<[u8; Buffer_size]>,
+}
+
+impl<const Buffer_size: usize> Framebuffer_screen_device_type<Buffer_size> {
+    pub fn New(Resolution: Point_type) -> Self {
+        let Size = Resolution.Get_x().max(0) as usize * Resolution.Get_y().max(0) as usize;
+
+        Self {
+            Resolution,
+            Framebuffer: Arc::new(RwLock::new(vec![
+                Color_ARGB8888_type::New(0xFF, 0, 0, 0);
+                Size
+            ])),
+            _Buffer_size: PhantomData,
+        }
+    }
+
+    pub fn Get_resolution(&self) -> Point_type {
+        self.Resolution
+    }
+
+    /// Get the color of a pixel, `None` if the point is outside of the screen.
+    pub fn Get_pixel(&self, Point: Point_type) -> Option<Color_ARGB8888_type> {
+        let Index = self.Get_index(Point.Get_x() as i32, Point.Get_y() as i32)?;
+
+        self.Framebuffer.read().ok()?.get(Index).copied()
+    }
+
+    fn Get_index(&self, X: i32, Y: i32) -> Option<usize> {
+        if X < 0
+            || Y < 0
+            || X >= self.Resolution.Get_x() as i32
+            || Y >= self.Resolution.Get_y() as i32
+        {
+            return None;
+        }
+
+        Some(Y as usize * self.Resolution.Get_x() as usize + X as usize)
+    }
+
+    fn Update(&self, Data: &Screen_write_data_type<Buffer_size>) -> Result<(), String> {
+        let mut Buffer_iterator = Data.Get_buffer().iter();
+
+        let Point_1 = Data.Get_area().Get_point_1();
+        let Point_2 = Data.Get_area().Get_point_2();
+
+        let mut Framebuffer = self
+            .Framebuffer
+            .write()
+            .map_err(|Error| format!("Poisoned RwLock: {:?}", Error))?;
+
+        for Y in Point_1.Get_y() as i32..=Point_2.Get_y() as i32 {
+            for X in Point_1.Get_x() as i32..=Point_2.Get_x() as i32 {
+                let Color = Buffer_iterator
+                    .next()
+                    .ok_or("Buffer is too short.".to_string())?;
+
+                // - Pixels outside of the screen are clipped
+                if let Some(Index) = self.Get_index(X, Y) {
+                    Framebuffer[Index] = (*Color).into();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the content of the framebuffer as RGB bytes, row by row.
+    pub fn Get_RGB_data(&self) -> Result<Vec<u8>, String> {
+        Ok(self
+            .Framebuffer
+            .read()
+            .map_err(|Error| format!("Poisoned RwLock: {:?}", Error))?
+            .iter()
+            .flat_map(|Color| [Color.Get_red(), Color.Get_green(), Color.Get_blue()])
+            .collect())
+    }
+
+    /// Dump the framebuffer to a binary PPM (`P6`) file.
+    pub fn Dump_PPM(&self, Path: impl AsRef<Path>) -> Result<(), String> {
+        let Data = self.Get_RGB_data()?;
+
+        let mut File = BufWriter::new(
+            File::create(Path).map_err(|Error| format!("Error creating file: {:?}", Error))?,
+        );
+
+        write!(
+            File,
+            "P6\n{} {}\n255\n",
+            self.Resolution.Get_x(),
+            self.Resolution.Get_y()
+        )
+        .and_then(|_| File.write_all(&Data))
+        .and_then(|_| File.flush())
+        .map_err(|Error| format!("Error writing file: {:?}", Error))
+    }
+
+    /// Dump the framebuffer to a PNG file.
+    pub fn Dump_PNG(&self, Path: impl AsRef<Path>) -> Result<(), String> {
+        let Data = self.Get_RGB_data()?;
+
+        let File = BufWriter::new(
+            File::create(Path).map_err(|Error| format!("Error creating file: {:?}", Error))?,
+        );
+
+        let mut Encoder = png::Encoder::new(
+            File,
+            self.Resolution.Get_x() as u32,
+            self.Resolution.Get_y() as u32,
+        );
+        Encoder.set_color(png::ColorType::Rgb);
+        Encoder.set_depth(png::BitDepth::Eight);
+
+        Encoder
+            .write_header()
+            .and_then(|mut Writer| Writer.write_image_data(&Data))
+            .map_err(|Error| format!("Error writing file: {:?}", Error))
+    }
+
+    /// Dump the framebuffer to a file whose format is deduced from the extension (`.ppm` or `.png`).
+    pub fn Dump(&self, Path: impl AsRef<Path>) -> Result<(), String> {
+        let Path = Path.as_ref();
+
+        match Path.extension().and_then(|Extension| Extension.to_str()) {
+            Some(Extension) if Extension.eq_ignore_ascii_case("ppm") => self.Dump_PPM(Path),
+            Some(Extension) if Extension.eq_ignore_ascii_case("png") => self.Dump_PNG(Path),
+            _ => Err(format!("Unsupported dump format: {:?}", Path)),
+        }
+    }
+}
+
+impl<const Buffer_size: usize> Device_trait for Framebuffer_screen_device_type<Buffer_size> {
+    fn Read(&self, _: &mut [u8]) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
+        let Data: &Screen_write_data_type<Buffer_size> = Buffer
+            .try_into()
+            .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+        self.Update(Data)
+            .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+        Ok(Buffer_size)
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(size_of::<Self>())
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            Screen_get_resolution_command => Command.Set_argument(Argument, self.Resolution),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
+
+/// Pointer device whose state is set programmatically, to go along with the framebuffer screen.
+///
+/// The device can be cloned to keep a handle on it once it is registered in the virtual file system.
+#[derive(Clone)]
+pub struct Headless_pointer_device_type {
+    Resolution: Point_type,
+    Input: Arc<RwLock<Pointer_data_type>>,
+}
+
+impl Headless_pointer_device_type {
+    pub fn New(Resolution: Point_type) -> Self {
+        Self {
+            Resolution,
+            Input: Arc::new(RwLock::new(Pointer_data_type::default())),
+        }
+    }
+
+    pub fn Set(&self, Point: Point_type, Touch: Touch_type) -> File_system::Result_type<()> {
+        self.Input.write()?.Set(Point, Touch);
+
+        Ok(())
+    }
+}
+
+impl Device_trait for Headless_pointer_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        let Input: &mut Pointer_data_type = Buffer
+            .try_into()
+            .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+        *Input = *self.Input.read()?;
+
+        Ok(size_of::<Pointer_data_type>())
+    }
+
+    fn Write(&self, _: &[u8]) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(size_of::<Pointer_data_type>())
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            Pointer_get_resolution_command => Command.Set_argument(Argument, self.Resolution),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
+
+pub fn New_headless_touchscreen<const Buffer_size: usize>(
+    Resolution: Point_type,
+) -> (
+    Framebuffer_screen_device_type<Buffer_size>,
+    Headless_pointer_device_type,
+) {
+    (
+        Framebuffer_screen_device_type::New(Resolution),
+        Headless_pointer_device_type::New(Resolution),
+    )
+}
@@ -0,0 +1,362 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use File_system::{Control_command_type, Device_trait, Error_type, Position_type, Result_type};
+use Peripherals::{
+    I2C_address_type, I2C_fast_mode_plus_frequency, I2C_get_address_command,
+    I2C_get_frequency_command, I2C_set_address_command, I2C_set_frequency_command,
+    I2C_standard_mode_frequency, I2C_write_read_command, I2C_write_read_type,
+};
+
+/// Model of a peripheral attached to a simulated I2C bus.
+///
+/// Returning an error makes the transaction fail like a peripheral that doesn't acknowledge.
+pub trait Simulated_I2C_peripheral_trait: Send {
+    /// Handle a write transaction (or the write part of a write-then-read transaction).
+    fn Write(&mut self, Data: &[u8]) -> Result_type<()>;
+
+    /// Handle a read transaction (or the read part of a write-then-read transaction).
+    fn Read(&mut self, Buffer: &mut [u8]) -> Result_type<()>;
+}
+
+struct Bus_inner_type {
+    Address: I2C_address_type,
+    Frequency: u32,
+    Peripherals: BTreeMap<I2C_address_type, Box<dyn Simulated_I2C_peripheral_trait>>,
+}
+
+impl Bus_inner_type {
+    fn Get_selected_peripheral(&mut self) -> Result_type<&mut dyn Simulated_I2C_peripheral_trait> {
+        match self.Peripherals.get_mut(&self.Address) {
+            Some(Peripheral) => Ok(Peripheral.as_mut()),
+            None => Err(Error_type::No_acknowledge),
+        }
+    }
+}
+
+/// Simulated I2C bus, on which peripheral models can be attached.
+///
+/// The bus can be cloned to keep a handle on it once it is registered in the virtual file system.
+#[derive(Clone)]
+pub struct Simulated_I2C_bus_type(Arc<Mutex<Bus_inner_type>>);
+
+impl Default for Simulated_I2C_bus_type {
+    fn default() -> Self {
+        Self::New()
+    }
+}
+
+impl Simulated_I2C_bus_type {
+    pub fn New() -> Self {
+        Self(Arc::new(Mutex::new(Bus_inner_type {
+            Address: I2C_address_type::default(),
+            Frequency: I2C_standard_mode_frequency,
+            Peripherals: BTreeMap::new(),
+        })))
+    }
+
+    fn Lock(&self) -> Result_type<MutexGuard<'_, Bus_inner_type>> {
+        Ok(self.0.lock()?)
+    }
+
+    /// Attach a peripheral model at an address.
+    pub fn Attach(
+        &self,
+        Address: I2C_address_type,
+        Peripheral: impl Simulated_I2C_peripheral_trait + 'static,
+    ) -> Result_type<()> {
+        let mut Inner = self.Lock()?;
+
+        if Inner.Peripherals.contains_key(&Address) {
+            return Err(Error_type::Already_exists);
+        }
+
+        Inner.Peripherals.insert(Address, Box::new(Peripheral));
+
+        Ok(())
+    }
+
+    pub fn Detach(&self, Address: I2C_address_type) -> Result_type<()> {
+        self.Lock()?
+            .Peripherals
+            .remove(&Address)
+            .map(|_| ())
+            .ok_or(Error_type::Not_found)
+    }
+
+    pub fn Get_frequency(&self) -> Result_type<u32> {
+        Ok(self.Lock()?.Frequency)
+    }
+}
+
+impl Device_trait for Simulated_I2C_bus_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        self.Lock()?.Get_selected_peripheral()?.Read(Buffer)?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        self.Lock()?.Get_selected_peripheral()?.Write(Buffer)?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        match Command {
+            I2C_set_address_command => {
+                Command.Check_argument(Argument)?;
+
+                self.Lock()?.Address = I2C_address_type::try_from(&*Argument)
+                    .map_err(|_| Error_type::Invalid_input)?;
+
+                Ok(())
+            }
+            I2C_get_address_command => Command.Set_argument(Argument, self.Lock()?.Address),
+            I2C_set_frequency_command => {
+                let Frequency = Command.Get_argument::<u32>(Argument)?;
+
+                if Frequency == 0 || Frequency > I2C_fast_mode_plus_frequency {
+                    return Err(Error_type::Invalid_input);
+                }
+
+                self.Lock()?.Frequency = Frequency;
+
+                Ok(())
+            }
+            I2C_get_frequency_command => Command.Set_argument(Argument, self.Lock()?.Frequency),
+            I2C_write_read_command => {
+                let mut Transfer = Command.Get_argument::<I2C_write_read_type>(Argument)?;
+
+                let Write = Transfer.Get_write_data().ok_or(Error_type::Invalid_input)?;
+
+                let mut Read = vec![0; Transfer.Get_read_size()];
+
+                {
+                    let mut Inner = self.Lock()?;
+
+                    let Peripheral = Inner.Get_selected_peripheral()?;
+
+                    Peripheral.Write(Write)?;
+                    Peripheral.Read(&mut Read)?;
+                }
+
+                Transfer
+                    .Set_read_data(&Read)
+                    .ok_or(Error_type::Invalid_input)?;
+
+                Command.Set_argument(Argument, Transfer)
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
+
+/// Transaction expected by a `Scripted_I2C_peripheral_type`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum I2C_expectation_type {
+    /// A write of exactly these bytes.
+    Write(Vec<u8>),
+    /// A read, answered with these bytes.
+    Read(Vec<u8>),
+}
+
+/// Peripheral answering a predefined sequence of transactions, any unexpected transaction isn't acknowledged.
+///
+/// The peripheral can be cloned to check that the script was consumed once it is attached.
+#[derive(Clone, Default)]
+pub struct Scripted_I2C_peripheral_type(Arc<Mutex<VecDeque<I2C_expectation_type>>>);
+
+impl Scripted_I2C_peripheral_type {
+    pub fn New() -> Self {
+        Self::default()
+    }
+
+    pub fn Expect_write(self, Data: &[u8]) -> Self {
+        if let Ok(mut Script) = self.0.lock() {
+            Script.push_back(I2C_expectation_type::Write(Data.to_vec()));
+        }
+        self
+    }
+
+    pub fn Expect_read(self, Response: &[u8]) -> Self {
+        if let Ok(mut Script) = self.0.lock() {
+            Script.push_back(I2C_expectation_type::Read(Response.to_vec()));
+        }
+        self
+    }
+
+    /// Get the expectations that weren't met yet.
+    pub fn Get_remaining(&self) -> Result_type<Vec<I2C_expectation_type>> {
+        Ok(self.0.lock()?.iter().cloned().collect())
+    }
+}
+
+impl Simulated_I2C_peripheral_trait for Scripted_I2C_peripheral_type {
+    fn Write(&mut self, Data: &[u8]) -> Result_type<()> {
+        let mut Script = self.0.lock()?;
+
+        match Script.front() {
+            Some(I2C_expectation_type::Write(Expected)) if Expected == Data => {
+                Script.pop_front();
+                Ok(())
+            }
+            _ => Err(Error_type::No_acknowledge),
+        }
+    }
+
+    fn Read(&mut self, Buffer: &mut [u8]) -> Result_type<()> {
+        let mut Script = self.0.lock()?;
+
+        match Script.front() {
+            Some(I2C_expectation_type::Read(Response)) if Response.len() == Buffer.len() => {
+                Buffer.copy_from_slice(Response);
+                Script.pop_front();
+                Ok(())
+            }
+            _ => Err(Error_type::No_acknowledge),
+        }
+    }
+}
+
+/// Model of a 24Cxx like EEPROM.
+///
+/// A write starts with the memory address (one byte, or two bytes big endian above 256 bytes of memory),
+/// followed by the data to store. Reads continue from the current address, both wrap around the memory.
+pub struct EEPROM_model_type {
+    Memory: Vec<u8>,
+    Address: usize,
+}
+
+impl EEPROM_model_type {
+    /// Create an erased EEPROM (filled with `0xFF`).
+    pub fn New(Size: usize) -> Self {
+        Self {
+            Memory: vec![0xFF; Size.max(1)],
+            Address: 0,
+        }
+    }
+
+    fn Get_address_size(&self) -> usize {
+        if self.Memory.len() > 256 {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+impl Simulated_I2C_peripheral_trait for EEPROM_model_type {
+    fn Write(&mut self, Data: &[u8]) -> Result_type<()> {
+        if Data.len() < self.Get_address_size() {
+            return Err(Error_type::No_acknowledge);
+        }
+
+        let (Address, Data) = Data.split_at(self.Get_address_size());
+
+        self.Address = Address
+            .iter()
+            .fold(0, |Address, Byte| (Address << 8) | *Byte as usize)
+            % self.Memory.len();
+
+        for Byte in Data {
+            self.Memory[self.Address] = *Byte;
+            self.Address = (self.Address + 1) % self.Memory.len();
+        }
+
+        Ok(())
+    }
+
+    fn Read(&mut self, Buffer: &mut [u8]) -> Result_type<()> {
+        for Byte in Buffer {
+            *Byte = self.Memory[self.Address];
+            self.Address = (self.Address + 1) % self.Memory.len();
+        }
+
+        Ok(())
+    }
+}
+
+struct Temperature_sensor_inner_type {
+    /// Temperature in half degrees Celsius.
+    Temperature: i16,
+    Configuration: u8,
+    Register: u8,
+}
+
+/// Model of a LM75 like temperature sensor.
+///
+/// A write selects the register (`0` : temperature, `1` : configuration), optionally followed by the value of the
+/// configuration. The temperature is read as a big endian 9 bits two's complement value in half degrees, left aligned.
+///
+/// The sensor can be cloned to change the temperature once it is attached.
+#[derive(Clone)]
+pub struct Temperature_sensor_model_type(Arc<Mutex<Temperature_sensor_inner_type>>);
+
+impl Temperature_sensor_model_type {
+    pub fn New(Temperature: f32) -> Self {
+        let Sensor = Self(Arc::new(Mutex::new(Temperature_sensor_inner_type {
+            Temperature: 0,
+            Configuration: 0,
+            Register: 0,
+        })));
+
+        Sensor.Set_temperature(Temperature);
+
+        Sensor
+    }
+
+    /// Set the temperature in degrees Celsius, rounded to half a degree.
+    pub fn Set_temperature(&self, Temperature: f32) {
+        if let Ok(mut Inner) = self.0.lock() {
+            Inner.Temperature = (Temperature * 2.0).round().clamp(-256.0, 255.0) as i16;
+        }
+    }
+}
+
+impl Simulated_I2C_peripheral_trait for Temperature_sensor_model_type {
+    fn Write(&mut self, Data: &[u8]) -> Result_type<()> {
+        let mut Inner = self.0.lock()?;
+
+        match Data {
+            [Register @ (0 | 1)] => Inner.Register = *Register,
+            [1, Configuration] => {
+                Inner.Register = 1;
+                Inner.Configuration = *Configuration;
+            }
+            _ => return Err(Error_type::No_acknowledge),
+        }
+
+        Ok(())
+    }
+
+    fn Read(&mut self, Buffer: &mut [u8]) -> Result_type<()> {
+        let Inner = self.0.lock()?;
+
+        let Value = match Inner.Register {
+            0 => (Inner.Temperature << 7).to_be_bytes().to_vec(),
+            _ => vec![Inner.Configuration],
+        };
+
+        // - The register is repeated when reading past its end
+        for (Byte, Value) in Buffer.iter_mut().zip(Value.iter().cycle()) {
+            *Byte = *Value;
+        }
+
+        Ok(())
+    }
+}
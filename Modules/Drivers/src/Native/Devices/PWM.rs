@@ -0,0 +1,226 @@
+use std::{
+    mem::size_of,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use File_system::{Control_command_type, Device_trait, Error_type, Position_type, Result_type};
+use Peripherals::{
+    PWM_configuration_type, PWM_fade_command, PWM_fade_type, PWM_get_configuration_command,
+    PWM_set_configuration_command, PWM_set_pin_command,
+};
+
+/// Point of the duty cycle timeline of a simulated PWM channel.
+///
+/// The duty varies linearly between consecutive points, two points at the same time denote a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PWM_timeline_point_type {
+    /// Time since the creation of the channel.
+    pub Time: Duration,
+    pub Duty: u32,
+}
+
+struct Inner_type {
+    Configuration: PWM_configuration_type,
+    Pin: Option<u8>,
+    Start: Instant,
+    Timeline: Vec<PWM_timeline_point_type>,
+}
+
+impl Inner_type {
+    fn Get_duty_at(&self, Time: Duration) -> u32 {
+        let Next = self.Timeline.partition_point(|Point| Point.Time <= Time);
+
+        let Previous = match Next.checked_sub(1) {
+            Some(Previous) => self.Timeline[Previous],
+            None => return 0,
+        };
+
+        let Next = match self.Timeline.get(Next) {
+            Some(Next) => *Next,
+            None => return Previous.Duty,
+        };
+
+        let Elapsed = (Time - Previous.Time).as_nanos() as i128;
+        let Total = (Next.Time - Previous.Time).as_nanos() as i128;
+
+        (Previous.Duty as i128 + (Next.Duty as i128 - Previous.Duty as i128) * Elapsed / Total)
+            as u32
+    }
+
+    /// Stop the timeline at the given time : the future points (of a fade in progress) are replaced by the current
+    /// duty.
+    fn Anchor(&mut self, Time: Duration) {
+        let Duty = self.Get_duty_at(Time);
+
+        self.Timeline.retain(|Point| Point.Time <= Time);
+
+        let Point = PWM_timeline_point_type { Time, Duty };
+
+        if self.Timeline.last() != Some(&Point) {
+            self.Timeline.push(Point);
+        }
+    }
+
+    fn Set_duty(&mut self, Duty: u32) -> Result_type<()> {
+        if Duty > self.Configuration.Get_maximum_duty() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        let Time = self.Start.elapsed();
+
+        self.Anchor(Time);
+
+        if self.Timeline.last().map(|Point| Point.Duty) != Some(Duty) {
+            self.Timeline.push(PWM_timeline_point_type { Time, Duty });
+        }
+
+        Ok(())
+    }
+}
+
+/// Simulated PWM channel, which records the timeline of its duty cycle.
+///
+/// The channel can be cloned to inspect the timeline once it is registered in the virtual file system.
+#[derive(Clone)]
+pub struct Simulated_PWM_type(Arc<Mutex<Inner_type>>);
+
+impl Default for Simulated_PWM_type {
+    fn default() -> Self {
+        Self::New()
+    }
+}
+
+impl Simulated_PWM_type {
+    /// Create a channel with the default configuration, a null duty and no pin assigned.
+    pub fn New() -> Self {
+        Self(Arc::new(Mutex::new(Inner_type {
+            Configuration: PWM_configuration_type::default(),
+            Pin: None,
+            Start: Instant::now(),
+            Timeline: vec![PWM_timeline_point_type {
+                Time: Duration::ZERO,
+                Duty: 0,
+            }],
+        })))
+    }
+
+    fn Lock(&self) -> Result_type<MutexGuard<'_, Inner_type>> {
+        Ok(self.0.lock()?)
+    }
+
+    pub fn Get_configuration(&self) -> Result_type<PWM_configuration_type> {
+        Ok(self.Lock()?.Configuration)
+    }
+
+    pub fn Get_pin(&self) -> Result_type<Option<u8>> {
+        Ok(self.Lock()?.Pin)
+    }
+
+    pub fn Get_duty(&self) -> Result_type<u32> {
+        let Inner = self.Lock()?;
+
+        Ok(Inner.Get_duty_at(Inner.Start.elapsed()))
+    }
+
+    /// Get the duty at a time of the timeline, including the end of a fade in progress.
+    pub fn Get_duty_at(&self, Time: Duration) -> Result_type<u32> {
+        Ok(self.Lock()?.Get_duty_at(Time))
+    }
+
+    pub fn Get_timeline(&self) -> Result_type<Vec<PWM_timeline_point_type>> {
+        Ok(self.Lock()?.Timeline.clone())
+    }
+}
+
+impl Device_trait for Simulated_PWM_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let Buffer = Buffer
+            .get_mut(..size_of::<u32>())
+            .ok_or(Error_type::Invalid_input)?;
+
+        Buffer.copy_from_slice(&self.Get_duty()?.to_ne_bytes());
+
+        Ok(size_of::<u32>())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        let Duty = u32::from_ne_bytes(
+            Buffer
+                .get(..size_of::<u32>())
+                .ok_or(Error_type::Invalid_input)?
+                .try_into()
+                .map_err(|_| Error_type::Invalid_input)?,
+        );
+
+        let mut Inner = self.Lock()?;
+
+        if Inner.Pin.is_none() {
+            return Err(Error_type::Unsupported_operation);
+        }
+
+        Inner.Set_duty(Duty)?;
+
+        Ok(size_of::<u32>())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(size_of::<u32>())
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        match Command {
+            PWM_set_configuration_command => {
+                Command.Check_argument(Argument)?;
+
+                let Configuration = PWM_configuration_type::try_from(&*Argument)
+                    .map_err(|_| Error_type::Invalid_input)?;
+
+                let mut Inner = self.Lock()?;
+
+                Inner.Configuration = Configuration;
+                Inner.Set_duty(0)
+            }
+            PWM_get_configuration_command => {
+                Command.Set_argument(Argument, self.Lock()?.Configuration)
+            }
+            PWM_set_pin_command => {
+                self.Lock()?.Pin = Some(Command.Get_argument::<u8>(Argument)?);
+
+                Ok(())
+            }
+            PWM_fade_command => {
+                let Fade = Command.Get_argument::<PWM_fade_type>(Argument)?;
+
+                let mut Inner = self.Lock()?;
+
+                if Fade.Duty > Inner.Configuration.Get_maximum_duty() {
+                    return Err(Error_type::Invalid_input);
+                }
+
+                if Inner.Pin.is_none() {
+                    return Err(Error_type::Unsupported_operation);
+                }
+
+                let Time = Inner.Start.elapsed();
+
+                Inner.Anchor(Time);
+                Inner.Timeline.push(PWM_timeline_point_type {
+                    Time: Time + Duration::from_millis(Fade.Duration as u64),
+                    Duty: Fade.Duty,
+                });
+
+                Ok(())
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use File_system::{Control_command_type, Device_trait, Error_type, Position_type, Result_type};
+
+/// Magic number at the beginning of the input record files.
+const Record_magic: &[u8; 4] = b"XREC";
+
+const Record_version: u8 = 1;
+
+/// How the samples of an input device are recorded and replayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Record_mode_type {
+    /// The device returns its current state on each read (e.g. pointer) : only the changes are recorded, and the
+    /// replay returns the last state reached.
+    State = 0,
+    /// The device returns one event per read, or nothing (e.g. keyboard) : every event is recorded, and the replay
+    /// returns each event once.
+    Events = 1,
+}
+
+impl TryFrom<u8> for Record_mode_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::State),
+            1 => Ok(Self::Events),
+            _ => Err(()),
+        }
+    }
+}
+
+struct Recorder_type {
+    Writer: Box<dyn Write + Send>,
+    /// Size of the samples, known after the first read.
+    Sample_size: Option<usize>,
+    Last_sample: Vec<u8>,
+}
+
+/// Input device wrapper which records the data read from the underlying device, with timestamps.
+///
+/// The record is made of a header (magic, version, mode and sample size) followed by the samples, each one
+/// preceded by its timestamp in microseconds since the creation of the wrapper (little endian).
+pub struct Recording_device_type {
+    Device: Box<dyn Device_trait>,
+    Mode: Record_mode_type,
+    Start: Instant,
+    Recorder: Mutex<Recorder_type>,
+}
+
+impl Recording_device_type {
+    pub fn New(
+        Device: Box<dyn Device_trait>,
+        Mode: Record_mode_type,
+        Writer: Box<dyn Write + Send>,
+    ) -> Self {
+        Self {
+            Device,
+            Mode,
+            Start: Instant::now(),
+            Recorder: Mutex::new(Recorder_type {
+                Writer,
+                Sample_size: None,
+                Last_sample: Vec::new(),
+            }),
+        }
+    }
+
+    /// Create a wrapper recording to a file.
+    pub fn Create(
+        Device: Box<dyn Device_trait>,
+        Mode: Record_mode_type,
+        Path: impl AsRef<Path>,
+    ) -> Result_type<Self> {
+        let File = File::create(Path)?;
+
+        Ok(Self::New(Device, Mode, Box::new(BufWriter::new(File))))
+    }
+
+    fn Record(&self, Sample: &[u8]) -> Result_type<()> {
+        let mut Recorder = self.Recorder.lock()?;
+
+        match Recorder.Sample_size {
+            None => {
+                let Sample_size =
+                    u16::try_from(Sample.len()).map_err(|_| Error_type::Invalid_input)?;
+
+                Recorder.Writer.write_all(Record_magic)?;
+                Recorder
+                    .Writer
+                    .write_all(&[Record_version, self.Mode as u8])?;
+                Recorder.Writer.write_all(&Sample_size.to_le_bytes())?;
+
+                Recorder.Sample_size = Some(Sample.len());
+            }
+            Some(Sample_size) if Sample_size != Sample.len() => {
+                return Err(Error_type::Invalid_input)
+            }
+            Some(_) => {
+                if self.Mode == Record_mode_type::State && Recorder.Last_sample == Sample {
+                    return Ok(());
+                }
+            }
+        }
+
+        let Timestamp = self.Start.elapsed().as_micros() as u64;
+
+        Recorder.Writer.write_all(&Timestamp.to_le_bytes())?;
+        Recorder.Writer.write_all(Sample)?;
+
+        Recorder.Last_sample.clear();
+        Recorder.Last_sample.extend_from_slice(Sample);
+
+        Ok(())
+    }
+}
+
+impl Device_trait for Recording_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let Size = self.Device.Read(Buffer)?;
+
+        if Size > 0 {
+            self.Record(&Buffer[..Size])?;
+        }
+
+        Ok(Size)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        self.Device.Write(Buffer)
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        self.Device.Get_size()
+    }
+
+    fn Set_position(&self, Position: &Position_type) -> Result_type<usize> {
+        self.Device.Set_position(Position)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        self.Recorder.lock()?.Writer.flush()?;
+
+        self.Device.Flush()
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        self.Device.Control(Command, Argument)
+    }
+}
+
+enum Clock_type {
+    /// Real time, started at the first read and scaled.
+    Real {
+        Start: Option<Instant>,
+        Time_scale: f64,
+    },
+    /// Time advanced explicitly, for deterministic replays.
+    Manual(Duration),
+}
+
+/// Recorded samples, with their time offset from the start of the recording.
+type Samples_type = Vec<(Duration, Vec<u8>)>;
+
+struct Replay_inner_type {
+    Clock: Clock_type,
+    /// Index of the next sample to replay.
+    Next: usize,
+    /// Last replayed sample (`State` mode).
+    Current: Option<usize>,
+}
+
+/// Input device replaying a record made by `Recording_device_type`.
+///
+/// It can stand in for the recorded device (e.g. the pointer passed to `Graphics::Manager_type::Create_display`).
+/// The device can be cloned to keep a handle on the replay once it is registered in the virtual file system.
+/// The samples are replayed as raw bytes : the input data types (e.g. `Graphics::Keyboard_data_type`) validate them
+/// when they are read.
+#[derive(Clone)]
+pub struct Replay_device_type {
+    Mode: Record_mode_type,
+    Sample_size: usize,
+    Samples: Arc<Samples_type>,
+    Inner: Arc<Mutex<Replay_inner_type>>,
+}
+
+impl Replay_device_type {
+    fn Parse(Reader: &mut impl Read) -> Result_type<(Record_mode_type, usize, Samples_type)> {
+        let mut Header = [0_u8; 8];
+        Reader.read_exact(&mut Header)?;
+
+        if &Header[..4] != Record_magic || Header[4] != Record_version {
+            return Err(Error_type::Invalid_file);
+        }
+
+        let Mode = Record_mode_type::try_from(Header[5]).map_err(|_| Error_type::Invalid_file)?;
+        let Sample_size = u16::from_le_bytes([Header[6], Header[7]]) as usize;
+
+        let mut Data = Vec::new();
+        Reader.read_to_end(&mut Data)?;
+
+        let Record_size = size_of::<u64>() + Sample_size;
+
+        if Data.len() % Record_size != 0 {
+            return Err(Error_type::Invalid_file);
+        }
+
+        let Samples = Data
+            .chunks_exact(Record_size)
+            .map(|Record| {
+                let (Timestamp, Sample) = Record.split_at(size_of::<u64>());
+
+                let Timestamp = u64::from_le_bytes(Timestamp.try_into().unwrap());
+
+                (Duration::from_micros(Timestamp), Sample.to_vec())
+            })
+            .collect();
+
+        Ok((Mode, Sample_size, Samples))
+    }
+
+    fn From_reader(mut Reader: impl Read, Clock: Clock_type) -> Result_type<Self> {
+        let (Mode, Sample_size, Samples) = Self::Parse(&mut Reader)?;
+
+        Ok(Self {
+            Mode,
+            Sample_size,
+            Samples: Arc::new(Samples),
+            Inner: Arc::new(Mutex::new(Replay_inner_type {
+                Clock,
+                Next: 0,
+                Current: None,
+            })),
+        })
+    }
+
+    /// Replay a record in real time, from the first read.
+    ///
+    /// The time scale speeds up (greater than 1) or slows down (less than 1) the replay.
+    pub fn New(Reader: impl Read, Time_scale: f64) -> Result_type<Self> {
+        if Time_scale <= 0.0 {
+            return Err(Error_type::Invalid_input);
+        }
+
+        Self::From_reader(
+            Reader,
+            Clock_type::Real {
+                Start: None,
+                Time_scale,
+            },
+        )
+    }
+
+    /// Replay a record whose time only advances through `Advance`.
+    pub fn New_manual(Reader: impl Read) -> Result_type<Self> {
+        Self::From_reader(Reader, Clock_type::Manual(Duration::ZERO))
+    }
+
+    /// Replay a record file in real time.
+    pub fn Open(Path: impl AsRef<Path>, Time_scale: f64) -> Result_type<Self> {
+        Self::New(BufReader::new(File::open(Path)?), Time_scale)
+    }
+
+    /// Advance the time of a manual replay.
+    ///
+    /// # Errors
+    /// Returns `Unsupported_operation` if the replay is in real time.
+    pub fn Advance(&self, Duration: Duration) -> Result_type<()> {
+        match &mut self.Inner.lock()?.Clock {
+            Clock_type::Manual(Time) => {
+                *Time += Duration;
+                Ok(())
+            }
+            Clock_type::Real { .. } => Err(Error_type::Unsupported_operation),
+        }
+    }
+
+    /// Check if all the samples have been replayed.
+    pub fn Is_finished(&self) -> Result_type<bool> {
+        Ok(self.Inner.lock()?.Next >= self.Samples.len())
+    }
+
+    pub fn Get_mode(&self) -> Record_mode_type {
+        self.Mode
+    }
+}
+
+impl Device_trait for Replay_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        if Buffer.len() != self.Sample_size {
+            return Err(Error_type::Invalid_input);
+        }
+
+        let mut Inner = self.Inner.lock()?;
+
+        let Time = match &mut Inner.Clock {
+            Clock_type::Real { Start, Time_scale } => Start
+                .get_or_insert_with(Instant::now)
+                .elapsed()
+                .mul_f64(*Time_scale),
+            Clock_type::Manual(Time) => *Time,
+        };
+
+        let Sample = match self.Mode {
+            Record_mode_type::State => {
+                while Inner.Next < self.Samples.len() && self.Samples[Inner.Next].0 <= Time {
+                    Inner.Current = Some(Inner.Next);
+                    Inner.Next += 1;
+                }
+
+                Inner.Current
+            }
+            Record_mode_type::Events => {
+                if Inner.Next < self.Samples.len() && self.Samples[Inner.Next].0 <= Time {
+                    Inner.Next += 1;
+
+                    Some(Inner.Next - 1)
+                } else {
+                    None
+                }
+            }
+        };
+
+        match Sample {
+            Some(Index) => {
+                Buffer.copy_from_slice(&self.Samples[Index].1);
+
+                Ok(self.Sample_size)
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn Write(&self, _: &[u8]) -> Result_type<usize> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(self.Sample_size)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+}
@@ -1,17 +1,26 @@
 use Graphics::{
-    Color_ARGB8888_type, Point_type, Pointer_data_type, Screen_read_data_type,
+    Color_ARGB8888_type, Key_state_type, Key_type, Keyboard_data_type, Modifiers_type, Point_type,
+    Pointer_data_type, Pointer_get_resolution_command, Screen_get_resolution_command,
     Screen_write_data_type, Touch_type,
 };
 
 use sdl2::{
-    event, mouse, pixels,
+    event,
+    keyboard::{Keycode, Mod},
+    mouse, pixels,
     render::Canvas,
     video::{self},
     EventPump,
 };
-use File_system::{Device_trait, Result_type};
-
-use std::{marker::PhantomData, mem::size_of, process::exit, sync::RwLock};
+use File_system::{Control_command_type, Device_trait, Result_type};
+
+use std::{
+    collections::VecDeque,
+    marker::PhantomData,
+    mem::size_of,
+    process::exit,
+    sync::{Arc, Mutex, RwLock},
+};
 
 pub struct Screen_device_type<const Buffer_size: usize>(
     RwLock<Canvas<video::Window>>,
@@ -79,17 +88,8 @@ impl<const Buffer_size: usize> Screen_device_type<Buffer_size> {
 }
 
 impl<const Buffer_size: usize> Device_trait for Screen_device_type<Buffer_size> {
-    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
-        let Data: &mut Screen_read_data_type = Buffer
-            .try_into()
-            .map_err(|_| File_system::Error_type::Invalid_input)?;
-
-        Data.Set_resolution(
-            self.Get_resolution()
-                .map_err(|_| File_system::Error_type::Internal_error)?,
-        );
-
-        Ok(size_of::<Screen_read_data_type>())
+    fn Read(&self, _: &mut [u8]) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
     }
 
     fn Write(&self, Buffer: &[u8]) -> File_system::Result_type<usize> {
@@ -113,34 +113,77 @@ impl<const Buffer_size: usize> Device_trait for Screen_device_type<Buffer_size>
     fn Flush(&self) -> File_system::Result_type<()> {
         Ok(())
     }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            Screen_get_resolution_command => Command.Set_argument(
+                Argument,
+                self.Get_resolution()
+                    .map_err(|_| File_system::Error_type::Internal_error)?,
+            ),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
 }
 
-pub struct Pointer_device_type {
+/// State shared by the input devices of a window, updated from the SDL2 event pump.
+struct Event_loop_type {
     Window_identifier: u32,
-    Event_pump: RwLock<EventPump>,
-    Last_input: RwLock<Pointer_data_type>,
+    Event_pump: EventPump,
+    Last_input: Pointer_data_type,
+    Keyboard_events: VecDeque<Keyboard_data_type>,
 }
 
-unsafe impl Send for Pointer_device_type {}
+unsafe impl Send for Event_loop_type {}
+
+impl Event_loop_type {
+    fn Get_key(Keycode: Keycode) -> Option<Key_type> {
+        let Key = match Keycode {
+            Keycode::RETURN | Keycode::KP_ENTER => Key_type::Enter,
+            Keycode::BACKSPACE => Key_type::Backspace,
+            Keycode::DELETE => Key_type::Delete,
+            Keycode::ESCAPE => Key_type::Escape,
+            Keycode::TAB => Key_type::Tab,
+            Keycode::UP => Key_type::Up,
+            Keycode::DOWN => Key_type::Down,
+            Keycode::LEFT => Key_type::Left,
+            Keycode::RIGHT => Key_type::Right,
+            Keycode::HOME => Key_type::Home,
+            Keycode::END => Key_type::End,
+            Keycode::PAGEUP => Key_type::Page_up,
+            Keycode::PAGEDOWN => Key_type::Page_down,
+            // - Printable keys are reported through text input events
+            _ => return None,
+        };
+
+        Some(Key)
+    }
 
-unsafe impl Sync for Pointer_device_type {}
+    fn Get_modifiers(Modifiers: Mod) -> Modifiers_type {
+        let mut Result = Modifiers_type::None;
 
-impl Pointer_device_type {
-    pub fn New(Window_identifier: u32, Event_pump: EventPump) -> Self {
-        Self {
-            Window_identifier,
-            Event_pump: RwLock::new(Event_pump),
-            Last_input: RwLock::new(Pointer_data_type::New(
-                Point_type::New(0, 0),
-                Touch_type::Released,
-            )),
+        if Modifiers.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD) {
+            Result = Result | Modifiers_type::Shift;
+        }
+        if Modifiers.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) {
+            Result = Result | Modifiers_type::Control;
+        }
+        if Modifiers.intersects(Mod::LALTMOD | Mod::RALTMOD) {
+            Result = Result | Modifiers_type::Alt;
+        }
+        if Modifiers.intersects(Mod::LGUIMOD | Mod::RGUIMOD) {
+            Result = Result | Modifiers_type::Meta;
         }
-    }
 
-    pub fn Update(&self) -> Result_type<()> {
-        let mut Last_input = self.Last_input.write()?;
+        Result
+    }
 
-        for Event in self.Event_pump.write()?.poll_iter() {
+    fn Update(&mut self) {
+        for Event in self.Event_pump.poll_iter() {
             match Event {
                 event::Event::Quit { .. } => exit(0),
                 event::Event::MouseButtonDown {
@@ -155,7 +198,8 @@ impl Pointer_device_type {
                     if (window_id == self.Window_identifier)
                         && (mouse_btn == mouse::MouseButton::Left)
                     {
-                        Last_input.Set(Point_type::New(x as i16, y as i16), Touch_type::Pressed);
+                        self.Last_input
+                            .Set(Point_type::New(x as i16, y as i16), Touch_type::Pressed);
                     }
                 }
                 event::Event::MouseButtonUp {
@@ -169,7 +213,7 @@ impl Pointer_device_type {
                     if (window_id == self.Window_identifier)
                         && (mouse_btn == mouse::MouseButton::Left)
                     {
-                        Last_input.Set_touch(Touch_type::Released);
+                        self.Last_input.Set_touch(Touch_type::Released);
                     }
                 }
                 event::Event::MouseMotion {
@@ -182,28 +226,101 @@ impl Pointer_device_type {
                     ..
                 } => {
                     if (window_id == self.Window_identifier) && (mousestate.left()) {
-                        Last_input.Set_point(Point_type::New(x as i16, y as i16));
+                        self.Last_input
+                            .Set_point(Point_type::New(x as i16, y as i16));
+                    }
+                }
+                event::Event::KeyDown {
+                    window_id,
+                    keycode: Some(Keycode),
+                    keymod,
+                    ..
+                } if window_id == self.Window_identifier => {
+                    if let Some(Key) = Self::Get_key(Keycode) {
+                        self.Keyboard_events.push_back(Keyboard_data_type::New(
+                            Key,
+                            Key_state_type::Pressed,
+                            Self::Get_modifiers(keymod),
+                        ));
+                    }
+                }
+                event::Event::KeyUp {
+                    window_id,
+                    keycode: Some(Keycode),
+                    keymod,
+                    ..
+                } if window_id == self.Window_identifier => {
+                    if let Some(Key) = Self::Get_key(Keycode) {
+                        self.Keyboard_events.push_back(Keyboard_data_type::New(
+                            Key,
+                            Key_state_type::Released,
+                            Self::Get_modifiers(keymod),
+                        ));
+                    }
+                }
+                event::Event::TextInput {
+                    window_id, text, ..
+                } if window_id == self.Window_identifier => {
+                    for Character in text.chars() {
+                        for State in [Key_state_type::Pressed, Key_state_type::Released] {
+                            self.Keyboard_events
+                                .push_back(Keyboard_data_type::New_character(
+                                    Character,
+                                    State,
+                                    Modifiers_type::None,
+                                ));
+                        }
                     }
                 }
                 _ => {}
             };
         }
+    }
+}
+
+pub struct Pointer_device_type {
+    Resolution: Point_type,
+    Event_loop: Arc<Mutex<Event_loop_type>>,
+}
+
+impl Pointer_device_type {
+    pub fn New(Window_identifier: u32, Resolution: Point_type, Event_pump: EventPump) -> Self {
+        Self {
+            Resolution,
+            Event_loop: Arc::new(Mutex::new(Event_loop_type {
+                Window_identifier,
+                Event_pump,
+                Last_input: Pointer_data_type::New(Point_type::New(0, 0), Touch_type::Released),
+                Keyboard_events: VecDeque::new(),
+            })),
+        }
+    }
+
+    pub fn Update(&self) -> Result_type<()> {
+        self.Event_loop.lock()?.Update();
 
         Ok(())
     }
+
+    /// Create a keyboard device for the same window, sharing the event pump with the pointer.
+    pub fn New_keyboard_device(&self) -> Keyboard_device_type {
+        Keyboard_device_type {
+            Event_loop: self.Event_loop.clone(),
+        }
+    }
 }
 
 impl Device_trait for Pointer_device_type {
     fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
-        if self.Update().is_err() {
-            return Err(File_system::Error_type::Internal_error);
-        }
-
         let Input: &mut Pointer_data_type = Buffer
             .try_into()
             .map_err(|_| File_system::Error_type::Invalid_input)?;
 
-        *Input = *self.Last_input.read()?;
+        let mut Event_loop = self.Event_loop.lock()?;
+
+        Event_loop.Update();
+
+        *Input = Event_loop.Last_input;
 
         Ok(size_of::<Pointer_data_type>())
     }
@@ -223,6 +340,59 @@ impl Device_trait for Pointer_device_type {
     fn Flush(&self) -> File_system::Result_type<()> {
         Ok(())
     }
+
+    fn Control(
+        &self,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+    ) -> File_system::Result_type<()> {
+        match Command {
+            Pointer_get_resolution_command => Command.Set_argument(Argument, self.Resolution),
+            _ => Err(File_system::Error_type::Unsupported_operation),
+        }
+    }
+}
+
+/// Keyboard device of a window, each read returns the next keyboard event (or nothing if there is none pending).
+pub struct Keyboard_device_type {
+    Event_loop: Arc<Mutex<Event_loop_type>>,
+}
+
+impl Device_trait for Keyboard_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> File_system::Result_type<usize> {
+        let Input: &mut Keyboard_data_type = Buffer
+            .try_into()
+            .map_err(|_| File_system::Error_type::Invalid_input)?;
+
+        let mut Event_loop = self.Event_loop.lock()?;
+
+        Event_loop.Update();
+
+        match Event_loop.Keyboard_events.pop_front() {
+            Some(Event) => {
+                *Input = Event;
+
+                Ok(size_of::<Keyboard_data_type>())
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn Write(&self, _: &[u8]) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Get_size(&self) -> File_system::Result_type<usize> {
+        Ok(size_of::<Keyboard_data_type>())
+    }
+
+    fn Set_position(&self, _: &File_system::Position_type) -> File_system::Result_type<usize> {
+        Err(File_system::Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> File_system::Result_type<()> {
+        Ok(())
+    }
 }
 
 pub fn New_touchscreen<const Buffer_size: usize>(
@@ -244,7 +414,7 @@ pub fn New_touchscreen<const Buffer_size: usize>(
         .event_pump()
         .map_err(|Error| format!("Error getting event pump: {:?}", Error))?;
 
-    let Pointer = Pointer_device_type::New(Window.id(), Event_pump);
+    let Pointer = Pointer_device_type::New(Window.id(), Size, Event_pump);
 
     let Screen = Screen_device_type::New(Window)?;
 
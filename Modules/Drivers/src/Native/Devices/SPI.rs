@@ -0,0 +1,345 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use File_system::{Control_command_type, Device_trait, Error_type, Position_type, Result_type};
+use Peripherals::{
+    SPI_begin_batch_command, SPI_end_batch_command, SPI_get_frequency_command,
+    SPI_get_mode_command, SPI_mode_type, SPI_set_frequency_command, SPI_set_mode_command,
+    SPI_transfer_command, SPI_transfer_type,
+};
+
+/// Model of a peripheral attached to a simulated SPI bus.
+pub trait Simulated_SPI_peripheral_trait: Send {
+    /// Called when the chip select is asserted.
+    fn Select(&mut self) {}
+
+    /// Exchange data : the buffer holds the data transmitted by the bus, to replace by the data received.
+    fn Transfer(&mut self, Mode: SPI_mode_type, Buffer: &mut [u8]) -> Result_type<()>;
+
+    /// Called when the chip select is released.
+    fn Deselect(&mut self) {}
+}
+
+struct Bus_inner_type {
+    Peripherals: BTreeMap<u8, Box<dyn Simulated_SPI_peripheral_trait>>,
+    /// Chip select of the device in a batch, if any.
+    Batch: Option<u8>,
+}
+
+impl Bus_inner_type {
+    /// Transfer data with a peripheral, a chip select without peripheral receives ones (floating MISO line pulled up).
+    fn Transfer(
+        &mut self,
+        Chip_select: u8,
+        Mode: SPI_mode_type,
+        Buffer: &mut [u8],
+    ) -> Result_type<()> {
+        let In_batch = match self.Batch {
+            Some(Batch) if Batch == Chip_select => true,
+            Some(_) => return Err(Error_type::Ressource_busy),
+            None => false,
+        };
+
+        let Peripheral = match self.Peripherals.get_mut(&Chip_select) {
+            Some(Peripheral) => Peripheral,
+            None => {
+                Buffer.fill(0xFF);
+                return Ok(());
+            }
+        };
+
+        if !In_batch {
+            Peripheral.Select();
+        }
+
+        let Result = Peripheral.Transfer(Mode, Buffer);
+
+        if !In_batch {
+            Peripheral.Deselect();
+        }
+
+        Result
+    }
+}
+
+/// Simulated SPI bus, on which peripheral models can be attached to chip selects.
+///
+/// The bus can be cloned to create devices or attach peripherals once devices are registered in the virtual file
+/// system.
+#[derive(Clone)]
+pub struct Simulated_SPI_bus_type(Arc<Mutex<Bus_inner_type>>);
+
+impl Default for Simulated_SPI_bus_type {
+    fn default() -> Self {
+        Self::New()
+    }
+}
+
+impl Simulated_SPI_bus_type {
+    pub fn New() -> Self {
+        Self(Arc::new(Mutex::new(Bus_inner_type {
+            Peripherals: BTreeMap::new(),
+            Batch: None,
+        })))
+    }
+
+    fn Lock(&self) -> Result_type<MutexGuard<'_, Bus_inner_type>> {
+        Ok(self.0.lock()?)
+    }
+
+    /// Attach a peripheral model to a chip select.
+    pub fn Attach(
+        &self,
+        Chip_select: u8,
+        Peripheral: impl Simulated_SPI_peripheral_trait + 'static,
+    ) -> Result_type<()> {
+        let mut Inner = self.Lock()?;
+
+        if Inner.Peripherals.contains_key(&Chip_select) {
+            return Err(Error_type::Already_exists);
+        }
+
+        Inner.Peripherals.insert(Chip_select, Box::new(Peripheral));
+
+        Ok(())
+    }
+
+    pub fn Detach(&self, Chip_select: u8) -> Result_type<()> {
+        self.Lock()?
+            .Peripherals
+            .remove(&Chip_select)
+            .map(|_| ())
+            .ok_or(Error_type::Not_found)
+    }
+
+    /// Get a device for the peripheral on a chip select (mode 0 at 1 MHz by default).
+    pub fn New_device(&self, Chip_select: u8) -> Simulated_SPI_device_type {
+        Simulated_SPI_device_type {
+            Bus: self.clone(),
+            Chip_select,
+            Configuration: Mutex::new((SPI_mode_type::Mode_0, 1_000_000)),
+        }
+    }
+}
+
+/// Device of a peripheral on a simulated SPI bus.
+pub struct Simulated_SPI_device_type {
+    Bus: Simulated_SPI_bus_type,
+    Chip_select: u8,
+    /// Mode and frequency.
+    Configuration: Mutex<(SPI_mode_type, u32)>,
+}
+
+impl Simulated_SPI_device_type {
+    fn Transfer(&self, Buffer: &mut [u8]) -> Result_type<()> {
+        let Mode = self.Configuration.lock()?.0;
+
+        self.Bus.Lock()?.Transfer(self.Chip_select, Mode, Buffer)
+    }
+
+    fn Begin_batch(&self) -> Result_type<()> {
+        let mut Inner = self.Bus.Lock()?;
+
+        if Inner.Batch.is_some() {
+            return Err(Error_type::Ressource_busy);
+        }
+
+        Inner.Batch = Some(self.Chip_select);
+
+        if let Some(Peripheral) = Inner.Peripherals.get_mut(&self.Chip_select) {
+            Peripheral.Select();
+        }
+
+        Ok(())
+    }
+
+    fn End_batch(&self) -> Result_type<()> {
+        let mut Inner = self.Bus.Lock()?;
+
+        if Inner.Batch != Some(self.Chip_select) {
+            return Err(Error_type::Invalid_input);
+        }
+
+        Inner.Batch = None;
+
+        if let Some(Peripheral) = Inner.Peripherals.get_mut(&self.Chip_select) {
+            Peripheral.Deselect();
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Simulated_SPI_device_type {
+    fn drop(&mut self) {
+        // - Release the bus if the device is dropped during a batch
+        let _ = self.End_batch();
+    }
+}
+
+impl Device_trait for Simulated_SPI_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        Buffer.fill(0);
+
+        self.Transfer(Buffer)?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        self.Transfer(&mut Buffer.to_vec())?;
+
+        Ok(Buffer.len())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        match Command {
+            SPI_set_mode_command => {
+                self.Configuration.lock()?.0 =
+                    SPI_mode_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                        .map_err(|_| Error_type::Invalid_input)?;
+
+                Ok(())
+            }
+            SPI_get_mode_command => {
+                Command.Set_argument(Argument, self.Configuration.lock()?.0 as u8)
+            }
+            SPI_set_frequency_command => {
+                let Frequency = Command.Get_argument::<u32>(Argument)?;
+
+                if Frequency == 0 {
+                    return Err(Error_type::Invalid_input);
+                }
+
+                self.Configuration.lock()?.1 = Frequency;
+
+                Ok(())
+            }
+            SPI_get_frequency_command => {
+                Command.Set_argument(Argument, self.Configuration.lock()?.1)
+            }
+            SPI_transfer_command => {
+                let mut Transfer = Command.Get_argument::<SPI_transfer_type>(Argument)?;
+
+                self.Transfer(
+                    Transfer
+                        .Get_data_mutable()
+                        .ok_or(Error_type::Invalid_input)?,
+                )?;
+
+                Command.Set_argument(Argument, Transfer)
+            }
+            SPI_begin_batch_command => self.Begin_batch(),
+            SPI_end_batch_command => self.End_batch(),
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
+
+/// Peripheral whose MISO line is wired to the MOSI line : the data received is the data transmitted.
+pub struct Loopback_SPI_peripheral_type;
+
+impl Simulated_SPI_peripheral_trait for Loopback_SPI_peripheral_type {
+    fn Transfer(&mut self, _: SPI_mode_type, _: &mut [u8]) -> Result_type<()> {
+        Ok(())
+    }
+}
+
+struct Register_inner_type {
+    Registers: [u8; 128],
+    Mode: SPI_mode_type,
+    /// Register accessed by the next byte and direction, set by the first byte after selection.
+    Access: Option<(u8, bool)>,
+}
+
+/// Model of a sensor with 128 registers, like many SPI sensors.
+///
+/// After selection, the first byte holds the register address in its 7 low bits and the direction in its high bit
+/// (`1` to read). The next bytes read or write the following registers, until deselection. The peripheral only
+/// answers in its configured mode, it receives ones otherwise (like an unselected peripheral).
+///
+/// The peripheral can be cloned to access the registers once it is attached.
+#[derive(Clone)]
+pub struct Register_SPI_peripheral_type(Arc<Mutex<Register_inner_type>>);
+
+impl Register_SPI_peripheral_type {
+    pub fn New(Mode: SPI_mode_type) -> Self {
+        Self(Arc::new(Mutex::new(Register_inner_type {
+            Registers: [0; 128],
+            Mode,
+            Access: None,
+        })))
+    }
+
+    pub fn Get_register(&self, Address: u8) -> Result_type<u8> {
+        self.0
+            .lock()?
+            .Registers
+            .get(Address as usize)
+            .copied()
+            .ok_or(Error_type::Invalid_input)
+    }
+
+    pub fn Set_register(&self, Address: u8, Value: u8) -> Result_type<()> {
+        *self
+            .0
+            .lock()?
+            .Registers
+            .get_mut(Address as usize)
+            .ok_or(Error_type::Invalid_input)? = Value;
+
+        Ok(())
+    }
+}
+
+impl Simulated_SPI_peripheral_trait for Register_SPI_peripheral_type {
+    fn Select(&mut self) {
+        if let Ok(mut Inner) = self.0.lock() {
+            Inner.Access = None;
+        }
+    }
+
+    fn Transfer(&mut self, Mode: SPI_mode_type, Buffer: &mut [u8]) -> Result_type<()> {
+        let mut Inner = self.0.lock()?;
+
+        if Mode != Inner.Mode {
+            Buffer.fill(0xFF);
+            return Ok(());
+        }
+
+        for Byte in Buffer {
+            match Inner.Access {
+                None => {
+                    Inner.Access = Some((*Byte & 0x7F, *Byte & 0x80 != 0));
+                    *Byte = 0;
+                }
+                Some((Address, Read)) => {
+                    if Read {
+                        *Byte = Inner.Registers[Address as usize];
+                    } else {
+                        Inner.Registers[Address as usize] = *Byte;
+                        *Byte = 0;
+                    }
+
+                    Inner.Access = Some(((Address + 1) & 0x7F, Read));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    ffi::CStr,
+    mem::MaybeUninit,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+    sync::Mutex,
+};
+
+use File_system::{Control_command_type, Device_trait, Error_type, Position_type, Result_type};
+use Peripherals::{
+    Flow_control_type, Parity_type, Serial_configuration_type, Serial_get_configuration_command,
+    Serial_set_configuration_command, Stop_bits_type,
+};
+
+/// Serial port backed by a Linux pseudo-terminal.
+///
+/// The device is the master side of the pseudo-terminal, the other end (slave) can be opened by any program
+/// (or test) using the path returned by `Get_slave_path`.
+pub struct Serial_device_type {
+    Master: OwnedFd,
+    Slave_path: String,
+    Configuration: Mutex<Serial_configuration_type>,
+}
+
+impl Serial_device_type {
+    pub fn New() -> Result_type<Self> {
+        let Master =
+            unsafe { libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY | libc::O_NONBLOCK) };
+
+        if Master < 0 {
+            return Err(Error_type::Internal_error);
+        }
+
+        let Master = unsafe { OwnedFd::from_raw_fd(Master) };
+
+        if unsafe { libc::grantpt(Master.as_raw_fd()) } != 0
+            || unsafe { libc::unlockpt(Master.as_raw_fd()) } != 0
+        {
+            return Err(Error_type::Internal_error);
+        }
+
+        let mut Slave_path = [0 as libc::c_char; 64];
+
+        if unsafe {
+            libc::ptsname_r(
+                Master.as_raw_fd(),
+                Slave_path.as_mut_ptr(),
+                Slave_path.len(),
+            )
+        } != 0
+        {
+            return Err(Error_type::Internal_error);
+        }
+
+        let Slave_path = unsafe { CStr::from_ptr(Slave_path.as_ptr()) }
+            .to_str()
+            .map_err(|_| Error_type::Internal_error)?
+            .to_string();
+
+        let Device = Self {
+            Master,
+            Slave_path,
+            Configuration: Mutex::new(Serial_configuration_type::default()),
+        };
+
+        Device.Apply_configuration(&Serial_configuration_type::default())?;
+
+        Ok(Device)
+    }
+
+    /// Get the path of the other end of the pseudo-terminal (e.g. `/dev/pts/3`).
+    pub fn Get_slave_path(&self) -> &str {
+        &self.Slave_path
+    }
+
+    fn Get_speed(Baud_rate: u32) -> Result_type<libc::speed_t> {
+        let Speed = match Baud_rate {
+            50 => libc::B50,
+            75 => libc::B75,
+            110 => libc::B110,
+            134 => libc::B134,
+            150 => libc::B150,
+            200 => libc::B200,
+            300 => libc::B300,
+            600 => libc::B600,
+            1200 => libc::B1200,
+            1800 => libc::B1800,
+            2400 => libc::B2400,
+            4800 => libc::B4800,
+            9600 => libc::B9600,
+            19200 => libc::B19200,
+            38400 => libc::B38400,
+            57600 => libc::B57600,
+            115200 => libc::B115200,
+            230400 => libc::B230400,
+            460800 => libc::B460800,
+            500000 => libc::B500000,
+            576000 => libc::B576000,
+            921600 => libc::B921600,
+            1000000 => libc::B1000000,
+            1152000 => libc::B1152000,
+            1500000 => libc::B1500000,
+            2000000 => libc::B2000000,
+            2500000 => libc::B2500000,
+            3000000 => libc::B3000000,
+            3500000 => libc::B3500000,
+            4000000 => libc::B4000000,
+            _ => return Err(Error_type::Invalid_input),
+        };
+
+        Ok(Speed)
+    }
+
+    fn Apply_configuration(&self, Configuration: &Serial_configuration_type) -> Result_type<()> {
+        let mut Termios = MaybeUninit::<libc::termios>::zeroed();
+
+        if unsafe { libc::tcgetattr(self.Master.as_raw_fd(), Termios.as_mut_ptr()) } != 0 {
+            return Err(Error_type::Internal_error);
+        }
+
+        let mut Termios = unsafe { Termios.assume_init() };
+
+        // - Transfer the data as is
+        unsafe { libc::cfmakeraw(&mut Termios) };
+
+        if unsafe {
+            libc::cfsetspeed(
+                &mut Termios,
+                Self::Get_speed(Configuration.Get_baud_rate())?,
+            )
+        } != 0
+        {
+            return Err(Error_type::Invalid_input);
+        }
+
+        Termios.c_cflag &= !(libc::CSIZE | libc::PARENB | libc::PARODD | libc::CSTOPB);
+        Termios.c_cflag &= !libc::CRTSCTS;
+        Termios.c_iflag &= !(libc::IXON | libc::IXOFF | libc::IXANY);
+
+        Termios.c_cflag |= match Configuration.Get_data_bits() {
+            5 => libc::CS5,
+            6 => libc::CS6,
+            7 => libc::CS7,
+            8 => libc::CS8,
+            _ => return Err(Error_type::Invalid_input),
+        };
+
+        Termios.c_cflag |= match Configuration.Get_parity() {
+            Parity_type::None => 0,
+            Parity_type::Even => libc::PARENB,
+            Parity_type::Odd => libc::PARENB | libc::PARODD,
+        };
+
+        Termios.c_cflag |= match Configuration.Get_stop_bits() {
+            Stop_bits_type::One => 0,
+            Stop_bits_type::Two => libc::CSTOPB,
+            Stop_bits_type::One_and_half => return Err(Error_type::Unsupported_operation),
+        };
+
+        match Configuration.Get_flow_control() {
+            Flow_control_type::None => (),
+            Flow_control_type::Hardware => Termios.c_cflag |= libc::CRTSCTS,
+            Flow_control_type::Software => Termios.c_iflag |= libc::IXON | libc::IXOFF,
+        }
+
+        if unsafe { libc::tcsetattr(self.Master.as_raw_fd(), libc::TCSANOW, &Termios) } != 0 {
+            return Err(Error_type::Internal_error);
+        }
+
+        Ok(())
+    }
+}
+
+impl Device_trait for Serial_device_type {
+    /// Read the available data, without blocking.
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let Size = unsafe {
+            libc::read(
+                self.Master.as_raw_fd(),
+                Buffer.as_mut_ptr() as *mut libc::c_void,
+                Buffer.len(),
+            )
+        };
+
+        if Size < 0 {
+            return match std::io::Error::last_os_error().raw_os_error() {
+                // - No data available or the other end is not opened
+                Some(libc::EAGAIN) | Some(libc::EIO) => Ok(0),
+                _ => Err(Error_type::Internal_error),
+            };
+        }
+
+        Ok(Size as usize)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        let Size = unsafe {
+            libc::write(
+                self.Master.as_raw_fd(),
+                Buffer.as_ptr() as *const libc::c_void,
+                Buffer.len(),
+            )
+        };
+
+        if Size < 0 {
+            return match std::io::Error::last_os_error().raw_os_error() {
+                Some(libc::EAGAIN) => Ok(0),
+                _ => Err(Error_type::Internal_error),
+            };
+        }
+
+        Ok(Size as usize)
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        match Command {
+            Serial_set_configuration_command => {
+                Command.Check_argument(Argument)?;
+
+                let Configuration = Serial_configuration_type::try_from(&*Argument)
+                    .map_err(|_| Error_type::Invalid_input)?;
+
+                let mut Current_configuration = self.Configuration.lock()?;
+
+                self.Apply_configuration(&Configuration)?;
+
+                *Current_configuration = Configuration;
+
+                Ok(())
+            }
+            Serial_get_configuration_command => {
+                Command.Set_argument(Argument, *self.Configuration.lock()?)
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
@@ -1,12 +1,33 @@
+mod ADC;
+mod GPIO;
+mod Headless;
+mod I2C;
+mod PWM;
+mod Replay;
 mod SDL2;
+mod SPI;
+#[cfg(target_os = "linux")]
+mod Serial;
 
-use File_system::Path_type;
+use File_system::{Device_class_type, Path_type, Permissions_type};
 use Graphics::{Get_recommended_buffer_size, Point_type};
+pub use Headless::*;
+pub use Replay::*;
+#[cfg(target_os = "linux")]
+pub use Serial::*;
+pub use ADC::*;
+pub use GPIO::*;
+pub use I2C::*;
+pub use PWM::*;
 pub use SDL2::*;
+pub use SPI::*;
 
 const Pointer_device_path: &Path_type =
     unsafe { Path_type::New_unchecked_constant("/Devices/Pointer") };
 
+const Keyboard_device_path: &Path_type =
+    unsafe { Path_type::New_unchecked_constant("/Devices/Keyboard") };
+
 const Screen_device_path: &Path_type =
     unsafe { Path_type::New_unchecked_constant("/Devices/Screen") };
 
@@ -20,12 +41,39 @@ pub fn Mount_devices(
     let (Screen_device, Pointer_device) =
         New_touchscreen::<Buffer_size>(Resolution).expect("Error creating touchscreen");
 
+    let Keyboard_device = Pointer_device.New_keyboard_device();
+
     Virtual_file_systems
-        .Add_device(&Pointer_device_path, Box::new(Pointer_device))
+        .Add_device(
+            Pointer_device_path,
+            Device_class_type::Input,
+            Box::new(Pointer_device),
+            Users::Root_user_identifier,
+            Users::Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
         .expect("Error adding pointer device");
 
     Virtual_file_systems
-        .Add_device(&Screen_device_path, Box::new(Screen_device))
+        .Add_device(
+            Keyboard_device_path,
+            Device_class_type::Input,
+            Box::new(Keyboard_device),
+            Users::Root_user_identifier,
+            Users::Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
+        .expect("Error adding keyboard device");
+
+    Virtual_file_systems
+        .Add_device(
+            Screen_device_path,
+            Device_class_type::Display,
+            Box::new(Screen_device),
+            Users::Root_user_identifier,
+            Users::Root_group_identifier,
+            Permissions_type::New_standard_file(),
+        )
         .expect("Error adding screen device");
 
     Ok(())
@@ -11,6 +11,8 @@ use std::io::{ErrorKind, Read, Seek, Write};
 use std::sync::RwLock;
 
 use Task::Task_identifier_type;
+use Time::Time_type;
+use Users::{Group_identifier_type, User_identifier_type};
 
 pub fn Mount_file_systems(Virtual_file_system: &Virtual_file_system_type) -> Result<(), String> {
     let File_system = File_system_type::New().map_err(|Error| format!("{:?}", Error))?;
@@ -31,6 +33,15 @@ fn From_file_type(value: FileType) -> Type_type {
     Type_type::File
 }
 
+/// Set the modification time of a file with the time of the system, the host time is kept if it isn't initialized.
+fn Update_modification_time(File: &File) -> Result_type<()> {
+    if let Ok(Time_manager) = Time::Get_instance() {
+        File.set_modified(Time_manager.Get_current_time()?.into())?;
+    }
+
+    Ok(())
+}
+
 fn Apply_flags_to_open_options(Flags: Flags_type, Open_options: &mut OpenOptions) {
     Open_options
         .read(Flags.Get_mode().Get_read())
@@ -40,6 +51,10 @@ fn Apply_flags_to_open_options(Flags: Flags_type, Open_options: &mut OpenOptions
 pub struct File_system_type {
     Virtual_root_path: Path_owned_type,
     Open_files: RwLock<BTreeMap<u32, RwLock<File>>>,
+    /// Owners of the files, indexed by their path. The files without entry belong to the root user and group.
+    ///
+    /// The host file system can't store them, so they are kept as long as the file system lives.
+    Owners: RwLock<BTreeMap<String, (User_identifier_type, Group_identifier_type)>>,
 }
 
 impl File_system_type {
@@ -47,6 +62,7 @@ impl File_system_type {
         Ok(File_system_type {
             Virtual_root_path: Self::Get_root_path().ok_or(Error_type::Unknown)?,
             Open_files: RwLock::new(BTreeMap::new()),
+            Owners: RwLock::new(BTreeMap::new()),
         })
     }
 
@@ -175,14 +191,18 @@ impl File_system_traits for File_system_type {
         let Local_file_identifier =
             Self::Get_local_file_identifier(Task_identifier, File_identifier);
 
-        Ok(self
-            .Open_files
-            .write()?
-            .get_mut(&Local_file_identifier)
+        let Open_files = self.Open_files.read()?;
+
+        let mut File = Open_files
+            .get(&Local_file_identifier)
             .ok_or(Error_type::Invalid_identifier)?
-            .write()?
-            .write(Buffer)?
-            .into())
+            .write()?;
+
+        let Size = File.write(Buffer)?;
+
+        Update_modification_time(&File)?;
+
+        Ok(Size.into())
     }
 
     fn Flush(&self, Task: Task_identifier_type, File: File_identifier_type) -> Result_type<()> {
@@ -240,23 +260,76 @@ impl File_system_traits for File_system_type {
     fn Delete(&self, Path: &dyn AsRef<Path_type>) -> Result_type<()> {
         let Full_path = self.Get_full_path(&Path)?;
 
-        remove_file(Full_path.as_ref() as &Path_type).map_err(|Error| Error.kind().into())
+        remove_file(Full_path.as_ref() as &Path_type).map_err(|Error| Error.kind())?;
+
+        self.Owners.write()?.remove(Path.as_ref().As_str());
+
+        Ok(())
     }
 
     fn Create_directory(&self, Path: &dyn AsRef<Path_type>) -> Result_type<()> {
         let Full_path = self.Get_full_path(&Path)?;
 
-        create_dir(Full_path.as_ref() as &Path_type).map_err(|Error| Error.kind().into())
+        create_dir(Full_path.as_ref() as &Path_type).map_err(|Error| Error.kind())?;
+
+        Update_modification_time(&File::open(Full_path.as_ref() as &Path_type)?)
+    }
+
+    fn Get_directory_entries(
+        &self,
+        Path: &dyn AsRef<Path_type>,
+    ) -> Result_type<Vec<Path_owned_type>> {
+        let Full_path = self.Get_full_path(&Path)?;
+
+        let mut Entries = Vec::new();
+
+        for Entry in read_dir(Full_path.as_ref() as &Path_type)? {
+            let Entry = Entry?;
+
+            // - The symbolic links aren't followed, they could lead outside of the file system
+            if Entry.file_type()?.is_symlink() {
+                continue;
+            }
+
+            let Name = Entry.file_name();
+
+            let Entry_path = Name
+                .to_str()
+                .and_then(|Name| Path.as_ref().to_owned().Append(Name))
+                .ok_or(Error_type::Invalid_path)?;
+
+            Entries.push(Entry_path);
+        }
+
+        Ok(Entries)
     }
 
     fn Create_file(&self, Path: &dyn AsRef<Path_type>) -> Result_type<()> {
         let Full_path = self.Get_full_path(&Path)?;
 
-        OpenOptions::new()
+        let File = OpenOptions::new()
             .write(true)
             .create_new(true)
             .open(Full_path.as_ref() as &Path_type)?;
 
+        Update_modification_time(&File)
+    }
+
+    fn Get_modification_time(&self, Path: &dyn AsRef<Path_type>) -> Result_type<Time_type> {
+        let Full_path = self.Get_full_path(&Path)?;
+        let Metadata = metadata(Full_path.as_ref() as &Path_type)?;
+        Ok(Metadata.modified()?.into())
+    }
+
+    fn Set_modification_time(
+        &self,
+        Path: &dyn AsRef<Path_type>,
+        Time: Time_type,
+    ) -> Result_type<()> {
+        let Full_path = self.Get_full_path(&Path)?;
+
+        File::open(Full_path.as_ref() as &Path_type)?.set_modified(Time.into())?;
+
         Ok(())
     }
 
@@ -303,15 +376,79 @@ impl File_system_traits for File_system_type {
         Source: &dyn AsRef<Path_type>,
         Destination: &dyn AsRef<Path_type>,
     ) -> Result_type<()> {
-        let Source = self.Get_full_path(Source)?;
-        let Destination = self.Get_full_path(Destination)?;
+        let Full_source = self.Get_full_path(Source)?;
+        let Full_destination = self.Get_full_path(Destination)?;
 
         rename(
-            Source.as_ref() as &Path_type,
-            Destination.as_ref() as &Path_type,
+            Full_source.as_ref() as &Path_type,
+            Full_destination.as_ref() as &Path_type,
         )?;
+
+        // - The owners follow the file and, for a directory, its content
+        let (Source, Destination) = (Source.as_ref().As_str(), Destination.as_ref().As_str());
+
+        let mut Owners = self.Owners.write()?;
+
+        let Moved: Vec<String> = Owners
+            .keys()
+            .filter(|Path| {
+                Path.strip_prefix(Source)
+                    .is_some_and(|Rest| Rest.is_empty() || Rest.starts_with('/'))
+            })
+            .cloned()
+            .collect();
+
+        for Path in Moved {
+            if let Some(Owner) = Owners.remove(&Path) {
+                Owners.insert(format!("{}{}", Destination, &Path[Source.len()..]), Owner);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn Set_owner(
+        &self,
+        Path: &dyn AsRef<Path_type>,
+        User: Option<User_identifier_type>,
+        Group: Option<Group_identifier_type>,
+    ) -> Result_type<()> {
+        if !self.Exists(Path)? {
+            return Err(Error_type::Not_found);
+        }
+
+        let mut Owners = self.Owners.write()?;
+
+        let Owner = Owners
+            .entry(Path.as_ref().As_str().to_string())
+            .or_insert((Users::Root_user_identifier, Users::Root_group_identifier));
+
+        if let Some(User) = User {
+            Owner.0 = User;
+        }
+
+        if let Some(Group) = Group {
+            Owner.1 = Group;
+        }
+
         Ok(())
     }
+
+    fn Get_owner(
+        &self,
+        Path: &dyn AsRef<Path_type>,
+    ) -> Result_type<(User_identifier_type, Group_identifier_type)> {
+        if !self.Exists(Path)? {
+            return Err(Error_type::Not_found);
+        }
+
+        Ok(self
+            .Owners
+            .read()?
+            .get(Path.as_ref().As_str())
+            .copied()
+            .unwrap_or((Users::Root_user_identifier, Users::Root_group_identifier)))
+    }
 }
 
 // - Test
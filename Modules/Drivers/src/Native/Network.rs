@@ -0,0 +1,255 @@
+use std::{
+    io::{Read, Write},
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream, UdpSocket},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use File_system::{
+    Error_type, Network_trait, Protocol_type, Result_type, Size_type, Socket_address_type,
+    Socket_trait,
+};
+
+/// State of a host socket.
+///
+/// The host TCP sockets only exist once they listen or connect, since the standard library doesn't expose unconnected sockets.
+enum State_type {
+    /// TCP socket not yet listening nor connected, with its bound address if any.
+    TCP_unbound(Option<SocketAddr>),
+    TCP_listener(Arc<TcpListener>),
+    TCP_stream(Arc<TcpStream>),
+    /// UDP socket, bound on first use if not explicitly bound.
+    UDP_socket(Option<Arc<UdpSocket>>),
+}
+
+/// Socket over the host network stack.
+///
+/// Binding a TCP socket before connecting it is not supported.
+pub struct Socket_type {
+    State: RwLock<State_type>,
+    Non_blocking: AtomicBool,
+}
+
+impl Socket_type {
+    fn New(State: State_type) -> Self {
+        Self {
+            State: RwLock::new(State),
+            Non_blocking: AtomicBool::new(false),
+        }
+    }
+
+    fn Get_unspecified_address(Peer: SocketAddr) -> SocketAddr {
+        match Peer {
+            SocketAddr::V4(_) => SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+            SocketAddr::V6(_) => SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)),
+        }
+    }
+
+    fn Is_UDP(&self) -> Result_type<bool> {
+        Ok(matches!(&*self.State.read()?, State_type::UDP_socket(_)))
+    }
+
+    fn Get_stream(&self) -> Result_type<Arc<TcpStream>> {
+        match &*self.State.read()? {
+            State_type::TCP_stream(Stream) => Ok(Stream.clone()),
+            _ => Err(Error_type::Not_connected),
+        }
+    }
+
+    /// Get the UDP socket, binding it on an ephemeral port to reach `Peer` if it isn't bound yet.
+    fn Get_UDP_socket(&self, Peer: Option<SocketAddr>) -> Result_type<Arc<UdpSocket>> {
+        let mut State = self.State.write()?;
+
+        match &*State {
+            State_type::UDP_socket(Some(Socket)) => Ok(Socket.clone()),
+            State_type::UDP_socket(None) => {
+                let Peer = Peer.ok_or(Error_type::Not_connected)?;
+
+                let Socket = Arc::new(UdpSocket::bind(Self::Get_unspecified_address(Peer))?);
+
+                Socket.set_nonblocking(self.Non_blocking.load(Ordering::Relaxed))?;
+
+                *State = State_type::UDP_socket(Some(Socket.clone()));
+
+                Ok(Socket)
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
+
+impl Socket_trait for Socket_type {
+    fn Bind(&self, Address: Socket_address_type) -> Result_type<()> {
+        let mut State = self.State.write()?;
+
+        match &*State {
+            State_type::TCP_unbound(None) => {
+                *State = State_type::TCP_unbound(Some(Address.into()));
+            }
+            State_type::UDP_socket(None) => {
+                let Socket = UdpSocket::bind(SocketAddr::from(Address))?;
+
+                Socket.set_nonblocking(self.Non_blocking.load(Ordering::Relaxed))?;
+
+                *State = State_type::UDP_socket(Some(Arc::new(Socket)));
+            }
+            _ => return Err(Error_type::Invalid_input),
+        }
+
+        Ok(())
+    }
+
+    fn Listen(&self, _: usize) -> Result_type<()> {
+        let mut State = self.State.write()?;
+
+        let Address = match &*State {
+            State_type::TCP_unbound(Address) => {
+                Address.unwrap_or(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))
+            }
+            State_type::TCP_listener(_) => return Ok(()),
+            _ => return Err(Error_type::Unsupported_operation),
+        };
+
+        let Listener = TcpListener::bind(Address)?;
+
+        Listener.set_nonblocking(self.Non_blocking.load(Ordering::Relaxed))?;
+
+        *State = State_type::TCP_listener(Arc::new(Listener));
+
+        Ok(())
+    }
+
+    fn Accept(&self) -> Result_type<(Box<dyn Socket_trait>, Socket_address_type)> {
+        let Listener = match &*self.State.read()? {
+            State_type::TCP_listener(Listener) => Listener.clone(),
+            _ => return Err(Error_type::Invalid_input),
+        };
+
+        // - Don't hold the lock while blocking
+        let (Stream, Address) = Listener.accept()?;
+
+        // - The accepted sockets are blocking whatever the mode of the listener
+        Stream.set_nonblocking(false)?;
+
+        Ok((
+            Box::new(Socket_type::New(State_type::TCP_stream(Arc::new(Stream)))),
+            Address.into(),
+        ))
+    }
+
+    fn Connect(&self, Address: Socket_address_type) -> Result_type<()> {
+        let Address = SocketAddr::from(Address);
+
+        if self.Is_UDP()? {
+            return Ok(self.Get_UDP_socket(Some(Address))?.connect(Address)?);
+        }
+
+        match &*self.State.read()? {
+            State_type::TCP_unbound(None) => {}
+            State_type::TCP_unbound(Some(_)) => return Err(Error_type::Unsupported_operation),
+            _ => return Err(Error_type::Invalid_input),
+        }
+
+        // - Don't hold the lock while blocking
+        let Stream = TcpStream::connect(Address)?;
+
+        Stream.set_nonblocking(self.Non_blocking.load(Ordering::Relaxed))?;
+
+        let mut State = self.State.write()?;
+
+        // - Another task may have connected the socket meanwhile
+        if !matches!(&*State, State_type::TCP_unbound(None)) {
+            return Err(Error_type::Invalid_input);
+        }
+
+        *State = State_type::TCP_stream(Arc::new(Stream));
+
+        Ok(())
+    }
+
+    fn Send(&self, Buffer: &[u8]) -> Result_type<Size_type> {
+        if self.Is_UDP()? {
+            return Ok(self.Get_UDP_socket(None)?.send(Buffer)?.into());
+        }
+
+        Ok((&*self.Get_stream()?).write(Buffer)?.into())
+    }
+
+    fn Receive(&self, Buffer: &mut [u8]) -> Result_type<Size_type> {
+        if self.Is_UDP()? {
+            return Ok(self.Get_UDP_socket(None)?.recv(Buffer)?.into());
+        }
+
+        Ok((&*self.Get_stream()?).read(Buffer)?.into())
+    }
+
+    fn Send_to(&self, Buffer: &[u8], Address: Socket_address_type) -> Result_type<Size_type> {
+        let Address = SocketAddr::from(Address);
+
+        Ok(self
+            .Get_UDP_socket(Some(Address))?
+            .send_to(Buffer, Address)?
+            .into())
+    }
+
+    fn Receive_from(&self, Buffer: &mut [u8]) -> Result_type<(Size_type, Socket_address_type)> {
+        let (Size, Address) = self.Get_UDP_socket(None)?.recv_from(Buffer)?;
+
+        Ok((Size.into(), Address.into()))
+    }
+
+    fn Get_local_address(&self) -> Result_type<Socket_address_type> {
+        let Address = match &*self.State.read()? {
+            State_type::TCP_unbound(Address) => {
+                Address.unwrap_or(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))
+            }
+            State_type::TCP_listener(Listener) => Listener.local_addr()?,
+            State_type::TCP_stream(Stream) => Stream.local_addr()?,
+            State_type::UDP_socket(Some(Socket)) => Socket.local_addr()?,
+            State_type::UDP_socket(None) => SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+        };
+
+        Ok(Address.into())
+    }
+
+    fn Get_peer_address(&self) -> Result_type<Socket_address_type> {
+        let Address = match &*self.State.read()? {
+            State_type::TCP_stream(Stream) => Stream.peer_addr()?,
+            State_type::UDP_socket(Some(Socket)) => Socket.peer_addr()?,
+            _ => return Err(Error_type::Not_connected),
+        };
+
+        Ok(Address.into())
+    }
+
+    fn Set_non_blocking(&self, Non_blocking: bool) -> Result_type<()> {
+        let State = self.State.read()?;
+
+        match &*State {
+            State_type::TCP_listener(Listener) => Listener.set_nonblocking(Non_blocking)?,
+            State_type::TCP_stream(Stream) => Stream.set_nonblocking(Non_blocking)?,
+            State_type::UDP_socket(Some(Socket)) => Socket.set_nonblocking(Non_blocking)?,
+            State_type::TCP_unbound(_) | State_type::UDP_socket(None) => {}
+        }
+
+        self.Non_blocking.store(Non_blocking, Ordering::Relaxed);
+
+        Ok(())
+    }
+}
+
+/// Network backend over the sockets of the host.
+pub struct Network_type;
+
+impl Network_trait for Network_type {
+    fn New_socket(&self, Protocol: Protocol_type) -> Result_type<Box<dyn Socket_trait>> {
+        let State = match Protocol {
+            Protocol_type::TCP => State_type::TCP_unbound(None),
+            Protocol_type::UDP => State_type::UDP_socket(None),
+        };
+
+        Ok(Box::new(Socket_type::New(State)))
+    }
+}
@@ -1,5 +1,9 @@
+mod Clock;
 mod Devices;
 mod File_system;
+mod Network;
 
+pub use Clock::*;
 pub use Devices::*;
 pub use File_system::*;
+pub use Network::*;
@@ -0,0 +1,82 @@
+use std::mem::size_of;
+
+use File_system::{
+    Clock_get_date_time_command, Clock_get_monotonic_time_command, Clock_get_time_zone_command,
+    Clock_set_time_zone_command, Control_command_type, Device_trait, Error_type, Position_type,
+    Result_type,
+};
+use Time::{Time_type, Time_zone_type};
+
+/// Device exposing the time of the system (`/Devices/Clock`).
+///
+/// Reading the device gets the wall clock time and writing it sets the time (microseconds since the Unix epoch,
+/// `u64`).
+pub struct Clock_device_type(&'static Time::Manager_type);
+
+impl Clock_device_type {
+    /// Create a clock device on top of the time manager, which must be initialized.
+    pub fn New() -> Result_type<Self> {
+        Ok(Self(Time::Get_instance()?))
+    }
+}
+
+impl Device_trait for Clock_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let Buffer = Buffer
+            .get_mut(..size_of::<u64>())
+            .ok_or(Error_type::Invalid_input)?;
+
+        let Time = self.0.Get_current_time()?;
+
+        Buffer.copy_from_slice(&Time.Get_microseconds().to_ne_bytes());
+
+        Ok(size_of::<u64>())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        let Time = u64::from_ne_bytes(
+            Buffer
+                .get(..size_of::<u64>())
+                .ok_or(Error_type::Invalid_input)?
+                .try_into()
+                .map_err(|_| Error_type::Invalid_input)?,
+        );
+
+        self.0.Set_current_time(Time_type::New(Time))?;
+
+        Ok(size_of::<u64>())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(size_of::<u64>())
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        match Command {
+            Clock_get_monotonic_time_command => {
+                Command.Set_argument(Argument, self.0.Get_monotonic_time().as_micros() as u64)
+            }
+            Clock_get_time_zone_command => Command.Set_argument(Argument, self.0.Get_time_zone()?),
+            Clock_set_time_zone_command => {
+                Command.Check_argument(Argument)?;
+
+                let Time_zone =
+                    Time_zone_type::try_from(&*Argument).map_err(|_| Error_type::Invalid_input)?;
+
+                Ok(self.0.Set_time_zone(Time_zone)?)
+            }
+            Clock_get_date_time_command => {
+                Command.Set_argument(Argument, self.0.Get_current_date_time()?)
+            }
+            _ => Err(Error_type::Unsupported_operation),
+        }
+    }
+}
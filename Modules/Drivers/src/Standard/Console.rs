@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+
+use File_system::{Device_trait, Position_type, Result_type};
+
+/// Device that forwards the data written to it to the logging output, line by line.
+///
+/// Reading from the console returns end of file.
+pub struct Console_device_type(Mutex<Vec<u8>>);
+
+impl Console_device_type {
+    pub fn New() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+
+    fn Log(Line: &[u8]) {
+        log::info!("{}", String::from_utf8_lossy(Line));
+    }
+}
+
+impl Default for Console_device_type {
+    fn default() -> Self {
+        Self::New()
+    }
+}
+
+impl Device_trait for Console_device_type {
+    fn Read(&self, _: &mut [u8]) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        let mut Line = self.0.lock()?;
+
+        for Byte in Buffer {
+            if *Byte == b'\n' {
+                Self::Log(&Line);
+                Line.clear();
+            } else {
+                Line.push(*Byte);
+            }
+        }
+
+        Ok(Buffer.len())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        let mut Line = self.0.lock()?;
+
+        if !Line.is_empty() {
+            Self::Log(&Line);
+            Line.clear();
+        }
+
+        Ok(())
+    }
+}
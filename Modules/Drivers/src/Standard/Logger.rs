@@ -0,0 +1,52 @@
+use std::io::Write;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Logger writing the records to the standard error, prefixed with the local date and time of the system.
+///
+/// The date and time are omitted until the time manager is initialized.
+pub struct Logger_type;
+
+static Logger: Logger_type = Logger_type;
+
+/// Install the logger as the global logger of the `log` crate.
+pub fn Initialize_logger(Level: LevelFilter) -> Result<(), String> {
+    log::set_logger(&Logger).map_err(|Error| format!("Error setting logger: {}", Error))?;
+
+    log::set_max_level(Level);
+
+    Ok(())
+}
+
+impl Log for Logger_type {
+    fn enabled(&self, Metadata: &Metadata) -> bool {
+        Metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, Record: &Record) {
+        if !self.enabled(Record.metadata()) {
+            return;
+        }
+
+        let mut Standard_error = std::io::stderr().lock();
+
+        if let Some(Date_time) = Time::Get_instance()
+            .ok()
+            .and_then(|Manager| Manager.Get_current_date_time().ok())
+        {
+            let _ = write!(Standard_error, "{} ", Date_time);
+        }
+
+        let _ = writeln!(
+            Standard_error,
+            "[{}] {}: {}",
+            Record.level(),
+            Record.target(),
+            Record.args()
+        );
+    }
+
+    fn flush(&self) {
+        let _ = std::io::stderr().flush();
+    }
+}
@@ -0,0 +1,26 @@
+use File_system::{Device_trait, Position_type, Result_type};
+
+/// Device that discards all the data written to it and returns end of file when read (`/dev/null`).
+pub struct Null_device_type;
+
+impl Device_trait for Null_device_type {
+    fn Read(&self, _: &mut [u8]) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        Ok(Buffer.len())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Mutex;
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+use File_system::{Device_trait, Error_type, Position_type, Result_type};
+
+type Seed_type = <ChaCha20Rng as SeedableRng>::Seed;
+
+/// Get a seed from the hardware random number generator (Espressif) or the operating system (Linux).
+fn Get_seed() -> Result_type<Seed_type> {
+    let mut Seed = Seed_type::default();
+
+    #[cfg(target_os = "espidf")]
+    unsafe {
+        esp_idf_sys::esp_fill_random(Seed.as_mut_ptr() as *mut _, Seed.len());
+    }
+
+    #[cfg(not(target_os = "espidf"))]
+    getrandom::getrandom(&mut Seed).map_err(|_| Error_type::Internal_error)?;
+
+    Ok(Seed)
+}
+
+/// Cryptographically secure pseudo-random number generator device (`/dev/random`).
+///
+/// The generator is seeded from the platform entropy source.
+/// Data written to the device is mixed into a new seed.
+pub struct Random_device_type(Mutex<ChaCha20Rng>);
+
+impl Random_device_type {
+    pub fn New() -> Result_type<Self> {
+        Ok(Self(Mutex::new(ChaCha20Rng::from_seed(Get_seed()?))))
+    }
+}
+
+impl Device_trait for Random_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        self.0.lock()?.fill_bytes(Buffer);
+
+        Ok(Buffer.len())
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        let mut Generator = self.0.lock()?;
+
+        let mut Seed = Get_seed()?;
+
+        let mut Current = Seed_type::default();
+        Generator.fill_bytes(&mut Current);
+
+        for (Index, Byte) in Buffer.iter().enumerate() {
+            Seed[Index % Seed.len()] ^= Byte;
+        }
+
+        for (Byte, Current) in Seed.iter_mut().zip(Current) {
+            *Byte ^= Current;
+        }
+
+        *Generator = ChaCha20Rng::from_seed(Seed);
+
+        Ok(Buffer.len())
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Ok(0)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+mod Clock;
+mod Console;
+mod Logger;
+mod Null;
+mod Random;
+mod Zero;
+
+pub use Clock::*;
+pub use Console::*;
+pub use Logger::*;
+pub use Null::*;
+pub use Random::*;
+pub use Zero::*;
+
+use File_system::{Device_class_type, Path_type, Permissions_type, Virtual_file_system_type};
+use Users::{Root_group_identifier, Root_user_identifier};
+
+pub const Null_device_path: &Path_type =
+    unsafe { Path_type::New_unchecked_constant("/Devices/Null") };
+
+pub const Zero_device_path: &Path_type =
+    unsafe { Path_type::New_unchecked_constant("/Devices/Zero") };
+
+pub const Random_device_path: &Path_type =
+    unsafe { Path_type::New_unchecked_constant("/Devices/Random") };
+
+pub const Console_device_path: &Path_type =
+    unsafe { Path_type::New_unchecked_constant("/Devices/Console") };
+
+pub const Clock_device_path: &Path_type =
+    unsafe { Path_type::New_unchecked_constant("/Devices/Clock") };
+
+/// Register the standard character devices (null, zero, random, console and clock).
+///
+/// The time manager must be initialized before.
+pub fn Mount_devices(Virtual_file_system: &Virtual_file_system_type) -> Result<(), String> {
+    let Random_device = Random_device_type::New()
+        .map_err(|Error| format!("Error creating random device: {:?}", Error))?;
+
+    let Clock_device = Clock_device_type::New()
+        .map_err(|Error| format!("Error creating clock device: {:?}", Error))?;
+
+    let Devices: [(&Path_type, Box<dyn File_system::Device_trait>); 5] = [
+        (Null_device_path, Box::new(Null_device_type)),
+        (Zero_device_path, Box::new(Zero_device_type)),
+        (Random_device_path, Box::new(Random_device)),
+        (Console_device_path, Box::new(Console_device_type::New())),
+        (Clock_device_path, Box::new(Clock_device)),
+    ];
+
+    for (Path, Device) in Devices {
+        Virtual_file_system
+            .Add_device(
+                Path,
+                Device_class_type::Character,
+                Device,
+                Root_user_identifier,
+                Root_group_identifier,
+                Permissions_type::New_standard_file(),
+            )
+            .map_err(|Error| format!("Error adding device {}: {:?}", Path.As_str(), Error))?;
+    }
+
+    Ok(())
+}
@@ -8,6 +8,8 @@ pub mod Espressif;
 #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
 pub mod Native;
 
+pub mod Standard;
+
 pub fn Mount_file_systems(
     Virtual_file_systems: &File_system::Virtual_file_system_type,
 ) -> Result<(), String> {
@@ -20,6 +22,8 @@ pub fn Mount_file_systems(
 pub fn Mount_devices(
     Virtual_file_systems: &File_system::Virtual_file_system_type,
 ) -> Result<(), String> {
+    Standard::Mount_devices(Virtual_file_systems)?;
+
     #[cfg(target_vendor = "espressif")]
     Espressif::Mount_devices(Virtual_file_systems)?;
 
@@ -0,0 +1,45 @@
+use Time::{Date_time_type, Time_zone_type};
+
+use super::{
+    Control_argument_trait, Control_command_type, Control_direction_type,
+    Control_plain_argument_trait,
+};
+
+/// Kind of the clock device control commands.
+///
+/// Reading or writing a clock device transfers the wall clock time, in microseconds since the Unix epoch (`u64`).
+pub const Clock_control_kind: u8 = 0x90;
+
+unsafe impl Control_argument_trait for Date_time_type {}
+
+unsafe impl Control_plain_argument_trait for Date_time_type {}
+
+unsafe impl Control_argument_trait for Time_zone_type {}
+
+/// Get the time elapsed since the boot, in microseconds (argument : `u64`).
+pub const Clock_get_monotonic_time_command: Control_command_type =
+    Control_command_type::New::<u64>(Control_direction_type::Read, Clock_control_kind, 0);
+
+/// Get the time zone of the system (argument : `Time_zone_type`).
+pub const Clock_get_time_zone_command: Control_command_type =
+    Control_command_type::New::<Time_zone_type>(
+        Control_direction_type::Read,
+        Clock_control_kind,
+        1,
+    );
+
+/// Set the time zone of the system (argument : `Time_zone_type`).
+pub const Clock_set_time_zone_command: Control_command_type =
+    Control_command_type::New::<Time_zone_type>(
+        Control_direction_type::Write,
+        Clock_control_kind,
+        2,
+    );
+
+/// Get the current local date and time (argument : `Date_time_type`).
+pub const Clock_get_date_time_command: Control_command_type =
+    Control_command_type::New::<Date_time_type>(
+        Control_direction_type::Read,
+        Clock_control_kind,
+        3,
+    );
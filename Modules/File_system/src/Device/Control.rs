@@ -0,0 +1,198 @@
+use core::mem::size_of;
+
+use crate::{Error_type, Result_type};
+
+/// Types whose values can be written to the argument of a control command (see [Control_command_type::Set_argument]).
+///
+/// # Safety
+/// The type must not have any padding byte, so that every byte of the argument is initialized.
+pub unsafe trait Control_argument_trait: Copy {}
+
+/// Types whose values can be read from the argument of a control command (see [Control_command_type::Get_argument]).
+///
+/// The types containing enumerations or booleans should be decoded with `TryFrom<&[u8]>` instead.
+///
+/// # Safety
+/// Every bit pattern must be a valid value of the type (no enumeration, boolean or reference).
+pub unsafe trait Control_plain_argument_trait: Control_argument_trait {}
+
+macro_rules! Implement_plain_argument {
+    ($($Type:ty),*) => {
+        $(
+            unsafe impl Control_argument_trait for $Type {}
+            unsafe impl Control_plain_argument_trait for $Type {}
+        )*
+    };
+}
+
+Implement_plain_argument!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+unsafe impl<T: Control_plain_argument_trait, const N: usize> Control_argument_trait for [T; N] {}
+unsafe impl<T: Control_plain_argument_trait, const N: usize> Control_plain_argument_trait
+    for [T; N]
+{
+}
+
+/// Direction of the data exchanged through the argument of a control command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Control_direction_type {
+    /// The command doesn't have any argument.
+    None = 0,
+    /// The device reads the argument (e.g. set a configuration).
+    Write = 1,
+    /// The device writes the argument (e.g. get a configuration).
+    Read = 2,
+    /// The device reads and writes the argument.
+    Read_write = 3,
+}
+
+/// Command code of a device control operation (similar to `ioctl`).
+///
+/// The code is composed of :
+/// - The direction of the argument (2 bits).
+/// - The size of the argument (14 bits).
+/// - The kind of device concerned by the command (8 bits).
+/// - The number of the command for this kind of device (8 bits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Control_command_type(u32);
+
+impl Control_command_type {
+    const Number_shift: u32 = 0;
+    const Kind_shift: u32 = 8;
+    const Size_shift: u32 = 16;
+    const Direction_shift: u32 = 30;
+
+    const Size_mask: u32 = 0x3FFF;
+
+    /// Create a new command whose argument is of type `T`.
+    pub const fn New<T>(Direction: Control_direction_type, Kind: u8, Number: u8) -> Self {
+        let Size = match Direction {
+            Control_direction_type::None => 0,
+            _ => size_of::<T>() as u32 & Self::Size_mask,
+        };
+
+        Self(
+            (Direction as u32) << Self::Direction_shift
+                | Size << Self::Size_shift
+                | (Kind as u32) << Self::Kind_shift
+                | (Number as u32) << Self::Number_shift,
+        )
+    }
+
+    pub const fn Get_direction(&self) -> Control_direction_type {
+        match self.0 >> Self::Direction_shift {
+            1 => Control_direction_type::Write,
+            2 => Control_direction_type::Read,
+            3 => Control_direction_type::Read_write,
+            _ => Control_direction_type::None,
+        }
+    }
+
+    pub const fn Get_size(&self) -> usize {
+        ((self.0 >> Self::Size_shift) & Self::Size_mask) as usize
+    }
+
+    pub const fn Get_kind(&self) -> u8 {
+        (self.0 >> Self::Kind_shift) as u8
+    }
+
+    pub const fn Get_number(&self) -> u8 {
+        (self.0 >> Self::Number_shift) as u8
+    }
+
+    /// Check that the argument buffer matches the size of the command argument.
+    pub fn Check_argument(&self, Argument: &[u8]) -> Result_type<()> {
+        if Argument.len() != self.Get_size() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        Ok(())
+    }
+
+    /// Get the argument of the command as a value of type `T`.
+    ///
+    /// # Errors
+    /// Returns `Invalid_input` if the size of the argument doesn't match `T`.
+    pub fn Get_argument<T: Control_plain_argument_trait>(&self, Argument: &[u8]) -> Result_type<T> {
+        self.Check_argument(Argument)?;
+
+        if Argument.len() != size_of::<T>() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        Ok(unsafe { (Argument.as_ptr() as *const T).read_unaligned() })
+    }
+
+    /// Set the argument of the command from a value of type `T`.
+    ///
+    /// # Errors
+    /// Returns `Invalid_input` if the size of the argument doesn't match `T`.
+    pub fn Set_argument<T: Control_argument_trait>(
+        &self,
+        Argument: &mut [u8],
+        Value: T,
+    ) -> Result_type<()> {
+        self.Check_argument(Argument)?;
+
+        if Argument.len() != size_of::<T>() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        unsafe { (Argument.as_mut_ptr() as *mut T).write_unaligned(Value) };
+
+        Ok(())
+    }
+}
+
+impl From<u32> for Control_command_type {
+    fn from(Value: u32) -> Self {
+        Self(Value)
+    }
+}
+
+impl From<Control_command_type> for u32 {
+    fn from(Value: Control_command_type) -> Self {
+        Value.0
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_command() {
+        const Command: Control_command_type =
+            Control_command_type::New::<u64>(Control_direction_type::Read_write, 0x12, 0x34);
+
+        assert_eq!(Command.Get_direction(), Control_direction_type::Read_write);
+        assert_eq!(Command.Get_size(), size_of::<u64>());
+        assert_eq!(Command.Get_kind(), 0x12);
+        assert_eq!(Command.Get_number(), 0x34);
+
+        let Command_none = Control_command_type::New::<u64>(Control_direction_type::None, 1, 2);
+        assert_eq!(Command_none.Get_size(), 0);
+        assert_eq!(Command_none.Get_direction(), Control_direction_type::None);
+    }
+
+    #[test]
+    fn Test_argument() {
+        let Command = Control_command_type::New::<u32>(Control_direction_type::Read, 1, 1);
+
+        let mut Argument = [0_u8; 4];
+
+        Command.Set_argument(&mut Argument, 0xDEADBEEF_u32).unwrap();
+        assert_eq!(Command.Get_argument::<u32>(&Argument), Ok(0xDEADBEEF));
+
+        assert_eq!(
+            Command.Get_argument::<u16>(&Argument),
+            Err(Error_type::Invalid_input)
+        );
+        assert_eq!(
+            Command.Set_argument(&mut [0_u8; 2], 0_u32),
+            Err(Error_type::Invalid_input)
+        );
+    }
+}
@@ -1,4 +1,36 @@
-use crate::{Position_type, Result_type};
+use crate::{Error_type, Path_owned_type, Position_type, Result_type, Type_type};
+
+use super::Control_command_type;
+
+/// Class of a device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Device_class_type {
+    Block,
+    Character,
+    Input,
+    Display,
+    GPIO,
+    /// Communication bus (I2C, SPI, ...).
+    Bus,
+}
+
+impl From<Device_class_type> for Type_type {
+    fn from(Class: Device_class_type) -> Self {
+        match Class {
+            Device_class_type::Block => Type_type::Block_device,
+            _ => Type_type::Character_device,
+        }
+    }
+}
+
+/// Event sent to the subscribers when the registered devices change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Device_event_type {
+    Added(Path_owned_type, Device_class_type),
+    Removed(Path_owned_type),
+    Replaced(Path_owned_type, Device_class_type),
+}
 
 pub trait Device_trait: Send + Sync {
     fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize>;
@@ -10,4 +42,14 @@ pub trait Device_trait: Send + Sync {
     fn Set_position(&self, Position: &Position_type) -> Result_type<usize>;
 
     fn Flush(&self) -> Result_type<()>;
+
+    /// Perform a control operation on the device (configuration, status, etc.).
+    ///
+    /// The size of `Argument` must match the size encoded in the command.
+    ///
+    /// # Errors
+    /// Returns `Unsupported_operation` if the device doesn't support the command.
+    fn Control(&self, _Command: Control_command_type, _Argument: &mut [u8]) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
 }
@@ -1,30 +1,88 @@
 use std::{
     collections::{BTreeMap, HashMap},
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use Task::Task_identifier_type;
-use Users::{
-    Group_identifier_type, Root_group_identifier, Root_user_identifier, User_identifier_type,
-};
+use Users::{Group_identifier_type, User_identifier_type};
 
 use crate::{
-    Error_type, File_identifier_type, File_system_traits, Flags_type, Path_type, Permissions_type,
-    Position_type, Result_type, Size_type, Type_type,
+    Error_type, File_identifier_type, File_system_traits, Flags_type, Path_owned_type, Path_type,
+    Permissions_type, Position_type, Result_type, Size_type, Type_type,
 };
 
-use super::Device_trait;
+use super::{Control_command_type, Device_class_type, Device_trait};
+
+/// A registered device shared with the opened handles.
+///
+/// When the device is removed or replaced, it is flagged so that the opened handles get an error instead of using it.
+struct Registered_device_type {
+    Device: Box<dyn Device_trait>,
+    Removed: AtomicBool,
+}
+
+impl Registered_device_type {
+    fn New(Device: Box<dyn Device_trait>) -> Arc<Self> {
+        Arc::new(Self {
+            Device,
+            Removed: AtomicBool::new(false),
+        })
+    }
+
+    fn Get(&self) -> Result_type<&dyn Device_trait> {
+        if self.Removed.load(Ordering::Acquire) {
+            return Err(Error_type::Device_removed);
+        }
+
+        Ok(self.Device.as_ref())
+    }
+
+    fn Remove(&self) {
+        self.Removed.store(true, Ordering::Release);
+    }
+}
+
+// - The handle given to the virtual file system checks the removal on each operation
+impl Device_trait for Registered_device_type {
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        self.Get()?.Read(Buffer)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        self.Get()?.Write(Buffer)
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        self.Get()?.Get_size()
+    }
+
+    fn Set_position(&self, Position: &Position_type) -> Result_type<usize> {
+        self.Get()?.Set_position(Position)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        self.Get()?.Flush()
+    }
+
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        self.Get()?.Control(Command, Argument)
+    }
+}
 
 struct Internal_device_type {
-    pub Device: Arc<Box<dyn Device_trait>>,
+    pub Device: Arc<Registered_device_type>,
+    pub Class: Device_class_type,
     pub User: User_identifier_type,
-    pub Group: User_identifier_type,
+    pub Group: Group_identifier_type,
     pub Permissions: Permissions_type,
 }
 
 struct Inner_type {
-    Devices: HashMap<&'static Path_type, Internal_device_type>,
-    Opened_devices: BTreeMap<u32, (Arc<Box<dyn Device_trait>>, Flags_type)>,
+    Devices: HashMap<Path_owned_type, Internal_device_type>,
+    Opened_devices: BTreeMap<u32, (Arc<Registered_device_type>, Flags_type)>,
 }
 
 pub struct File_system_type(RwLock<Inner_type>);
@@ -53,6 +111,22 @@ impl File_system_type {
 
         Err(Error_type::Too_many_open_files)
     }
+
+    /// Get an opened device, if it has not been removed in the meantime.
+    fn Get_opened_device(
+        &self,
+        Task: Task_identifier_type,
+        File: File_identifier_type,
+    ) -> Result_type<Arc<Registered_device_type>> {
+        Ok(self
+            .0
+            .read()?
+            .Opened_devices
+            .get(&Self::Get_local_file_identifier(Task, File))
+            .ok_or(Error_type::Not_found)?
+            .0
+            .clone())
+    }
 }
 
 impl File_system_traits for File_system_type {
@@ -80,6 +154,8 @@ impl File_system_traits for File_system_type {
             .Device
             .clone();
 
+        Opened_device.Get()?;
+
         let File_identifier = self.Get_new_file_identifier(Task)?;
 
         self.0.write()?.Opened_devices.insert(
@@ -139,7 +215,9 @@ impl File_system_traits for File_system_type {
             .write()?
             .Devices
             .remove(Path.as_ref())
-            .ok_or(Error_type::Not_found)?;
+            .ok_or(Error_type::Not_found)?
+            .Device
+            .Remove();
 
         Ok(())
     }
@@ -150,12 +228,8 @@ impl File_system_traits for File_system_type {
         File: File_identifier_type,
         Buffer: &mut [u8],
     ) -> Result_type<Size_type> {
-        self.0
-            .read()?
-            .Opened_devices
-            .get(&Self::Get_local_file_identifier(Task, File))
-            .ok_or(Error_type::Not_found)?
-            .0
+        self.Get_opened_device(Task, File)?
+            .Get()?
             .Read(Buffer)
             .map(|Size| Size.into())
     }
@@ -166,12 +240,8 @@ impl File_system_traits for File_system_type {
         File: File_identifier_type,
         Buffer: &[u8],
     ) -> Result_type<Size_type> {
-        self.0
-            .read()?
-            .Opened_devices
-            .get(&Self::Get_local_file_identifier(Task, File))
-            .ok_or(Error_type::Not_found)?
-            .0
+        self.Get_opened_device(Task, File)?
+            .Get()?
             .Write(Buffer)
             .map(|Size| Size.into())
     }
@@ -186,28 +256,26 @@ impl File_system_traits for File_system_type {
         File: File_identifier_type,
         Position: &Position_type,
     ) -> Result_type<Size_type> {
-        self.0
-            .read()?
-            .Opened_devices
-            .get(&Self::Get_local_file_identifier(Task, File))
-            .ok_or(Error_type::Not_found)?
-            .0
+        self.Get_opened_device(Task, File)?
+            .Get()?
             .Set_position(Position)
             .map(|Size| Size.into())
     }
 
     fn Flush(&self, Task: Task_identifier_type, File: File_identifier_type) -> Result_type<()> {
-        self.0
-            .read()?
-            .Opened_devices
-            .get(&Self::Get_local_file_identifier(Task, File))
-            .ok_or(Error_type::Not_found)?
-            .0
-            .Flush()
+        self.Get_opened_device(Task, File)?.Get()?.Flush()
     }
 
-    fn Get_type(&self, _: &dyn AsRef<Path_type>) -> Result_type<Type_type> {
-        Ok(Type_type::Character_device)
+    fn Get_device(
+        &self,
+        Task: Task_identifier_type,
+        File: File_identifier_type,
+    ) -> Result_type<Arc<dyn Device_trait>> {
+        Ok(self.Get_opened_device(Task, File)?)
+    }
+
+    fn Get_type(&self, Path: &dyn AsRef<Path_type>) -> Result_type<Type_type> {
+        Ok(self.Get_device_class(Path)?.into())
     }
 
     fn Get_size(&self, Path: &dyn AsRef<Path_type>) -> Result_type<Size_type> {
@@ -217,6 +285,7 @@ impl File_system_traits for File_system_type {
             .get(Path.as_ref())
             .ok_or(Error_type::Not_found)?
             .Device
+            .Get()?
             .Get_size()
             .map(|Size| Size.into())
     }
@@ -227,8 +296,12 @@ impl File_system_traits for File_system_type {
 
     fn Add_device(
         &self,
-        Path: &'static dyn AsRef<Path_type>,
+        Path: &dyn AsRef<Path_type>,
+        Class: Device_class_type,
         Device: Box<dyn Device_trait>,
+        User: User_identifier_type,
+        Group: Group_identifier_type,
+        Permissions: Permissions_type,
     ) -> Result_type<()> {
         let Inner = &mut self.0.write()?;
 
@@ -237,18 +310,50 @@ impl File_system_traits for File_system_type {
         }
 
         Inner.Devices.insert(
-            Path.as_ref(),
+            Path.as_ref().to_owned(),
             Internal_device_type {
-                Device: Arc::new(Device),
-                User: Root_user_identifier,
-                Group: Root_group_identifier,
-                Permissions: Permissions_type::New_standard_file(),
+                Device: Registered_device_type::New(Device),
+                Class,
+                User,
+                Group,
+                Permissions,
             },
         );
 
         Ok(())
     }
 
+    fn Replace_device(
+        &self,
+        Path: &dyn AsRef<Path_type>,
+        Class: Device_class_type,
+        Device: Box<dyn Device_trait>,
+    ) -> Result_type<()> {
+        let Inner = &mut self.0.write()?;
+
+        let Internal_device = Inner
+            .Devices
+            .get_mut(Path.as_ref())
+            .ok_or(Error_type::Not_found)?;
+
+        // - The handles opened on the previous device are invalidated, the metadata are kept.
+        Internal_device.Device.Remove();
+        Internal_device.Device = Registered_device_type::New(Device);
+        Internal_device.Class = Class;
+
+        Ok(())
+    }
+
+    fn Get_device_class(&self, Path: &dyn AsRef<Path_type>) -> Result_type<Device_class_type> {
+        Ok(self
+            .0
+            .read()?
+            .Devices
+            .get(Path.as_ref())
+            .ok_or(Error_type::Not_found)?
+            .Class)
+    }
+
     fn Set_permissions(
         &self,
         Path: &dyn AsRef<Path_type>,
@@ -0,0 +1,557 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+use Task::Task_identifier_type;
+
+use super::{
+    Control_argument_trait, Control_command_type, Control_direction_type,
+    Control_plain_argument_trait, Device_trait,
+};
+use crate::{Error_type, Position_type, Result_type};
+
+/// Mode of a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Terminal_mode_type {
+    /// The data is passed as is to the reader, without any processing.
+    Raw = 0,
+    /// The data is edited line by line (erase, interrupt) and only complete lines are passed to the reader.
+    Canonical = 1,
+}
+
+impl TryFrom<u8> for Terminal_mode_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Raw),
+            1 => Ok(Self::Canonical),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Size of the window of a terminal, in characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Window_size_type {
+    Rows: u16,
+    Columns: u16,
+}
+
+unsafe impl Control_argument_trait for Window_size_type {}
+
+unsafe impl Control_plain_argument_trait for Window_size_type {}
+
+impl Default for Window_size_type {
+    fn default() -> Self {
+        Self::New(24, 80)
+    }
+}
+
+impl Window_size_type {
+    pub const fn New(Rows: u16, Columns: u16) -> Self {
+        Self { Rows, Columns }
+    }
+
+    pub const fn Get_rows(&self) -> u16 {
+        self.Rows
+    }
+
+    pub const fn Get_columns(&self) -> u16 {
+        self.Columns
+    }
+}
+
+pub const Terminal_control_kind: u8 = 0x40;
+
+pub const Terminal_set_mode_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, Terminal_control_kind, 0);
+
+pub const Terminal_get_mode_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Read, Terminal_control_kind, 1);
+
+pub const Terminal_set_echo_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, Terminal_control_kind, 2);
+
+pub const Terminal_get_echo_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Read, Terminal_control_kind, 3);
+
+pub const Terminal_set_window_size_command: Control_command_type =
+    Control_command_type::New::<Window_size_type>(
+        Control_direction_type::Write,
+        Terminal_control_kind,
+        4,
+    );
+
+pub const Terminal_get_window_size_command: Control_command_type =
+    Control_command_type::New::<Window_size_type>(
+        Control_direction_type::Read,
+        Terminal_control_kind,
+        5,
+    );
+
+/// The argument is the identifier of the task (`u32`).
+pub const Terminal_set_foreground_task_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Write, Terminal_control_kind, 6);
+
+/// The argument is the identifier of the task (`u32`), `u32::MAX` if there is no foreground task.
+pub const Terminal_get_foreground_task_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Read, Terminal_control_kind, 7);
+
+const Interrupt_character: u8 = 0x03; // Ctrl-C
+const Backspace_character: u8 = 0x08;
+const Delete_character: u8 = 0x7F;
+
+struct Inner_type {
+    Mode: Terminal_mode_type,
+    Echo: bool,
+    Window_size: Window_size_type,
+    Foreground_task: Option<Task_identifier_type>,
+    /// Line being edited (canonical mode).
+    Line: Vec<u8>,
+    /// Data ready to be read.
+    Ready: VecDeque<u8>,
+}
+
+/// Terminal line discipline on top of a character device (serial port, pipe, console, etc.).
+///
+/// In canonical mode, the erase characters (backspace and delete) remove the last character of the line being
+/// edited, and the interrupt character (`Ctrl-C`) discards it and notifies the foreground task through the task
+/// manager. The device is polled when the terminal is read, so reading never blocks.
+pub struct Terminal_type {
+    Device: Box<dyn Device_trait>,
+    Inner: Mutex<Inner_type>,
+}
+
+impl Terminal_type {
+    /// Create a terminal in canonical mode with echo enabled.
+    pub fn New(Device: Box<dyn Device_trait>) -> Self {
+        Self {
+            Device,
+            Inner: Mutex::new(Inner_type {
+                Mode: Terminal_mode_type::Canonical,
+                Echo: true,
+                Window_size: Window_size_type::default(),
+                Foreground_task: None,
+                Line: Vec::new(),
+                Ready: VecDeque::new(),
+            }),
+        }
+    }
+
+    fn Echo(&self, Inner: &Inner_type, Data: &[u8]) -> Result_type<()> {
+        if Inner.Echo && !Data.is_empty() {
+            self.Device.Write(Data)?;
+        }
+
+        Ok(())
+    }
+
+    fn Process_canonical(&self, Inner: &mut Inner_type, Byte: u8) -> Result_type<()> {
+        match Byte {
+            Backspace_character | Delete_character => {
+                if Inner.Line.pop().is_some() {
+                    self.Echo(Inner, b"\x08 \x08")?;
+                }
+            }
+            Interrupt_character => {
+                Inner.Line.clear();
+
+                self.Echo(Inner, b"^C\n")?;
+
+                if let Some(Task) = Inner.Foreground_task {
+                    match Task::Get_instance()?.Raise_signal(Task, Task::Signal_type::Interrupt) {
+                        Ok(()) => {}
+                        // - The foreground task has exited
+                        Err(Task::Error_type::Invalid_task_identifier) => {
+                            Inner.Foreground_task = None
+                        }
+                        Err(Error) => return Err(Error.into()),
+                    }
+                }
+            }
+            b'\r' | b'\n' => {
+                Inner.Line.push(b'\n');
+
+                self.Echo(Inner, b"\n")?;
+
+                let Line = std::mem::take(&mut Inner.Line);
+                Inner.Ready.extend(Line);
+            }
+            Byte => {
+                Inner.Line.push(Byte);
+
+                self.Echo(Inner, &[Byte])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read the available data from the device and process it according to the mode.
+    fn Poll(&self, Inner: &mut Inner_type) -> Result_type<()> {
+        let mut Buffer = [0_u8; 64];
+
+        loop {
+            let Size = self.Device.Read(&mut Buffer)?;
+
+            if Size == 0 {
+                return Ok(());
+            }
+
+            match Inner.Mode {
+                Terminal_mode_type::Raw => {
+                    Inner.Ready.extend(&Buffer[..Size]);
+
+                    self.Echo(Inner, &Buffer[..Size])?;
+                }
+                Terminal_mode_type::Canonical => {
+                    for Byte in &Buffer[..Size] {
+                        self.Process_canonical(Inner, *Byte)?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Device_trait for Terminal_type {
+    /// Read the processed data, returns 0 if none is available (in canonical mode, until a line is complete).
+    fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+        let mut Inner = self.Inner.lock()?;
+
+        self.Poll(&mut Inner)?;
+
+        let Size = Buffer.len().min(Inner.Ready.len());
+
+        for (Destination, Source) in Buffer.iter_mut().zip(Inner.Ready.drain(..Size)) {
+            *Destination = Source;
+        }
+
+        Ok(Size)
+    }
+
+    fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+        self.Device.Write(Buffer)
+    }
+
+    fn Get_size(&self) -> Result_type<usize> {
+        Ok(self.Inner.lock()?.Ready.len())
+    }
+
+    fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self) -> Result_type<()> {
+        self.Device.Flush()
+    }
+
+    /// Handle the terminal commands, the other commands are forwarded to the underlying device.
+    fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        match Command {
+            Terminal_set_mode_command => {
+                let Mode = Terminal_mode_type::try_from(Command.Get_argument::<u8>(Argument)?)
+                    .map_err(|_| Error_type::Invalid_input)?;
+
+                // - Pass the line being edited to the reader when switching to raw mode
+                if Mode == Terminal_mode_type::Raw {
+                    let Line = std::mem::take(&mut Inner.Line);
+                    Inner.Ready.extend(Line);
+                }
+
+                Inner.Mode = Mode;
+            }
+            Terminal_get_mode_command => Command.Set_argument(Argument, Inner.Mode as u8)?,
+            Terminal_set_echo_command => Inner.Echo = Command.Get_argument::<u8>(Argument)? != 0,
+            Terminal_get_echo_command => Command.Set_argument(Argument, Inner.Echo as u8)?,
+            Terminal_set_window_size_command => {
+                Inner.Window_size = Command.Get_argument::<Window_size_type>(Argument)?
+            }
+            Terminal_get_window_size_command => {
+                Command.Set_argument(Argument, Inner.Window_size)?
+            }
+            Terminal_set_foreground_task_command => {
+                Inner.Foreground_task = match Command.Get_argument::<u32>(Argument)? {
+                    u32::MAX => None,
+                    Task => {
+                        let Task = Task.into();
+
+                        // - The foreground task receives the interrupts, which aren't checked by the task manager
+                        let Task_manager = Task::Get_instance()?;
+
+                        let Current_task_owner =
+                            Task_manager.Get_owner(Task_manager.Get_current_task_identifier()?)?;
+
+                        if !Users::Manager_type::Is_root(Current_task_owner)
+                            && Task_manager.Get_owner(Task)? != Current_task_owner
+                        {
+                            return Err(Error_type::Permission_denied);
+                        }
+
+                        Some(Task)
+                    }
+                }
+            }
+            Terminal_get_foreground_task_command => {
+                Command.Set_argument(Argument, Inner.Foreground_task.map_or(u32::MAX, u32::from))?
+            }
+            _ => {
+                drop(Inner);
+                return self.Device.Control(Command, Argument);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Loopback_device_type {
+        Input: Arc<Mutex<VecDeque<u8>>>,
+        Output: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl Device_trait for Loopback_device_type {
+        fn Read(&self, Buffer: &mut [u8]) -> Result_type<usize> {
+            let mut Input = self.Input.lock()?;
+
+            let Size = Buffer.len().min(Input.len());
+
+            for (Destination, Source) in Buffer.iter_mut().zip(Input.drain(..Size)) {
+                *Destination = Source;
+            }
+
+            Ok(Size)
+        }
+
+        fn Write(&self, Buffer: &[u8]) -> Result_type<usize> {
+            self.Output.lock()?.extend_from_slice(Buffer);
+
+            Ok(Buffer.len())
+        }
+
+        fn Get_size(&self) -> Result_type<usize> {
+            Ok(0)
+        }
+
+        fn Set_position(&self, _: &Position_type) -> Result_type<usize> {
+            Err(Error_type::Unsupported_operation)
+        }
+
+        fn Flush(&self) -> Result_type<()> {
+            Ok(())
+        }
+    }
+
+    impl Loopback_device_type {
+        fn Type(&self, Data: &[u8]) {
+            self.Input.lock().unwrap().extend(Data);
+        }
+
+        fn Take_output(&self) -> Vec<u8> {
+            std::mem::take(&mut *self.Output.lock().unwrap())
+        }
+    }
+
+    fn Read_all(Terminal: &Terminal_type) -> Vec<u8> {
+        let mut Buffer = [0_u8; 64];
+
+        let Size = Terminal.Read(&mut Buffer).unwrap();
+
+        Buffer[..Size].to_vec()
+    }
+
+    #[test]
+    fn Test_canonical_mode() {
+        let Device = Loopback_device_type::default();
+        let Terminal = Terminal_type::New(Box::new(Device.clone()));
+
+        // - Incomplete line
+        Device.Type(b"Helo");
+        assert_eq!(Read_all(&Terminal), b"");
+        assert_eq!(Device.Take_output(), b"Helo");
+
+        // - Erase and complete the line
+        Device.Type(b"\x7Flo\r");
+        assert_eq!(Read_all(&Terminal), b"Hello\n");
+        assert_eq!(Device.Take_output(), b"\x08 \x08lo\n");
+
+        // - Erase on an empty line does nothing
+        Device.Type(b"\x08A\n");
+        assert_eq!(Read_all(&Terminal), b"A\n");
+        assert_eq!(Device.Take_output(), b"A\n");
+
+        // - Without echo
+        let mut Argument = [0_u8];
+        Terminal
+            .Control(Terminal_set_echo_command, &mut Argument)
+            .unwrap();
+
+        Device.Type(b"Secret\n");
+        assert_eq!(Read_all(&Terminal), b"Secret\n");
+        assert_eq!(Device.Take_output(), b"");
+
+        // - Write is passed as is
+        assert_eq!(Terminal.Write(b"Output"), Ok(6));
+        assert_eq!(Device.Take_output(), b"Output");
+    }
+
+    #[test]
+    fn Test_raw_mode() {
+        let Device = Loopback_device_type::default();
+        let Terminal = Terminal_type::New(Box::new(Device.clone()));
+
+        Device.Type(b"Line");
+
+        Terminal
+            .Control(
+                Terminal_set_mode_command,
+                &mut [Terminal_mode_type::Raw as u8],
+            )
+            .unwrap();
+
+        Device.Type(b"\x7F\x03\r");
+        assert_eq!(Read_all(&Terminal), b"Line\x7F\x03\r");
+
+        let mut Argument = [0_u8];
+        Terminal
+            .Control(Terminal_get_mode_command, &mut Argument)
+            .unwrap();
+        assert_eq!(Argument, [Terminal_mode_type::Raw as u8]);
+
+        assert_eq!(
+            Terminal.Control(Terminal_set_mode_command, &mut [2]),
+            Err(Error_type::Invalid_input)
+        );
+    }
+
+    #[test]
+    fn Test_window_size() {
+        let Terminal = Terminal_type::New(Box::new(Loopback_device_type::default()));
+
+        let mut Argument = [0_u8; size_of::<Window_size_type>()];
+
+        Terminal_set_window_size_command
+            .Set_argument(&mut Argument, Window_size_type::New(50, 132))
+            .unwrap();
+        Terminal
+            .Control(Terminal_set_window_size_command, &mut Argument)
+            .unwrap();
+
+        let mut Argument = [0_u8; size_of::<Window_size_type>()];
+        Terminal
+            .Control(Terminal_get_window_size_command, &mut Argument)
+            .unwrap();
+        assert_eq!(
+            Terminal_get_window_size_command.Get_argument::<Window_size_type>(&Argument),
+            Ok(Window_size_type::New(50, 132))
+        );
+
+        // - Unknown commands are forwarded to the device
+        assert_eq!(
+            Terminal.Control(
+                Control_command_type::New::<u8>(Control_direction_type::Write, 0xFF, 0),
+                &mut [0]
+            ),
+            Err(Error_type::Unsupported_operation)
+        );
+    }
+
+    #[test]
+    fn Test_interrupt() {
+        let Task_manager = match Task::Get_instance() {
+            Ok(Task_manager) => Task_manager,
+            Err(_) => Task::Initialize().unwrap(),
+        };
+
+        let Task = Task_manager.Get_current_task_identifier().unwrap();
+
+        let Device = Loopback_device_type::default();
+        let Terminal = Terminal_type::New(Box::new(Device.clone()));
+
+        let mut Argument = [0_u8; 4];
+        Terminal_set_foreground_task_command
+            .Set_argument(&mut Argument, u32::from(Task))
+            .unwrap();
+        Terminal
+            .Control(Terminal_set_foreground_task_command, &mut Argument)
+            .unwrap();
+
+        let mut Argument = [0_u8; 4];
+        Terminal
+            .Control(Terminal_get_foreground_task_command, &mut Argument)
+            .unwrap();
+        assert_eq!(
+            Terminal_get_foreground_task_command.Get_argument::<u32>(&Argument),
+            Ok(u32::from(Task))
+        );
+
+        Device.Type(b"Discarded\x03Kept\n");
+        assert_eq!(Read_all(&Terminal), b"Kept\n");
+        assert_eq!(Device.Take_output(), b"Discarded^C\nKept\n");
+
+        assert!(Task_manager.Take_interrupt(Task).unwrap());
+        assert!(!Task_manager.Take_interrupt(Task).unwrap());
+
+        // - Only the tasks of the same user can be set as foreground task
+        let (_, Join_handle) = Task_manager
+            .New_task(
+                None,
+                Some(1),
+                "Other user task",
+                None,
+                None,
+                None,
+                move || {
+                    let mut Argument = [0_u8; 4];
+                    Terminal_set_foreground_task_command
+                        .Set_argument(&mut Argument, u32::from(Task))
+                        .unwrap();
+
+                    Terminal_type::New(Box::new(Loopback_device_type::default()))
+                        .Control(Terminal_set_foreground_task_command, &mut Argument)
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            Join_handle.Join().unwrap(),
+            Err(Error_type::Permission_denied)
+        );
+
+        // - The foreground task is cleared once it has exited
+        let (Child_task, Join_handle) = Task_manager
+            .New_task(None, None, "Foreground task", None, None, None, || ())
+            .unwrap();
+
+        let mut Argument = [0_u8; 4];
+        Terminal_set_foreground_task_command
+            .Set_argument(&mut Argument, u32::from(Child_task))
+            .unwrap();
+        Terminal
+            .Control(Terminal_set_foreground_task_command, &mut Argument)
+            .unwrap();
+
+        Join_handle.Join().unwrap();
+
+        Device.Type(b"\x03");
+        assert_eq!(Read_all(&Terminal), b"");
+
+        let mut Argument = [0_u8; 4];
+        Terminal
+            .Control(Terminal_get_foreground_task_command, &mut Argument)
+            .unwrap();
+        assert_eq!(
+            Terminal_get_foreground_task_command.Get_argument::<u32>(&Argument),
+            Ok(u32::MAX)
+        );
+    }
+}
@@ -1,6 +1,12 @@
+mod Clock;
+mod Control;
 #[allow(clippy::module_inception)]
 mod Device;
 mod File_system;
+mod Terminal;
 
+pub use Clock::*;
+pub use Control::*;
 pub use Device::*;
 pub use File_system::*;
+pub use Terminal::*;
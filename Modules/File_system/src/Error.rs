@@ -32,6 +32,18 @@ pub enum Error_type {
     Failed_to_get_task_manager_instance,
     Invalid_input,
     Other,
+    Open_files_limit_exceeded,
+    Device_removed,
+    /// The addressed peripheral didn't acknowledge a bus transfer.
+    No_acknowledge,
+    /// No network backend is registered.
+    Network_unavailable,
+    Address_in_use,
+    Address_not_available,
+    Connection_refused,
+    Connection_reset,
+    Not_connected,
+    Timed_out,
 }
 
 #[cfg(feature = "std")]
@@ -45,6 +57,15 @@ impl From<std::io::ErrorKind> for Error_type {
             ErrorKind::AlreadyExists => Error_type::Already_exists,
             ErrorKind::InvalidInput => Error_type::Invalid_path,
             ErrorKind::InvalidData => Error_type::Invalid_file,
+            ErrorKind::WouldBlock => Error_type::Ressource_busy,
+            ErrorKind::AddrInUse => Error_type::Address_in_use,
+            ErrorKind::AddrNotAvailable => Error_type::Address_not_available,
+            ErrorKind::ConnectionRefused => Error_type::Connection_refused,
+            ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::BrokenPipe => {
+                Error_type::Connection_reset
+            }
+            ErrorKind::NotConnected => Error_type::Not_connected,
+            ErrorKind::TimedOut => Error_type::Timed_out,
             _ => Error_type::Unknown,
         }
     }
@@ -63,6 +84,22 @@ impl From<Task::Error_type> for Error_type {
     }
 }
 
+impl From<Time::Error_type> for Error_type {
+    fn from(Error: Time::Error_type) -> Self {
+        match Error {
+            Time::Error_type::Poisoned_lock => Error_type::Poisoned_lock,
+            Time::Error_type::Not_initialized => Error_type::Not_initialized,
+            Time::Error_type::Already_initialized => Error_type::Already_initialized,
+            Time::Error_type::Invalid_time_zone | Time::Error_type::Invalid_date_time => {
+                Error_type::Invalid_input
+            }
+            Time::Error_type::Unsupported_operation => Error_type::Unsupported_operation,
+            Time::Error_type::Real_time_clock_error => Error_type::Internal_error,
+            Time::Error_type::Permission_denied => Error_type::Permission_denied,
+        }
+    }
+}
+
 impl<T> From<PoisonError<T>> for Error_type {
     fn from(_: PoisonError<T>) -> Self {
         Error_type::Poisoned_lock
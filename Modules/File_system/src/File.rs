@@ -3,8 +3,8 @@ use std::fmt::Debug;
 use Task::Task_identifier_type;
 
 use super::{
-    Flags_type, Path_type, Position_type, Result_type, Size_type, Status_type,
-    Unique_file_identifier_type, Virtual_file_system::Virtual_file_system_type,
+    Control_command_type, Flags_type, Path_type, Position_type, Result_type, Size_type,
+    Status_type, Unique_file_identifier_type, Virtual_file_system::Virtual_file_system_type,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -100,6 +100,12 @@ impl File_type {
         self.File_system
             .Read(self.Get_file_identifier(), Buffer, self.Task)
     }
+    /// Perform a control operation on the file (mainly used by devices).
+    pub fn Control(&self, Command: Control_command_type, Argument: &mut [u8]) -> Result_type<()> {
+        self.File_system
+            .Control(self.Get_file_identifier(), Command, Argument, self.Task)
+    }
+
     pub fn Read_line(&self, Buffer: &mut [u8]) -> Result_type<()> {
         let mut Index = 0;
         loop {
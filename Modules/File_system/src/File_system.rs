@@ -1,11 +1,15 @@
-use crate::Mode_type;
+use std::sync::Arc;
+
+use crate::{Mode_type, Socket_trait};
 
 use super::{
-    Device::Device_trait, Error_type, File_identifier_type, Flags_type, Path_owned_type, Path_type,
-    Permissions_type, Position_type, Result_type, Size_type, Status_type, Type_type,
+    Device::{Device_class_type, Device_trait},
+    Error_type, File_identifier_type, Flags_type, Path_owned_type, Path_type, Permissions_type,
+    Position_type, Result_type, Size_type, Status_type, Type_type,
 };
 
 use Task::Task_identifier_type;
+use Time::Time_type;
 use Users::{Group_identifier_type, User_identifier_type};
 
 /// File system trait.
@@ -109,6 +113,19 @@ pub trait File_system_traits: Send + Sync {
 
     fn Flush(&self, Task: Task_identifier_type, File: File_identifier_type) -> Result_type<()>;
 
+    /// Get a device opened by a task.
+    ///
+    /// # Errors
+    /// - If the file is not opened (invalid file identifier).
+    /// - If the file is not a device.
+    fn Get_device(
+        &self,
+        _: Task_identifier_type,
+        _: File_identifier_type,
+    ) -> Result_type<Arc<dyn Device_trait>> {
+        Err(Error_type::Unsupported_operation)
+    }
+
     // - Metadata
     // - - Size
 
@@ -125,6 +142,29 @@ pub trait File_system_traits: Send + Sync {
     /// - If the user / group doesn't have the permission to get the size (no execute permission on parent directory).
     fn Get_size(&self, Path: &dyn AsRef<Path_type>) -> Result_type<Size_type>;
 
+    // - - Time
+
+    /// Get the time of the last modification of the file.
+    ///
+    /// # Errors
+    /// - If the file doesn't exists.
+    /// - If the file system doesn't keep track of the modification times.
+    fn Get_modification_time(&self, _: &dyn AsRef<Path_type>) -> Result_type<Time_type> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    /// Set the time of the last modification of the file.
+    ///
+    /// The implementations should set the modification time with the time of the system (`Time::Get_instance`)
+    /// when a file is created or written, if it is initialized.
+    ///
+    /// # Errors
+    /// - If the file doesn't exists.
+    /// - If the file system doesn't keep track of the modification times.
+    fn Set_modification_time(&self, _: &dyn AsRef<Path_type>, _: Time_type) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+
     // - - Security
 
     /// Set the owner of the file.
@@ -184,18 +224,52 @@ pub trait File_system_traits: Send + Sync {
     /// Returns an error if the user / group doesn't have the permission to create the directory (no write permission on parent directory).
     fn Create_directory(&self, Path: &dyn AsRef<Path_type>) -> Result_type<()>;
 
+    /// Get the paths of the entries of a directory, it is used to rebuild the usage of the quotas at mount.
+    ///
+    /// The default implementation doesn't list any entry : the usage starts empty.
+    ///
+    /// # Errors
+    /// Returns an error if the directory doesn't exists.
+    fn Get_directory_entries(&self, _: &dyn AsRef<Path_type>) -> Result_type<Vec<Path_owned_type>> {
+        Ok(vec![])
+    }
+
     fn Create_named_pipe(&self, _: &dyn AsRef<Path_type>, _: Size_type) -> Result_type<()> {
         Err(Error_type::Unsupported_operation)
     }
 
+    /// Register a device at the given path, owned by the given user and group.
+    ///
+    /// # Errors
+    /// Returns an error if a device is already registered at this path.
     fn Add_device(
         &self,
-        _: &'static dyn AsRef<Path_type>,
+        _: &dyn AsRef<Path_type>,
+        _: Device_class_type,
+        _: Box<dyn Device_trait>,
+        _: User_identifier_type,
+        _: Group_identifier_type,
+        _: Permissions_type,
+    ) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    /// Replace a registered device, keeping its owner and permissions.
+    ///
+    /// The files opened on the previous device return `Device_removed` afterwards.
+    fn Replace_device(
+        &self,
+        _: &dyn AsRef<Path_type>,
+        _: Device_class_type,
         _: Box<dyn Device_trait>,
     ) -> Result_type<()> {
         Err(Error_type::Unsupported_operation)
     }
 
+    fn Get_device_class(&self, _: &dyn AsRef<Path_type>) -> Result_type<Device_class_type> {
+        Err(Error_type::Unsupported_operation)
+    }
+
     fn Create_unnamed_pipe(
         &self,
         _: Task_identifier_type,
@@ -205,6 +279,28 @@ pub trait File_system_traits: Send + Sync {
         Err(Error_type::Unsupported_operation)
     }
 
+    /// Register an opened socket for a task, returns its file identifier.
+    fn Add_socket(
+        &self,
+        _: Task_identifier_type,
+        _: Box<dyn Socket_trait>,
+    ) -> Result_type<File_identifier_type> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    /// Get a socket opened by a task.
+    ///
+    /// # Errors
+    /// - If the file is not opened (invalid file identifier).
+    /// - If the file is not a socket.
+    fn Get_socket(
+        &self,
+        _: Task_identifier_type,
+        _: File_identifier_type,
+    ) -> Result_type<Arc<dyn Socket_trait>> {
+        Err(Error_type::Unsupported_operation)
+    }
+
     /// Combine task identifier and file identifier to get a unique file identifier.
     fn Get_local_file_identifier(
         Task_identifier: Task_identifier_type,
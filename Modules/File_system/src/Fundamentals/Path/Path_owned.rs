@@ -106,24 +106,51 @@ impl Path_owned_type {
     pub fn Canonicalize(mut self) -> Self {
         let mut Stack: Vec<&str> = Vec::new();
 
-        if self.Is_absolute() {
+        let Is_absolute = self.Is_absolute();
+
+        if Is_absolute {
             Stack.push("");
         }
 
         for Component in self.0.split('/') {
             match Component {
                 ".." => {
-                    Stack.pop();
+                    // The root directory is its own parent.
+                    if !(Is_absolute && Stack.len() == 1) {
+                        Stack.pop();
+                    }
                 }
                 "." | "" => continue,
                 _ => Stack.push(Component),
             }
         }
 
-        self.0 = Stack.join("/");
+        self.0 = if Is_absolute && Stack.len() == 1 {
+            Separator.to_string()
+        } else {
+            Stack.join("/")
+        };
 
         self
     }
+
+    /// Resolve a path against a working directory.
+    ///
+    /// If the path is relative, it is joined to the working directory, then the result is canonicalized.
+    pub fn Resolve(
+        Working_directory: impl AsRef<Path_type>,
+        Path: impl AsRef<Path_type>,
+    ) -> Option<Self> {
+        let Path = Path.as_ref();
+
+        let Path = if Path.Is_absolute() {
+            Path.to_owned()
+        } else {
+            Working_directory.as_ref().to_owned().Join(Path)?
+        };
+
+        Some(Path.Canonicalize())
+    }
 }
 
 pub fn Is_valid_string(String: &str) -> bool {
@@ -234,5 +261,32 @@ mod tests {
 
         let Path = Path_owned_type::try_from("./home/../home/user/./file.txt").unwrap();
         assert_eq!(Path.Canonicalize().As_str(), "home/user/file.txt");
+
+        let Path = Path_owned_type::try_from("/home/..").unwrap();
+        assert_eq!(Path.Canonicalize().As_str(), "/");
+
+        let Path = Path_owned_type::try_from("/../../home").unwrap();
+        assert_eq!(Path.Canonicalize().As_str(), "/home");
+    }
+
+    #[test]
+    fn Test_resolve() {
+        let Working_directory = Path_type::New("/home/user").unwrap();
+
+        let Path = Path_owned_type::Resolve(Working_directory, Path_type::New("file.txt").unwrap());
+        assert_eq!(Path.unwrap().As_str(), "/home/user/file.txt");
+
+        let Path =
+            Path_owned_type::Resolve(Working_directory, Path_type::New("./file.txt").unwrap());
+        assert_eq!(Path.unwrap().As_str(), "/home/user/file.txt");
+
+        let Path = Path_owned_type::Resolve(Working_directory, Path_type::New("../other").unwrap());
+        assert_eq!(Path.unwrap().As_str(), "/home/other");
+
+        let Path = Path_owned_type::Resolve(Working_directory, Path_type::New("..").unwrap());
+        assert_eq!(Path.unwrap().As_str(), "/home");
+
+        let Path = Path_owned_type::Resolve(Working_directory, Path_type::New("/etc/./x").unwrap());
+        assert_eq!(Path.unwrap().As_str(), "/etc/x");
     }
 }
@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use Users::{Group_identifier_type, User_identifier_type};
+
+use super::{Block_type, Size_type};
+use crate::{Error_type, Result_type};
+
+/// Subject of a quota (a user or a group).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub enum Quota_subject_type {
+    User(User_identifier_type),
+    Group(Group_identifier_type),
+}
+
+/// Soft and hard limits of a quota.
+///
+/// The soft limit can be exceeded (it is only reported), while the hard limit cannot.
+/// A value of `None` means that there is no limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Quota_limit_type {
+    Soft: Option<u64>,
+    Hard: Option<u64>,
+}
+
+impl Quota_limit_type {
+    pub const fn New(Soft: Option<u64>, Hard: Option<u64>) -> Self {
+        Self { Soft, Hard }
+    }
+
+    pub const fn Get_soft(&self) -> Option<u64> {
+        self.Soft
+    }
+
+    pub const fn Get_hard(&self) -> Option<u64> {
+        self.Hard
+    }
+
+    pub fn Is_soft_exceeded(&self, Usage: u64) -> bool {
+        self.Soft.is_some_and(|Soft| Usage > Soft)
+    }
+
+    pub fn Is_hard_exceeded(&self, Usage: u64) -> bool {
+        self.Hard.is_some_and(|Hard| Usage > Hard)
+    }
+}
+
+/// Block and file count quota of a user or a group on a mounted file system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Quota_type {
+    Blocks: Quota_limit_type,
+    Files: Quota_limit_type,
+}
+
+impl Quota_type {
+    /// Creates a quota without any limit.
+    pub const fn New_unlimited() -> Self {
+        Self {
+            Blocks: Quota_limit_type::New(None, None),
+            Files: Quota_limit_type::New(None, None),
+        }
+    }
+
+    pub fn Set_blocks(mut self, Soft: Option<u64>, Hard: Option<u64>) -> Self {
+        self.Blocks = Quota_limit_type::New(Soft, Hard);
+        self
+    }
+
+    pub fn Set_files(mut self, Soft: Option<u64>, Hard: Option<u64>) -> Self {
+        self.Files = Quota_limit_type::New(Soft, Hard);
+        self
+    }
+
+    pub const fn Get_blocks(&self) -> Quota_limit_type {
+        self.Blocks
+    }
+
+    pub const fn Get_files(&self) -> Quota_limit_type {
+        self.Files
+    }
+}
+
+/// Resources used by a user or a group on a mounted file system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Quota_usage_type {
+    Blocks: u64,
+    Files: u64,
+}
+
+impl Quota_usage_type {
+    pub const fn New(Blocks: u64, Files: u64) -> Self {
+        Self { Blocks, Files }
+    }
+
+    /// Number of blocks (of the size of `Block_type`) used.
+    pub const fn Get_blocks(&self) -> u64 {
+        self.Blocks
+    }
+
+    /// Number of files (including directories) owned.
+    pub const fn Get_files(&self) -> u64 {
+        self.Files
+    }
+}
+
+/// Quota and usage of a user or a group on a mounted file system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Quota_report_type {
+    Quota: Quota_type,
+    Usage: Quota_usage_type,
+}
+
+impl Quota_report_type {
+    pub const fn Get_quota(&self) -> Quota_type {
+        self.Quota
+    }
+
+    pub const fn Get_usage(&self) -> Quota_usage_type {
+        self.Usage
+    }
+
+    pub fn Is_soft_limit_exceeded(&self) -> bool {
+        self.Quota.Blocks.Is_soft_exceeded(self.Usage.Blocks)
+            || self.Quota.Files.Is_soft_exceeded(self.Usage.Files)
+    }
+
+    pub fn Is_hard_limit_exceeded(&self) -> bool {
+        self.Quota.Blocks.Is_hard_exceeded(self.Usage.Blocks)
+            || self.Quota.Files.Is_hard_exceeded(self.Usage.Files)
+    }
+}
+
+/// Get the number of blocks used by a file of a given size.
+pub fn Get_blocks_count(Size: Size_type) -> u64 {
+    u64::from(Size).div_ceil(std::mem::size_of::<Block_type>() as u64)
+}
+
+/// Get the signed difference of blocks used by a file resized from `Old_size` to `New_size`.
+pub fn Get_blocks_difference(Old_size: Size_type, New_size: Size_type) -> i64 {
+    Get_blocks_count(New_size) as i64 - Get_blocks_count(Old_size) as i64
+}
+
+/// Quotas and usages of the users and groups of a mounted file system.
+///
+/// The usage is tracked for every owner, even without quota, so that a quota set afterwards takes into account the files already created.
+#[derive(Debug, Default)]
+pub(crate) struct Quotas_type(BTreeMap<Quota_subject_type, Quota_report_type>);
+
+impl Quotas_type {
+    pub fn New() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    pub fn Set_quota(&mut self, Subject: Quota_subject_type, Quota: Quota_type) {
+        self.0.entry(Subject).or_default().Quota = Quota;
+    }
+
+    pub fn Get_report(&self, Subject: Quota_subject_type) -> Quota_report_type {
+        self.0.get(&Subject).copied().unwrap_or_default()
+    }
+
+    pub fn Get_reports(&self) -> Vec<(Quota_subject_type, Quota_report_type)> {
+        self.0
+            .iter()
+            .map(|(Subject, Report)| (*Subject, *Report))
+            .collect()
+    }
+
+    /// Check that allocating resources for an owner doesn't exceed the hard limits.
+    ///
+    /// # Errors
+    /// Returns `File_system_full` if a hard limit of the user or the group would be exceeded.
+    pub fn Check(
+        &self,
+        User: User_identifier_type,
+        Group: Group_identifier_type,
+        Blocks: u64,
+        Files: u64,
+    ) -> Result_type<()> {
+        for Subject in [
+            Quota_subject_type::User(User),
+            Quota_subject_type::Group(Group),
+        ] {
+            if let Some(Report) = self.0.get(&Subject) {
+                if Report
+                    .Quota
+                    .Blocks
+                    .Is_hard_exceeded(Report.Usage.Blocks.saturating_add(Blocks))
+                    || Report
+                        .Quota
+                        .Files
+                        .Is_hard_exceeded(Report.Usage.Files.saturating_add(Files))
+                {
+                    return Err(Error_type::File_system_full);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Account for resources allocated (positive) or released (negative) by an owner.
+    pub fn Charge(
+        &mut self,
+        User: User_identifier_type,
+        Group: Group_identifier_type,
+        Blocks: i64,
+        Files: i64,
+    ) {
+        for Subject in [
+            Quota_subject_type::User(User),
+            Quota_subject_type::Group(Group),
+        ] {
+            let Usage = &mut self.0.entry(Subject).or_default().Usage;
+
+            Usage.Blocks = Usage.Blocks.saturating_add_signed(Blocks);
+            Usage.Files = Usage.Files.saturating_add_signed(Files);
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_blocks_count() {
+        assert_eq!(Get_blocks_count(Size_type::from(0_u64)), 0);
+        assert_eq!(Get_blocks_count(Size_type::from(1_u64)), 1);
+        assert_eq!(Get_blocks_count(Size_type::from(512_u64)), 1);
+        assert_eq!(Get_blocks_count(Size_type::from(513_u64)), 2);
+    }
+
+    #[test]
+    fn Test_quotas() {
+        let mut Quotas = Quotas_type::New();
+
+        Quotas.Set_quota(
+            Quota_subject_type::User(1),
+            Quota_type::New_unlimited()
+                .Set_blocks(Some(2), Some(4))
+                .Set_files(None, Some(2)),
+        );
+
+        assert!(Quotas.Check(1, 1, 4, 2).is_ok());
+        assert_eq!(Quotas.Check(1, 1, 5, 0), Err(Error_type::File_system_full));
+        assert_eq!(Quotas.Check(1, 1, 0, 3), Err(Error_type::File_system_full));
+
+        Quotas.Charge(1, 1, 3, 1);
+
+        let Report = Quotas.Get_report(Quota_subject_type::User(1));
+        assert_eq!(Report.Get_usage(), Quota_usage_type::New(3, 1));
+        assert!(Report.Is_soft_limit_exceeded());
+        assert!(!Report.Is_hard_limit_exceeded());
+
+        // - Group usage is tracked even without quota
+        assert_eq!(
+            Quotas.Get_report(Quota_subject_type::Group(1)).Get_usage(),
+            Quota_usage_type::New(3, 1)
+        );
+
+        assert_eq!(Quotas.Check(1, 1, 2, 0), Err(Error_type::File_system_full));
+        assert!(Quotas.Check(2, 1, 2, 0).is_ok());
+
+        Quotas.Charge(1, 1, -3, -1);
+        assert_eq!(
+            Quotas.Get_report(Quota_subject_type::User(1)).Get_usage(),
+            Quota_usage_type::default()
+        );
+    }
+}
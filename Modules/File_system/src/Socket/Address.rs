@@ -0,0 +1,135 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// Address of a socket : an IPv4 or IPv6 address and a port.
+///
+/// The layout is flat (no enumeration) so that it can be safely read from a WASM app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Socket_address_type {
+    /// IP address in network byte order, IPv4 addresses only use the 4 first bytes.
+    IP_address: [u8; 16],
+    Port: u16,
+    /// IP version (`4` or `6`).
+    Version: u8,
+}
+
+impl Socket_address_type {
+    pub const fn New_IPv4(IP_address: [u8; 4], Port: u16) -> Self {
+        let [A, B, C, D] = IP_address;
+
+        Self {
+            IP_address: [A, B, C, D, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            Port,
+            Version: 4,
+        }
+    }
+
+    pub const fn New_IPv6(IP_address: [u8; 16], Port: u16) -> Self {
+        Self {
+            IP_address,
+            Port,
+            Version: 6,
+        }
+    }
+
+    /// Loopback IPv4 address (`127.0.0.1`) with a given port.
+    pub const fn New_localhost(Port: u16) -> Self {
+        Self::New_IPv4([127, 0, 0, 1], Port)
+    }
+
+    /// Unspecified IPv4 address (`0.0.0.0`) with a given port, used to bind on all the interfaces.
+    pub const fn New_any(Port: u16) -> Self {
+        Self::New_IPv4([0; 4], Port)
+    }
+
+    /// Check that the IP version is known (e.g. when coming from WASM).
+    pub const fn Is_valid(&self) -> bool {
+        self.Version == 4 || self.Version == 6
+    }
+
+    pub const fn Get_port(&self) -> u16 {
+        self.Port
+    }
+
+    pub const fn Is_IPv4(&self) -> bool {
+        self.Version == 4
+    }
+
+    /// Get the IPv4 address, if it is an IPv4 socket address.
+    pub const fn Get_IPv4_address(&self) -> Option<[u8; 4]> {
+        if !self.Is_IPv4() {
+            return None;
+        }
+
+        let [A, B, C, D, ..] = self.IP_address;
+
+        Some([A, B, C, D])
+    }
+
+    /// Get the IPv6 address, if it is an IPv6 socket address.
+    pub const fn Get_IPv6_address(&self) -> Option<[u8; 16]> {
+        if self.Version != 6 {
+            return None;
+        }
+
+        Some(self.IP_address)
+    }
+
+    pub fn Set_port(mut self, Port: u16) -> Self {
+        self.Port = Port;
+        self
+    }
+}
+
+impl From<SocketAddr> for Socket_address_type {
+    fn from(Address: SocketAddr) -> Self {
+        match Address.ip() {
+            IpAddr::V4(IP_address) => Self::New_IPv4(IP_address.octets(), Address.port()),
+            IpAddr::V6(IP_address) => Self::New_IPv6(IP_address.octets(), Address.port()),
+        }
+    }
+}
+
+/// Invalid addresses are converted to the unspecified IPv4 address.
+impl From<Socket_address_type> for SocketAddr {
+    fn from(Address: Socket_address_type) -> Self {
+        let IP_address = match (Address.Get_IPv4_address(), Address.Get_IPv6_address()) {
+            (Some(IP_address), _) => IpAddr::V4(Ipv4Addr::from(IP_address)),
+            (_, Some(IP_address)) => IpAddr::V6(Ipv6Addr::from(IP_address)),
+            _ => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        };
+
+        SocketAddr::new(IP_address, Address.Port)
+    }
+}
+
+impl std::fmt::Display for Socket_address_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(Formatter, "{}", SocketAddr::from(*self))
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_socket_address() {
+        let Address = Socket_address_type::New_localhost(8080);
+
+        assert!(Address.Is_valid());
+        assert_eq!(Address.Get_IPv4_address(), Some([127, 0, 0, 1]));
+        assert_eq!(Address.Get_IPv6_address(), None);
+        assert_eq!(Address.to_string(), "127.0.0.1:8080");
+
+        let Standard_address: SocketAddr = "[::1]:53".parse().unwrap();
+
+        let Address = Socket_address_type::from(Standard_address);
+
+        assert_eq!(Address.Get_port(), 53);
+        assert!(!Address.Is_IPv4());
+        assert_eq!(SocketAddr::from(Address), Standard_address);
+
+        assert_eq!(Address.Set_port(54).Get_port(), 54);
+    }
+}
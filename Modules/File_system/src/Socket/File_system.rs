@@ -0,0 +1,211 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+use Task::Task_identifier_type;
+use Time::Time_type;
+use Users::{Group_identifier_type, User_identifier_type};
+
+use crate::{
+    Error_type, File_identifier_type, File_system_traits, Flags_type, Path_type, Permissions_type,
+    Position_type, Result_type, Size_type, Type_type,
+};
+
+use super::Socket_trait;
+
+/// Special file system holding the sockets opened by the tasks.
+///
+/// Sockets have no path : they are only reachable through their file identifiers.
+pub struct File_system_type(RwLock<BTreeMap<u32, Arc<dyn Socket_trait>>>);
+
+impl File_system_type {
+    pub fn New() -> Self {
+        Self(RwLock::new(BTreeMap::new()))
+    }
+
+    fn Get_new_file_identifier(
+        Task: Task_identifier_type,
+        Opened_sockets: &BTreeMap<u32, Arc<dyn Socket_trait>>,
+    ) -> Result_type<File_identifier_type> {
+        let Start = Self::Get_local_file_identifier(Task, File_identifier_type::from(0));
+        let End = Self::Get_local_file_identifier(Task, File_identifier_type::from(0xFFFF));
+
+        (Start..=End)
+            .find(|Identifier| !Opened_sockets.contains_key(Identifier))
+            .map(|Identifier| File_identifier_type::from(Identifier as u16))
+            .ok_or(Error_type::Too_many_open_files)
+    }
+}
+
+impl File_system_traits for File_system_type {
+    fn Add_socket(
+        &self,
+        Task: Task_identifier_type,
+        Socket: Box<dyn Socket_trait>,
+    ) -> Result_type<File_identifier_type> {
+        let mut Opened_sockets = self.0.write()?;
+
+        let File_identifier = Self::Get_new_file_identifier(Task, &Opened_sockets)?;
+
+        Opened_sockets.insert(
+            Self::Get_local_file_identifier(Task, File_identifier),
+            Arc::from(Socket),
+        );
+
+        Ok(File_identifier)
+    }
+
+    fn Get_socket(
+        &self,
+        Task: Task_identifier_type,
+        File: File_identifier_type,
+    ) -> Result_type<Arc<dyn Socket_trait>> {
+        self.0
+            .read()?
+            .get(&Self::Get_local_file_identifier(Task, File))
+            .cloned()
+            .ok_or(Error_type::Invalid_identifier)
+    }
+
+    fn Exists(&self, _: &dyn AsRef<Path_type>) -> Result_type<bool> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Create_file(&self, _: &dyn AsRef<Path_type>) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Open(
+        &self,
+        _: Task_identifier_type,
+        _: &dyn AsRef<Path_type>,
+        _: Flags_type,
+    ) -> Result_type<File_identifier_type> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Close(&self, Task: Task_identifier_type, File: File_identifier_type) -> Result_type<()> {
+        // - The socket is closed once the pending operations of the other handles return
+        self.0
+            .write()?
+            .remove(&Self::Get_local_file_identifier(Task, File))
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Ok(())
+    }
+
+    fn Close_all(&self, Task: Task_identifier_type) -> Result_type<()> {
+        self.0
+            .write()?
+            .retain(|Key, _| Self::Decompose_local_file_identifier(*Key).0 != Task);
+
+        Ok(())
+    }
+
+    fn Transfert_file_identifier(
+        &self,
+        Old_task: Task_identifier_type,
+        New_task: Task_identifier_type,
+        File: File_identifier_type,
+    ) -> Result_type<File_identifier_type> {
+        let mut Opened_sockets = self.0.write()?;
+
+        let New_file_identifier = Self::Get_new_file_identifier(New_task, &Opened_sockets)?;
+
+        let Socket = Opened_sockets
+            .remove(&Self::Get_local_file_identifier(Old_task, File))
+            .ok_or(Error_type::Invalid_identifier)?;
+
+        Opened_sockets.insert(
+            Self::Get_local_file_identifier(New_task, New_file_identifier),
+            Socket,
+        );
+
+        Ok(New_file_identifier)
+    }
+
+    fn Delete(&self, _: &dyn AsRef<Path_type>) -> Result_type<()> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Read(
+        &self,
+        Task: Task_identifier_type,
+        File: File_identifier_type,
+        Buffer: &mut [u8],
+    ) -> Result_type<Size_type> {
+        // - Don't hold the lock while blocking
+        self.Get_socket(Task, File)?.Receive(Buffer)
+    }
+
+    fn Write(
+        &self,
+        Task: Task_identifier_type,
+        File: File_identifier_type,
+        Buffer: &[u8],
+    ) -> Result_type<Size_type> {
+        self.Get_socket(Task, File)?.Send(Buffer)
+    }
+
+    fn Move(&self, _: &dyn AsRef<Path_type>, _: &dyn AsRef<Path_type>) -> Result_type<()> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Set_position(
+        &self,
+        _: Task_identifier_type,
+        _: File_identifier_type,
+        _: &Position_type,
+    ) -> Result_type<Size_type> {
+        Err(Error_type::Unsupported_operation)
+    }
+
+    fn Flush(&self, _: Task_identifier_type, _: File_identifier_type) -> Result_type<()> {
+        Ok(())
+    }
+
+    fn Get_type(&self, _: &dyn AsRef<Path_type>) -> Result_type<Type_type> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Get_size(&self, _: &dyn AsRef<Path_type>) -> Result_type<Size_type> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Get_modification_time(&self, _: &dyn AsRef<Path_type>) -> Result_type<Time_type> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Set_modification_time(&self, _: &dyn AsRef<Path_type>, _: Time_type) -> Result_type<()> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Set_owner(
+        &self,
+        _: &dyn AsRef<Path_type>,
+        _: Option<User_identifier_type>,
+        _: Option<Group_identifier_type>,
+    ) -> Result_type<()> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Get_owner(
+        &self,
+        _: &dyn AsRef<Path_type>,
+    ) -> Result_type<(User_identifier_type, Group_identifier_type)> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Set_permissions(&self, _: &dyn AsRef<Path_type>, _: Permissions_type) -> Result_type<()> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Get_permissions(&self, _: &dyn AsRef<Path_type>) -> Result_type<Permissions_type> {
+        Err(Error_type::Not_found)
+    }
+
+    fn Create_directory(&self, _: &dyn AsRef<Path_type>) -> Result_type<()> {
+        Err(Error_type::Unsupported_operation)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{Error_type, Result_type, Size_type};
+
+use super::Socket_address_type;
+
+/// Transport protocol of a socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Protocol_type {
+    /// Reliable byte stream (`SOCK_STREAM`).
+    TCP,
+    /// Datagrams (`SOCK_DGRAM`).
+    UDP,
+}
+
+impl TryFrom<u8> for Protocol_type {
+    type Error = Error_type;
+
+    fn try_from(Value: u8) -> Result_type<Self> {
+        match Value {
+            0 => Ok(Protocol_type::TCP),
+            1 => Ok(Protocol_type::UDP),
+            _ => Err(Error_type::Invalid_input),
+        }
+    }
+}
+
+/// A socket of a network backend.
+///
+/// The socket is closed when it is dropped. Blocking operations must not hold locks shared with other sockets,
+/// since the sockets are used concurrently by different tasks.
+pub trait Socket_trait: Send + Sync {
+    fn Bind(&self, Address: Socket_address_type) -> Result_type<()>;
+
+    /// Listen for incoming connections (TCP only), `Backlog` is a hint of the pending connections queue size.
+    fn Listen(&self, Backlog: usize) -> Result_type<()>;
+
+    /// Accept an incoming connection (TCP only), returns the connected socket and the address of the peer.
+    fn Accept(&self) -> Result_type<(Box<dyn Socket_trait>, Socket_address_type)>;
+
+    /// Connect to a peer, for UDP this only sets the default destination and filters the received datagrams.
+    fn Connect(&self, Address: Socket_address_type) -> Result_type<()>;
+
+    /// Send data to the connected peer, returns the number of bytes sent.
+    fn Send(&self, Buffer: &[u8]) -> Result_type<Size_type>;
+
+    /// Receive data from the connected peer, returns `0` when the connection is closed (TCP).
+    fn Receive(&self, Buffer: &mut [u8]) -> Result_type<Size_type>;
+
+    /// Send a datagram to a given address (UDP only).
+    fn Send_to(&self, Buffer: &[u8], Address: Socket_address_type) -> Result_type<Size_type>;
+
+    /// Receive a datagram, returns its size and the address of the sender (UDP only).
+    fn Receive_from(&self, Buffer: &mut [u8]) -> Result_type<(Size_type, Socket_address_type)>;
+
+    fn Get_local_address(&self) -> Result_type<Socket_address_type>;
+
+    fn Get_peer_address(&self) -> Result_type<Socket_address_type>;
+
+    /// In non-blocking mode, the operations that would block return `Ressource_busy`.
+    fn Set_non_blocking(&self, Non_blocking: bool) -> Result_type<()>;
+}
+
+/// A network backend (host sockets, TCP/IP stack, ...) registered in the virtual file system.
+pub trait Network_trait: Send + Sync {
+    fn New_socket(&self, Protocol: Protocol_type) -> Result_type<Box<dyn Socket_trait>>;
+}
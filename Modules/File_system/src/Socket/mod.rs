@@ -0,0 +1,7 @@
+mod Address;
+mod File_system;
+mod Network;
+
+pub use Address::*;
+pub use File_system::*;
+pub use Network::*;
@@ -1,17 +1,37 @@
-use std::{collections::BTreeMap, sync::RwLock};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex, RwLock,
+    },
+};
 
 use Task::Task_identifier_type;
+use Time::Time_type;
 use Users::{Group_identifier_type, User_identifier_type};
 
 use super::{
-    Device, Device_trait, Error_type, File_system_identifier_type, File_system_traits, Flags_type,
-    Path_owned_type, Path_type, Permission_type, Permissions_type, Pipe, Position_type,
-    Result_type, Size_type, Status_type, Type_type, Unique_file_identifier_type,
+    Control_command_type, Device, Device_class_type, Device_event_type, Device_trait, Error_type,
+    File_system_identifier_type, File_system_traits, Flags_type, Get_blocks_count,
+    Get_blocks_difference, Network_trait, Path_owned_type, Path_type, Permission_type,
+    Permissions_type, Pipe, Position_type, Protocol_type, Quota_report_type, Quota_subject_type,
+    Quota_type, Quotas_type, Result_type, Size_type, Socket, Socket_address_type, Socket_trait,
+    Status_type, Type_type, Unique_file_identifier_type,
 };
 
 struct Internal_file_system_type {
     pub Mount_point: Option<Path_owned_type>,
     pub Inner: Box<dyn File_system_traits>,
+    /// Quotas and usages of the users and groups on this file system.
+    pub Quotas: RwLock<Quotas_type>,
+}
+
+/// File opened in write mode on a mounted file system (used to enforce the quotas).
+#[derive(Clone)]
+struct Written_file_type {
+    /// Path relative to the mount point.
+    pub Path: Path_owned_type,
+    pub Append: bool,
 }
 
 /// Instance of the virtual file system.
@@ -63,6 +83,15 @@ pub struct Virtual_file_system_type {
     User_manager: &'static Users::Manager_type,
     /// Mounted file systems.
     File_systems: RwLock<BTreeMap<File_system_identifier_type, Internal_file_system_type>>,
+    /// Number of files opened by each task (used to enforce the task limits).
+    Open_files: RwLock<BTreeMap<Task_identifier_type, usize>>,
+    /// Subscribers notified when devices are added, removed or replaced.
+    Device_event_subscribers: Mutex<Vec<Sender<Device_event_type>>>,
+    /// Files opened in write mode on mounted file systems.
+    Written_files:
+        RwLock<BTreeMap<(Task_identifier_type, Unique_file_identifier_type), Written_file_type>>,
+    /// Network backend used to create the sockets.
+    Network: RwLock<Option<Box<dyn Network_trait>>>,
 }
 
 impl Virtual_file_system_type {
@@ -70,6 +99,8 @@ impl Virtual_file_system_type {
         File_system_identifier_type::New(0);
     const Device_file_system_identifier: File_system_identifier_type =
         File_system_identifier_type::New(1);
+    const Socket_file_system_identifier: File_system_identifier_type =
+        File_system_identifier_type::New(2);
 
     fn New(
         Task_manager: &'static Task::Manager_type,
@@ -84,6 +115,7 @@ impl Virtual_file_system_type {
             Internal_file_system_type {
                 Mount_point: None,
                 Inner: Box::new(Pipe_file_system),
+                Quotas: RwLock::new(Quotas_type::New()),
             },
         );
 
@@ -94,6 +126,16 @@ impl Virtual_file_system_type {
             Internal_file_system_type {
                 Mount_point: None,
                 Inner: Box::new(Device_file_system),
+                Quotas: RwLock::new(Quotas_type::New()),
+            },
+        );
+
+        File_systems.insert(
+            Self::Socket_file_system_identifier,
+            Internal_file_system_type {
+                Mount_point: None,
+                Inner: Box::new(Socket::File_system_type::New()),
+                Quotas: RwLock::new(Quotas_type::New()),
             },
         );
 
@@ -101,6 +143,10 @@ impl Virtual_file_system_type {
             Task_manager,
             User_manager,
             File_systems: RwLock::new(File_systems),
+            Open_files: RwLock::new(BTreeMap::new()),
+            Written_files: RwLock::new(BTreeMap::new()),
+            Device_event_subscribers: Mutex::new(Vec::new()),
+            Network: RwLock::new(None),
         })
     }
 
@@ -180,6 +226,146 @@ impl Virtual_file_system_type {
             .ok_or(Error_type::Invalid_identifier)
     }
 
+    /// Account for newly opened files of a task, according to its limits.
+    ///
+    /// # Errors
+    /// Returns an error if the task would exceed its maximum number of open files.
+    fn Reserve_open_files(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Count: usize,
+    ) -> Result_type<()> {
+        let Maximum = self
+            .Task_manager
+            .Get_limits(Task_identifier)?
+            .Get_open_files();
+
+        let mut Open_files = self.Open_files.write()?;
+
+        let Current = Open_files.entry(Task_identifier).or_insert(0);
+
+        if let Some(Maximum) = Maximum {
+            if *Current + Count > Maximum {
+                return Err(Error_type::Open_files_limit_exceeded);
+            }
+        }
+
+        *Current += Count;
+
+        Ok(())
+    }
+
+    /// Account for closed files of a task.
+    fn Release_open_files(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Count: usize,
+    ) -> Result_type<()> {
+        let mut Open_files = self.Open_files.write()?;
+
+        if let Some(Current) = Open_files.get_mut(&Task_identifier) {
+            *Current = Current.saturating_sub(Count);
+
+            if *Current == 0 {
+                Open_files.remove(&Task_identifier);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the number of files opened by a task.
+    pub fn Get_open_files_count(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<usize> {
+        Ok(self
+            .Open_files
+            .read()?
+            .get(&Task_identifier)
+            .copied()
+            .unwrap_or(0))
+    }
+
+    /// Resolve a path against the working directory of a task.
+    ///
+    /// Absolute paths are only canonicalized (`.` and `..` are removed).
+    fn Resolve_path(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Path_owned_type> {
+        let Path = Path.as_ref();
+
+        if Path.Is_absolute() {
+            return Ok(Path.to_owned().Canonicalize());
+        }
+
+        let Working_directory = self.Task_manager.Get_working_directory(Task_identifier)?;
+
+        let Working_directory =
+            Path_type::New(&Working_directory).ok_or(Error_type::Invalid_path)?;
+
+        Path_owned_type::Resolve(Working_directory, Path).ok_or(Error_type::Invalid_path)
+    }
+
+    /// Resolve a path against the working directory of the current task.
+    ///
+    /// The current task is only looked up when the path is relative.
+    fn Resolve_path_for_current_task(
+        &self,
+        Path: impl AsRef<Path_type>,
+    ) -> Result_type<Path_owned_type> {
+        if Path.as_ref().Is_absolute() {
+            return Ok(Path.as_ref().to_owned().Canonicalize());
+        }
+
+        self.Resolve_path(Path, self.Task_manager.Get_current_task_identifier()?)
+    }
+
+    /// Get the working directory of a task.
+    pub fn Get_working_directory(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Path_owned_type> {
+        let Working_directory = self.Task_manager.Get_working_directory(Task_identifier)?;
+
+        Path_owned_type::New(Working_directory).ok_or(Error_type::Invalid_path)
+    }
+
+    /// Change the working directory of a task.
+    ///
+    /// The path can be relative to the current working directory of the task.
+    ///
+    /// # Errors
+    /// Returns an error if the path doesn't exists or is not a directory.
+    /// Returns an error if the task doesn't have the permission to traverse the directory (no execute permission).
+    pub fn Set_working_directory(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<()> {
+        let Path = self.Resolve_path(Path, Task_identifier)?;
+
+        self.Try_on_concerned_file_systems(&Path, |_, File_system, Relative_path| {
+            if File_system.Inner.Get_type(&Relative_path)? != Type_type::Directory {
+                return Err(Error_type::Invalid_directory);
+            }
+
+            self.Check_permission(
+                &*File_system.Inner,
+                Task_identifier,
+                Relative_path,
+                Permission_type::New_execute(),
+            )
+        })?;
+
+        self.Task_manager
+            .Set_working_directory(Task_identifier, Path.As_str())?;
+
+        Ok(())
+    }
+
     /// Mount a file system at a given mount point.
     pub fn Mount(
         &self,
@@ -196,6 +382,8 @@ impl Virtual_file_system_type {
             return Err(Error_type::Already_exists);
         }
 
+        let Quotas = Self::Rebuild_quotas(&*File_system)?;
+
         let mut File_systems = self.File_systems.write()?;
 
         let File_system_identifier = Self::Get_new_file_system_identifier(&File_systems)
@@ -206,12 +394,56 @@ impl Virtual_file_system_type {
             Internal_file_system_type {
                 Mount_point: Some(Mount_point.to_owned()),
                 Inner: File_system,
+                Quotas: RwLock::new(Quotas),
             },
         );
 
         Ok(File_system_identifier)
     }
 
+    /// Charge the owner of a file for the change of its size since `Old_size` (ignored if the file is no longer accessible).
+    fn Update_usage(
+        File_system: &Internal_file_system_type,
+        Relative_path: impl AsRef<Path_type>,
+        Old_size: Size_type,
+    ) {
+        let (Ok((User, Group)), Ok(New_size)) = (
+            File_system.Inner.Get_owner(&Relative_path),
+            File_system.Inner.Get_size(&Relative_path),
+        ) else {
+            return;
+        };
+
+        if let Ok(mut Quotas) = File_system.Quotas.write() {
+            Quotas.Charge(User, Group, Get_blocks_difference(Old_size, New_size), 0);
+        }
+    }
+
+    /// Rebuild the usage of the quotas from the owners and the sizes of the files of a file system.
+    fn Rebuild_quotas(File_system: &dyn File_system_traits) -> Result_type<Quotas_type> {
+        let mut Quotas = Quotas_type::New();
+
+        let mut Directories = vec![Path_type::Get_root().to_owned()];
+
+        while let Some(Directory) = Directories.pop() {
+            for Entry in File_system.Get_directory_entries(&Directory)? {
+                let (User, Group) = File_system.Get_owner(&Entry)?;
+
+                let Blocks = match File_system.Get_type(&Entry)? {
+                    Type_type::Directory => {
+                        Directories.push(Entry);
+                        0
+                    }
+                    _ => Get_blocks_count(File_system.Get_size(&Entry)?),
+                };
+
+                Quotas.Charge(User, Group, Blocks as i64, 1);
+            }
+        }
+
+        Ok(Quotas)
+    }
+
     /// Unmount a file system and return the file system.
     pub fn Unmount(
         &self,
@@ -223,21 +455,112 @@ impl Virtual_file_system_type {
             .remove(&File_system_identifier)
             .ok_or(Error_type::Invalid_identifier)?;
 
+        self.Written_files
+            .write()?
+            .retain(|(_, File), _| File.Split().0 != File_system_identifier);
+
         Ok(Internal_file_system.Inner)
     }
 
+    /// Set the quota of a user or a group on a mounted file system.
+    ///
+    /// Usage is computed from the owner metadata of the files when the file system is mounted, then tracked as files are created, written, deleted or change owner.
+    ///
+    /// # Errors
+    /// Returns an error if the task is not owned by the root user.
+    pub fn Set_quota(
+        &self,
+        File_system_identifier: File_system_identifier_type,
+        Subject: Quota_subject_type,
+        Quota: Quota_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<()> {
+        if !Users::Manager_type::Is_root(self.Task_manager.Get_owner(Task_identifier)?) {
+            return Err(Error_type::Permission_denied);
+        }
+
+        let File_systems = self.File_systems.read()?;
+
+        Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
+            .Quotas
+            .write()?
+            .Set_quota(Subject, Quota);
+
+        Ok(())
+    }
+
+    /// Get the quota and the usage of a user or a group on a mounted file system.
+    pub fn Get_quota(
+        &self,
+        File_system_identifier: File_system_identifier_type,
+        Subject: Quota_subject_type,
+    ) -> Result_type<Quota_report_type> {
+        let File_systems = self.File_systems.read()?;
+
+        let Report = Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
+            .Quotas
+            .read()?
+            .Get_report(Subject);
+
+        Ok(Report)
+    }
+
+    /// Get the quotas and the usages of all the users and groups on a mounted file system.
+    pub fn Get_quotas(
+        &self,
+        File_system_identifier: File_system_identifier_type,
+    ) -> Result_type<Vec<(Quota_subject_type, Quota_report_type)>> {
+        let File_systems = self.File_systems.read()?;
+
+        let Reports = Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
+            .Quotas
+            .read()?
+            .Get_reports();
+
+        Ok(Reports)
+    }
+
+    /// Set the owner of a newly created file or directory and account for it in the quotas of its owner.
+    ///
+    /// The file belongs to the owner of the creating task and to the group of its parent directory.
+    /// The file is deleted if the owner would exceed one of its hard limits.
+    fn Charge_created_file(
+        File_system: &Internal_file_system_type,
+        Relative_path: &Path_type,
+        User: User_identifier_type,
+    ) -> Result_type<()> {
+        let (_, Group) = File_system
+            .Inner
+            .Get_owner(&Relative_path.Go_parent().unwrap_or(Path_type::Get_root()))?;
+
+        let mut Quotas = File_system.Quotas.write()?;
+
+        if let Err(Error) = Quotas.Check(User, Group, 0, 1).and_then(|_| {
+            File_system
+                .Inner
+                .Set_owner(&Relative_path, Some(User), Some(Group))
+        }) {
+            File_system.Inner.Delete(&Relative_path)?;
+            return Err(Error);
+        }
+
+        Quotas.Charge(User, Group, 0, 1);
+
+        Ok(())
+    }
+
     fn Get_file_system_from_mount_point<'b>(
         File_systems: &'b BTreeMap<File_system_identifier_type, Internal_file_system_type>,
         Path: &'b dyn AsRef<Path_type>,
     ) -> Result_type<(
         File_system_identifier_type,
-        &'b dyn File_system_traits,
+        &'b Internal_file_system_type,
         &'b Path_type,
     )> {
         let mut Result_score = 0;
         let mut Result: Option<(
             File_system_identifier_type,
-            &'b dyn File_system_traits,
+            &'b Internal_file_system_type,
             &'b Path_type,
         )> = None;
 
@@ -247,11 +570,7 @@ impl Virtual_file_system_type {
                     let Score = Relative_path.Get_length();
                     if Score > Result_score {
                         Result_score = Score;
-                        Result = Some((
-                            *File_system_identifier,
-                            File_system.Inner.as_ref(),
-                            Relative_path,
-                        ));
+                        Result = Some((*File_system_identifier, File_system, Relative_path));
                     }
                 }
             }
@@ -266,18 +585,65 @@ impl Virtual_file_system_type {
         Flags: Flags_type,
         Task_identifier: Task_identifier_type,
     ) -> Result_type<Unique_file_identifier_type> {
+        let Path = self.Resolve_path(Path, Task_identifier)?;
+
+        self.Reserve_open_files(Task_identifier, 1)?;
+
         // - Open file
-        self.Try_on_concerned_file_systems(
+        let Result = self.Try_on_concerned_file_systems(
             Path,
             |File_system_identifier, File_system, Relative_path| {
+                // - Opening a file for writing may truncate it, so its size is compared before and after
+                let Old_size = if File_system.Mount_point.is_some()
+                    && Flags.Get_mode().Get_write()
+                    && File_system.Inner.Exists(&Relative_path)?
+                {
+                    Some(File_system.Inner.Get_size(&Relative_path)?)
+                } else {
+                    None
+                };
+
                 File_system
                     .Inner
                     .Open(Task_identifier, &Relative_path, Flags)
                     .map(|File_identifier| {
-                        Unique_file_identifier_type::New(File_system_identifier, File_identifier)
+                        if let Some(Old_size) = Old_size {
+                            Self::Update_usage(File_system, Relative_path, Old_size);
+                        }
+
+                        let File = Unique_file_identifier_type::New(
+                            File_system_identifier,
+                            File_identifier,
+                        );
+
+                        // - Only files of mounted file systems are subject to quotas
+                        let Written_file = (File_system.Mount_point.is_some()
+                            && Flags.Get_mode().Get_write())
+                        .then(|| Written_file_type {
+                            Path: Relative_path.to_owned(),
+                            Append: Flags.Get_status().Get_append(),
+                        });
+
+                        (File, Written_file)
                     })
             },
-        )
+        );
+
+        match Result {
+            Ok((File, Written_file)) => {
+                if let Some(Written_file) = Written_file {
+                    self.Written_files
+                        .write()?
+                        .insert((Task_identifier, File), Written_file);
+                }
+
+                Ok(File)
+            }
+            Err(Error) => {
+                self.Release_open_files(Task_identifier, 1)?;
+                Err(Error)
+            }
+        }
     }
 
     fn Get_permission(
@@ -330,7 +696,11 @@ impl Virtual_file_system_type {
 
         Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
             .Inner
-            .Close(Task_identifier, File_identifier)
+            .Close(Task_identifier, File_identifier)?;
+
+        self.Written_files.write()?.remove(&(Task_identifier, File));
+
+        self.Release_open_files(Task_identifier, 1)
     }
 
     pub fn Read(
@@ -358,9 +728,65 @@ impl Virtual_file_system_type {
 
         let File_systems = self.File_systems.read()?; // Get the file systems
 
-        Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
+        let File_system =
+            Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?;
+
+        let Written_file = self
+            .Written_files
+            .read()?
+            .get(&(Task_identifier, File))
+            .cloned();
+
+        let Some(Written_file) = Written_file else {
+            return File_system
+                .Inner
+                .Write(Task_identifier, File_identifier, Buffer);
+        };
+
+        // - Check that the owner of the file doesn't exceed its quotas
+        let (User, Group) = File_system.Inner.Get_owner(&Written_file.Path)?;
+
+        let Old_size = File_system.Inner.Get_size(&Written_file.Path)?;
+
+        let Start = if Written_file.Append {
+            Old_size
+        } else {
+            File_system.Inner.Set_position(
+                Task_identifier,
+                File_identifier,
+                &Position_type::Current(0),
+            )?
+        };
+
+        let New_size = Size_type::from(u64::from(Start) + Buffer.len() as u64).max(Old_size);
+
+        let Reserved_blocks = Get_blocks_count(New_size) - Get_blocks_count(Old_size);
+
+        // - Reserve the blocks before writing, so that concurrent writes can't exceed the quota together
+        {
+            let mut Quotas = File_system.Quotas.write()?;
+
+            Quotas.Check(User, Group, Reserved_blocks, 0)?;
+
+            Quotas.Charge(User, Group, Reserved_blocks as i64, 0);
+        }
+
+        let Result = File_system
             .Inner
-            .Write(Task_identifier, File_identifier, Buffer)
+            .Write(Task_identifier, File_identifier, Buffer);
+
+        // - Adjust the reservation to the actual size of the file (kept if the size is unknown), which may be smaller than before if the file was truncated meanwhile
+        let Used_blocks = match File_system.Inner.Get_size(&Written_file.Path) {
+            Ok(New_size) => Get_blocks_difference(Old_size, New_size),
+            Err(_) => Reserved_blocks as i64,
+        };
+
+        File_system
+            .Quotas
+            .write()?
+            .Charge(User, Group, Used_blocks - Reserved_blocks as i64, 0);
+
+        Result
     }
 
     pub fn Set_position(
@@ -378,7 +804,32 @@ impl Virtual_file_system_type {
             .Set_position(Task_identifier, File_identifier, Position)
     }
 
+    /// Perform a control operation on an opened file (mainly used by devices).
+    pub fn Control(
+        &self,
+        File: Unique_file_identifier_type,
+        Command: Control_command_type,
+        Argument: &mut [u8],
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<()> {
+        let (File_system_identifier, File_identifier) = File.Split();
+
+        // - The file systems are not locked while the device is used, since control operations may block
+        let Device = Self::Get_file_system_from_identifier(
+            &*self.File_systems.read()?,
+            File_system_identifier,
+        )?
+        .Inner
+        .Get_device(Task_identifier, File_identifier)?;
+
+        Command.Check_argument(Argument)?;
+
+        Device.Control(Command, Argument)
+    }
+
     pub fn Exists(&self, Path: impl AsRef<Path_type>) -> Result_type<bool> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
         match self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
             File_system.Inner.Exists(&Relative_path)
         }) {
@@ -389,18 +840,46 @@ impl Virtual_file_system_type {
     }
 
     pub fn Get_size(&self, Path: impl AsRef<Path_type>) -> Result_type<Size_type> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
         self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
             File_system.Inner.Get_size(&Relative_path)
         })
     }
 
     pub fn Get_type(&self, Path: impl AsRef<Path_type>) -> Result_type<Type_type> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
         self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
             File_system.Inner.Get_type(&Relative_path)
         })
     }
 
+    pub fn Get_modification_time(&self, Path: impl AsRef<Path_type>) -> Result_type<Time_type> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
+        self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
+            File_system.Inner.Get_modification_time(&Relative_path)
+        })
+    }
+
+    pub fn Set_modification_time(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Time: Time_type,
+    ) -> Result_type<()> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
+        self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
+            File_system
+                .Inner
+                .Set_modification_time(&Relative_path, Time)
+        })
+    }
+
     pub fn Get_permissions(&self, Path: impl AsRef<Path_type>) -> Result_type<Permissions_type> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
         self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
             File_system.Inner.Get_permissions(&Relative_path)
         })
@@ -410,6 +889,8 @@ impl Virtual_file_system_type {
         &self,
         Path: impl AsRef<Path_type>,
     ) -> Result_type<(User_identifier_type, Group_identifier_type)> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
         self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
             File_system.Inner.Get_owner(&Relative_path)
         })
@@ -421,8 +902,40 @@ impl Virtual_file_system_type {
         User: Option<User_identifier_type>,
         Group: Option<Group_identifier_type>,
     ) -> Result_type<()> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
+        let Current_task_owner = self
+            .Task_manager
+            .Get_owner(self.Task_manager.Get_current_task_identifier()?)?;
+
         self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
-            File_system.Inner.Set_owner(&Relative_path, User, Group)
+            let (Old_user, Old_group) = File_system.Inner.Get_owner(&Relative_path)?;
+
+            // - Only the owner of the file or the root user can change its owner
+            if !Users::Manager_type::Is_root(Current_task_owner) && Old_user != Current_task_owner {
+                return Err(Error_type::Permission_denied);
+            }
+
+            if File_system.Mount_point.is_none() {
+                return File_system.Inner.Set_owner(&Relative_path, User, Group);
+            }
+
+            // - Transfer the usage of the file to the new owner
+
+            let (New_user, New_group) = (User.unwrap_or(Old_user), Group.unwrap_or(Old_group));
+
+            let Blocks = Get_blocks_count(File_system.Inner.Get_size(&Relative_path)?);
+
+            let mut Quotas = File_system.Quotas.write()?;
+
+            Quotas.Check(New_user, New_group, Blocks, 1)?;
+
+            File_system.Inner.Set_owner(&Relative_path, User, Group)?;
+
+            Quotas.Charge(Old_user, Old_group, -(Blocks as i64), -1);
+            Quotas.Charge(New_user, New_group, Blocks as i64, 1);
+
+            Ok(())
         })
     }
 
@@ -431,6 +944,8 @@ impl Virtual_file_system_type {
         Path: impl AsRef<Path_type>,
         Permissions: Permissions_type,
     ) -> Result_type<()> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
         self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
             File_system
                 .Inner
@@ -445,14 +960,58 @@ impl Virtual_file_system_type {
             File_system.Inner.Close_all(Task_identifier)?;
         }
 
+        self.Open_files.write()?.remove(&Task_identifier);
+
+        self.Written_files
+            .write()?
+            .retain(|(Task, _), _| *Task != Task_identifier);
+
         Ok(())
     }
 
+    /// Subscribe to the device events (additions, removals and replacements).
+    ///
+    /// The subscription ends when the receiver is dropped.
+    pub fn Subscribe_device_events(&self) -> Result_type<Receiver<Device_event_type>> {
+        let (Sender, Receiver) = channel();
+
+        self.Device_event_subscribers.lock()?.push(Sender);
+
+        Ok(Receiver)
+    }
+
+    fn Notify_device_event(&self, Event: Device_event_type) -> Result_type<()> {
+        // - Drop the subscribers whose receiver has been dropped
+        self.Device_event_subscribers
+            .lock()?
+            .retain(|Subscriber| Subscriber.send(Event.clone()).is_ok());
+
+        Ok(())
+    }
+
+    /// Register a device owned by `User` and `Group`.
+    ///
+    /// # Errors
+    /// Returns `Permission_denied` if the current task is neither owned by the root user nor by `User`.
     pub fn Add_device(
         &self,
-        Path: &'static dyn AsRef<Path_type>,
+        Path: impl AsRef<Path_type>,
+        Class: Device_class_type,
         Device: Box<dyn Device_trait>,
+        User: User_identifier_type,
+        Group: Group_identifier_type,
+        Permissions: Permissions_type,
     ) -> Result_type<()> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
+        let Current_task_owner = self
+            .Task_manager
+            .Get_owner(self.Task_manager.Get_current_task_identifier()?)?;
+
+        if !Users::Manager_type::Is_root(Current_task_owner) && User != Current_task_owner {
+            return Err(Error_type::Permission_denied);
+        }
+
         let File_systems = self.File_systems.read()?; // Get the file systems
 
         let File_system = Self::Get_file_system_from_identifier(
@@ -460,7 +1019,95 @@ impl Virtual_file_system_type {
             Self::Device_file_system_identifier,
         )?;
 
-        File_system.Inner.Add_device(Path, Device)
+        File_system
+            .Inner
+            .Add_device(&Path, Class, Device, User, Group, Permissions)?;
+
+        self.Notify_device_event(Device_event_type::Added(Path, Class))
+    }
+
+    /// Check that the current task is owned by the root user or by the owner of a device.
+    fn Check_device_owner(
+        &self,
+        File_system: &dyn File_system_traits,
+        Path: impl AsRef<Path_type>,
+    ) -> Result_type<()> {
+        let Current_task_owner = self
+            .Task_manager
+            .Get_owner(self.Task_manager.Get_current_task_identifier()?)?;
+
+        if Users::Manager_type::Is_root(Current_task_owner) {
+            return Ok(());
+        }
+
+        let (Device_user, _) = File_system.Get_owner(&Path)?;
+
+        if Device_user != Current_task_owner {
+            return Err(Error_type::Permission_denied);
+        }
+
+        Ok(())
+    }
+
+    /// Replace a device at runtime.
+    ///
+    /// The owner and the permissions of the device are kept, while the files opened on the previous device return `Device_removed`.
+    ///
+    /// # Errors
+    /// Returns `Permission_denied` if the current task is neither owned by the root user nor by the owner of the device.
+    pub fn Replace_device(
+        &self,
+        Path: impl AsRef<Path_type>,
+        Class: Device_class_type,
+        Device: Box<dyn Device_trait>,
+    ) -> Result_type<()> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
+        let File_systems = self.File_systems.read()?; // Get the file systems
+
+        let File_system = Self::Get_file_system_from_identifier(
+            &File_systems,
+            Self::Device_file_system_identifier,
+        )?;
+
+        self.Check_device_owner(&*File_system.Inner, &Path)?;
+
+        File_system.Inner.Replace_device(&Path, Class, Device)?;
+
+        self.Notify_device_event(Device_event_type::Replaced(Path, Class))
+    }
+
+    /// Remove a device at runtime.
+    ///
+    /// The files opened on the device return `Device_removed` afterwards.
+    ///
+    /// # Errors
+    /// Returns `Permission_denied` if the current task is neither owned by the root user nor by the owner of the device.
+    pub fn Remove_device(&self, Path: impl AsRef<Path_type>) -> Result_type<()> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
+        let File_systems = self.File_systems.read()?; // Get the file systems
+
+        let File_system = Self::Get_file_system_from_identifier(
+            &File_systems,
+            Self::Device_file_system_identifier,
+        )?;
+
+        self.Check_device_owner(&*File_system.Inner, &Path)?;
+
+        File_system.Inner.Delete(&Path)?;
+
+        self.Notify_device_event(Device_event_type::Removed(Path))
+    }
+
+    pub fn Get_device_class(&self, Path: impl AsRef<Path_type>) -> Result_type<Device_class_type> {
+        let Path = self.Resolve_path_for_current_task(Path)?;
+
+        let File_systems = self.File_systems.read()?; // Get the file systems
+
+        Self::Get_file_system_from_identifier(&File_systems, Self::Device_file_system_identifier)?
+            .Inner
+            .Get_device_class(&Path)
     }
 
     pub fn Create_named_pipe(
@@ -469,15 +1116,17 @@ impl Virtual_file_system_type {
         Size: Size_type,
         Task_identifier: Task_identifier_type,
     ) -> Result_type<()> {
+        let Path = self.Resolve_path(Path, Task_identifier)?;
+
         let mut File_systems = self.File_systems.write()?; // Get the file systems
 
-        let Parent_path = Path.as_ref().Go_parent().ok_or(Error_type::Invalid_path)?;
+        let Parent_path = Path.Go_parent().ok_or(Error_type::Invalid_path)?;
 
         let (_, File_system, Relative_path) =
             Self::Get_file_system_from_mount_point(&File_systems, &Parent_path)?; // Get the file system identifier and the relative path
 
         self.Check_permission(
-            File_system,
+            &*File_system.Inner,
             Task_identifier,
             Relative_path,
             Permission_type::New_write(),
@@ -487,7 +1136,7 @@ impl Virtual_file_system_type {
             .get_mut(&Self::Pipe_file_system_identifier)
             .ok_or(Error_type::Invalid_path)?
             .Inner
-            .Create_named_pipe(Path, Size)
+            .Create_named_pipe(&Path, Size)
     }
 
     pub fn Create_unnamed_pipe(
@@ -496,13 +1145,22 @@ impl Virtual_file_system_type {
         Status: Status_type,
         Task_identifier: Task_identifier_type,
     ) -> Result_type<(Unique_file_identifier_type, Unique_file_identifier_type)> {
-        let (Read, Write) = self
+        self.Reserve_open_files(Task_identifier, 2)?;
+
+        let (Read, Write) = match self
             .File_systems
             .write()?
             .get_mut(&Self::Pipe_file_system_identifier)
             .ok_or(Error_type::Invalid_path)?
             .Inner
-            .Create_unnamed_pipe(Task_identifier, Size, Status)?;
+            .Create_unnamed_pipe(Task_identifier, Size, Status)
+        {
+            Ok(Pipe) => Pipe,
+            Err(Error) => {
+                self.Release_open_files(Task_identifier, 2)?;
+                return Err(Error);
+            }
+        };
 
         Ok((
             Unique_file_identifier_type::New(Self::Pipe_file_system_identifier, Read),
@@ -515,6 +1173,8 @@ impl Virtual_file_system_type {
         Path: impl AsRef<Path_type>,
         Task_identifier: Task_identifier_type,
     ) -> Result_type<()> {
+        let Path = self.Resolve_path(Path, Task_identifier)?;
+
         let File_systems = self.File_systems.read()?; // Get the file systems
 
         let (_, File_system, Relative_path) =
@@ -522,13 +1182,17 @@ impl Virtual_file_system_type {
 
         // Check if the user has the right to create the file (write permission on the parent directory)
         self.Check_permission(
-            File_system,
+            &*File_system.Inner,
             Task_identifier,
             Relative_path.Go_parent().unwrap_or(Path_type::Get_root()),
             Permission_type::New_write(),
         )?;
 
-        File_system.Create_file(&Relative_path)
+        let Owner = self.Task_manager.Get_owner(Task_identifier)?;
+
+        File_system.Inner.Create_file(&Relative_path)?;
+
+        Self::Charge_created_file(File_system, Relative_path, Owner)
     }
 
     pub fn Create_directory(
@@ -537,15 +1201,17 @@ impl Virtual_file_system_type {
         Recursive: bool,
         Task_identifier: Task_identifier_type,
     ) -> Result_type<()> {
+        let Path = self.Resolve_path(Path, Task_identifier)?;
+
         if Recursive {
             // If the directory already exists, return Ok(()) (only if recursive is true).
-            if self.Exists(Path.as_ref())? {
+            if self.Exists(&Path)? {
                 return Ok(());
             }
 
             // Create the parent directory recursively.
             self.Create_directory(
-                Path.as_ref().Go_parent().ok_or(Error_type::Invalid_path)?,
+                Path.Go_parent().ok_or(Error_type::Invalid_path)?,
                 true,
                 Task_identifier,
             )?
@@ -559,13 +1225,17 @@ impl Virtual_file_system_type {
 
         // Check if the user has the right to create the directory (write permission and execute permission on the parent directory)
         self.Check_permission(
-            File_system,
+            &*File_system.Inner,
             Task_identifier,
             Relative_path,
             Permission_type::New_write_execute(),
         )?;
 
-        File_system.Create_directory(&Relative_path)
+        let Owner = self.Task_manager.Get_owner(Task_identifier)?;
+
+        File_system.Inner.Create_directory(&Relative_path)?;
+
+        Self::Charge_created_file(File_system, Relative_path, Owner)
     }
 
     pub fn Delete(
@@ -574,12 +1244,14 @@ impl Virtual_file_system_type {
         Recursive: bool,
         Task_identifier: Task_identifier_type,
     ) -> Result_type<()> {
+        let Path = self.Resolve_path(Path, Task_identifier)?;
+
         if Recursive {
             todo!()
         }
 
         // Delete current directory / file.
-        self.Try_on_concerned_file_systems(Path.as_ref(), |_, File_system, Relative_path| {
+        self.Try_on_concerned_file_systems(&Path, |_, File_system, Relative_path| {
             // Check if the user has the right to delete the file (write permission on the parent directory)
             self.Check_permission(
                 &*File_system.Inner,
@@ -589,10 +1261,40 @@ impl Virtual_file_system_type {
             )
         })?;
 
-        self.Try_on_concerned_file_systems(Path, |_, File_system, Relative_path| {
-            // Delete the file
-            File_system.Inner.Delete(&Relative_path)
-        })
+        let File_system_identifier = self.Try_on_concerned_file_systems(
+            &Path,
+            |File_system_identifier, File_system, Relative_path| {
+                if File_system.Mount_point.is_none() {
+                    File_system.Inner.Delete(&Relative_path)?;
+
+                    return Ok(File_system_identifier);
+                }
+
+                // - Get the owner and the size before deleting the file to release its usage
+                let (User, Group) = File_system.Inner.Get_owner(&Relative_path)?;
+
+                let Blocks = match File_system.Inner.Get_type(&Relative_path)? {
+                    Type_type::Directory => 0,
+                    _ => Get_blocks_count(File_system.Inner.Get_size(&Relative_path)?),
+                };
+
+                // Delete the file
+                File_system.Inner.Delete(&Relative_path)?;
+
+                File_system
+                    .Quotas
+                    .write()?
+                    .Charge(User, Group, -(Blocks as i64), -1);
+
+                Ok(File_system_identifier)
+            },
+        )?;
+
+        if File_system_identifier == Self::Device_file_system_identifier {
+            self.Notify_device_event(Device_event_type::Removed(Path))?;
+        }
+
+        Ok(())
     }
 
     pub fn Transfert_file(
@@ -605,15 +1307,32 @@ impl Virtual_file_system_type {
 
         let File_systems = self.File_systems.read()?; // Get the file systems
 
+        self.Reserve_open_files(New_task, 1)?;
+
         let New_file_identifier =
-            Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
+            match Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
                 .Inner
-                .Transfert_file_identifier(Current_task, New_task, File_identifier)?;
+                .Transfert_file_identifier(Current_task, New_task, File_identifier)
+            {
+                Ok(New_file_identifier) => New_file_identifier,
+                Err(Error) => {
+                    self.Release_open_files(New_task, 1)?;
+                    return Err(Error);
+                }
+            };
 
-        Ok(Unique_file_identifier_type::New(
-            File_system_identifier,
-            New_file_identifier,
-        ))
+        self.Release_open_files(Current_task, 1)?;
+
+        let New_file =
+            Unique_file_identifier_type::New(File_system_identifier, New_file_identifier);
+
+        let mut Written_files = self.Written_files.write()?;
+
+        if let Some(Written_file) = Written_files.remove(&(Current_task, File)) {
+            Written_files.insert((New_task, New_file), Written_file);
+        }
+
+        Ok(New_file)
     }
 
     pub fn Flush(
@@ -629,4 +1348,183 @@ impl Virtual_file_system_type {
             .Inner
             .Flush(Task_identifier, File_identifier)
     }
+
+    /// Register the network backend used to create the sockets, replacing the previous one if any.
+    ///
+    /// The sockets already opened keep using the previous backend.
+    ///
+    /// # Errors
+    /// Returns an error if the current task is not owned by the root user.
+    pub fn Set_network(&self, Network: Box<dyn Network_trait>) -> Result_type<()> {
+        let Current_task_owner = self
+            .Task_manager
+            .Get_owner(self.Task_manager.Get_current_task_identifier()?)?;
+
+        if !Users::Manager_type::Is_root(Current_task_owner) {
+            return Err(Error_type::Permission_denied);
+        }
+
+        *self.Network.write()? = Some(Network);
+
+        Ok(())
+    }
+
+    /// Register a socket for a task, accounting for its limits.
+    fn Add_socket(
+        &self,
+        Socket: Box<dyn Socket_trait>,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Unique_file_identifier_type> {
+        self.Reserve_open_files(Task_identifier, 1)?;
+
+        let Result = Self::Get_file_system_from_identifier(
+            &*self.File_systems.read()?,
+            Self::Socket_file_system_identifier,
+        )?
+        .Inner
+        .Add_socket(Task_identifier, Socket);
+
+        match Result {
+            Ok(File_identifier) => Ok(Unique_file_identifier_type::New(
+                Self::Socket_file_system_identifier,
+                File_identifier,
+            )),
+            Err(Error) => {
+                self.Release_open_files(Task_identifier, 1)?;
+                Err(Error)
+            }
+        }
+    }
+
+    /// Get a socket opened by a task.
+    ///
+    /// The file systems are not locked while the socket is used, since socket operations may block.
+    fn Get_socket(
+        &self,
+        File: Unique_file_identifier_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Arc<dyn Socket_trait>> {
+        let (File_system_identifier, File_identifier) = File.Split();
+
+        let File_systems = self.File_systems.read()?; // Get the file systems
+
+        Self::Get_file_system_from_identifier(&File_systems, File_system_identifier)?
+            .Inner
+            .Get_socket(Task_identifier, File_identifier)
+    }
+
+    /// Create a socket with the network backend, it is closed with `Close` like any other file.
+    ///
+    /// The opened socket can be read and written with `Read` and `Write` once connected.
+    pub fn New_socket(
+        &self,
+        Protocol: Protocol_type,
+        Status: Status_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Unique_file_identifier_type> {
+        let Socket = self
+            .Network
+            .read()?
+            .as_ref()
+            .ok_or(Error_type::Network_unavailable)?
+            .New_socket(Protocol)?;
+
+        if Status.Get_non_blocking() {
+            Socket.Set_non_blocking(true)?;
+        }
+
+        self.Add_socket(Socket, Task_identifier)
+    }
+
+    pub fn Bind(
+        &self,
+        File: Unique_file_identifier_type,
+        Address: Socket_address_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<()> {
+        self.Get_socket(File, Task_identifier)?.Bind(Address)
+    }
+
+    pub fn Listen(
+        &self,
+        File: Unique_file_identifier_type,
+        Backlog: usize,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<()> {
+        self.Get_socket(File, Task_identifier)?.Listen(Backlog)
+    }
+
+    /// Accept an incoming connection on a listening socket, returns the connected socket and the address of the peer.
+    pub fn Accept(
+        &self,
+        File: Unique_file_identifier_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<(Unique_file_identifier_type, Socket_address_type)> {
+        let (Socket, Address) = self.Get_socket(File, Task_identifier)?.Accept()?;
+
+        Ok((self.Add_socket(Socket, Task_identifier)?, Address))
+    }
+
+    pub fn Connect(
+        &self,
+        File: Unique_file_identifier_type,
+        Address: Socket_address_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<()> {
+        self.Get_socket(File, Task_identifier)?.Connect(Address)
+    }
+
+    pub fn Send(
+        &self,
+        File: Unique_file_identifier_type,
+        Buffer: &[u8],
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Size_type> {
+        self.Get_socket(File, Task_identifier)?.Send(Buffer)
+    }
+
+    pub fn Receive(
+        &self,
+        File: Unique_file_identifier_type,
+        Buffer: &mut [u8],
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Size_type> {
+        self.Get_socket(File, Task_identifier)?.Receive(Buffer)
+    }
+
+    pub fn Send_to(
+        &self,
+        File: Unique_file_identifier_type,
+        Buffer: &[u8],
+        Address: Socket_address_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Size_type> {
+        self.Get_socket(File, Task_identifier)?
+            .Send_to(Buffer, Address)
+    }
+
+    pub fn Receive_from(
+        &self,
+        File: Unique_file_identifier_type,
+        Buffer: &mut [u8],
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<(Size_type, Socket_address_type)> {
+        self.Get_socket(File, Task_identifier)?.Receive_from(Buffer)
+    }
+
+    pub fn Get_local_address(
+        &self,
+        File: Unique_file_identifier_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Socket_address_type> {
+        self.Get_socket(File, Task_identifier)?.Get_local_address()
+    }
+
+    pub fn Get_peer_address(
+        &self,
+        File: Unique_file_identifier_type,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Socket_address_type> {
+        self.Get_socket(File, Task_identifier)?.Get_peer_address()
+    }
 }
@@ -8,11 +8,22 @@ mod File;
 mod File_system;
 mod Fundamentals;
 mod Pipe;
+mod Socket;
 mod Virtual_file_system;
 
-pub use Device::Device_trait;
+pub use Device::{
+    Clock_control_kind, Clock_get_date_time_command, Clock_get_monotonic_time_command,
+    Clock_get_time_zone_command, Clock_set_time_zone_command, Control_argument_trait,
+    Control_command_type, Control_direction_type, Control_plain_argument_trait, Device_class_type,
+    Device_event_type, Device_trait, Terminal_control_kind, Terminal_get_echo_command,
+    Terminal_get_foreground_task_command, Terminal_get_mode_command,
+    Terminal_get_window_size_command, Terminal_mode_type, Terminal_set_echo_command,
+    Terminal_set_foreground_task_command, Terminal_set_mode_command,
+    Terminal_set_window_size_command, Terminal_type, Window_size_type,
+};
 pub use Error::*;
 pub use File::*;
 pub use File_system::*;
 pub use Fundamentals::*;
+pub use Socket::{Network_trait, Protocol_type, Socket_address_type, Socket_trait};
 pub use Virtual_file_system::*;
@@ -1,7 +1,12 @@
+use std::{ffi::c_void, mem::size_of};
+
 use lvgl::input_device::{pointer, InputDriver};
 use File_system::File_type;
 
-use crate::{Display::Display_type, Pointer_data_type, Result_type};
+use crate::{
+    Display::Display_type, Error_type, Key_state_type, Keyboard_data_type, Pointer_data_type,
+    Result_type,
+};
 
 pub struct Input_type {
     #[allow(dead_code)]
@@ -31,3 +36,100 @@ impl Input_type {
         })
     }
 }
+
+struct Keyboard_state_type {
+    File: File_type,
+    Last: Keyboard_data_type,
+}
+
+/// Keyboard registered as an LVGL keypad input device.
+///
+/// The keypad is bound to a group which is set as the default one, so that the focusable objects (text areas,
+/// buttons, etc.) created afterwards can be navigated and edited with the keyboard.
+///
+/// The driver and its state are leaked since LVGL keeps references to them and doesn't allow to unregister an input
+/// device.
+pub struct Keyboard_input_type {
+    Input_device: *mut lvgl::sys::lv_indev_t,
+    Group: *mut lvgl::sys::lv_group_t,
+}
+
+unsafe impl Send for Keyboard_input_type {}
+
+unsafe impl Sync for Keyboard_input_type {}
+
+unsafe extern "C" fn Keyboard_read_callback(
+    Driver: *mut lvgl::sys::lv_indev_drv_t,
+    Data: *mut lvgl::sys::lv_indev_data_t,
+) {
+    let State = &mut *((*Driver).user_data as *mut Keyboard_state_type);
+
+    let mut Keyboard_data = Keyboard_data_type::default();
+
+    // - The device returns nothing when there is no pending event : the last key keeps its state
+    if let Ok(Size) = State.File.Read(Keyboard_data.as_mut()) {
+        if usize::from(Size) == size_of::<Keyboard_data_type>() {
+            State.Last = Keyboard_data;
+            (*Data).continue_reading = true;
+        }
+    }
+
+    (*Data).key = State.Last.Get_lvgl_key();
+    (*Data).state = match State.Last.Get_state() {
+        Key_state_type::Pressed => lvgl::sys::lv_indev_state_t_LV_INDEV_STATE_PRESSED,
+        Key_state_type::Released => lvgl::sys::lv_indev_state_t_LV_INDEV_STATE_RELEASED,
+    };
+}
+
+impl Keyboard_input_type {
+    /// Register a keyboard device file as a keypad on the default display.
+    pub fn New(File: File_type) -> Result_type<Self> {
+        let State = Box::leak(Box::new(Keyboard_state_type {
+            File,
+            Last: Keyboard_data_type::default(),
+        }));
+
+        let Driver = Box::leak(Box::new(unsafe {
+            core::mem::zeroed::<lvgl::sys::lv_indev_drv_t>()
+        }));
+
+        unsafe {
+            lvgl::sys::lv_indev_drv_init(Driver);
+        }
+
+        Driver.type_ = lvgl::sys::lv_indev_type_t_LV_INDEV_TYPE_KEYPAD;
+        Driver.read_cb = Some(Keyboard_read_callback);
+        Driver.user_data = State as *mut Keyboard_state_type as *mut c_void;
+
+        let Input_device = unsafe { lvgl::sys::lv_indev_drv_register(Driver) };
+
+        if Input_device.is_null() {
+            return Err(Error_type::Failed_to_register);
+        }
+
+        let Group = unsafe { lvgl::sys::lv_group_create() };
+
+        if Group.is_null() {
+            return Err(Error_type::Out_of_memory);
+        }
+
+        unsafe {
+            lvgl::sys::lv_group_set_default(Group);
+            lvgl::sys::lv_indev_set_group(Input_device, Group);
+        }
+
+        Ok(Self {
+            Input_device,
+            Group,
+        })
+    }
+
+    pub fn Get_lvgl_input_device(&self) -> *mut lvgl::sys::lv_indev_t {
+        self.Input_device
+    }
+
+    /// Get the group of objects navigated with the keyboard.
+    pub fn Get_group(&self) -> *mut lvgl::sys::lv_group_t {
+        self.Group
+    }
+}
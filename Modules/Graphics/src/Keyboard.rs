@@ -0,0 +1,263 @@
+use core::mem::size_of;
+use std::{mem::transmute, ops::BitOr};
+
+/// Key of a keyboard event.
+///
+/// The printable keys are reported as `Character`, with the typed text in the event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Key_type {
+    Character,
+    Enter,
+    Backspace,
+    Delete,
+    Escape,
+    Tab,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    Page_up,
+    Page_down,
+    Unknown = 0xFF,
+}
+
+impl TryFrom<u8> for Key_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Character),
+            1 => Ok(Self::Enter),
+            2 => Ok(Self::Backspace),
+            3 => Ok(Self::Delete),
+            4 => Ok(Self::Escape),
+            5 => Ok(Self::Tab),
+            6 => Ok(Self::Up),
+            7 => Ok(Self::Down),
+            8 => Ok(Self::Left),
+            9 => Ok(Self::Right),
+            10 => Ok(Self::Home),
+            11 => Ok(Self::End),
+            12 => Ok(Self::Page_up),
+            13 => Ok(Self::Page_down),
+            0xFF => Ok(Self::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Key_state_type {
+    Pressed,
+    Released,
+}
+
+impl TryFrom<u8> for Key_state_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Pressed),
+            1 => Ok(Self::Released),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Modifier keys held during a keyboard event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct Modifiers_type(u8);
+
+impl Modifiers_type {
+    pub const None: Self = Self(0);
+    pub const Shift: Self = Self(1 << 0);
+    pub const Control: Self = Self(1 << 1);
+    pub const Alt: Self = Self(1 << 2);
+    pub const Meta: Self = Self(1 << 3);
+
+    pub const fn Contains(&self, Modifiers: Self) -> bool {
+        self.0 & Modifiers.0 == Modifiers.0
+    }
+}
+
+impl BitOr for Modifiers_type {
+    type Output = Self;
+
+    fn bitor(self, Other: Self) -> Self {
+        Self(self.0 | Other.0)
+    }
+}
+
+/// Maximum size of the UTF-8 text of a keyboard event (one character).
+pub const Keyboard_text_size: usize = 4;
+
+/// Keyboard event read from a keyboard device.
+///
+/// The key and the state are stored as bytes, since the event is filled from the raw data of a device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Keyboard_data_type {
+    Key: u8,
+    State: u8,
+    Modifiers: Modifiers_type,
+    Text_length: u8,
+    Text: [u8; Keyboard_text_size],
+}
+
+impl Default for Keyboard_data_type {
+    fn default() -> Self {
+        Self::New(
+            Key_type::Unknown,
+            Key_state_type::Released,
+            Modifiers_type::None,
+        )
+    }
+}
+
+impl Keyboard_data_type {
+    pub const fn New(Key: Key_type, State: Key_state_type, Modifiers: Modifiers_type) -> Self {
+        Self {
+            Key: Key as u8,
+            State: State as u8,
+            Modifiers,
+            Text_length: 0,
+            Text: [0; Keyboard_text_size],
+        }
+    }
+
+    /// Create an event for a printable key, which typed `Character`.
+    pub fn New_character(
+        Character: char,
+        State: Key_state_type,
+        Modifiers: Modifiers_type,
+    ) -> Self {
+        let mut Data = Self::New(Key_type::Character, State, Modifiers);
+
+        Data.Text_length = Character.encode_utf8(&mut Data.Text).len() as u8;
+
+        Data
+    }
+
+    /// Get the key of the event, `Unknown` if the byte read from the device is invalid.
+    pub fn Get_key(&self) -> Key_type {
+        Key_type::try_from(self.Key).unwrap_or(Key_type::Unknown)
+    }
+
+    /// Get the state of the key, `Released` if the byte read from the device is invalid.
+    pub fn Get_state(&self) -> Key_state_type {
+        Key_state_type::try_from(self.State).unwrap_or(Key_state_type::Released)
+    }
+
+    pub const fn Get_modifiers(&self) -> Modifiers_type {
+        self.Modifiers
+    }
+
+    /// Get the typed text (empty for non printable keys).
+    pub fn Get_text(&self) -> &str {
+        let Length = (self.Text_length as usize).min(Keyboard_text_size);
+
+        core::str::from_utf8(&self.Text[..Length]).unwrap_or_default()
+    }
+
+    pub fn Get_character(&self) -> Option<char> {
+        self.Get_text().chars().next()
+    }
+
+    pub fn Set_state(&mut self, State: Key_state_type) {
+        self.State = State as u8;
+    }
+
+    /// Get the LVGL key code of the event (Unicode code point for the characters).
+    pub fn Get_lvgl_key(&self) -> u32 {
+        match self.Get_key() {
+            Key_type::Character => self.Get_character().map_or(0, u32::from),
+            Key_type::Enter => 10,
+            Key_type::Backspace => 8,
+            Key_type::Delete => 127,
+            Key_type::Escape => 27,
+            Key_type::Tab if self.Modifiers.Contains(Modifiers_type::Shift) => 11, // Previous
+            Key_type::Tab => 9,                                                    // Next
+            Key_type::Up => 17,
+            Key_type::Down => 18,
+            Key_type::Right => 19,
+            Key_type::Left => 20,
+            Key_type::Home => 2,
+            Key_type::End => 3,
+            Key_type::Page_up | Key_type::Page_down | Key_type::Unknown => 0,
+        }
+    }
+}
+
+impl TryFrom<&mut [u8]> for &mut Keyboard_data_type {
+    type Error = ();
+
+    fn try_from(Value: &mut [u8]) -> Result<Self, Self::Error> {
+        if Value.len() != size_of::<Keyboard_data_type>() {
+            return Err(());
+        }
+        if Value.as_ptr() as usize % core::mem::align_of::<Keyboard_data_type>() != 0 {
+            return Err(());
+        }
+
+        #[allow(clippy::transmute_ptr_to_ref)]
+        Ok(unsafe { transmute::<*mut u8, Self>(Value.as_mut_ptr()) })
+    }
+}
+
+impl AsMut<[u8]> for Keyboard_data_type {
+    fn as_mut(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self as *mut _ as *mut u8, size_of::<Self>()) }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_character() {
+        let Data = Keyboard_data_type::New_character(
+            'é',
+            Key_state_type::Pressed,
+            Modifiers_type::Shift | Modifiers_type::Alt,
+        );
+
+        assert_eq!(Data.Get_key(), Key_type::Character);
+        assert_eq!(Data.Get_text(), "é");
+        assert_eq!(Data.Get_character(), Some('é'));
+        assert_eq!(Data.Get_lvgl_key(), 'é' as u32);
+        assert!(Data.Get_modifiers().Contains(Modifiers_type::Shift));
+        assert!(!Data.Get_modifiers().Contains(Modifiers_type::Control));
+    }
+
+    #[test]
+    fn Test_navigation_keys() {
+        let Next =
+            Keyboard_data_type::New(Key_type::Tab, Key_state_type::Pressed, Modifiers_type::None);
+        let Previous = Keyboard_data_type::New(
+            Key_type::Tab,
+            Key_state_type::Pressed,
+            Modifiers_type::Shift,
+        );
+
+        assert_eq!(Next.Get_text(), "");
+        assert_eq!(Next.Get_lvgl_key(), 9);
+        assert_eq!(Previous.Get_lvgl_key(), 11);
+    }
+
+    #[test]
+    fn Test_invalid_data() {
+        let mut Data = Keyboard_data_type::default();
+
+        Data.as_mut()[..2].copy_from_slice(&[0x42, 0x42]);
+
+        assert_eq!(Data.Get_key(), Key_type::Unknown);
+        assert_eq!(Data.Get_state(), Key_state_type::Released);
+        assert_eq!(Data.Get_lvgl_key(), 0);
+    }
+}
@@ -1,9 +1,12 @@
-use std::sync::Mutex;
+use std::{mem::size_of, sync::Mutex};
 
 use super::Point_type;
 use File_system::File_type;
 
-use crate::{Display_type, Error_type, Input_type, Result_type, Screen_read_data_type};
+use crate::{
+    Display_type, Error_type, Input_type, Keyboard_input_type, Result_type,
+    Screen_get_resolution_command,
+};
 
 /// Avoid using Arc, because the manager is a singleton.
 static mut Manager_instance: Option<Manager_type> = None;
@@ -29,13 +32,19 @@ pub fn Get_instance() -> Result_type<&'static Manager_type> {
     unsafe { Manager_instance.as_ref().ok_or(Error_type::Not_initialized) }
 }
 
-struct Inner(Option<Input_type>);
+struct Inner {
+    Pointer: Option<Input_type>,
+    Keyboard: Option<Keyboard_input_type>,
+}
 
 pub struct Manager_type(Mutex<Inner>);
 
 impl Manager_type {
     fn New() -> Self {
-        Self(Mutex::new(Inner(None)))
+        Self(Mutex::new(Inner {
+            Pointer: None,
+            Keyboard: None,
+        }))
     }
 
     pub fn Create_display<const Buffer_size: usize>(
@@ -43,20 +52,43 @@ impl Manager_type {
         Screen_file: File_type,
         Pointer_file: File_type,
     ) -> Result_type<Display_type> {
-        let mut Screen_read_data = Screen_read_data_type::default();
+        let mut Resolution = [0_u8; size_of::<Point_type>()];
 
         Screen_file
-            .Read(Screen_read_data.as_mut())
+            .Control(Screen_get_resolution_command, &mut Resolution)
             .map_err(|_| Error_type::Failed_to_get_resolution)?;
 
-        let Resolution: Point_type = Screen_read_data.Get_resolution();
+        let Resolution: Point_type = Screen_get_resolution_command
+            .Get_argument(&Resolution)
+            .map_err(|_| Error_type::Failed_to_get_resolution)?;
 
         let Display = Display_type::New::<Buffer_size>(Screen_file, Resolution)?;
 
         let Input = Input_type::New(Pointer_file, &Display)?;
 
-        self.0.lock()?.0.replace(Input);
+        self.0.lock()?.Pointer.replace(Input);
 
         Ok(Display)
     }
+
+    /// Register a keyboard device as a keypad of the display, for text input and focus navigation.
+    ///
+    /// The display must be created beforehand, only one keyboard can be registered.
+    pub fn Add_keyboard(&self, Keyboard_file: File_type) -> Result_type<()> {
+        let mut Inner = self.0.lock()?;
+
+        if Inner.Pointer.is_none() {
+            return Err(Error_type::Not_registered);
+        }
+
+        if Inner.Keyboard.is_some() {
+            return Err(Error_type::Already_in_use);
+        }
+
+        Inner
+            .Keyboard
+            .replace(Keyboard_input_type::New(Keyboard_file)?);
+
+        Ok(())
+    }
 }
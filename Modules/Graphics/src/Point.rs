@@ -1,11 +1,17 @@
 use embedded_graphics::geometry::Point;
+use File_system::{Control_argument_trait, Control_plain_argument_trait};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
 pub struct Point_type {
     X: i16,
     Y: i16,
 }
 
+unsafe impl Control_argument_trait for Point_type {}
+
+unsafe impl Control_plain_argument_trait for Point_type {}
+
 impl Point_type {
     pub const fn New(X: i16, Y: i16) -> Self {
         Self { X, Y }
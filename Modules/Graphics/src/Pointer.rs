@@ -2,36 +2,51 @@ use core::mem::size_of;
 use std::mem::transmute;
 
 use lvgl::input_device::{pointer, BufferStatus};
+use File_system::{Control_command_type, Control_direction_type};
 
 use crate::Point_type;
 
+/// Kind of the pointer device control commands.
+pub const Pointer_control_kind: u8 = 0x11;
+
+/// Get the resolution of the pointer coordinates space (argument : `Point_type`).
+pub const Pointer_get_resolution_command: Control_command_type =
+    Control_command_type::New::<Point_type>(Control_direction_type::Read, Pointer_control_kind, 0);
+
+/// Pointer state read from a pointer device.
+///
+/// The touch is stored as a byte, since the state is filled from the raw data of a device.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(C)]
 pub struct Pointer_data_type {
     pub Point: Point_type,
-    pub Touch: Touch_type,
+    Touch: u8,
 }
 
 impl Default for Pointer_data_type {
     fn default() -> Self {
         Self {
             Point: Point_type::New(0, 0),
-            Touch: Touch_type::Released,
+            Touch: Touch_type::Released as u8,
         }
     }
 }
 
 impl Pointer_data_type {
     pub const fn New(Point: Point_type, Touch: Touch_type) -> Self {
-        Self { Point, Touch }
+        Self {
+            Point,
+            Touch: Touch as u8,
+        }
     }
 
     pub const fn Get_point(&self) -> &Point_type {
         &self.Point
     }
 
-    pub const fn Get_touch(&self) -> &Touch_type {
-        &self.Touch
+    /// Get the touch state, `Released` if the byte read from the device is invalid.
+    pub fn Get_touch(&self) -> Touch_type {
+        Touch_type::try_from(self.Touch).unwrap_or(Touch_type::Released)
     }
 
     pub fn Set_point(&mut self, Point: Point_type) {
@@ -39,12 +54,12 @@ impl Pointer_data_type {
     }
 
     pub fn Set_touch(&mut self, Touch: Touch_type) {
-        self.Touch = Touch;
+        self.Touch = Touch as u8;
     }
 
     pub fn Set(&mut self, Point: Point_type, Touch: Touch_type) {
         self.Point = Point;
-        self.Touch = Touch;
+        self.Touch = Touch as u8;
     }
 }
 
@@ -2,6 +2,7 @@ use core::mem::{size_of, transmute};
 use std::mem::align_of;
 
 use lvgl::DisplayRefresh;
+use File_system::{Control_command_type, Control_direction_type};
 
 use crate::{Area_type, Color_type, Point_type};
 
@@ -52,44 +53,9 @@ impl<const Buffer_size: usize> Screen_write_data_type<Buffer_size> {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[repr(transparent)]
-pub struct Screen_read_data_type(Point_type);
-
-impl Default for Screen_read_data_type {
-    fn default() -> Self {
-        Self(Point_type::New(0, 0))
-    }
-}
-
-impl Screen_read_data_type {
-    pub fn Get_resolution(&self) -> Point_type {
-        self.0
-    }
+/// Kind of the screen device control commands.
+pub const Screen_control_kind: u8 = 0x10;
 
-    pub fn Set_resolution(&mut self, Value: Point_type) {
-        self.0 = Value;
-    }
-}
-
-impl AsMut<[u8]> for Screen_read_data_type {
-    fn as_mut(&mut self) -> &mut [u8] {
-        unsafe { std::slice::from_raw_parts_mut(self as *mut _ as *mut u8, size_of::<Self>()) }
-    }
-}
-
-impl TryFrom<&mut [u8]> for &mut Screen_read_data_type {
-    type Error = ();
-
-    fn try_from(Value: &mut [u8]) -> Result<Self, Self::Error> {
-        if Value.len() != size_of::<Screen_read_data_type>() {
-            return Err(());
-        }
-        if Value.as_ptr() as usize % align_of::<Screen_read_data_type>() != 0 {
-            return Err(());
-        }
-
-        #[allow(clippy::transmute_ptr_to_ref)]
-        Ok(unsafe { transmute::<*mut u8, Self>(Value.as_mut_ptr()) })
-    }
-}
+/// Get the resolution of the screen (argument : `Point_type`).
+pub const Screen_get_resolution_command: Control_command_type =
+    Control_command_type::New::<Point_type>(Control_direction_type::Read, Screen_control_kind, 0);
@@ -8,6 +8,7 @@ mod Display;
 mod Draw_buffer;
 mod Error;
 mod Input;
+mod Keyboard;
 mod Manager;
 mod Point;
 mod Pointer;
@@ -21,6 +22,7 @@ pub use Display::*;
 pub use Draw_buffer::*;
 pub use Error::*;
 pub use Input::*;
+pub use Keyboard::*;
 pub use Manager::*;
 pub use Point::*;
 pub use Pointer::*;
@@ -0,0 +1,293 @@
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+
+use std::thread;
+
+use File_system::{Error_type, Network_trait, Protocol_type, Socket_address_type, Socket_trait};
+use Network::{Stack_type, Virtual_cable_end_type};
+
+const Server_address: [u8; 4] = [10, 0, 0, 1];
+const Client_address: [u8; 4] = [10, 0, 0, 2];
+
+fn New_stacks() -> (Stack_type, Stack_type) {
+    let (Server_end, Client_end) = Virtual_cable_end_type::New_pair();
+
+    (
+        Stack_type::New(Server_end, Server_address),
+        Stack_type::New(Client_end, Client_address),
+    )
+}
+
+/// Payload large enough to exceed the window of the receiver.
+fn Get_payload() -> Vec<u8> {
+    (0..100_000).map(|Index| (Index % 251) as u8).collect()
+}
+
+#[test]
+fn Test_TCP() {
+    let (Server, Client) = New_stacks();
+
+    let Listener = Server.New_socket(Protocol_type::TCP).unwrap();
+
+    Listener.Bind(Socket_address_type::New_any(8080)).unwrap();
+    Listener.Listen(1).unwrap();
+
+    let Server_thread = thread::spawn(move || {
+        let (Connection, Peer) = Listener.Accept().unwrap();
+
+        assert_eq!(Peer.Get_IPv4_address(), Some(Client_address));
+        assert_eq!(
+            Connection.Get_local_address(),
+            Ok(Socket_address_type::New_IPv4(Server_address, 8080))
+        );
+
+        // - Echo until the client closes the connection
+        let mut Buffer = [0_u8; 1000];
+
+        loop {
+            let Size: usize = Connection.Receive(&mut Buffer).unwrap().into();
+
+            if Size == 0 {
+                break;
+            }
+
+            let mut Sent = 0;
+
+            while Sent < Size {
+                Sent += usize::from(Connection.Send(&Buffer[Sent..Size]).unwrap());
+            }
+        }
+    });
+
+    let Socket = Client.New_socket(Protocol_type::TCP).unwrap();
+
+    Socket
+        .Connect(Socket_address_type::New_IPv4(Server_address, 8080))
+        .unwrap();
+
+    assert_eq!(
+        Socket.Get_peer_address(),
+        Ok(Socket_address_type::New_IPv4(Server_address, 8080))
+    );
+
+    let Payload = Get_payload();
+
+    // - Send and receive from separate threads since the echo can't be buffered entirely
+    let Socket = std::sync::Arc::<dyn Socket_trait>::from(Socket);
+
+    let Sender = {
+        let Socket = Socket.clone();
+        let Payload = Payload.clone();
+
+        thread::spawn(move || {
+            let mut Sent = 0;
+
+            while Sent < Payload.len() {
+                Sent += usize::from(Socket.Send(&Payload[Sent..]).unwrap());
+            }
+        })
+    };
+
+    let mut Received = Vec::new();
+    let mut Buffer = [0_u8; 4096];
+
+    while Received.len() < Payload.len() {
+        let Size: usize = Socket.Receive(&mut Buffer).unwrap().into();
+
+        assert_ne!(Size, 0);
+
+        Received.extend_from_slice(&Buffer[..Size]);
+    }
+
+    assert_eq!(Received, Payload);
+
+    Sender.join().unwrap();
+
+    drop(Socket);
+
+    Server_thread.join().unwrap();
+}
+
+#[test]
+fn Test_TCP_lossy_link() {
+    let (Server_end, Client_end) = Virtual_cable_end_type::New_pair();
+
+    // - The SYN and the SYN-ACK are lost
+    Client_end.Drop_next_packets(1).unwrap();
+    Server_end.Drop_next_packets(1).unwrap();
+
+    let Server = Stack_type::New(Server_end, Server_address);
+    let Client = Stack_type::New(Client_end, Client_address);
+
+    let Listener = Server.New_socket(Protocol_type::TCP).unwrap();
+
+    Listener.Bind(Socket_address_type::New_any(80)).unwrap();
+    Listener.Listen(1).unwrap();
+
+    let Server_thread = thread::spawn(move || {
+        let (Connection, _) = Listener.Accept().unwrap();
+
+        let mut Received = Vec::new();
+        let mut Buffer = [0_u8; 4096];
+
+        loop {
+            let Size: usize = Connection.Receive(&mut Buffer).unwrap().into();
+
+            if Size == 0 {
+                break Received;
+            }
+
+            Received.extend_from_slice(&Buffer[..Size]);
+        }
+    });
+
+    let Socket = Client.New_socket(Protocol_type::TCP).unwrap();
+
+    Socket
+        .Connect(Socket_address_type::New_IPv4(Server_address, 80))
+        .unwrap();
+
+    let Payload = Get_payload();
+    let mut Sent = 0;
+
+    while Sent < Payload.len() {
+        Sent += usize::from(Socket.Send(&Payload[Sent..]).unwrap());
+    }
+
+    drop(Socket);
+
+    // - The closed socket lives in the stack until the peer acknowledged everything
+    while !Server_thread.is_finished() {
+        Client.Poll().unwrap();
+        Task::Task_type::Sleep(std::time::Duration::from_millis(1));
+    }
+
+    assert_eq!(Server_thread.join().unwrap(), Payload);
+}
+
+#[test]
+fn Test_TCP_refused() {
+    let (Server, Client) = New_stacks();
+
+    let Socket = Client.New_socket(Protocol_type::TCP).unwrap();
+
+    let Poller = thread::spawn(move || {
+        for _ in 0..100 {
+            Server.Poll().unwrap();
+            Task::Task_type::Sleep(std::time::Duration::from_millis(1));
+        }
+    });
+
+    assert_eq!(
+        Socket.Connect(Socket_address_type::New_IPv4(Server_address, 1234)),
+        Err(Error_type::Connection_refused)
+    );
+
+    Poller.join().unwrap();
+}
+
+#[test]
+fn Test_UDP() {
+    let (Server, Client) = New_stacks();
+
+    let First = Server.New_socket(Protocol_type::UDP).unwrap();
+    let Second = Client.New_socket(Protocol_type::UDP).unwrap();
+
+    First.Bind(Socket_address_type::New_any(5000)).unwrap();
+    Second.Set_non_blocking(true).unwrap();
+
+    let mut Buffer = [0_u8; 16];
+
+    assert_eq!(
+        Second.Receive_from(&mut Buffer),
+        Err(Error_type::Ressource_busy)
+    );
+
+    assert_eq!(
+        Second.Send_to(b"Data", Socket_address_type::New_IPv4(Server_address, 5000)),
+        Ok(4_usize.into())
+    );
+
+    let (Size, Source) = First.Receive_from(&mut Buffer).unwrap();
+
+    assert_eq!(&Buffer[..Size.into()], b"Data");
+    assert_eq!(Source.Get_IPv4_address(), Some(Client_address));
+    assert_eq!(Source, Second.Get_local_address().unwrap());
+
+    // - Connected socket
+    First.Connect(Source).unwrap();
+
+    assert_eq!(First.Send(b"Back"), Ok(4_usize.into()));
+
+    let Result = loop {
+        match Second.Receive_from(&mut Buffer) {
+            Err(Error_type::Ressource_busy) => {
+                Task::Task_type::Sleep(std::time::Duration::from_millis(1))
+            }
+            Result => break Result,
+        }
+    };
+
+    assert_eq!(
+        Result,
+        Ok((
+            4_usize.into(),
+            Socket_address_type::New_IPv4(Server_address, 5000)
+        ))
+    );
+    assert_eq!(&Buffer[..4], b"Back");
+
+    // - The port is already used
+    let Third = Server.New_socket(Protocol_type::UDP).unwrap();
+
+    assert_eq!(
+        Third.Bind(Socket_address_type::New_any(5000)),
+        Err(Error_type::Address_in_use)
+    );
+    assert_eq!(
+        Third.Bind(Socket_address_type::New_IPv4([10, 0, 0, 3], 5001)),
+        Err(Error_type::Address_not_available)
+    );
+}
+
+#[test]
+fn Test_loopback() {
+    let Stack = Stack_type::New_loopback();
+
+    let Listener = Stack.New_socket(Protocol_type::TCP).unwrap();
+
+    Listener
+        .Bind(Socket_address_type::New_localhost(0))
+        .unwrap();
+    Listener.Listen(1).unwrap();
+
+    let Address = Listener.Get_local_address().unwrap();
+
+    assert_ne!(Address.Get_port(), 0);
+
+    let Client = Stack.New_socket(Protocol_type::TCP).unwrap();
+
+    Client.Set_non_blocking(true).unwrap();
+
+    // - The handshake isn't over after the first poll
+    assert_eq!(Client.Connect(Address), Err(Error_type::Ressource_busy));
+
+    let (Connection, Peer) = Listener.Accept().unwrap();
+
+    assert_eq!(Client.Connect(Address), Ok(()));
+    assert_eq!(Client.Get_local_address(), Ok(Peer));
+
+    Client.Set_non_blocking(false).unwrap();
+
+    assert_eq!(Client.Send(b"Ping"), Ok(4_usize.into()));
+
+    let mut Buffer = [0_u8; 4];
+
+    assert_eq!(Connection.Receive(&mut Buffer), Ok(4_usize.into()));
+    assert_eq!(&Buffer, b"Ping");
+
+    drop(Client);
+
+    assert_eq!(Connection.Receive(&mut Buffer), Ok(0_usize.into()));
+}
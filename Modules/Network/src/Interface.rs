@@ -0,0 +1,123 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use File_system::{Error_type, Result_type};
+
+/// Default maximum transmission unit of the interfaces.
+pub const Default_MTU: usize = 1500;
+
+/// Network interface device carrying IP packets for a stack.
+///
+/// Interfaces never block : packets are queued by the device and the stack polls them.
+pub trait Network_interface_trait: Send + Sync {
+    /// Transmit an IP packet, which must not exceed the MTU of the interface.
+    fn Transmit(&self, Packet: &[u8]) -> Result_type<()>;
+
+    /// Receive a pending IP packet, if any.
+    fn Receive(&self) -> Option<Vec<u8>>;
+
+    /// Maximum transmission unit (size of the largest IP packet) of the interface.
+    fn Get_MTU(&self) -> usize {
+        Default_MTU
+    }
+}
+
+/// Interface receiving the packets it transmits.
+pub struct Loopback_interface_type(Mutex<VecDeque<Vec<u8>>>);
+
+impl Loopback_interface_type {
+    pub fn New() -> Self {
+        Self(Mutex::new(VecDeque::new()))
+    }
+}
+
+impl Default for Loopback_interface_type {
+    fn default() -> Self {
+        Self::New()
+    }
+}
+
+impl Network_interface_trait for Loopback_interface_type {
+    fn Transmit(&self, Packet: &[u8]) -> Result_type<()> {
+        if Packet.len() > self.Get_MTU() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        self.0.lock()?.push_back(Packet.to_vec());
+
+        Ok(())
+    }
+
+    fn Receive(&self) -> Option<Vec<u8>> {
+        self.0.lock().ok()?.pop_front()
+    }
+}
+
+/// Queue of the packets travelling in one direction of a virtual cable.
+struct Wire_type {
+    Packets: VecDeque<Vec<u8>>,
+    /// Number of the next packets to lose, to simulate an unreliable link.
+    Dropped: usize,
+}
+
+/// In-memory cable linking two interfaces : the packets transmitted on one end are received on the other.
+pub struct Virtual_cable_end_type {
+    Outgoing: Arc<Mutex<Wire_type>>,
+    Incoming: Arc<Mutex<Wire_type>>,
+}
+
+impl Virtual_cable_end_type {
+    /// Create a cable, returning its two ends.
+    pub fn New_pair() -> (Self, Self) {
+        let First = Arc::new(Mutex::new(Wire_type {
+            Packets: VecDeque::new(),
+            Dropped: 0,
+        }));
+        let Second = Arc::new(Mutex::new(Wire_type {
+            Packets: VecDeque::new(),
+            Dropped: 0,
+        }));
+
+        (
+            Self {
+                Outgoing: First.clone(),
+                Incoming: Second.clone(),
+            },
+            Self {
+                Outgoing: Second,
+                Incoming: First,
+            },
+        )
+    }
+
+    /// Lose the next `Count` packets transmitted on this end.
+    pub fn Drop_next_packets(&self, Count: usize) -> Result_type<()> {
+        self.Outgoing.lock()?.Dropped += Count;
+
+        Ok(())
+    }
+}
+
+impl Network_interface_trait for Virtual_cable_end_type {
+    fn Transmit(&self, Packet: &[u8]) -> Result_type<()> {
+        if Packet.len() > self.Get_MTU() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        let mut Wire = self.Outgoing.lock()?;
+
+        if Wire.Dropped > 0 {
+            Wire.Dropped -= 1;
+        } else {
+            Wire.Packets.push_back(Packet.to_vec());
+        }
+
+        Ok(())
+    }
+
+    fn Receive(&self) -> Option<Vec<u8>> {
+        self.Incoming.lock().ok()?.Packets.pop_front()
+    }
+}
@@ -0,0 +1,363 @@
+//! Parsing and building of the IPv4, UDP and TCP headers.
+
+pub type IPv4_address_type = [u8; 4];
+
+pub const IPv4_header_size: usize = 20;
+pub const UDP_header_size: usize = 8;
+pub const TCP_header_size: usize = 20;
+
+pub const Protocol_TCP: u8 = 6;
+pub const Protocol_UDP: u8 = 17;
+
+pub const Default_time_to_live: u8 = 64;
+
+pub const Flag_FIN: u8 = 0x01;
+pub const Flag_SYN: u8 = 0x02;
+pub const Flag_RST: u8 = 0x04;
+pub const Flag_PSH: u8 = 0x08;
+pub const Flag_ACK: u8 = 0x10;
+
+/// Add `Data` to a one's complement sum.
+fn Sum(Data: &[u8], mut Accumulator: u32) -> u32 {
+    let mut Chunks = Data.chunks_exact(2);
+
+    for Chunk in &mut Chunks {
+        Accumulator += u16::from_be_bytes([Chunk[0], Chunk[1]]) as u32;
+    }
+
+    if let [Last] = Chunks.remainder() {
+        Accumulator += (*Last as u32) << 8;
+    }
+
+    Accumulator
+}
+
+/// Fold a one's complement sum into an internet checksum.
+fn Fold(mut Accumulator: u32) -> u16 {
+    while Accumulator > 0xFFFF {
+        Accumulator = (Accumulator & 0xFFFF) + (Accumulator >> 16);
+    }
+
+    !(Accumulator as u16)
+}
+
+/// Sum of the pseudo-header used by the UDP and TCP checksums.
+fn Pseudo_header_sum(
+    Source: IPv4_address_type,
+    Destination: IPv4_address_type,
+    Protocol: u8,
+    Length: usize,
+) -> u32 {
+    let Accumulator = Sum(&Source, 0);
+    let Accumulator = Sum(&Destination, Accumulator);
+
+    Accumulator + Protocol as u32 + Length as u32
+}
+
+/// Header of a received IPv4 packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IPv4_header_type {
+    pub Source: IPv4_address_type,
+    pub Destination: IPv4_address_type,
+    pub Protocol: u8,
+}
+
+/// Parse an IPv4 packet, returns its header and its payload.
+///
+/// Fragmented packets are not supported and are discarded like the malformed ones.
+pub fn Parse_IPv4(Packet: &[u8]) -> Option<(IPv4_header_type, &[u8])> {
+    if Packet.len() < IPv4_header_size || Packet[0] >> 4 != 4 {
+        return None;
+    }
+
+    let Header_size = ((Packet[0] & 0x0F) as usize) * 4;
+    let Total_size = u16::from_be_bytes([Packet[2], Packet[3]]) as usize;
+
+    if Header_size < IPv4_header_size || Total_size < Header_size || Total_size > Packet.len() {
+        return None;
+    }
+
+    if Fold(Sum(&Packet[..Header_size], 0)) != 0 {
+        return None;
+    }
+
+    // - More fragments flag or fragment offset
+    if u16::from_be_bytes([Packet[6], Packet[7]]) & 0x3FFF != 0 {
+        return None;
+    }
+
+    let Header = IPv4_header_type {
+        Source: [Packet[12], Packet[13], Packet[14], Packet[15]],
+        Destination: [Packet[16], Packet[17], Packet[18], Packet[19]],
+        Protocol: Packet[9],
+    };
+
+    Some((Header, &Packet[Header_size..Total_size]))
+}
+
+/// Build an IPv4 packet around a payload.
+pub fn Build_IPv4(
+    Source: IPv4_address_type,
+    Destination: IPv4_address_type,
+    Protocol: u8,
+    Identification: u16,
+    Payload: &[u8],
+) -> Vec<u8> {
+    let Total_size = (IPv4_header_size + Payload.len()) as u16;
+
+    let mut Packet = Vec::with_capacity(Total_size as usize);
+
+    Packet.extend_from_slice(&[0x45, 0]);
+    Packet.extend_from_slice(&Total_size.to_be_bytes());
+    Packet.extend_from_slice(&Identification.to_be_bytes());
+    // - Don't fragment
+    Packet.extend_from_slice(&[0x40, 0]);
+    Packet.extend_from_slice(&[Default_time_to_live, Protocol, 0, 0]);
+    Packet.extend_from_slice(&Source);
+    Packet.extend_from_slice(&Destination);
+
+    let Checksum = Fold(Sum(&Packet, 0));
+
+    Packet[10..12].copy_from_slice(&Checksum.to_be_bytes());
+
+    Packet.extend_from_slice(Payload);
+
+    Packet
+}
+
+/// Parse an UDP datagram, returns its source port, destination port and payload.
+pub fn Parse_UDP<'a>(
+    Header: &IPv4_header_type,
+    Datagram: &'a [u8],
+) -> Option<(u16, u16, &'a [u8])> {
+    if Datagram.len() < UDP_header_size {
+        return None;
+    }
+
+    let Length = u16::from_be_bytes([Datagram[4], Datagram[5]]) as usize;
+
+    if Length < UDP_header_size || Length > Datagram.len() {
+        return None;
+    }
+
+    let Datagram = &Datagram[..Length];
+
+    // - A zero checksum means that the sender didn't compute it
+    if Datagram[6..8] != [0, 0] {
+        let Accumulator =
+            Pseudo_header_sum(Header.Source, Header.Destination, Protocol_UDP, Length);
+
+        if Fold(Sum(Datagram, Accumulator)) != 0 {
+            return None;
+        }
+    }
+
+    Some((
+        u16::from_be_bytes([Datagram[0], Datagram[1]]),
+        u16::from_be_bytes([Datagram[2], Datagram[3]]),
+        &Datagram[UDP_header_size..],
+    ))
+}
+
+/// Build an UDP datagram.
+pub fn Build_UDP(
+    Source: IPv4_address_type,
+    Source_port: u16,
+    Destination: IPv4_address_type,
+    Destination_port: u16,
+    Payload: &[u8],
+) -> Vec<u8> {
+    let Length = UDP_header_size + Payload.len();
+
+    let mut Datagram = Vec::with_capacity(Length);
+
+    Datagram.extend_from_slice(&Source_port.to_be_bytes());
+    Datagram.extend_from_slice(&Destination_port.to_be_bytes());
+    Datagram.extend_from_slice(&(Length as u16).to_be_bytes());
+    Datagram.extend_from_slice(&[0, 0]);
+    Datagram.extend_from_slice(Payload);
+
+    let Checksum = match Fold(Sum(
+        &Datagram,
+        Pseudo_header_sum(Source, Destination, Protocol_UDP, Length),
+    )) {
+        // - A computed zero checksum is transmitted as all ones
+        0 => 0xFFFF,
+        Checksum => Checksum,
+    };
+
+    Datagram[6..8].copy_from_slice(&Checksum.to_be_bytes());
+
+    Datagram
+}
+
+/// TCP segment, the options are ignored when parsing and never emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TCP_segment_type<'a> {
+    pub Source_port: u16,
+    pub Destination_port: u16,
+    pub Sequence: u32,
+    pub Acknowledgement: u32,
+    pub Flags: u8,
+    pub Window: u16,
+    pub Payload: &'a [u8],
+}
+
+impl TCP_segment_type<'_> {
+    pub fn Has_flag(&self, Flag: u8) -> bool {
+        self.Flags & Flag != 0
+    }
+
+    /// Length of the segment in the sequence space (the SYN and FIN flags count for one).
+    pub fn Get_sequence_length(&self) -> u32 {
+        self.Payload.len() as u32 + self.Has_flag(Flag_SYN) as u32 + self.Has_flag(Flag_FIN) as u32
+    }
+}
+
+/// Parse a TCP segment.
+pub fn Parse_TCP<'a>(Header: &IPv4_header_type, Segment: &'a [u8]) -> Option<TCP_segment_type<'a>> {
+    if Segment.len() < TCP_header_size {
+        return None;
+    }
+
+    let Header_size = ((Segment[12] >> 4) as usize) * 4;
+
+    if Header_size < TCP_header_size || Header_size > Segment.len() {
+        return None;
+    }
+
+    let Accumulator = Pseudo_header_sum(
+        Header.Source,
+        Header.Destination,
+        Protocol_TCP,
+        Segment.len(),
+    );
+
+    if Fold(Sum(Segment, Accumulator)) != 0 {
+        return None;
+    }
+
+    Some(TCP_segment_type {
+        Source_port: u16::from_be_bytes([Segment[0], Segment[1]]),
+        Destination_port: u16::from_be_bytes([Segment[2], Segment[3]]),
+        Sequence: u32::from_be_bytes([Segment[4], Segment[5], Segment[6], Segment[7]]),
+        Acknowledgement: u32::from_be_bytes([Segment[8], Segment[9], Segment[10], Segment[11]]),
+        Flags: Segment[13],
+        Window: u16::from_be_bytes([Segment[14], Segment[15]]),
+        Payload: &Segment[Header_size..],
+    })
+}
+
+/// Build a TCP segment.
+pub fn Build_TCP(
+    Source: IPv4_address_type,
+    Destination: IPv4_address_type,
+    Segment: &TCP_segment_type,
+) -> Vec<u8> {
+    let Length = TCP_header_size + Segment.Payload.len();
+
+    let mut Data = Vec::with_capacity(Length);
+
+    Data.extend_from_slice(&Segment.Source_port.to_be_bytes());
+    Data.extend_from_slice(&Segment.Destination_port.to_be_bytes());
+    Data.extend_from_slice(&Segment.Sequence.to_be_bytes());
+    Data.extend_from_slice(&Segment.Acknowledgement.to_be_bytes());
+    Data.extend_from_slice(&[((TCP_header_size / 4) as u8) << 4, Segment.Flags]);
+    Data.extend_from_slice(&Segment.Window.to_be_bytes());
+    // - Checksum and urgent pointer
+    Data.extend_from_slice(&[0, 0, 0, 0]);
+    Data.extend_from_slice(Segment.Payload);
+
+    let Checksum = Fold(Sum(
+        &Data,
+        Pseudo_header_sum(Source, Destination, Protocol_TCP, Length),
+    ));
+
+    Data[16..18].copy_from_slice(&Checksum.to_be_bytes());
+
+    Data
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    const Source: IPv4_address_type = [10, 0, 0, 1];
+    const Destination: IPv4_address_type = [10, 0, 0, 2];
+
+    #[test]
+    fn Test_IPv4() {
+        let Packet = Build_IPv4(Source, Destination, Protocol_UDP, 1, b"Payload");
+
+        let (Header, Payload) = Parse_IPv4(&Packet).unwrap();
+
+        assert_eq!(Header.Source, Source);
+        assert_eq!(Header.Destination, Destination);
+        assert_eq!(Header.Protocol, Protocol_UDP);
+        assert_eq!(Payload, b"Payload");
+
+        // - Corrupted header
+        let mut Corrupted = Packet.clone();
+        Corrupted[15] ^= 1;
+
+        assert_eq!(Parse_IPv4(&Corrupted), None);
+
+        // - Truncated packet
+        assert_eq!(Parse_IPv4(&Packet[..Packet.len() - 1]), None);
+    }
+
+    #[test]
+    fn Test_UDP() {
+        let Header = IPv4_header_type {
+            Source,
+            Destination,
+            Protocol: Protocol_UDP,
+        };
+
+        let Datagram = Build_UDP(Source, 1234, Destination, 80, b"Odd");
+
+        assert_eq!(
+            Parse_UDP(&Header, &Datagram),
+            Some((1234, 80, b"Odd".as_slice()))
+        );
+
+        let mut Corrupted = Datagram.clone();
+        Corrupted[UDP_header_size] ^= 1;
+
+        assert_eq!(Parse_UDP(&Header, &Corrupted), None);
+    }
+
+    #[test]
+    fn Test_TCP() {
+        let Header = IPv4_header_type {
+            Source,
+            Destination,
+            Protocol: Protocol_TCP,
+        };
+
+        let Segment = TCP_segment_type {
+            Source_port: 1234,
+            Destination_port: 80,
+            Sequence: 0xFFFF_FFFF,
+            Acknowledgement: 42,
+            Flags: Flag_SYN | Flag_ACK,
+            Window: 1024,
+            Payload: b"Data",
+        };
+
+        let Data = Build_TCP(Source, Destination, &Segment);
+
+        let Parsed = Parse_TCP(&Header, &Data).unwrap();
+
+        assert_eq!(Parsed, Segment);
+        assert_eq!(Parsed.Get_sequence_length(), 5);
+
+        // - Segment received from another host
+        let Header = IPv4_header_type {
+            Source: [10, 0, 0, 3],
+            ..Header
+        };
+
+        assert_eq!(Parse_TCP(&Header, &Data), None);
+    }
+}
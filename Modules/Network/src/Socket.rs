@@ -0,0 +1,429 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use File_system::{
+    Error_type, Protocol_type, Result_type, Size_type, Socket_address_type, Socket_trait,
+};
+
+use crate::{
+    Stack::{
+        Socket_data_type, Socket_handle_type, Sockets_type, Stack_inner_type,
+        TCP_socket_state_type, TCP_socket_type, UDP_socket_type, Unspecified_address,
+    },
+    TCP::{Connection_type, Endpoint_type},
+};
+
+/// Delay between two polls of the stack while a blocking operation waits.
+const Poll_interval: Duration = Duration::from_millis(1);
+
+fn Get_endpoint(Address: Socket_address_type) -> Result_type<Endpoint_type> {
+    let IP_address = Address
+        .Get_IPv4_address()
+        .ok_or(Error_type::Address_not_available)?;
+
+    Ok((IP_address, Address.Get_port()))
+}
+
+fn Get_socket_address((Address, Port): Endpoint_type) -> Socket_address_type {
+    Socket_address_type::New_IPv4(Address, Port)
+}
+
+/// Socket of a [`crate::Stack_type`].
+///
+/// Connecting a TCP socket always waits for the handshake, even in non-blocking mode where it returns `Ressource_busy`
+/// until the connection is established.
+pub struct Socket_type {
+    pub(crate) Stack: Arc<Stack_inner_type>,
+    pub(crate) Handle: Socket_handle_type,
+    pub(crate) Non_blocking: AtomicBool,
+}
+
+impl Socket_type {
+    /// Poll the stack until `Function` returns a value.
+    fn Wait<T>(
+        &self,
+        mut Function: impl FnMut(&Stack_inner_type, &mut Sockets_type) -> Result_type<Option<T>>,
+    ) -> Result_type<T> {
+        loop {
+            {
+                let mut Sockets = self.Stack.Lock()?;
+
+                self.Stack.Poll(&mut Sockets)?;
+
+                if let Some(Value) = Function(&self.Stack, &mut Sockets)? {
+                    // - Transmit what the operation produced right away
+                    self.Stack.Poll(&mut Sockets)?;
+
+                    return Ok(Value);
+                }
+            }
+
+            if self.Non_blocking.load(Ordering::Relaxed) {
+                return Err(Error_type::Ressource_busy);
+            }
+
+            Task::Task_type::Sleep(Poll_interval);
+        }
+    }
+
+    /// Bind the socket on an ephemeral port if it isn't bound yet, returns its local endpoint.
+    fn Bind_if_needed(
+        Stack: &Stack_inner_type,
+        Sockets: &mut Sockets_type,
+        Handle: Socket_handle_type,
+    ) -> Result_type<Endpoint_type> {
+        let Protocol = match Sockets.Get(Handle)? {
+            Socket_data_type::UDP_socket(UDP_socket_type {
+                Local: Some(Local), ..
+            })
+            | Socket_data_type::TCP_socket(TCP_socket_type {
+                Local: Some(Local), ..
+            }) => return Ok(*Local),
+            Socket_data_type::UDP_socket(_) => Protocol_type::UDP,
+            Socket_data_type::TCP_socket(_) => Protocol_type::TCP,
+        };
+
+        let Local = (Stack.Get_address(), Sockets.Allocate_port(Protocol)?);
+
+        match Sockets.Get(Handle)? {
+            Socket_data_type::UDP_socket(Socket) => Socket.Local = Some(Local),
+            Socket_data_type::TCP_socket(Socket) => Socket.Local = Some(Local),
+        }
+
+        Ok(Local)
+    }
+
+    fn Receive_datagram(&self, Buffer: &mut [u8]) -> Result_type<(Size_type, Socket_address_type)> {
+        self.Wait(|_, Sockets| match Sockets.Get(self.Handle)? {
+            Socket_data_type::UDP_socket(Socket) => {
+                Ok(Socket.Received.pop_front().map(|(Source, Data)| {
+                    // - The end of the datagram is lost if the buffer is too small
+                    let Size = Data.len().min(Buffer.len());
+
+                    Buffer[..Size].copy_from_slice(&Data[..Size]);
+
+                    (Size.into(), Get_socket_address(Source))
+                }))
+            }
+            Socket_data_type::TCP_socket(_) => Err(Error_type::Unsupported_operation),
+        })
+    }
+}
+
+impl Drop for Socket_type {
+    fn drop(&mut self) {
+        let _ = self.Stack.Close(self.Handle);
+    }
+}
+
+impl Socket_trait for Socket_type {
+    fn Bind(&self, Address: Socket_address_type) -> Result_type<()> {
+        let (Address, Port) = Get_endpoint(Address)?;
+
+        if !self.Stack.Is_local_address(Address) {
+            return Err(Error_type::Address_not_available);
+        }
+
+        let mut Sockets = self.Stack.Lock()?;
+
+        let Protocol = match Sockets.Get(self.Handle)? {
+            Socket_data_type::UDP_socket(UDP_socket_type { Local: None, .. }) => Protocol_type::UDP,
+            Socket_data_type::TCP_socket(TCP_socket_type {
+                Local: None,
+                State: TCP_socket_state_type::Unconnected,
+                ..
+            }) => Protocol_type::TCP,
+            _ => return Err(Error_type::Invalid_input),
+        };
+
+        let Port = match Port {
+            0 => Sockets.Allocate_port(Protocol)?,
+            Port if Sockets.Is_port_used(Protocol, Port) => return Err(Error_type::Address_in_use),
+            Port => Port,
+        };
+
+        match Sockets.Get(self.Handle)? {
+            Socket_data_type::UDP_socket(Socket) => Socket.Local = Some((Address, Port)),
+            Socket_data_type::TCP_socket(Socket) => Socket.Local = Some((Address, Port)),
+        }
+
+        Ok(())
+    }
+
+    fn Listen(&self, Backlog: usize) -> Result_type<()> {
+        let mut Sockets = self.Stack.Lock()?;
+
+        match Sockets.Get(self.Handle)? {
+            Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Unconnected,
+                ..
+            }) => {}
+            Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Listener { .. },
+                ..
+            }) => return Ok(()),
+            Socket_data_type::TCP_socket(_) => return Err(Error_type::Invalid_input),
+            Socket_data_type::UDP_socket(_) => return Err(Error_type::Unsupported_operation),
+        }
+
+        Self::Bind_if_needed(&self.Stack, &mut Sockets, self.Handle)?;
+
+        if let Socket_data_type::TCP_socket(Socket) = Sockets.Get(self.Handle)? {
+            Socket.State = TCP_socket_state_type::Listener {
+                Backlog: Backlog.max(1),
+                Pending: Default::default(),
+            };
+        }
+
+        Ok(())
+    }
+
+    fn Accept(&self) -> Result_type<(Box<dyn Socket_trait>, Socket_address_type)> {
+        let (Handle, Remote) = self.Wait(|_, Sockets| {
+            let Pending = match Sockets.Get(self.Handle)? {
+                Socket_data_type::TCP_socket(TCP_socket_type {
+                    State: TCP_socket_state_type::Listener { Pending, .. },
+                    ..
+                }) => Pending.clone(),
+                _ => return Err(Error_type::Invalid_input),
+            };
+
+            let mut Accepted = None;
+            let mut Failed = Vec::new();
+
+            for Handle in Pending {
+                if let Some(Socket_data_type::TCP_socket(TCP_socket_type {
+                    State: TCP_socket_state_type::Connection(Connection),
+                    ..
+                })) = Sockets.Sockets.get(&Handle)
+                {
+                    if Connection.Is_finished() && Connection.Get_error().is_some() {
+                        Failed.push(Handle);
+                    } else if !Connection.Is_connecting() {
+                        Accepted = Some((Handle, Connection.Get_remote()));
+                        break;
+                    }
+                }
+            }
+
+            // - Forget the connections reset before being accepted
+            for Handle in &Failed {
+                Sockets.Sockets.remove(Handle);
+            }
+
+            if let Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Listener { Pending, .. },
+                ..
+            }) = Sockets.Get(self.Handle)?
+            {
+                Pending.retain(|Handle| {
+                    !Failed.contains(Handle) && Some(*Handle) != Accepted.map(|(Handle, _)| Handle)
+                });
+            }
+
+            Ok(Accepted)
+        })?;
+
+        Ok((
+            Box::new(Socket_type {
+                Stack: self.Stack.clone(),
+                Handle,
+                // - The accepted sockets are blocking whatever the mode of the listener
+                Non_blocking: AtomicBool::new(false),
+            }),
+            Get_socket_address(Remote),
+        ))
+    }
+
+    fn Connect(&self, Address: Socket_address_type) -> Result_type<()> {
+        let Remote = Get_endpoint(Address)?;
+
+        {
+            let mut Sockets = self.Stack.Lock()?;
+
+            match Sockets.Get(self.Handle)? {
+                Socket_data_type::UDP_socket(_) => {
+                    Self::Bind_if_needed(&self.Stack, &mut Sockets, self.Handle)?;
+
+                    if let Socket_data_type::UDP_socket(Socket) = Sockets.Get(self.Handle)? {
+                        Socket.Remote = Some(Remote);
+                    }
+
+                    return Ok(());
+                }
+                Socket_data_type::TCP_socket(TCP_socket_type {
+                    State: TCP_socket_state_type::Unconnected,
+                    ..
+                }) => {
+                    let (_, Port) = Self::Bind_if_needed(&self.Stack, &mut Sockets, self.Handle)?;
+
+                    let Connection = Connection_type::New_active(
+                        (self.Stack.Get_address(), Port),
+                        Remote,
+                        Sockets.Get_initial_sequence(),
+                        self.Stack.Get_maximum_segment_size(),
+                    );
+
+                    if let Socket_data_type::TCP_socket(Socket) = Sockets.Get(self.Handle)? {
+                        Socket.Local = Some(Connection.Get_local());
+                        Socket.State = TCP_socket_state_type::Connection(Connection);
+                    }
+                }
+                // - Connection in progress
+                Socket_data_type::TCP_socket(TCP_socket_type {
+                    State: TCP_socket_state_type::Connection(Connection),
+                    ..
+                }) if Connection.Get_remote() == Remote => {}
+                Socket_data_type::TCP_socket(_) => return Err(Error_type::Invalid_input),
+            }
+        }
+
+        self.Wait(|_, Sockets| match Sockets.Get(self.Handle)? {
+            Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Connection(Connection),
+                ..
+            }) => {
+                if let Some(Error) = Connection.Get_error() {
+                    Err(Error)
+                } else if Connection.Is_connecting() {
+                    Ok(None)
+                } else {
+                    Ok(Some(()))
+                }
+            }
+            _ => Err(Error_type::Invalid_input),
+        })
+    }
+
+    fn Send(&self, Buffer: &[u8]) -> Result_type<Size_type> {
+        self.Wait(|Stack, Sockets| {
+            let (Local_port, Remote) = match Sockets.Get(self.Handle)? {
+                Socket_data_type::UDP_socket(UDP_socket_type {
+                    Local: Some((_, Port)),
+                    Remote: Some(Remote),
+                    ..
+                }) => (*Port, *Remote),
+                Socket_data_type::UDP_socket(_) => return Err(Error_type::Not_connected),
+                Socket_data_type::TCP_socket(TCP_socket_type {
+                    State: TCP_socket_state_type::Connection(Connection),
+                    ..
+                }) => {
+                    if let Some(Error) = Connection.Get_error() {
+                        return Err(Error);
+                    }
+
+                    if Connection.Is_connecting() {
+                        return Ok(None);
+                    }
+
+                    if !Connection.Can_send() {
+                        return Err(Error_type::Not_connected);
+                    }
+
+                    return Ok(match Connection.Write(Buffer) {
+                        0 if !Buffer.is_empty() => None,
+                        Size => Some(Size.into()),
+                    });
+                }
+                Socket_data_type::TCP_socket(_) => return Err(Error_type::Not_connected),
+            };
+
+            Stack.Transmit_UDP(Sockets, Local_port, Remote, Buffer)?;
+
+            Ok(Some(Buffer.len().into()))
+        })
+    }
+
+    fn Receive(&self, Buffer: &mut [u8]) -> Result_type<Size_type> {
+        let Is_UDP = matches!(
+            self.Stack.Lock()?.Get(self.Handle)?,
+            Socket_data_type::UDP_socket(_)
+        );
+
+        if Is_UDP {
+            return self.Receive_datagram(Buffer).map(|(Size, _)| Size);
+        }
+
+        self.Wait(|_, Sockets| match Sockets.Get(self.Handle)? {
+            Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Connection(Connection),
+                ..
+            }) => {
+                if Connection.Has_received_data() {
+                    Ok(Some(Connection.Read(Buffer).into()))
+                } else if Connection.Is_end_of_stream() {
+                    Ok(Some(0_usize.into()))
+                } else if let Some(Error) = Connection.Get_error() {
+                    Err(Error)
+                } else if Connection.Is_finished() {
+                    Err(Error_type::Not_connected)
+                } else {
+                    Ok(None)
+                }
+            }
+            _ => Err(Error_type::Not_connected),
+        })
+    }
+
+    fn Send_to(&self, Buffer: &[u8], Address: Socket_address_type) -> Result_type<Size_type> {
+        let Remote = Get_endpoint(Address)?;
+
+        let mut Sockets = self.Stack.Lock()?;
+
+        if let Socket_data_type::TCP_socket(_) = Sockets.Get(self.Handle)? {
+            return Err(Error_type::Unsupported_operation);
+        }
+
+        let (_, Port) = Self::Bind_if_needed(&self.Stack, &mut Sockets, self.Handle)?;
+
+        self.Stack
+            .Transmit_UDP(&mut Sockets, Port, Remote, Buffer)?;
+
+        Ok(Buffer.len().into())
+    }
+
+    fn Receive_from(&self, Buffer: &mut [u8]) -> Result_type<(Size_type, Socket_address_type)> {
+        self.Receive_datagram(Buffer)
+    }
+
+    fn Get_local_address(&self) -> Result_type<Socket_address_type> {
+        let mut Sockets = self.Stack.Lock()?;
+
+        let Local = match Sockets.Get(self.Handle)? {
+            Socket_data_type::UDP_socket(Socket) => Socket.Local,
+            Socket_data_type::TCP_socket(Socket) => Socket.Local,
+        };
+
+        Ok(Get_socket_address(
+            Local.unwrap_or((Unspecified_address, 0)),
+        ))
+    }
+
+    fn Get_peer_address(&self) -> Result_type<Socket_address_type> {
+        let mut Sockets = self.Stack.Lock()?;
+
+        let Remote = match Sockets.Get(self.Handle)? {
+            Socket_data_type::UDP_socket(Socket) => Socket.Remote,
+            Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Connection(Connection),
+                ..
+            }) => Some(Connection.Get_remote()),
+            Socket_data_type::TCP_socket(_) => None,
+        };
+
+        Remote
+            .map(Get_socket_address)
+            .ok_or(Error_type::Not_connected)
+    }
+
+    fn Set_non_blocking(&self, Non_blocking: bool) -> Result_type<()> {
+        self.Non_blocking.store(Non_blocking, Ordering::Relaxed);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,593 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{atomic::AtomicBool, Arc, Mutex, MutexGuard},
+    time::Instant,
+};
+
+use File_system::{Error_type, Network_trait, Protocol_type, Result_type, Socket_trait};
+
+use crate::{
+    Network_interface_trait,
+    Packet::{
+        Build_IPv4, Build_TCP, Build_UDP, Flag_ACK, Flag_RST, Flag_SYN, IPv4_address_type,
+        IPv4_header_size, Parse_IPv4, Parse_TCP, Parse_UDP, Protocol_TCP, Protocol_UDP,
+        TCP_header_size, TCP_segment_type, UDP_header_size,
+    },
+    Socket_type,
+    TCP::{Connection_type, Endpoint_type, Segment_type, State_type},
+};
+
+/// Address accepting the packets sent to any address of the stack.
+pub(crate) const Unspecified_address: IPv4_address_type = [0, 0, 0, 0];
+
+const Broadcast_address: IPv4_address_type = [255, 255, 255, 255];
+
+/// Range of the ports allocated to the sockets that aren't explicitly bound.
+const Ephemeral_ports: std::ops::RangeInclusive<u16> = 49152..=65535;
+
+/// Maximum number of datagrams waiting to be received on an UDP socket.
+const Maximum_pending_datagrams: usize = 64;
+
+/// Maximum number of packets processed per poll, so that a flooded interface can't starve the sockets.
+const Maximum_packets_per_poll: usize = 256;
+
+pub(crate) type Socket_handle_type = u32;
+
+pub(crate) struct UDP_socket_type {
+    pub Local: Option<Endpoint_type>,
+    pub Remote: Option<Endpoint_type>,
+    pub Received: VecDeque<(Endpoint_type, Vec<u8>)>,
+}
+
+pub(crate) enum TCP_socket_state_type {
+    Unconnected,
+    Listener {
+        Backlog: usize,
+        /// Connections not yet accepted, established or still in the handshake.
+        Pending: VecDeque<Socket_handle_type>,
+    },
+    Connection(Connection_type),
+}
+
+pub(crate) struct TCP_socket_type {
+    pub Local: Option<Endpoint_type>,
+    pub State: TCP_socket_state_type,
+    /// The socket was created by a listener, so it shares the port of the listener.
+    pub Accepted: bool,
+    /// The user closed the socket, it is forgotten once the connection is over.
+    pub Orphan: bool,
+}
+
+pub(crate) enum Socket_data_type {
+    UDP_socket(UDP_socket_type),
+    TCP_socket(TCP_socket_type),
+}
+
+impl Socket_data_type {
+    fn Get_local(&self) -> Option<Endpoint_type> {
+        match self {
+            Socket_data_type::UDP_socket(Socket) => Socket.Local,
+            Socket_data_type::TCP_socket(Socket) => Socket.Local,
+        }
+    }
+
+    fn Get_protocol(&self) -> Protocol_type {
+        match self {
+            Socket_data_type::UDP_socket(_) => Protocol_type::UDP,
+            Socket_data_type::TCP_socket(_) => Protocol_type::TCP,
+        }
+    }
+}
+
+pub(crate) struct Sockets_type {
+    pub Sockets: BTreeMap<Socket_handle_type, Socket_data_type>,
+    Next_handle: Socket_handle_type,
+    Next_port: u16,
+    Next_initial_sequence: u32,
+    Next_identification: u16,
+}
+
+impl Sockets_type {
+    pub fn Get(&mut self, Handle: Socket_handle_type) -> Result_type<&mut Socket_data_type> {
+        self.Sockets
+            .get_mut(&Handle)
+            .ok_or(Error_type::Invalid_identifier)
+    }
+
+    pub fn Insert(&mut self, Socket: Socket_data_type) -> Socket_handle_type {
+        let Handle = self.Next_handle;
+
+        self.Next_handle = self.Next_handle.wrapping_add(1);
+        self.Sockets.insert(Handle, Socket);
+
+        Handle
+    }
+
+    /// Check if a local port is used by a socket of a given protocol, the accepted connections excluded.
+    pub fn Is_port_used(&self, Protocol: Protocol_type, Port: u16) -> bool {
+        self.Sockets.values().any(|Socket| {
+            Socket.Get_protocol() == Protocol
+                && !matches!(
+                    Socket,
+                    Socket_data_type::TCP_socket(TCP_socket_type { Accepted: true, .. })
+                )
+                && Socket
+                    .Get_local()
+                    .is_some_and(|(_, Local_port)| Local_port == Port)
+        })
+    }
+
+    pub fn Allocate_port(&mut self, Protocol: Protocol_type) -> Result_type<u16> {
+        let Count = Ephemeral_ports.len();
+
+        for _ in 0..Count {
+            let Port = self.Next_port;
+
+            self.Next_port = if Port == *Ephemeral_ports.end() {
+                *Ephemeral_ports.start()
+            } else {
+                Port + 1
+            };
+
+            if !self.Is_port_used(Protocol, Port) {
+                return Ok(Port);
+            }
+        }
+
+        Err(Error_type::Address_in_use)
+    }
+
+    pub fn Get_initial_sequence(&mut self) -> u32 {
+        let Sequence = self.Next_initial_sequence;
+
+        self.Next_initial_sequence = self.Next_initial_sequence.wrapping_add(64000);
+
+        Sequence
+    }
+}
+
+pub(crate) struct Stack_inner_type {
+    Interface: Box<dyn Network_interface_trait>,
+    Address: IPv4_address_type,
+    Sockets: Mutex<Sockets_type>,
+}
+
+impl Stack_inner_type {
+    pub fn Get_address(&self) -> IPv4_address_type {
+        self.Address
+    }
+
+    pub fn Lock(&self) -> Result_type<MutexGuard<'_, Sockets_type>> {
+        Ok(self.Sockets.lock()?)
+    }
+
+    /// Maximum TCP payload size of a segment.
+    pub fn Get_maximum_segment_size(&self) -> usize {
+        self.Interface.Get_MTU() - IPv4_header_size - TCP_header_size
+    }
+
+    /// Maximum UDP payload size of a datagram.
+    pub fn Get_maximum_datagram_size(&self) -> usize {
+        self.Interface.Get_MTU() - IPv4_header_size - UDP_header_size
+    }
+
+    /// Check if a local address is one of the stack.
+    pub fn Is_local_address(&self, Address: IPv4_address_type) -> bool {
+        Address == Unspecified_address || Address == self.Address
+    }
+
+    fn Transmit(
+        &self,
+        Sockets: &mut Sockets_type,
+        Destination: IPv4_address_type,
+        Protocol: u8,
+        Payload: &[u8],
+    ) -> Result_type<()> {
+        let Identification = Sockets.Next_identification;
+
+        Sockets.Next_identification = Sockets.Next_identification.wrapping_add(1);
+
+        self.Interface.Transmit(&Build_IPv4(
+            self.Address,
+            Destination,
+            Protocol,
+            Identification,
+            Payload,
+        ))
+    }
+
+    pub fn Transmit_UDP(
+        &self,
+        Sockets: &mut Sockets_type,
+        Source_port: u16,
+        Destination: Endpoint_type,
+        Payload: &[u8],
+    ) -> Result_type<()> {
+        if Payload.len() > self.Get_maximum_datagram_size() {
+            return Err(Error_type::Invalid_input);
+        }
+
+        let Datagram = Build_UDP(
+            self.Address,
+            Source_port,
+            Destination.0,
+            Destination.1,
+            Payload,
+        );
+
+        self.Transmit(Sockets, Destination.0, Protocol_UDP, &Datagram)
+    }
+
+    pub fn Transmit_TCP(
+        &self,
+        Sockets: &mut Sockets_type,
+        Local_port: u16,
+        Remote: Endpoint_type,
+        Segment: &Segment_type,
+    ) -> Result_type<()> {
+        let Data = Build_TCP(
+            self.Address,
+            Remote.0,
+            &TCP_segment_type {
+                Source_port: Local_port,
+                Destination_port: Remote.1,
+                Sequence: Segment.Sequence,
+                Acknowledgement: Segment.Acknowledgement,
+                Flags: Segment.Flags,
+                Window: Segment.Window,
+                Payload: &Segment.Payload,
+            },
+        );
+
+        self.Transmit(Sockets, Remote.0, Protocol_TCP, &Data)
+    }
+
+    /// Process the received packets, then transmit the pending segments and forget the finished connections.
+    pub fn Poll(&self, Sockets: &mut Sockets_type) -> Result_type<()> {
+        let Now = Instant::now();
+
+        for _ in 0..Maximum_packets_per_poll {
+            match self.Interface.Receive() {
+                Some(Packet) => self.Process_packet(Sockets, &Packet, Now)?,
+                None => break,
+            }
+        }
+
+        let mut Outgoing = Vec::new();
+        let mut Finished = Vec::new();
+
+        for (Handle, Socket) in Sockets.Sockets.iter_mut() {
+            let Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Connection(Connection),
+                Orphan,
+                ..
+            }) = Socket
+            else {
+                continue;
+            };
+
+            if *Orphan {
+                Connection.Discard_received_data();
+            }
+
+            for Segment in Connection.Dispatch(Now) {
+                Outgoing.push((Connection.Get_local().1, Connection.Get_remote(), Segment));
+            }
+
+            if *Orphan && Connection.Is_finished() {
+                Finished.push(*Handle);
+            }
+        }
+
+        for Handle in Finished {
+            Sockets.Sockets.remove(&Handle);
+        }
+
+        for (Local_port, Remote, Segment) in Outgoing {
+            self.Transmit_TCP(Sockets, Local_port, Remote, &Segment)?;
+        }
+
+        Ok(())
+    }
+
+    fn Process_packet(
+        &self,
+        Sockets: &mut Sockets_type,
+        Packet: &[u8],
+        Now: Instant,
+    ) -> Result_type<()> {
+        let Some((Header, Payload)) = Parse_IPv4(Packet) else {
+            return Ok(());
+        };
+
+        if Header.Destination != self.Address && Header.Destination != Broadcast_address {
+            return Ok(());
+        }
+
+        match Header.Protocol {
+            Protocol_UDP => {
+                let Some((Source_port, Destination_port, Data)) = Parse_UDP(&Header, Payload)
+                else {
+                    return Ok(());
+                };
+
+                let Source = (Header.Source, Source_port);
+
+                let Receiver = Sockets
+                    .Sockets
+                    .values_mut()
+                    .find_map(|Socket| match Socket {
+                        Socket_data_type::UDP_socket(Socket)
+                            if Socket.Local.is_some_and(|(Address, Port)| {
+                                Port == Destination_port
+                                    && (Address == Unspecified_address
+                                        || Address == Header.Destination)
+                            }) && (Socket.Remote.is_none() || Socket.Remote == Some(Source)) =>
+                        {
+                            Some(Socket)
+                        }
+                        _ => None,
+                    });
+
+                // - Datagrams are dropped when nobody listens or when the receiver is overwhelmed
+                if let Some(Receiver) = Receiver {
+                    if Receiver.Received.len() < Maximum_pending_datagrams {
+                        Receiver.Received.push_back((Source, Data.to_vec()));
+                    }
+                }
+
+                Ok(())
+            }
+            Protocol_TCP => {
+                let Some(Segment) = Parse_TCP(&Header, Payload) else {
+                    return Ok(());
+                };
+
+                self.Process_TCP(Sockets, (Header.Source, Segment.Source_port), &Segment, Now)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn Process_TCP(
+        &self,
+        Sockets: &mut Sockets_type,
+        Remote: Endpoint_type,
+        Segment: &TCP_segment_type,
+        Now: Instant,
+    ) -> Result_type<()> {
+        let Local = (self.Address, Segment.Destination_port);
+
+        // - Existing connection
+        let Connection = Sockets
+            .Sockets
+            .values_mut()
+            .find_map(|Socket| match Socket {
+                Socket_data_type::TCP_socket(TCP_socket_type {
+                    State: TCP_socket_state_type::Connection(Connection),
+                    ..
+                }) if Connection.Get_local() == Local
+                    && Connection.Get_remote() == Remote
+                    && Connection.Get_state() != State_type::Closed =>
+                {
+                    Some(Connection)
+                }
+                _ => None,
+            });
+
+        if let Some(Connection) = Connection {
+            Connection.Process(Segment, Now);
+
+            return Ok(());
+        }
+
+        // - New connection on a listener
+        let Listener = Sockets
+            .Sockets
+            .iter()
+            .find_map(|(Handle, Socket)| match Socket {
+                Socket_data_type::TCP_socket(TCP_socket_type {
+                    Local: Some((Address, Port)),
+                    State: TCP_socket_state_type::Listener { .. },
+                    ..
+                }) if *Port == Local.1 && self.Is_local_address(*Address) => Some(*Handle),
+                _ => None,
+            });
+
+        if let Some(Listener) = Listener {
+            if !Segment.Has_flag(Flag_SYN) || Segment.Has_flag(Flag_ACK | Flag_RST) {
+                return self.Reset(Sockets, Local.1, Remote, Segment);
+            }
+
+            let Initial_sequence = Sockets.Get_initial_sequence();
+
+            let Connection = Connection_type::New_passive(
+                Local,
+                Remote,
+                Initial_sequence,
+                self.Get_maximum_segment_size(),
+                Segment,
+            );
+
+            if let Ok(Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Listener { Backlog, Pending },
+                ..
+            })) = Sockets.Get(Listener)
+            {
+                // - The SYN is ignored when the backlog is full, so the peer retries later
+                if Pending.len() >= *Backlog {
+                    return Ok(());
+                }
+            }
+
+            let Handle = Sockets.Insert(Socket_data_type::TCP_socket(TCP_socket_type {
+                Local: Some(Local),
+                State: TCP_socket_state_type::Connection(Connection),
+                Accepted: true,
+                Orphan: false,
+            }));
+
+            if let Ok(Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Listener { Pending, .. },
+                ..
+            })) = Sockets.Get(Listener)
+            {
+                Pending.push_back(Handle);
+            }
+
+            return Ok(());
+        }
+
+        self.Reset(Sockets, Local.1, Remote, Segment)
+    }
+
+    /// Answer with a reset to a segment which doesn't belong to any connection.
+    fn Reset(
+        &self,
+        Sockets: &mut Sockets_type,
+        Local_port: u16,
+        Remote: Endpoint_type,
+        Segment: &TCP_segment_type,
+    ) -> Result_type<()> {
+        if Segment.Has_flag(Flag_RST) {
+            return Ok(());
+        }
+
+        let Reset = if Segment.Has_flag(Flag_ACK) {
+            Segment_type {
+                Sequence: Segment.Acknowledgement,
+                Acknowledgement: 0,
+                Flags: Flag_RST,
+                Window: 0,
+                Payload: Vec::new(),
+            }
+        } else {
+            Segment_type {
+                Sequence: 0,
+                Acknowledgement: Segment.Sequence.wrapping_add(Segment.Get_sequence_length()),
+                Flags: Flag_RST | Flag_ACK,
+                Window: 0,
+                Payload: Vec::new(),
+            }
+        };
+
+        self.Transmit_TCP(Sockets, Local_port, Remote, &Reset)
+    }
+
+    /// Close a socket when its last handle is dropped.
+    pub fn Close(&self, Handle: Socket_handle_type) -> Result_type<()> {
+        let mut Sockets = self.Lock()?;
+
+        let Some(Socket) = Sockets.Sockets.remove(&Handle) else {
+            return Ok(());
+        };
+
+        match Socket {
+            Socket_data_type::UDP_socket(_)
+            | Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Unconnected,
+                ..
+            }) => {}
+            Socket_data_type::TCP_socket(TCP_socket_type {
+                State: TCP_socket_state_type::Listener { Pending, .. },
+                ..
+            }) => {
+                // - Reset the connections that will never be accepted
+                for Pending_handle in Pending {
+                    if let Some(Socket_data_type::TCP_socket(TCP_socket_type {
+                        State: TCP_socket_state_type::Connection(mut Connection),
+                        ..
+                    })) = Sockets.Sockets.remove(&Pending_handle)
+                    {
+                        if let Some(Reset) = Connection.Abort() {
+                            self.Transmit_TCP(
+                                &mut Sockets,
+                                Connection.Get_local().1,
+                                Connection.Get_remote(),
+                                &Reset,
+                            )?;
+                        }
+                    }
+                }
+            }
+            Socket_data_type::TCP_socket(mut Socket) => {
+                if let TCP_socket_state_type::Connection(Connection) = &mut Socket.State {
+                    Connection.Close();
+                }
+
+                // - The connection lives until the pending data and the FIN are acknowledged
+                Socket.Orphan = true;
+
+                Sockets
+                    .Sockets
+                    .insert(Handle, Socket_data_type::TCP_socket(Socket));
+            }
+        }
+
+        self.Poll(&mut Sockets)
+    }
+}
+
+/// In-process TCP/IP stack over a network interface.
+///
+/// The stack has no thread of its own : it is polled by the operations of its sockets,
+/// and [`Stack_type::Poll`] should be called periodically to keep the connections alive when no socket operation is pending.
+/// The packets sent to the address of the stack go through the interface like the others, use a loopback interface to talk to itself.
+#[derive(Clone)]
+pub struct Stack_type(Arc<Stack_inner_type>);
+
+impl Stack_type {
+    pub fn New(Interface: impl Network_interface_trait + 'static, Address: [u8; 4]) -> Self {
+        Self(Arc::new(Stack_inner_type {
+            Interface: Box::new(Interface),
+            Address,
+            Sockets: Mutex::new(Sockets_type {
+                Sockets: BTreeMap::new(),
+                Next_handle: 0,
+                Next_port: *Ephemeral_ports.start(),
+                // - Differ between stacks so that the connections of two stacks are easy to tell apart
+                Next_initial_sequence: u32::from_be_bytes(Address).rotate_left(16),
+                Next_identification: 0,
+            }),
+        }))
+    }
+
+    /// Create a stack over a loopback interface, with the `127.0.0.1` address.
+    pub fn New_loopback() -> Self {
+        Self::New(crate::Loopback_interface_type::New(), [127, 0, 0, 1])
+    }
+
+    pub fn Get_address(&self) -> [u8; 4] {
+        self.0.Get_address()
+    }
+
+    pub fn Poll(&self) -> Result_type<()> {
+        self.0.Poll(&mut *self.0.Lock()?)
+    }
+}
+
+impl Network_trait for Stack_type {
+    fn New_socket(&self, Protocol: Protocol_type) -> Result_type<Box<dyn Socket_trait>> {
+        let Socket = match Protocol {
+            Protocol_type::TCP => Socket_data_type::TCP_socket(TCP_socket_type {
+                Local: None,
+                State: TCP_socket_state_type::Unconnected,
+                Accepted: false,
+                Orphan: false,
+            }),
+            Protocol_type::UDP => Socket_data_type::UDP_socket(UDP_socket_type {
+                Local: None,
+                Remote: None,
+                Received: VecDeque::new(),
+            }),
+        };
+
+        let Handle = self.0.Lock()?.Insert(Socket);
+
+        Ok(Box::new(Socket_type {
+            Stack: self.0.clone(),
+            Handle,
+            Non_blocking: AtomicBool::new(false),
+        }))
+    }
+}
@@ -0,0 +1,518 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use File_system::Error_type;
+
+use crate::Packet::{
+    Flag_ACK, Flag_FIN, Flag_PSH, Flag_RST, Flag_SYN, IPv4_address_type, TCP_segment_type,
+};
+
+/// Address and port of one side of a connection.
+pub(crate) type Endpoint_type = (IPv4_address_type, u16);
+
+/// Size of the send and receive buffers of a connection.
+pub(crate) const Buffer_size: usize = 16 * 1024;
+
+/// Delay before retransmitting the unacknowledged segments.
+const Retransmission_timeout: Duration = Duration::from_millis(200);
+
+/// Number of unanswered retransmissions after which the connection is considered lost.
+const Maximum_retransmissions: u32 = 8;
+
+/// `A < B` in the sequence space.
+fn Is_before(A: u32, B: u32) -> bool {
+    (A.wrapping_sub(B) as i32) < 0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum State_type {
+    Syn_sent,
+    Syn_received,
+    Established,
+    Fin_wait_1,
+    Fin_wait_2,
+    Close_wait,
+    Closing,
+    Last_ack,
+    /// The connection is over, the stack doesn't linger since no stray segment can outlive it on the virtual links.
+    Time_wait,
+    Closed,
+}
+
+/// Segment to transmit, the ports are filled by the stack.
+pub(crate) struct Segment_type {
+    pub Sequence: u32,
+    pub Acknowledgement: u32,
+    pub Flags: u8,
+    pub Window: u16,
+    pub Payload: Vec<u8>,
+}
+
+/// Transmission control block of a TCP connection.
+///
+/// The retransmission is go-back-N with a fixed timeout, and the out of order segments are discarded :
+/// this keeps the implementation small while being reliable over lossy links.
+pub(crate) struct Connection_type {
+    State: State_type,
+    Local: Endpoint_type,
+    Remote: Endpoint_type,
+    /// Oldest unacknowledged sequence number, the send buffer starts there once the SYN is acknowledged.
+    Send_unacknowledged: u32,
+    Send_next: u32,
+    Send_buffer: VecDeque<u8>,
+    Send_window: u32,
+    Receive_next: u32,
+    Receive_buffer: VecDeque<u8>,
+    Maximum_segment_size: usize,
+    /// The user closed the connection : a FIN is sent once the send buffer is flushed.
+    Close_requested: bool,
+    Fin_received: bool,
+    Acknowledgement_needed: bool,
+    Retransmission_deadline: Option<Instant>,
+    Retransmissions: u32,
+    Error: Option<Error_type>,
+}
+
+impl Connection_type {
+    fn New(
+        State: State_type,
+        Local: Endpoint_type,
+        Remote: Endpoint_type,
+        Initial_sequence: u32,
+        Maximum_segment_size: usize,
+    ) -> Self {
+        Self {
+            State,
+            Local,
+            Remote,
+            Send_unacknowledged: Initial_sequence,
+            Send_next: Initial_sequence,
+            Send_buffer: VecDeque::new(),
+            Send_window: 0,
+            Receive_next: 0,
+            Receive_buffer: VecDeque::new(),
+            Maximum_segment_size,
+            Close_requested: false,
+            Fin_received: false,
+            Acknowledgement_needed: false,
+            Retransmission_deadline: None,
+            Retransmissions: 0,
+            Error: None,
+        }
+    }
+
+    /// Open a connection to `Remote`, the SYN is sent on the next dispatch.
+    pub fn New_active(
+        Local: Endpoint_type,
+        Remote: Endpoint_type,
+        Initial_sequence: u32,
+        Maximum_segment_size: usize,
+    ) -> Self {
+        Self::New(
+            State_type::Syn_sent,
+            Local,
+            Remote,
+            Initial_sequence,
+            Maximum_segment_size,
+        )
+    }
+
+    /// Answer to a SYN received by a listener, the SYN-ACK is sent on the next dispatch.
+    pub fn New_passive(
+        Local: Endpoint_type,
+        Remote: Endpoint_type,
+        Initial_sequence: u32,
+        Maximum_segment_size: usize,
+        Syn: &TCP_segment_type,
+    ) -> Self {
+        let mut Connection = Self::New(
+            State_type::Syn_received,
+            Local,
+            Remote,
+            Initial_sequence,
+            Maximum_segment_size,
+        );
+
+        Connection.Receive_next = Syn.Sequence.wrapping_add(1);
+        Connection.Send_window = Syn.Window as u32;
+
+        Connection
+    }
+
+    pub fn Get_state(&self) -> State_type {
+        self.State
+    }
+
+    pub fn Get_local(&self) -> Endpoint_type {
+        self.Local
+    }
+
+    pub fn Get_remote(&self) -> Endpoint_type {
+        self.Remote
+    }
+
+    pub fn Get_error(&self) -> Option<Error_type> {
+        self.Error
+    }
+
+    /// The connection is over and can be forgotten once the user closed it.
+    pub fn Is_finished(&self) -> bool {
+        matches!(self.State, State_type::Time_wait | State_type::Closed)
+    }
+
+    pub fn Is_connecting(&self) -> bool {
+        matches!(self.State, State_type::Syn_sent | State_type::Syn_received)
+    }
+
+    fn Get_receive_window(&self) -> u16 {
+        (Buffer_size - self.Receive_buffer.len()).min(u16::MAX as usize) as u16
+    }
+
+    /// Queue data to send, returns the number of bytes accepted by the send buffer.
+    pub fn Write(&mut self, Data: &[u8]) -> usize {
+        let Size = Data.len().min(Buffer_size - self.Send_buffer.len());
+
+        self.Send_buffer.extend(&Data[..Size]);
+
+        Size
+    }
+
+    /// Read the received data, returns the number of bytes read.
+    pub fn Read(&mut self, Buffer: &mut [u8]) -> usize {
+        let Previous_window = self.Get_receive_window() as usize;
+
+        let Size = Buffer.len().min(self.Receive_buffer.len());
+
+        for (Destination, Byte) in Buffer.iter_mut().zip(self.Receive_buffer.drain(..Size)) {
+            *Destination = Byte;
+        }
+
+        // - Tell the peer that the window reopened
+        if Previous_window < self.Maximum_segment_size
+            && self.Get_receive_window() as usize >= self.Maximum_segment_size
+        {
+            self.Acknowledgement_needed = true;
+        }
+
+        Size
+    }
+
+    pub fn Has_received_data(&self) -> bool {
+        !self.Receive_buffer.is_empty()
+    }
+
+    /// The peer closed its side of the connection and all its data was read.
+    pub fn Is_end_of_stream(&self) -> bool {
+        self.Fin_received && self.Receive_buffer.is_empty()
+    }
+
+    pub fn Can_send(&self) -> bool {
+        matches!(self.State, State_type::Established | State_type::Close_wait)
+            && !self.Close_requested
+    }
+
+    /// Discard the received data, used once nobody can read it anymore.
+    pub fn Discard_received_data(&mut self) {
+        if !self.Receive_buffer.is_empty() {
+            self.Receive_buffer.clear();
+            self.Acknowledgement_needed = true;
+        }
+    }
+
+    /// Close the connection gracefully once the pending data is sent.
+    pub fn Close(&mut self) {
+        match self.State {
+            State_type::Syn_sent => self.State = State_type::Closed,
+            _ => self.Close_requested = true,
+        }
+    }
+
+    /// Close the connection immediately, returns the reset to send to the peer if needed.
+    pub fn Abort(&mut self) -> Option<Segment_type> {
+        let Synchronized = !matches!(
+            self.State,
+            State_type::Syn_sent | State_type::Time_wait | State_type::Closed
+        );
+
+        self.State = State_type::Closed;
+
+        Synchronized.then(|| Segment_type {
+            Sequence: self.Send_next,
+            Acknowledgement: self.Receive_next,
+            Flags: Flag_RST | Flag_ACK,
+            Window: 0,
+            Payload: Vec::new(),
+        })
+    }
+
+    /// Sequence number of the FIN, once all the data is queued.
+    fn Get_fin_sequence(&self) -> u32 {
+        self.Send_unacknowledged
+            .wrapping_add(self.Send_buffer.len() as u32)
+    }
+
+    /// Process a segment received on this connection.
+    pub fn Process(&mut self, Segment: &TCP_segment_type, Now: Instant) {
+        if Segment.Has_flag(Flag_RST) {
+            match self.State {
+                State_type::Syn_sent => {
+                    if Segment.Has_flag(Flag_ACK) && Segment.Acknowledgement == self.Send_next {
+                        self.Error = Some(Error_type::Connection_refused);
+                        self.State = State_type::Closed;
+                    }
+                }
+                State_type::Time_wait | State_type::Closed => {}
+                _ => {
+                    if Segment.Sequence == self.Receive_next {
+                        self.Error = Some(Error_type::Connection_reset);
+                        self.State = State_type::Closed;
+                    }
+                }
+            }
+
+            return;
+        }
+
+        match self.State {
+            State_type::Syn_sent => {
+                if Segment.Has_flag(Flag_SYN)
+                    && Segment.Has_flag(Flag_ACK)
+                    && Segment.Acknowledgement == self.Send_next
+                {
+                    self.Receive_next = Segment.Sequence.wrapping_add(1);
+                    self.Send_unacknowledged = Segment.Acknowledgement;
+                    self.Send_window = Segment.Window as u32;
+                    self.State = State_type::Established;
+                    self.Retransmission_deadline = None;
+                    self.Retransmissions = 0;
+                    self.Acknowledgement_needed = true;
+                }
+
+                return;
+            }
+            State_type::Syn_received => {
+                if Segment.Has_flag(Flag_SYN) {
+                    // - Our SYN-ACK was lost
+                    self.Send_next = self.Send_unacknowledged;
+                    return;
+                }
+
+                if !Segment.Has_flag(Flag_ACK)
+                    || Segment.Acknowledgement != self.Send_unacknowledged.wrapping_add(1)
+                {
+                    return;
+                }
+
+                self.State = State_type::Established;
+            }
+            State_type::Closed => return,
+            _ => {}
+        }
+
+        if Segment.Has_flag(Flag_ACK) {
+            self.Process_acknowledgement(Segment, Now);
+        }
+
+        // - A duplicated SYN means that our acknowledgement of the handshake was lost
+        if Segment.Payload.is_empty() && !Segment.Has_flag(Flag_FIN) && !Segment.Has_flag(Flag_SYN)
+        {
+            return;
+        }
+
+        // - Out of order or duplicated segments are only acknowledged
+        self.Acknowledgement_needed = true;
+
+        if Segment.Sequence != self.Receive_next {
+            return;
+        }
+
+        let Accepted = if matches!(
+            self.State,
+            State_type::Established | State_type::Fin_wait_1 | State_type::Fin_wait_2
+        ) {
+            let Size = Segment
+                .Payload
+                .len()
+                .min(Buffer_size - self.Receive_buffer.len());
+
+            self.Receive_buffer.extend(&Segment.Payload[..Size]);
+            self.Receive_next = self.Receive_next.wrapping_add(Size as u32);
+
+            Size
+        } else {
+            0
+        };
+
+        if Segment.Has_flag(Flag_FIN) && Accepted == Segment.Payload.len() && !self.Fin_received {
+            self.Receive_next = self.Receive_next.wrapping_add(1);
+            self.Fin_received = true;
+
+            self.State = match self.State {
+                State_type::Established => State_type::Close_wait,
+                State_type::Fin_wait_1 => State_type::Closing,
+                State_type::Fin_wait_2 => State_type::Time_wait,
+                State => State,
+            };
+        }
+    }
+
+    fn Process_acknowledgement(&mut self, Segment: &TCP_segment_type, Now: Instant) {
+        // - The peer is alive
+        self.Retransmissions = 0;
+        self.Send_window = Segment.Window as u32;
+
+        let Acknowledgement = Segment.Acknowledgement;
+
+        if !Is_before(self.Send_unacknowledged, Acknowledgement)
+            || Is_before(self.Send_next, Acknowledgement)
+        {
+            return;
+        }
+
+        let Acknowledged = Acknowledgement.wrapping_sub(self.Send_unacknowledged) as usize;
+        let Fin_sequence = self.Get_fin_sequence();
+
+        // - The SYN was acknowledged in the handshake, so only the data and the FIN remain
+        self.Send_buffer
+            .drain(..Acknowledged.min(self.Send_buffer.len()));
+        self.Send_unacknowledged = Acknowledgement;
+
+        self.Retransmission_deadline =
+            (self.Send_unacknowledged != self.Send_next).then(|| Now + Retransmission_timeout);
+
+        if self.Close_requested && Acknowledgement == Fin_sequence.wrapping_add(1) {
+            self.State = match self.State {
+                State_type::Fin_wait_1 => State_type::Fin_wait_2,
+                State_type::Closing => State_type::Time_wait,
+                State_type::Last_ack => State_type::Closed,
+                State => State,
+            };
+        }
+    }
+
+    fn New_segment(&self, Sequence: u32, Flags: u8, Payload: Vec<u8>) -> Segment_type {
+        Segment_type {
+            Sequence,
+            Acknowledgement: if Flags & Flag_ACK != 0 {
+                self.Receive_next
+            } else {
+                0
+            },
+            Flags,
+            Window: self.Get_receive_window(),
+            Payload,
+        }
+    }
+
+    /// Get the segments to transmit : handshake, data, FIN, acknowledgements and retransmissions.
+    pub fn Dispatch(&mut self, Now: Instant) -> Vec<Segment_type> {
+        let mut Segments = Vec::new();
+
+        if self
+            .Retransmission_deadline
+            .is_some_and(|Deadline| Now >= Deadline)
+        {
+            self.Retransmissions += 1;
+            self.Retransmission_deadline = None;
+
+            if self.Retransmissions > Maximum_retransmissions {
+                self.Error = Some(Error_type::Timed_out);
+                self.State = State_type::Closed;
+
+                return Segments;
+            }
+
+            // - Go back to the oldest unacknowledged segment, or probe a closed window
+            self.Send_next = self.Send_unacknowledged;
+            self.Send_window = self.Send_window.max(1);
+        }
+
+        match self.State {
+            State_type::Syn_sent | State_type::Syn_received => {
+                if self.Send_next == self.Send_unacknowledged {
+                    let Flags = match self.State {
+                        State_type::Syn_sent => Flag_SYN,
+                        _ => Flag_SYN | Flag_ACK,
+                    };
+
+                    Segments.push(self.New_segment(self.Send_next, Flags, Vec::new()));
+
+                    self.Send_next = self.Send_next.wrapping_add(1);
+                }
+            }
+            State_type::Closed => return Segments,
+            _ => {
+                let Fin_sequence = self.Get_fin_sequence();
+
+                // - Data
+                while Is_before(self.Send_next, Fin_sequence) {
+                    let Offset = self.Send_next.wrapping_sub(self.Send_unacknowledged) as usize;
+
+                    let Size = (self.Send_buffer.len() - Offset)
+                        .min(self.Maximum_segment_size)
+                        .min((self.Send_window as usize).saturating_sub(Offset));
+
+                    if Size == 0 {
+                        break;
+                    }
+
+                    let Payload = self
+                        .Send_buffer
+                        .range(Offset..Offset + Size)
+                        .copied()
+                        .collect();
+
+                    Segments.push(self.New_segment(self.Send_next, Flag_ACK | Flag_PSH, Payload));
+
+                    self.Send_next = self.Send_next.wrapping_add(Size as u32);
+                }
+
+                // - FIN
+                if self.Close_requested
+                    && self.Send_next == Fin_sequence
+                    && matches!(
+                        self.State,
+                        State_type::Established
+                            | State_type::Close_wait
+                            | State_type::Fin_wait_1
+                            | State_type::Closing
+                            | State_type::Last_ack
+                    )
+                {
+                    Segments.push(self.New_segment(
+                        self.Send_next,
+                        Flag_FIN | Flag_ACK,
+                        Vec::new(),
+                    ));
+
+                    self.Send_next = self.Send_next.wrapping_add(1);
+
+                    self.State = match self.State {
+                        State_type::Established => State_type::Fin_wait_1,
+                        State_type::Close_wait => State_type::Last_ack,
+                        State => State,
+                    };
+                }
+
+                if self.Acknowledgement_needed && Segments.is_empty() {
+                    Segments.push(self.New_segment(self.Send_next, Flag_ACK, Vec::new()));
+                }
+            }
+        }
+
+        if !Segments.is_empty() {
+            self.Acknowledgement_needed = false;
+        }
+
+        // - Also probe the window of the peer when it blocks the pending data
+        if self.Retransmission_deadline.is_none()
+            && (self.Send_next != self.Send_unacknowledged || !self.Send_buffer.is_empty())
+        {
+            self.Retransmission_deadline = Some(Now + Retransmission_timeout);
+        }
+
+        Segments
+    }
+}
@@ -0,0 +1,21 @@
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+
+//! In-process TCP/IP stack running over abstract network interfaces.
+//!
+//! The stack handles IPv4, UDP and TCP by itself and only needs an interface able to transmit and receive IP packets,
+//! so it can run on targets without relying on the network stack of the platform, and in deterministic tests.
+
+mod Interface;
+pub use Interface::*;
+
+mod Packet;
+
+mod Socket;
+pub use Socket::*;
+
+mod Stack;
+pub use Stack::*;
+
+mod TCP;
@@ -0,0 +1,95 @@
+use File_system::{Control_command_type, Control_direction_type};
+
+/// Attenuation of the input of an ADC channel, which sets the range of measurable voltages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ADC_attenuation_type {
+    Attenuation_0_dB = 0,
+    Attenuation_2_5_dB = 1,
+    Attenuation_6_dB = 2,
+    #[default]
+    Attenuation_11_dB = 3,
+}
+
+impl TryFrom<u8> for ADC_attenuation_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Attenuation_0_dB),
+            1 => Ok(Self::Attenuation_2_5_dB),
+            2 => Ok(Self::Attenuation_6_dB),
+            3 => Ok(Self::Attenuation_11_dB),
+            _ => Err(()),
+        }
+    }
+}
+
+impl ADC_attenuation_type {
+    /// Get the nominal voltage of a full scale reading, in millivolts.
+    pub const fn Get_full_scale_voltage(&self) -> u16 {
+        match self {
+            Self::Attenuation_0_dB => 950,
+            Self::Attenuation_2_5_dB => 1250,
+            Self::Attenuation_6_dB => 1750,
+            Self::Attenuation_11_dB => 2450,
+        }
+    }
+}
+
+/// Maximum number of samples buffered during a continuous sampling, the oldest samples are dropped beyond.
+pub const ADC_sampling_buffer_size: usize = 1024;
+
+/// Kind of the ADC channel device control commands.
+///
+/// Reading an ADC channel device performs a single conversion and returns its raw value (`u16`). During a continuous
+/// sampling, reading returns the buffered raw samples instead (`u16` each, oldest first), without blocking.
+pub const ADC_control_kind: u8 = 0x80;
+
+/// Set the attenuation of the channel (argument : `ADC_attenuation_type` as `u8`).
+pub const ADC_set_attenuation_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, ADC_control_kind, 0);
+
+/// Get the attenuation of the channel (argument : `ADC_attenuation_type` as `u8`).
+pub const ADC_get_attenuation_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Read, ADC_control_kind, 1);
+
+/// Get the resolution of the raw values, in bits (argument : `u8`).
+pub const ADC_get_resolution_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Read, ADC_control_kind, 2);
+
+/// Perform a single conversion and get the calibrated voltage, in millivolts (argument : `u16`).
+pub const ADC_get_millivolts_command: Control_command_type =
+    Control_command_type::New::<u16>(Control_direction_type::Read, ADC_control_kind, 3);
+
+/// Convert a raw value (e.g. a sample) to a calibrated voltage in millivolts, with the current attenuation
+/// (argument : `u16`, raw value then voltage).
+pub const ADC_convert_command: Control_command_type =
+    Control_command_type::New::<u16>(Control_direction_type::Read_write, ADC_control_kind, 4);
+
+/// Start sampling continuously at a frequency in Hz (argument : `u32`), which clears the buffered samples.
+pub const ADC_start_sampling_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Write, ADC_control_kind, 5);
+
+/// Stop the continuous sampling, the buffered samples are dropped.
+pub const ADC_stop_sampling_command: Control_command_type =
+    Control_command_type::New::<()>(Control_direction_type::None, ADC_control_kind, 6);
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_attenuation() {
+        assert_eq!(
+            ADC_attenuation_type::try_from(2),
+            Ok(ADC_attenuation_type::Attenuation_6_dB)
+        );
+        assert!(ADC_attenuation_type::try_from(4).is_err());
+
+        assert!(
+            ADC_attenuation_type::Attenuation_0_dB.Get_full_scale_voltage()
+                < ADC_attenuation_type::Attenuation_11_dB.Get_full_scale_voltage()
+        );
+    }
+}
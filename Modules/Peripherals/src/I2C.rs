@@ -0,0 +1,238 @@
+use core::mem::{offset_of, size_of};
+
+use File_system::{
+    Control_argument_trait, Control_command_type, Control_direction_type,
+    Control_plain_argument_trait,
+};
+
+/// Address of a peripheral on an I2C bus, either 7 or 10 bits long.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct I2C_address_type {
+    Address: u16,
+    Ten_bits: bool,
+    /// Explicit padding, so that the address can be copied as raw bytes.
+    _Reserved: u8,
+}
+
+unsafe impl Control_argument_trait for I2C_address_type {}
+
+impl I2C_address_type {
+    /// Create a 7 bits address, returns `None` if it is out of range.
+    pub const fn New_seven_bits(Address: u8) -> Option<Self> {
+        if Address > 0x7F {
+            return None;
+        }
+
+        Some(Self {
+            Address: Address as u16,
+            Ten_bits: false,
+            _Reserved: 0,
+        })
+    }
+
+    /// Create a 10 bits address, returns `None` if it is out of range.
+    pub const fn New_ten_bits(Address: u16) -> Option<Self> {
+        if Address > 0x3FF {
+            return None;
+        }
+
+        Some(Self {
+            Address,
+            Ten_bits: true,
+            _Reserved: 0,
+        })
+    }
+
+    pub const fn Get_address(&self) -> u16 {
+        self.Address
+    }
+
+    pub const fn Is_ten_bits(&self) -> bool {
+        self.Ten_bits
+    }
+}
+
+/// Decode an address from raw bytes (e.g. a control argument coming from WASM), validating its range.
+impl TryFrom<&[u8]> for I2C_address_type {
+    type Error = ();
+
+    fn try_from(Value: &[u8]) -> Result<Self, Self::Error> {
+        if Value.len() != size_of::<Self>() {
+            return Err(());
+        }
+
+        let Address_offset = offset_of!(Self, Address);
+
+        let Address = u16::from_ne_bytes(
+            Value[Address_offset..Address_offset + size_of::<u16>()]
+                .try_into()
+                .map_err(|_| ())?,
+        );
+
+        match Value[offset_of!(Self, Ten_bits)] {
+            0 => Self::New_seven_bits(u8::try_from(Address).map_err(|_| ())?),
+            1 => Self::New_ten_bits(Address),
+            _ => None,
+        }
+        .ok_or(())
+    }
+}
+
+/// Standard mode clock frequency (in Hz).
+pub const I2C_standard_mode_frequency: u32 = 100_000;
+
+/// Fast mode clock frequency (in Hz).
+pub const I2C_fast_mode_frequency: u32 = 400_000;
+
+/// Fast mode plus clock frequency (in Hz).
+pub const I2C_fast_mode_plus_frequency: u32 = 1_000_000;
+
+/// Maximum size of each part of a write-then-read transaction.
+pub const I2C_write_read_buffer_size: usize = 32;
+
+/// Argument of the `I2C_write_read_command`.
+///
+/// The data to write is taken from the start of the buffer, which is then overwritten by the data read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct I2C_write_read_type {
+    Write_size: u8,
+    Read_size: u8,
+    Buffer: [u8; I2C_write_read_buffer_size],
+}
+
+unsafe impl Control_argument_trait for I2C_write_read_type {}
+
+unsafe impl Control_plain_argument_trait for I2C_write_read_type {}
+
+impl Default for I2C_write_read_type {
+    fn default() -> Self {
+        Self {
+            Write_size: 0,
+            Read_size: 0,
+            Buffer: [0; I2C_write_read_buffer_size],
+        }
+    }
+}
+
+impl I2C_write_read_type {
+    /// Returns `None` if the data to write or the size to read exceeds `I2C_write_read_buffer_size`.
+    pub fn New(Write: &[u8], Read_size: usize) -> Option<Self> {
+        if Write.len() > I2C_write_read_buffer_size || Read_size > I2C_write_read_buffer_size {
+            return None;
+        }
+
+        let mut Buffer = [0; I2C_write_read_buffer_size];
+        Buffer[..Write.len()].copy_from_slice(Write);
+
+        Some(Self {
+            Write_size: Write.len() as u8,
+            Read_size: Read_size as u8,
+            Buffer,
+        })
+    }
+
+    /// Returns `None` if the sizes exceed the buffer (e.g. a malformed argument).
+    pub fn Get_write_data(&self) -> Option<&[u8]> {
+        self.Buffer.get(..self.Write_size as usize)
+    }
+
+    /// Returns `None` if the sizes exceed the buffer (e.g. a malformed argument).
+    pub fn Get_read_data(&self) -> Option<&[u8]> {
+        self.Buffer.get(..self.Read_size as usize)
+    }
+
+    pub fn Get_read_size(&self) -> usize {
+        self.Read_size as usize
+    }
+
+    /// Store the data read in the buffer.
+    pub fn Set_read_data(&mut self, Data: &[u8]) -> Option<()> {
+        self.Buffer.get_mut(..Data.len())?.copy_from_slice(Data);
+        self.Read_size = Data.len() as u8;
+
+        Some(())
+    }
+}
+
+/// Kind of the I2C bus device control commands.
+///
+/// Reading or writing an I2C bus device performs a read or write transaction with the selected peripheral.
+pub const I2C_control_kind: u8 = 0x50;
+
+/// Select the peripheral addressed by the next transactions (argument : `I2C_address_type`).
+pub const I2C_set_address_command: Control_command_type =
+    Control_command_type::New::<I2C_address_type>(
+        Control_direction_type::Write,
+        I2C_control_kind,
+        0,
+    );
+
+/// Get the selected peripheral address (argument : `I2C_address_type`).
+pub const I2C_get_address_command: Control_command_type =
+    Control_command_type::New::<I2C_address_type>(
+        Control_direction_type::Read,
+        I2C_control_kind,
+        1,
+    );
+
+/// Set the clock frequency of the bus, in Hz (argument : `u32`).
+pub const I2C_set_frequency_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Write, I2C_control_kind, 2);
+
+/// Get the clock frequency of the bus, in Hz (argument : `u32`).
+pub const I2C_get_frequency_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Read, I2C_control_kind, 3);
+
+/// Write then read the selected peripheral with a repeated start, typically to read a register
+/// (argument : `I2C_write_read_type`).
+pub const I2C_write_read_command: Control_command_type =
+    Control_command_type::New::<I2C_write_read_type>(
+        Control_direction_type::Read_write,
+        I2C_control_kind,
+        4,
+    );
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_address() {
+        assert!(I2C_address_type::New_seven_bits(0x80).is_none());
+        assert!(I2C_address_type::New_ten_bits(0x400).is_none());
+
+        let Address = I2C_address_type::New_ten_bits(0x2A5).unwrap();
+
+        let mut Argument = [0_u8; size_of::<I2C_address_type>()];
+
+        I2C_set_address_command
+            .Set_argument(&mut Argument, Address)
+            .unwrap();
+
+        assert_eq!(I2C_address_type::try_from(Argument.as_slice()), Ok(Address));
+
+        // - 7 bits address out of range
+        Argument[offset_of!(I2C_address_type, Ten_bits)] = 0;
+        assert!(I2C_address_type::try_from(Argument.as_slice()).is_err());
+
+        // - Invalid flag
+        Argument[offset_of!(I2C_address_type, Ten_bits)] = 2;
+        assert!(I2C_address_type::try_from(Argument.as_slice()).is_err());
+    }
+
+    #[test]
+    fn Test_write_read() {
+        let mut Transfer = I2C_write_read_type::New(&[0x10, 0x20], 4).unwrap();
+
+        assert_eq!(Transfer.Get_write_data(), Some([0x10, 0x20].as_slice()));
+        assert_eq!(Transfer.Get_read_size(), 4);
+
+        Transfer.Set_read_data(&[1, 2, 3]).unwrap();
+        assert_eq!(Transfer.Get_read_data(), Some([1, 2, 3].as_slice()));
+
+        assert!(I2C_write_read_type::New(&[0; I2C_write_read_buffer_size + 1], 0).is_none());
+        assert!(I2C_write_read_type::New(&[], I2C_write_read_buffer_size + 1).is_none());
+    }
+}
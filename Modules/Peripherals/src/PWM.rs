@@ -0,0 +1,173 @@
+use core::mem::{offset_of, size_of};
+
+use File_system::{
+    Control_argument_trait, Control_command_type, Control_direction_type,
+    Control_plain_argument_trait,
+};
+
+/// Maximum resolution of the duty cycle, in bits.
+pub const PWM_maximum_resolution: u8 = 20;
+
+/// Configuration of a PWM channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct PWM_configuration_type {
+    /// Frequency of the signal, in Hz.
+    Frequency: u32,
+    /// Resolution of the duty cycle, in bits.
+    Resolution: u8,
+    /// Explicit padding, so that the configuration can be copied as raw bytes.
+    _Reserved: [u8; 3],
+}
+
+unsafe impl Control_argument_trait for PWM_configuration_type {}
+
+impl Default for PWM_configuration_type {
+    /// 1 kHz with a 10 bits resolution.
+    fn default() -> Self {
+        Self::New(1000, 10)
+    }
+}
+
+impl PWM_configuration_type {
+    pub const fn New(Frequency: u32, Resolution: u8) -> Self {
+        Self {
+            Frequency,
+            Resolution,
+            _Reserved: [0; 3],
+        }
+    }
+
+    pub const fn Get_frequency(&self) -> u32 {
+        self.Frequency
+    }
+
+    pub const fn Get_resolution(&self) -> u8 {
+        self.Resolution
+    }
+
+    /// Get the duty value of a 100 % duty cycle.
+    pub const fn Get_maximum_duty(&self) -> u32 {
+        1 << self.Resolution
+    }
+
+    pub fn Set_frequency(mut self, Frequency: u32) -> Self {
+        self.Frequency = Frequency;
+        self
+    }
+
+    pub fn Set_resolution(mut self, Resolution: u8) -> Self {
+        self.Resolution = Resolution;
+        self
+    }
+
+    pub const fn Is_valid(&self) -> bool {
+        self.Frequency > 0 && self.Resolution > 0 && self.Resolution <= PWM_maximum_resolution
+    }
+}
+
+/// Decode a configuration from raw bytes (e.g. a control argument coming from WASM), validating each field.
+impl TryFrom<&[u8]> for PWM_configuration_type {
+    type Error = ();
+
+    fn try_from(Value: &[u8]) -> Result<Self, Self::Error> {
+        if Value.len() != size_of::<Self>() {
+            return Err(());
+        }
+
+        let Frequency_offset = offset_of!(Self, Frequency);
+
+        let Configuration = Self::New(
+            u32::from_ne_bytes(
+                Value[Frequency_offset..Frequency_offset + size_of::<u32>()]
+                    .try_into()
+                    .map_err(|_| ())?,
+            ),
+            Value[offset_of!(Self, Resolution)],
+        );
+
+        if !Configuration.Is_valid() {
+            return Err(());
+        }
+
+        Ok(Configuration)
+    }
+}
+
+/// Argument of the `PWM_fade_command`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct PWM_fade_type {
+    /// Duty reached at the end of the fade.
+    pub Duty: u32,
+    /// Duration of the fade, in milliseconds.
+    pub Duration: u32,
+}
+
+unsafe impl Control_argument_trait for PWM_fade_type {}
+
+unsafe impl Control_plain_argument_trait for PWM_fade_type {}
+
+/// Kind of the PWM channel device control commands.
+///
+/// Reading or writing a PWM channel device transfers its duty (`u32`), from `0` to the maximum duty of the
+/// configuration (100 %). Writing the duty stops any fade in progress.
+///
+/// The channel must be assigned to a pin before writing its duty or starting a fade (`Unsupported_operation`
+/// otherwise).
+pub const PWM_control_kind: u8 = 0x70;
+
+/// Set the configuration of the channel, which resets the duty to `0` (argument : `PWM_configuration_type`).
+pub const PWM_set_configuration_command: Control_command_type =
+    Control_command_type::New::<PWM_configuration_type>(
+        Control_direction_type::Write,
+        PWM_control_kind,
+        0,
+    );
+
+/// Get the configuration of the channel (argument : `PWM_configuration_type`).
+pub const PWM_get_configuration_command: Control_command_type =
+    Control_command_type::New::<PWM_configuration_type>(
+        Control_direction_type::Read,
+        PWM_control_kind,
+        1,
+    );
+
+/// Assign the output of the channel to a pin (argument : `u8`).
+pub const PWM_set_pin_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, PWM_control_kind, 2);
+
+/// Start a linear fade from the current duty, without waiting for its end (argument : `PWM_fade_type`).
+pub const PWM_fade_command: Control_command_type =
+    Control_command_type::New::<PWM_fade_type>(Control_direction_type::Write, PWM_control_kind, 3);
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_configuration_from_bytes() {
+        let Configuration = PWM_configuration_type::default()
+            .Set_frequency(50)
+            .Set_resolution(16);
+
+        assert_eq!(Configuration.Get_maximum_duty(), 65536);
+
+        let mut Argument = [0_u8; size_of::<PWM_configuration_type>()];
+
+        PWM_set_configuration_command
+            .Set_argument(&mut Argument, Configuration)
+            .unwrap();
+
+        assert_eq!(
+            PWM_configuration_type::try_from(Argument.as_slice()),
+            Ok(Configuration)
+        );
+
+        // - Invalid resolution
+        Argument[offset_of!(PWM_configuration_type, Resolution)] = PWM_maximum_resolution + 1;
+        assert!(PWM_configuration_type::try_from(Argument.as_slice()).is_err());
+
+        assert!(!PWM_configuration_type::New(0, 8).Is_valid());
+    }
+}
@@ -1,4 +1,7 @@
-use core::mem::{size_of, transmute};
+use File_system::{
+    Control_argument_trait, Control_command_type, Control_direction_type,
+    Control_plain_argument_trait,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -53,99 +56,199 @@ pub enum Pull_type {
     Up_down = 3,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
-#[repr(C)]
-pub struct Pin_data_type {
-    Level: Option<Level_type>,
-    Direction: Option<Direction_type>,
-    Pull: Option<Pull_type>,
-}
+impl TryFrom<u8> for Pull_type {
+    type Error = ();
 
-impl Pin_data_type {
-    pub const fn New(
-        Level: Option<Level_type>,
-        Direction: Option<Direction_type>,
-        Pull: Option<Pull_type>,
-    ) -> Self {
-        Self {
-            Level,
-            Direction,
-            Pull,
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Up),
+            2 => Ok(Self::Down),
+            3 => Ok(Self::Up_down),
+            _ => Err(()),
         }
     }
+}
 
-    pub const fn Get_level(&self) -> Option<Level_type> {
-        self.Level
-    }
+/// Kind of the pin device control commands.
+///
+/// Reading or writing a pin device transfers its level (one byte, see `Level_type`).
+pub const Pin_control_kind: u8 = 0x20;
 
-    pub const fn Get_direction(&self) -> Option<Direction_type> {
-        self.Direction
-    }
+/// Set the direction of the pin (argument : `Direction_type` as `u8`).
+pub const Pin_set_direction_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, Pin_control_kind, 0);
 
-    pub const fn Get_pull(&self) -> Option<Pull_type> {
-        self.Pull
-    }
+/// Set the pull mode of the pin (argument : `Pull_type` as `u8`).
+pub const Pin_set_pull_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, Pin_control_kind, 1);
 
-    pub fn Set_level(&mut self, Level: Option<Level_type>) {
-        self.Level = Level;
-    }
+/// Condition triggering a pin interrupt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Interrupt_type {
+    None = 0,
+    Rising_edge = 1,
+    Falling_edge = 2,
+    Both_edges = 3,
+    /// While the level is low, an event is queued each time the level becomes low (or when enabling the interrupt
+    /// while the level is low).
+    Low_level = 4,
+    /// See `Low_level`.
+    High_level = 5,
+}
+
+impl TryFrom<u8> for Interrupt_type {
+    type Error = ();
 
-    pub fn Set_direction(&mut self, Direction: Option<Direction_type>) {
-        self.Direction = Direction;
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Rising_edge),
+            2 => Ok(Self::Falling_edge),
+            3 => Ok(Self::Both_edges),
+            4 => Ok(Self::Low_level),
+            5 => Ok(Self::High_level),
+            _ => Err(()),
+        }
     }
+}
 
-    pub fn Set_pull(&mut self, Pull: Option<Pull_type>) {
-        self.Pull = Pull;
+impl Interrupt_type {
+    /// Check if a change of level triggers the interrupt.
+    pub fn Is_triggered(&self, Previous: Level_type, Current: Level_type) -> bool {
+        match self {
+            Self::None => false,
+            Self::Rising_edge => Previous == Level_type::Low && Current == Level_type::High,
+            Self::Falling_edge => Previous == Level_type::High && Current == Level_type::Low,
+            Self::Both_edges => Previous != Current,
+            Self::Low_level => Previous != Current && Current == Level_type::Low,
+            Self::High_level => Previous != Current && Current == Level_type::High,
+        }
     }
+}
+
+/// Event queued by a pin interrupt.
+///
+/// The layout is flat (no enumeration, no padding) so that it can be safely read from a WASM app.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Pin_event_type {
+    /// Time of the interrupt, in microseconds (monotonic).
+    Timestamp: u64,
+    /// Level of the pin when the interrupt occurred (see `Level_type`).
+    Level: u8,
+    _Reserved: [u8; 7],
+}
 
-    pub fn Set(
-        &mut self,
-        Level: Option<Level_type>,
-        Direction: Option<Direction_type>,
-        Pull: Option<Pull_type>,
-    ) {
-        self.Level = Level;
-        self.Direction = Direction;
-        self.Pull = Pull;
+unsafe impl Control_argument_trait for Pin_event_type {}
+
+unsafe impl Control_plain_argument_trait for Pin_event_type {}
+
+impl Default for Pin_event_type {
+    fn default() -> Self {
+        Self::New(0, Level_type::Low)
     }
 }
 
-impl AsMut<[u8]> for Pin_data_type {
-    fn as_mut(&mut self) -> &mut [u8] {
-        unsafe {
-            std::slice::from_raw_parts_mut(self as *mut _ as *mut u8, core::mem::size_of::<Self>())
+impl Pin_event_type {
+    pub const fn New(Timestamp: u64, Level: Level_type) -> Self {
+        Self {
+            Timestamp,
+            Level: Level as u8,
+            _Reserved: [0; 7],
         }
     }
-}
 
-impl TryFrom<&[u8]> for &Pin_data_type {
-    type Error = ();
+    pub const fn Get_timestamp(&self) -> u64 {
+        self.Timestamp
+    }
 
-    fn try_from(Value: &[u8]) -> Result<Self, Self::Error> {
-        if Value.len() != size_of::<Pin_data_type>() {
-            return Err(());
-        }
-        if Value.as_ptr() as usize % core::mem::align_of::<Pin_data_type>() != 0 {
-            return Err(());
+    /// Get the level of the pin, returns `None` if it is invalid (e.g. when coming from WASM).
+    pub const fn Get_level(&self) -> Option<Level_type> {
+        match self.Level {
+            0 => Some(Level_type::Low),
+            1 => Some(Level_type::High),
+            _ => None,
         }
-
-        #[allow(clippy::transmute_ptr_to_ref)]
-        Ok(unsafe { transmute::<*const u8, Self>(Value.as_ptr()) })
     }
 }
 
-impl TryFrom<&mut [u8]> for &mut Pin_data_type {
-    type Error = ();
+/// Argument of the `Pin_wait_event_command`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Pin_wait_event_type {
+    /// Maximum time to wait, in microseconds (`u64::MAX` to wait forever).
+    pub Timeout: u64,
+    /// Event received.
+    pub Event: Pin_event_type,
+}
 
-    fn try_from(Value: &mut [u8]) -> Result<Self, Self::Error> {
-        if Value.len() != size_of::<Pin_data_type>() {
-            return Err(());
-        }
-        if Value.as_ptr() as usize % core::mem::align_of::<Pin_data_type>() != 0 {
-            return Err(());
-        }
+unsafe impl Control_argument_trait for Pin_wait_event_type {}
+
+unsafe impl Control_plain_argument_trait for Pin_wait_event_type {}
+
+/// Maximum number of events queued per pin, the next events are dropped and counted as overflows.
+pub const Pin_events_queue_size: usize = 16;
+
+/// Set the interrupt of the pin (argument : `Interrupt_type` as `u8`), which clears the queued events.
+pub const Pin_set_interrupt_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, Pin_control_kind, 2);
+
+/// Take the oldest queued event without blocking (argument : `Pin_event_type`).
+///
+/// Returns `Ressource_busy` if no event is queued.
+pub const Pin_get_event_command: Control_command_type =
+    Control_command_type::New::<Pin_event_type>(Control_direction_type::Read, Pin_control_kind, 3);
+
+/// Wait for an event (argument : `Pin_wait_event_type`).
+///
+/// Returns `Ressource_busy` if no event occurred before the timeout.
+pub const Pin_wait_event_command: Control_command_type =
+    Control_command_type::New::<Pin_wait_event_type>(
+        Control_direction_type::Read_write,
+        Pin_control_kind,
+        4,
+    );
+
+/// Get the number of events dropped because the queue was full (argument : `u32`).
+pub const Pin_get_overflow_count_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Read, Pin_control_kind, 5);
+
+/// Get the direction of the pin (argument : `Direction_type` as `u8`).
+pub const Pin_get_direction_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Read, Pin_control_kind, 6);
+
+/// Get the pull mode of the pin (argument : `Pull_type` as `u8`).
+pub const Pin_get_pull_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Read, Pin_control_kind, 7);
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_interrupt_trigger() {
+        use Level_type::{High, Low};
+
+        assert!(Interrupt_type::Rising_edge.Is_triggered(Low, High));
+        assert!(!Interrupt_type::Rising_edge.Is_triggered(High, Low));
+        assert!(Interrupt_type::Falling_edge.Is_triggered(High, Low));
+        assert!(Interrupt_type::Both_edges.Is_triggered(High, Low));
+        assert!(!Interrupt_type::Both_edges.Is_triggered(High, High));
+        assert!(Interrupt_type::Low_level.Is_triggered(High, Low));
+        assert!(!Interrupt_type::High_level.Is_triggered(High, Low));
+        assert!(!Interrupt_type::None.Is_triggered(Low, High));
+    }
+
+    #[test]
+    fn Test_event() {
+        let mut Event = Pin_event_type::New(42, Level_type::High);
+
+        assert_eq!(Event.Get_timestamp(), 42);
+        assert_eq!(Event.Get_level(), Some(Level_type::High));
 
-        #[allow(clippy::transmute_ptr_to_ref)]
-        Ok(unsafe { transmute::<*mut u8, Self>(Value.as_mut_ptr()) })
+        Event.Level = 2;
+        assert_eq!(Event.Get_level(), None);
     }
 }
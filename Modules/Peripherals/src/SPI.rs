@@ -0,0 +1,165 @@
+use File_system::{
+    Control_argument_trait, Control_command_type, Control_direction_type,
+    Control_plain_argument_trait,
+};
+
+/// Clock polarity (CPOL) and phase (CPHA) of a SPI bus.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SPI_mode_type {
+    /// Clock idle low, data sampled on the rising edge.
+    #[default]
+    Mode_0 = 0,
+    /// Clock idle low, data sampled on the falling edge.
+    Mode_1 = 1,
+    /// Clock idle high, data sampled on the falling edge.
+    Mode_2 = 2,
+    /// Clock idle high, data sampled on the rising edge.
+    Mode_3 = 3,
+}
+
+impl TryFrom<u8> for SPI_mode_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Mode_0),
+            1 => Ok(Self::Mode_1),
+            2 => Ok(Self::Mode_2),
+            3 => Ok(Self::Mode_3),
+            _ => Err(()),
+        }
+    }
+}
+
+impl SPI_mode_type {
+    /// Returns `true` if the clock is high when idle (CPOL = 1).
+    pub const fn Get_clock_polarity(&self) -> bool {
+        matches!(self, Self::Mode_2 | Self::Mode_3)
+    }
+
+    /// Returns `true` if the data is sampled on the second clock edge (CPHA = 1).
+    pub const fn Get_clock_phase(&self) -> bool {
+        matches!(self, Self::Mode_1 | Self::Mode_3)
+    }
+}
+
+/// Maximum size of a full-duplex transfer through the `SPI_transfer_command`.
+pub const SPI_transfer_buffer_size: usize = 64;
+
+/// Argument of the `SPI_transfer_command`.
+///
+/// The data to transmit is taken from the start of the buffer, which is then overwritten by the data received.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct SPI_transfer_type {
+    Size: u8,
+    Buffer: [u8; SPI_transfer_buffer_size],
+}
+
+unsafe impl Control_argument_trait for SPI_transfer_type {}
+
+unsafe impl Control_plain_argument_trait for SPI_transfer_type {}
+
+impl Default for SPI_transfer_type {
+    fn default() -> Self {
+        Self {
+            Size: 0,
+            Buffer: [0; SPI_transfer_buffer_size],
+        }
+    }
+}
+
+impl SPI_transfer_type {
+    /// Returns `None` if the data exceeds `SPI_transfer_buffer_size`.
+    pub fn New(Data: &[u8]) -> Option<Self> {
+        let mut Transfer = Self::default();
+
+        Transfer.Buffer.get_mut(..Data.len())?.copy_from_slice(Data);
+        Transfer.Size = Data.len() as u8;
+
+        Some(Transfer)
+    }
+
+    /// Returns `None` if the size exceeds the buffer (e.g. a malformed argument).
+    pub fn Get_data(&self) -> Option<&[u8]> {
+        self.Buffer.get(..self.Size as usize)
+    }
+
+    /// Returns `None` if the size exceeds the buffer (e.g. a malformed argument).
+    pub fn Get_data_mutable(&mut self) -> Option<&mut [u8]> {
+        self.Buffer.get_mut(..self.Size as usize)
+    }
+}
+
+/// Kind of the SPI device control commands.
+///
+/// A SPI device is a peripheral on a SPI bus, with its own chip select, mode and frequency. Reading a SPI device
+/// receives data while transmitting zeros, writing it transmits data and discards the data received. Each operation
+/// asserts the chip select during the transfer, unless a batch is in progress.
+pub const SPI_control_kind: u8 = 0x60;
+
+/// Set the mode of the device (argument : `SPI_mode_type` as `u8`).
+pub const SPI_set_mode_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Write, SPI_control_kind, 0);
+
+/// Get the mode of the device (argument : `SPI_mode_type` as `u8`).
+pub const SPI_get_mode_command: Control_command_type =
+    Control_command_type::New::<u8>(Control_direction_type::Read, SPI_control_kind, 1);
+
+/// Set the clock frequency used with the device, in Hz (argument : `u32`).
+pub const SPI_set_frequency_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Write, SPI_control_kind, 2);
+
+/// Get the clock frequency used with the device, in Hz (argument : `u32`).
+pub const SPI_get_frequency_command: Control_command_type =
+    Control_command_type::New::<u32>(Control_direction_type::Read, SPI_control_kind, 3);
+
+/// Perform a full-duplex transfer (argument : `SPI_transfer_type`).
+pub const SPI_transfer_command: Control_command_type = Control_command_type::New::<SPI_transfer_type>(
+    Control_direction_type::Read_write,
+    SPI_control_kind,
+    4,
+);
+
+/// Acquire the bus and keep the chip select asserted across the next transfers, until the batch ends.
+///
+/// If another device of the bus is in a batch, the command waits for it to end or returns `Ressource_busy`, depending
+/// on the backend.
+pub const SPI_begin_batch_command: Control_command_type =
+    Control_command_type::New::<()>(Control_direction_type::None, SPI_control_kind, 5);
+
+/// Release the chip select and the bus.
+pub const SPI_end_batch_command: Control_command_type =
+    Control_command_type::New::<()>(Control_direction_type::None, SPI_control_kind, 6);
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_mode() {
+        assert!(!SPI_mode_type::Mode_0.Get_clock_polarity());
+        assert!(!SPI_mode_type::Mode_0.Get_clock_phase());
+        assert!(SPI_mode_type::Mode_1.Get_clock_phase());
+        assert!(SPI_mode_type::Mode_2.Get_clock_polarity());
+        assert!(!SPI_mode_type::Mode_2.Get_clock_phase());
+        assert!(SPI_mode_type::Mode_3.Get_clock_polarity());
+
+        assert_eq!(SPI_mode_type::try_from(3), Ok(SPI_mode_type::Mode_3));
+        assert!(SPI_mode_type::try_from(4).is_err());
+    }
+
+    #[test]
+    fn Test_transfer() {
+        let mut Transfer = SPI_transfer_type::New(&[1, 2, 3]).unwrap();
+
+        Transfer
+            .Get_data_mutable()
+            .unwrap()
+            .copy_from_slice(&[4, 5, 6]);
+        assert_eq!(Transfer.Get_data(), Some([4, 5, 6].as_slice()));
+
+        assert!(SPI_transfer_type::New(&[0; SPI_transfer_buffer_size + 1]).is_none());
+    }
+}
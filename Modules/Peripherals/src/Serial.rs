@@ -0,0 +1,242 @@
+use core::mem::{offset_of, size_of};
+
+use File_system::{Control_argument_trait, Control_command_type, Control_direction_type};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Parity_type {
+    None = 0,
+    Even = 1,
+    Odd = 2,
+}
+
+impl TryFrom<u8> for Parity_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Even),
+            2 => Ok(Self::Odd),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Stop_bits_type {
+    One = 0,
+    One_and_half = 1,
+    Two = 2,
+}
+
+impl TryFrom<u8> for Stop_bits_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::One),
+            1 => Ok(Self::One_and_half),
+            2 => Ok(Self::Two),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Flow_control_type {
+    None = 0,
+    /// RTS / CTS lines.
+    Hardware = 1,
+    /// XON / XOFF characters.
+    Software = 2,
+}
+
+impl TryFrom<u8> for Flow_control_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Hardware),
+            2 => Ok(Self::Software),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Configuration of a serial port.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Serial_configuration_type {
+    Baud_rate: u32,
+    Data_bits: u8,
+    Parity: Parity_type,
+    Stop_bits: Stop_bits_type,
+    Flow_control: Flow_control_type,
+}
+
+unsafe impl Control_argument_trait for Serial_configuration_type {}
+
+impl Default for Serial_configuration_type {
+    /// 115200 bauds, 8 data bits, no parity, 1 stop bit and no flow control.
+    fn default() -> Self {
+        Self::New(
+            115200,
+            8,
+            Parity_type::None,
+            Stop_bits_type::One,
+            Flow_control_type::None,
+        )
+    }
+}
+
+impl Serial_configuration_type {
+    pub const fn New(
+        Baud_rate: u32,
+        Data_bits: u8,
+        Parity: Parity_type,
+        Stop_bits: Stop_bits_type,
+        Flow_control: Flow_control_type,
+    ) -> Self {
+        Self {
+            Baud_rate,
+            Data_bits,
+            Parity,
+            Stop_bits,
+            Flow_control,
+        }
+    }
+
+    pub const fn Get_baud_rate(&self) -> u32 {
+        self.Baud_rate
+    }
+
+    pub const fn Get_data_bits(&self) -> u8 {
+        self.Data_bits
+    }
+
+    pub const fn Get_parity(&self) -> Parity_type {
+        self.Parity
+    }
+
+    pub const fn Get_stop_bits(&self) -> Stop_bits_type {
+        self.Stop_bits
+    }
+
+    pub const fn Get_flow_control(&self) -> Flow_control_type {
+        self.Flow_control
+    }
+
+    pub fn Set_baud_rate(mut self, Baud_rate: u32) -> Self {
+        self.Baud_rate = Baud_rate;
+        self
+    }
+
+    pub fn Set_data_bits(mut self, Data_bits: u8) -> Self {
+        self.Data_bits = Data_bits;
+        self
+    }
+
+    pub fn Set_parity(mut self, Parity: Parity_type) -> Self {
+        self.Parity = Parity;
+        self
+    }
+
+    pub fn Set_stop_bits(mut self, Stop_bits: Stop_bits_type) -> Self {
+        self.Stop_bits = Stop_bits;
+        self
+    }
+
+    pub fn Set_flow_control(mut self, Flow_control: Flow_control_type) -> Self {
+        self.Flow_control = Flow_control;
+        self
+    }
+}
+
+/// Decode a configuration from raw bytes (e.g. a control argument coming from WASM), validating each field.
+impl TryFrom<&[u8]> for Serial_configuration_type {
+    type Error = ();
+
+    fn try_from(Value: &[u8]) -> Result<Self, Self::Error> {
+        if Value.len() != size_of::<Self>() {
+            return Err(());
+        }
+
+        let Baud_rate_offset = offset_of!(Self, Baud_rate);
+
+        let Baud_rate = u32::from_ne_bytes(
+            Value[Baud_rate_offset..Baud_rate_offset + size_of::<u32>()]
+                .try_into()
+                .map_err(|_| ())?,
+        );
+
+        let Data_bits = Value[offset_of!(Self, Data_bits)];
+
+        if !(5..=8).contains(&Data_bits) {
+            return Err(());
+        }
+
+        Ok(Self::New(
+            Baud_rate,
+            Data_bits,
+            Parity_type::try_from(Value[offset_of!(Self, Parity)])?,
+            Stop_bits_type::try_from(Value[offset_of!(Self, Stop_bits)])?,
+            Flow_control_type::try_from(Value[offset_of!(Self, Flow_control)])?,
+        ))
+    }
+}
+
+/// Kind of the serial device control commands.
+pub const Serial_control_kind: u8 = 0x30;
+
+/// Set the configuration of the serial port (argument : `Serial_configuration_type`).
+pub const Serial_set_configuration_command: Control_command_type =
+    Control_command_type::New::<Serial_configuration_type>(
+        Control_direction_type::Write,
+        Serial_control_kind,
+        0,
+    );
+
+/// Get the configuration of the serial port (argument : `Serial_configuration_type`).
+pub const Serial_get_configuration_command: Control_command_type =
+    Control_command_type::New::<Serial_configuration_type>(
+        Control_direction_type::Read,
+        Serial_control_kind,
+        1,
+    );
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_configuration_from_bytes() {
+        let Configuration = Serial_configuration_type::default()
+            .Set_baud_rate(9600)
+            .Set_data_bits(7)
+            .Set_parity(Parity_type::Even)
+            .Set_stop_bits(Stop_bits_type::Two)
+            .Set_flow_control(Flow_control_type::Hardware);
+
+        let mut Argument = [0_u8; size_of::<Serial_configuration_type>()];
+
+        Serial_set_configuration_command
+            .Set_argument(&mut Argument, Configuration)
+            .unwrap();
+
+        assert_eq!(
+            Serial_configuration_type::try_from(Argument.as_slice()),
+            Ok(Configuration)
+        );
+
+        // - Invalid parity
+        Argument[offset_of!(Serial_configuration_type, Parity)] = 3;
+        assert!(Serial_configuration_type::try_from(Argument.as_slice()).is_err());
+
+        // - Invalid size
+        assert!(Serial_configuration_type::try_from(&Argument[..4]).is_err());
+    }
+}
@@ -2,6 +2,16 @@
 #![allow(non_camel_case_types)]
 #![allow(non_upper_case_globals)]
 
+mod ADC;
+mod I2C;
+mod PWM;
 mod Pin;
+mod SPI;
+mod Serial;
 
 pub use Pin::*;
+pub use Serial::*;
+pub use ADC::*;
+pub use I2C::*;
+pub use PWM::*;
+pub use SPI::*;
@@ -16,6 +16,22 @@ pub enum Error_type {
     Too_many_tasks,
     Already_initialized,
     Not_initialized,
+    Invalid_working_directory,
+    Permission_denied,
+    Child_tasks_limit_exceeded,
+    Stack_size_limit_exceeded,
+    CPU_time_limit_exceeded,
+    Invalid_timer_identifier,
+    Invalid_timer_period,
+    Too_many_timers,
+    No_child_task,
+    Invalid_signal,
+    Signal_handler_failed,
+    Failed_to_set_priority,
+    Failed_to_set_affinity,
+    Invalid_priority,
+    Interrupted,
+    Buffer_too_small,
 }
 
 impl<T> From<PoisonError<T>> for Error_type {
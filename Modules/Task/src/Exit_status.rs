@@ -0,0 +1,26 @@
+/// How a task ended, as reported to its parent by [crate::Manager_type::Wait_child].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit_status_type {
+    /// The task returned, with the code set by `Set_exit_code` (`0` by default).
+    Exited(i32),
+    /// The task returned after a termination request, without setting an exit code.
+    Terminated,
+    /// The task panicked.
+    Panicked,
+}
+
+impl Exit_status_type {
+    pub const Success: Self = Self::Exited(0);
+
+    pub fn Is_success(&self) -> bool {
+        *self == Self::Success
+    }
+
+    /// Get the exit code, if the task exited on its own.
+    pub fn Get_code(&self) -> Option<i32> {
+        match self {
+            Self::Exited(Code) => Some(*Code),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::time::Duration;
+
+/// Resource limits of a task.
+///
+/// Limits are inherited by child tasks. Only the root user can raise a limit, other users can only lower them.
+/// A value of `None` means that the resource is unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Limits_type {
+    /// Maximum number of files opened simultaneously by the task.
+    Open_files: u32,
+    /// Maximum number of living child tasks.
+    Child_tasks: u32,
+    /// Maximum stack size (in bytes) of the child tasks.
+    Stack_size: u64,
+    /// Maximum size (in bytes) of the linear memory of a WASM instance.
+    Memory: u64,
+    /// Maximum CPU time (in milliseconds) consumed by the task.
+    CPU_time: u64,
+}
+
+impl Default for Limits_type {
+    fn default() -> Self {
+        Self::New_unlimited()
+    }
+}
+
+impl Limits_type {
+    const Unlimited_u32: u32 = u32::MAX;
+    const Unlimited_u64: u64 = u64::MAX;
+
+    /// Creates new limits without any restriction.
+    pub const fn New_unlimited() -> Self {
+        Self {
+            Open_files: Self::Unlimited_u32,
+            Child_tasks: Self::Unlimited_u32,
+            Stack_size: Self::Unlimited_u64,
+            Memory: Self::Unlimited_u64,
+            CPU_time: Self::Unlimited_u64,
+        }
+    }
+
+    /// Convert a finite limit, saturating below the value meaning unlimited.
+    fn Get_finite_u32(Value: usize) -> u32 {
+        u32::try_from(Value).map_or(Self::Unlimited_u32 - 1, |Value| {
+            Value.min(Self::Unlimited_u32 - 1)
+        })
+    }
+
+    /// Convert a finite limit, saturating below the value meaning unlimited.
+    fn Get_finite_u64(Value: u128) -> u64 {
+        u64::try_from(Value).map_or(Self::Unlimited_u64 - 1, |Value| {
+            Value.min(Self::Unlimited_u64 - 1)
+        })
+    }
+
+    /// Set the limit of open files, a finite value saturates to `u32::MAX - 1`.
+    pub fn Set_open_files(mut self, Value: Option<usize>) -> Self {
+        self.Open_files = Value.map_or(Self::Unlimited_u32, Self::Get_finite_u32);
+        self
+    }
+
+    /// Set the limit of child tasks, a finite value saturates to `u32::MAX - 1`.
+    pub fn Set_child_tasks(mut self, Value: Option<usize>) -> Self {
+        self.Child_tasks = Value.map_or(Self::Unlimited_u32, Self::Get_finite_u32);
+        self
+    }
+
+    pub fn Set_stack_size(mut self, Value: Option<usize>) -> Self {
+        self.Stack_size = Value.map_or(Self::Unlimited_u64, |Value| {
+            Self::Get_finite_u64(Value as u128)
+        });
+        self
+    }
+
+    pub fn Set_memory(mut self, Value: Option<usize>) -> Self {
+        self.Memory = Value.map_or(Self::Unlimited_u64, |Value| {
+            Self::Get_finite_u64(Value as u128)
+        });
+        self
+    }
+
+    pub fn Set_CPU_time(mut self, Value: Option<Duration>) -> Self {
+        self.CPU_time = Value.map_or(Self::Unlimited_u64, |Value| {
+            Self::Get_finite_u64(Value.as_millis())
+        });
+        self
+    }
+
+    pub fn Get_open_files(&self) -> Option<usize> {
+        (self.Open_files != Self::Unlimited_u32).then_some(self.Open_files as usize)
+    }
+
+    pub fn Get_child_tasks(&self) -> Option<usize> {
+        (self.Child_tasks != Self::Unlimited_u32).then_some(self.Child_tasks as usize)
+    }
+
+    pub fn Get_stack_size(&self) -> Option<usize> {
+        (self.Stack_size != Self::Unlimited_u64).then_some(self.Stack_size as usize)
+    }
+
+    pub fn Get_memory(&self) -> Option<usize> {
+        (self.Memory != Self::Unlimited_u64).then_some(self.Memory as usize)
+    }
+
+    pub fn Get_CPU_time(&self) -> Option<Duration> {
+        (self.CPU_time != Self::Unlimited_u64).then_some(Duration::from_millis(self.CPU_time))
+    }
+
+    /// Check if all the limits are lower or equal to the limits of `Other`.
+    pub fn Is_within(&self, Other: &Self) -> bool {
+        self.Open_files <= Other.Open_files
+            && self.Child_tasks <= Other.Child_tasks
+            && self.Stack_size <= Other.Stack_size
+            && self.Memory <= Other.Memory
+            && self.CPU_time <= Other.CPU_time
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_unlimited() {
+        let Limits = Limits_type::New_unlimited();
+
+        assert_eq!(Limits.Get_open_files(), None);
+        assert_eq!(Limits.Get_child_tasks(), None);
+        assert_eq!(Limits.Get_stack_size(), None);
+        assert_eq!(Limits.Get_memory(), None);
+        assert_eq!(Limits.Get_CPU_time(), None);
+        assert_eq!(Limits, Limits_type::default());
+    }
+
+    #[test]
+    fn Test_set_get() {
+        let Limits = Limits_type::New_unlimited()
+            .Set_open_files(Some(16))
+            .Set_child_tasks(Some(4))
+            .Set_stack_size(Some(4096))
+            .Set_memory(Some(64 * 1024))
+            .Set_CPU_time(Some(Duration::from_secs(2)));
+
+        assert_eq!(Limits.Get_open_files(), Some(16));
+        assert_eq!(Limits.Get_child_tasks(), Some(4));
+        assert_eq!(Limits.Get_stack_size(), Some(4096));
+        assert_eq!(Limits.Get_memory(), Some(64 * 1024));
+        assert_eq!(Limits.Get_CPU_time(), Some(Duration::from_secs(2)));
+
+        let Limits = Limits.Set_open_files(None);
+        assert_eq!(Limits.Get_open_files(), None);
+    }
+
+    #[test]
+    fn Test_saturation() {
+        // - Finite limits never become unlimited
+        let Limits = Limits_type::New_unlimited()
+            .Set_open_files(Some(u32::MAX as usize))
+            .Set_child_tasks(Some(usize::MAX))
+            .Set_memory(Some(usize::MAX))
+            .Set_CPU_time(Some(Duration::MAX));
+
+        assert_eq!(Limits.Get_open_files(), Some(u32::MAX as usize - 1));
+        assert_eq!(Limits.Get_child_tasks(), Some(u32::MAX as usize - 1));
+        assert!(Limits.Get_memory().is_some());
+        assert!(Limits.Get_CPU_time().is_some());
+    }
+
+    #[test]
+    fn Test_is_within() {
+        let Unlimited = Limits_type::New_unlimited();
+        let Limited = Limits_type::New_unlimited().Set_open_files(Some(8));
+
+        assert!(Limited.Is_within(&Unlimited));
+        assert!(!Unlimited.Is_within(&Limited));
+        assert!(Limited.Is_within(&Limited));
+    }
+}
@@ -5,8 +5,11 @@ use super::*;
 // - - - Standard library
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, HashMap},
-    sync::RwLock,
+    cell::Cell,
+    collections::{BTreeMap, HashMap, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    sync::{Condvar, Mutex, RwLock},
+    time::{Duration, Instant},
 };
 use Users::{Root_user_identifier, User_identifier_type};
 
@@ -14,12 +17,100 @@ use Users::{Root_user_identifier, User_identifier_type};
 struct Task_internal_type {
     /// The thread that runs the task.
     Thread: Thread_wrapper_type,
+    /// The identifier of the parent task (the root task is its own parent).
+    Parent: Task_identifier_type,
     /// The identifiers of the children of the task.
     Children: Vec<Task_identifier_type>,
     /// The identifier of the user that owns the task.
     Owner: User_identifier_type,
     /// Environment variables of the task.
     Environment_variables: HashMap<Cow<'static, str>, Cow<'static, str>>,
+    /// Absolute path of the working directory of the task.
+    Working_directory: String,
+    /// Resource limits of the task.
+    Limits: Limits_type,
+    /// Signals sent to the task and not delivered yet.
+    Pending_signals: Signal_set_type,
+    /// Signals blocked by the task, they stay pending until unblocked.
+    Signal_mask: Signal_set_type,
+    /// Signals for which the task registered a handler, the others get their default action.
+    Handled_signals: Signal_set_type,
+    /// Exit code set by the task before returning.
+    Exit_code: Option<i32>,
+    /// Whether the function of the task panicked.
+    Panicked: bool,
+    /// Children that exited and weren't waited for yet, the oldest first.
+    Exited_children: VecDeque<(Task_identifier_type, Exit_status_type)>,
+    /// What the task is doing.
+    State: Task_state_type,
+    /// Creation time of the task, relative to the creation of the manager.
+    Start_time: Duration,
+    /// Stack size requested for the thread of the task.
+    Stack_size: Option<usize>,
+    /// Scheduling priority of the task.
+    Priority: Priority_type,
+    /// Cores on which the task can run.
+    Affinity: Affinity_type,
+}
+
+/// Maximum number of exit statuses kept per task, the oldest are forgotten first.
+const Maximum_exited_children: usize = 64;
+
+impl Task_internal_type {
+    /// Pending signals that aren't blocked by the mask of the task.
+    fn Get_deliverable_signals(&self) -> Signal_set_type {
+        Signal_set_type::New(self.Pending_signals.Get_raw() & !self.Signal_mask.Get_raw())
+    }
+
+    /// Make a signal pending, unless it isn't handled and ignored by default.
+    fn Raise_signal(&mut self, Signal: Signal_type) {
+        if self.Handled_signals.Contains(Signal)
+            || Signal.Get_default_action() != Signal_action_type::Ignore
+        {
+            self.Pending_signals = self.Pending_signals.Add(Signal);
+        }
+    }
+
+    /// Whether a deliverable signal interrupts the blocking calls of the task, i.e. it terminates the task or has a handler.
+    fn Is_interrupted(&self) -> bool {
+        self.Is_terminating()
+            || self
+                .Get_deliverable_signals()
+                .Get_signals()
+                .any(|Signal| self.Handled_signals.Contains(Signal))
+    }
+
+    /// Whether a deliverable signal without handler terminates the task.
+    fn Is_terminating(&self) -> bool {
+        self.Get_deliverable_signals().Get_signals().any(|Signal| {
+            !self.Handled_signals.Contains(Signal)
+                && Signal.Get_default_action() == Signal_action_type::Terminate
+        })
+    }
+}
+
+thread_local! {
+    /// Task on behalf of which the current thread runs, when it isn't the thread of a task (e.g. the timer service).
+    static Delegated_task: Cell<Option<Task_identifier_type>> = const { Cell::new(None) };
+}
+
+/// Restore the previously delegated task when dropped, even if the delegated function panics.
+struct Delegation_guard_type(Option<Task_identifier_type>);
+
+impl Drop for Delegation_guard_type {
+    fn drop(&mut self) {
+        Delegated_task.set(self.0);
+    }
+}
+
+/// Run a function on the current thread as if it was the thread of a task.
+pub(crate) fn Run_on_behalf_of<T>(
+    Task_identifier: Task_identifier_type,
+    Function: impl FnOnce() -> T,
+) -> T {
+    let _Guard = Delegation_guard_type(Delegated_task.replace(Some(Task_identifier)));
+
+    Function()
 }
 
 static mut Manager_instance: Option<Manager_type> = None;
@@ -48,37 +139,69 @@ pub fn Is_initialized() -> bool {
 pub struct Manager_type {
     /// A map of all tasks managed by the Get_instance().unwrap().
     Tasks: RwLock<BTreeMap<Task_identifier_type, Task_internal_type>>,
+    /// Software timers of the tasks.
+    pub(crate) Timers: Timers_type,
+    /// Lock held while checking the exit statuses, so that no exit is missed before waiting on `Exit_condition`.
+    Exit_lock: Mutex<()>,
+    /// Notified each time a task exits.
+    Exit_condition: Condvar,
+    /// Creation time of the manager, the start times of the tasks are relative to it.
+    Start: Instant,
 }
 
 impl Manager_type {
+    const Root_task_identifier: Task_identifier_type = Task_identifier_type::New(0);
+
     fn New() -> Self {
         // Add current thread to tasks as root task
         let Task_internal = Task_internal_type {
             Thread: Thread_wrapper_type::Get_current(),
+            Parent: Self::Root_task_identifier,
             Children: Vec::new(),
             Owner: Root_user_identifier,
             Environment_variables: HashMap::new(),
+            Working_directory: "/".to_string(),
+            Limits: Limits_type::New_unlimited(),
+            Pending_signals: Signal_set_type::Empty,
+            Signal_mask: Signal_set_type::Empty,
+            Handled_signals: Signal_set_type::Empty,
+            Exit_code: None,
+            Panicked: false,
+            Exited_children: VecDeque::new(),
+            State: Task_state_type::Running,
+            Start_time: Duration::ZERO,
+            Stack_size: None,
+            Priority: Priority_type::Normal,
+            Affinity: Affinity_type::Any,
         };
 
         let mut Tasks_map = BTreeMap::new();
 
-        Tasks_map.insert(Task_identifier_type::from(0), Task_internal);
+        Tasks_map.insert(Self::Root_task_identifier, Task_internal);
 
         Manager_type {
             Tasks: RwLock::new(Tasks_map),
+            Timers: Timers_type::New(),
+            Exit_lock: Mutex::new(()),
+            Exit_condition: Condvar::new(),
+            Start: Instant::now(),
         }
     }
 
     fn Get_new_task_identifier(&self) -> Option<Task_identifier_type> {
+        let Tasks = self.Tasks.read().ok()?;
+
+        // - The identifiers of the exited children not yet waited for aren't reused
         (0..Task_identifier_type::Maximum)
+            .map(Task_identifier_type::from)
             .find(|Identifier| {
-                !self
-                    .Tasks
-                    .read()
-                    .unwrap()
-                    .contains_key(&(*Identifier).into())
+                !Tasks.contains_key(Identifier)
+                    && Tasks.values().all(|Task| {
+                        Task.Exited_children
+                            .iter()
+                            .all(|(Child, _)| Child != Identifier)
+                    })
             })
-            .map(|Identifier| Identifier.into())
     }
 
     pub fn Get_task_name(&self, Task_identifier: Task_identifier_type) -> Result_type<String> {
@@ -96,14 +219,19 @@ impl Manager_type {
     /// * `Parent_task_identifier` - The identifier of the parent task, if None, the current task is used.
     /// * `Name` - The human readable name of the task.
     /// * `Stack_size` - The size of the stack of the task.
+    /// * `Priority` - The scheduling priority of the task, if None, the priority of the parent task is used.
+    /// * `Affinity` - The cores on which the task can run, if None, the affinity of the parent task is used.
     /// * `Function` - The function that the task will execute.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn New_task<T, F>(
         &self,
         Parent_task_identifier: Option<Task_identifier_type>,
         User_identifier: Option<User_identifier_type>,
         Name: &str,
         Stack_size: Option<usize>,
+        Priority: Option<Priority_type>,
+        Affinity: Option<Affinity_type>,
         Function: F,
     ) -> Result_type<(Task_identifier_type, Join_handle_type<T>)>
     where
@@ -131,29 +259,89 @@ impl Manager_type {
             .get_mut(&Parent_task_identifier)
             .ok_or(Error_type::Invalid_task_identifier)?;
 
-        Parent_task.Children.push(Child_task_identifier);
+        // - Check the limits of the parent task
+        if let Some(Maximum) = Parent_task.Limits.Get_child_tasks() {
+            if Parent_task.Children.len() >= Maximum {
+                return Err(Error_type::Child_tasks_limit_exceeded);
+            }
+        }
+
+        let Stack_size = match (Stack_size, Parent_task.Limits.Get_stack_size()) {
+            (Some(Stack_size), Some(Maximum)) if Stack_size > Maximum => {
+                return Err(Error_type::Stack_size_limit_exceeded)
+            }
+            (None, Some(Maximum)) => Some(Maximum),
+            (Stack_size, _) => Stack_size,
+        };
+
+        // - Only the tasks of the root user can run with a higher priority than their parent
+        let Priority = match Priority {
+            Some(Priority)
+                if Priority > Parent_task.Priority
+                    && !Users::Manager_type::Is_root(Parent_task.Owner) =>
+            {
+                return Err(Error_type::Permission_denied)
+            }
+            Some(Priority) => Priority,
+            None => Parent_task.Priority,
+        };
+
+        let Affinity = Affinity.unwrap_or(Parent_task.Affinity);
 
         let Environment_variables = Parent_task.Environment_variables.clone();
 
+        let Working_directory = Parent_task.Working_directory.clone();
+
+        let Limits = Parent_task.Limits;
+
         let Function = move || {
-            let Result = Function();
-            let _ = Get_instance()
-                .expect("Failed to get instance")
-                .Delete_task(Child_task_identifier);
-            Result
+            let Result = panic::catch_unwind(AssertUnwindSafe(Function));
+
+            let Manager = Get_instance().expect("Failed to get instance");
+
+            if let Ok(mut Tasks) = Manager.Tasks.write() {
+                if let Some(Task) = Tasks.get_mut(&Child_task_identifier) {
+                    Task.State = Task_state_type::Exited;
+                    Task.Panicked = Result.is_err();
+                }
+            }
+
+            let _ = Manager.Delete_task(Child_task_identifier);
+
+            match Result {
+                Ok(Result) => Result,
+                Err(Payload) => panic::resume_unwind(Payload),
+            }
         };
 
-        let Join_handle = Thread_wrapper_type::New(Name, Stack_size, Function)?;
+        let Join_handle =
+            Thread_wrapper_type::New(Name, Stack_size, Some(Priority), Some(Affinity), Function)?;
 
         let Thread = Join_handle.Get_thread_wrapper();
 
+        Parent_task.Children.push(Child_task_identifier);
+
         Tasks.insert(
             Child_task_identifier,
             Task_internal_type {
                 Thread,
+                Parent: Parent_task_identifier,
                 Children: Vec::new(),
                 Owner,
                 Environment_variables,
+                Working_directory,
+                Limits,
+                Pending_signals: Signal_set_type::Empty,
+                Signal_mask: Signal_set_type::Empty,
+                Handled_signals: Signal_set_type::Empty,
+                Exit_code: None,
+                Panicked: false,
+                Exited_children: VecDeque::new(),
+                State: Task_state_type::Running,
+                Start_time: self.Start.elapsed(),
+                Stack_size,
+                Priority,
+                Affinity,
             },
         );
 
@@ -172,28 +360,381 @@ impl Manager_type {
             .Owner)
     }
 
-    fn Delete_task(&self, Task_identifier: Task_identifier_type) -> Result_type<()> {
-        // - Wait for all children to terminate
-        while !self
+    pub fn Get_parent(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Task_identifier_type> {
+        Ok(self
             .Tasks
             .read()?
             .get(&Task_identifier)
             .ok_or(Error_type::Invalid_task_identifier)?
-            .Children
-            .is_empty()
+            .Parent)
+    }
+
+    /// Remove an exited task, report its exit status to its parent and reparent its children to the root task.
+    fn Delete_task(&self, Task_identifier: Task_identifier_type) -> Result_type<()> {
+        // - No callback of the task must run once it is deleted
+        self.Timers.Delete_task_timers(Task_identifier)?;
+
         {
-            Task_type::Sleep(std::time::Duration::from_millis(10));
+            let mut Tasks = self.Tasks.write()?;
+
+            let Task = Tasks
+                .remove(&Task_identifier)
+                .ok_or(Error_type::Invalid_task_identifier)?;
+
+            let Exit_status = match (Task.Panicked, Task.Exit_code, Task.Is_terminating()) {
+                (true, _, _) => Exit_status_type::Panicked,
+                (false, Some(Code), _) => Exit_status_type::Exited(Code),
+                (false, None, true) => Exit_status_type::Terminated,
+                (false, None, false) => Exit_status_type::Success,
+            };
+
+            // - The children left behind are adopted by the root task, their exited siblings are forgotten
+            for Child in &Task.Children {
+                if let Some(Child) = Tasks.get_mut(Child) {
+                    Child.Parent = Self::Root_task_identifier;
+                }
+            }
+
+            if let Some(Root_task) = Tasks.get_mut(&Self::Root_task_identifier) {
+                Root_task.Children.extend(&Task.Children);
+            }
+
+            if let Some(Parent_task) = Tasks.get_mut(&Task.Parent) {
+                Parent_task
+                    .Children
+                    .retain(|Identifier| *Identifier != Task_identifier);
+
+                if Parent_task.Exited_children.len() >= Maximum_exited_children {
+                    Parent_task.Exited_children.pop_front();
+                }
+
+                Parent_task
+                    .Exited_children
+                    .push_back((Task_identifier, Exit_status));
+
+                Parent_task.Raise_signal(Signal_type::Child_exited);
+            }
+        }
+
+        self.Notify_waiters()
+    }
+
+    /// Wake up the tasks waiting in `Wait_child`, so that they check the exited children and their signals again.
+    fn Notify_waiters(&self) -> Result_type<()> {
+        let _Guard = self.Exit_lock.lock()?;
+
+        self.Exit_condition.notify_all();
+
+        Ok(())
+    }
+
+    /// Wait for a child of a task to exit, returns its identifier and its exit status.
+    ///
+    /// If `Child` is `None`, any child is waited for. The exit status is only returned once.
+    ///
+    /// # Errors
+    /// Returns [Error_type::Interrupted] if a signal that terminates the task or that it handles becomes deliverable while waiting.
+    pub fn Wait_child(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Child: Option<Task_identifier_type>,
+    ) -> Result_type<(Task_identifier_type, Exit_status_type)> {
+        self.Set_state(Task_identifier, Task_state_type::Blocked)?;
+
+        let Result = self.Wait_exited_child(Task_identifier, Child);
+
+        self.Set_state(Task_identifier, Task_state_type::Running)?;
+
+        Result
+    }
+
+    fn Wait_exited_child(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Child: Option<Task_identifier_type>,
+    ) -> Result_type<(Task_identifier_type, Exit_status_type)> {
+        let mut Guard = self.Exit_lock.lock()?;
+
+        loop {
+            {
+                let mut Tasks = self.Tasks.write()?;
+
+                let Task = Tasks
+                    .get_mut(&Task_identifier)
+                    .ok_or(Error_type::Invalid_task_identifier)?;
+
+                let Position = Task
+                    .Exited_children
+                    .iter()
+                    .position(|(Identifier, _)| Child.is_none() || Child == Some(*Identifier));
+
+                if let Some(Position) = Position {
+                    return Task
+                        .Exited_children
+                        .remove(Position)
+                        .ok_or(Error_type::No_child_task);
+                }
+
+                match Child {
+                    Some(Child) if !Task.Children.contains(&Child) => {
+                        return Err(Error_type::Invalid_task_identifier)
+                    }
+                    None if Task.Children.is_empty() => return Err(Error_type::No_child_task),
+                    _ => {}
+                }
+
+                if Task.Is_interrupted() {
+                    return Err(Error_type::Interrupted);
+                }
+            }
+
+            Guard = self.Exit_condition.wait(Guard)?;
+        }
+    }
+
+    /// Set the exit code reported to the parent of a task when it returns.
+    pub fn Set_exit_code(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Code: i32,
+    ) -> Result_type<()> {
+        self.Tasks
+            .write()?
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Exit_code = Some(Code);
+
+        Ok(())
+    }
+
+    /// Ask a task to terminate, by sending it the [Signal_type::Terminate] signal.
+    ///
+    /// The termination is cooperative : the task should check `Is_termination_requested` at safe points and return.
+    pub fn Request_termination(&self, Task_identifier: Task_identifier_type) -> Result_type<()> {
+        self.Send_signal(Task_identifier, Signal_type::Terminate)
+    }
+
+    /// Check whether a task should terminate, i.e. whether a deliverable signal has no handler and terminates by default.
+    pub fn Is_termination_requested(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<bool> {
+        Ok(self
+            .Tasks
+            .read()?
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Is_terminating())
+    }
+
+    /// Send a signal to a task.
+    ///
+    /// Only the tasks of the same owner can be signaled, unless the current task is owned by the root user.
+    /// A signal which is already pending isn't queued twice.
+    pub fn Send_signal(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Signal: Signal_type,
+    ) -> Result_type<()> {
+        let Current_task_owner = self.Get_owner(self.Get_current_task_identifier()?)?;
+
+        let mut Tasks = self.Tasks.write()?;
+
+        let Task = Tasks
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?;
+
+        if !Users::Manager_type::Is_root(Current_task_owner) && Task.Owner != Current_task_owner {
+            return Err(Error_type::Permission_denied);
+        }
+
+        Task.Raise_signal(Signal);
+
+        drop(Tasks);
+
+        self.Notify_waiters()
+    }
+
+    /// Send a signal to a task on behalf of the system (e.g. a device driver), without permission check.
+    pub fn Raise_signal(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Signal: Signal_type,
+    ) -> Result_type<()> {
+        self.Tasks
+            .write()?
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Raise_signal(Signal);
+
+        self.Notify_waiters()
+    }
+
+    /// Take the next deliverable signal that the task handles, the lowest numbers first.
+    ///
+    /// The runtime of the task should call this at safe points (e.g. system calls) and invoke the corresponding handler.
+    pub fn Take_signal(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Option<Signal_type>> {
+        let mut Tasks = self.Tasks.write()?;
+
+        let Task = Tasks
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?;
+
+        let Signal = Task
+            .Get_deliverable_signals()
+            .Get_signals()
+            .find(|Signal| Task.Handled_signals.Contains(*Signal));
+
+        if let Some(Signal) = Signal {
+            Task.Pending_signals = Task.Pending_signals.Remove(Signal);
         }
 
+        Ok(Signal)
+    }
+
+    pub fn Get_pending_signals(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Signal_set_type> {
+        Ok(self
+            .Tasks
+            .read()?
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Pending_signals)
+    }
+
+    pub fn Get_signal_mask(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Signal_set_type> {
+        Ok(self
+            .Tasks
+            .read()?
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Signal_mask)
+    }
+
+    /// Set the signals blocked by a task, the blocked signals stay pending until they are unblocked.
+    pub fn Set_signal_mask(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Mask: Signal_set_type,
+    ) -> Result_type<()> {
         self.Tasks
             .write()?
-            .remove(&Task_identifier)
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Signal_mask = Mask;
+
+        Ok(())
+    }
+
+    /// Declare whether a task handles a signal itself (see `Take_signal`) or lets its default action apply.
+    pub fn Set_signal_handled(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Signal: Signal_type,
+        Handled: bool,
+    ) -> Result_type<()> {
+        let mut Tasks = self.Tasks.write()?;
+
+        let Task = Tasks
+            .get_mut(&Task_identifier)
             .ok_or(Error_type::Invalid_task_identifier)?;
 
+        Task.Handled_signals = Task.Handled_signals.Set(Signal, Handled);
+
+        Ok(())
+    }
+
+    fn Set_state(
+        &self,
+        Task_identifier: Task_identifier_type,
+        State: Task_state_type,
+    ) -> Result_type<()> {
+        self.Tasks
+            .write()?
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .State = State;
+
         Ok(())
     }
 
+    /// Put the thread of the current task to sleep, the task is reported as sleeping meanwhile.
+    pub(crate) fn Sleep(&self, Duration: Duration) {
+        let Task_identifier = self.Get_current_task_identifier().ok();
+
+        if let Some(Task_identifier) = Task_identifier {
+            let _ = self.Set_state(Task_identifier, Task_state_type::Sleeping);
+        }
+
+        Thread_wrapper_type::Sleep(Duration);
+
+        if let Some(Task_identifier) = Task_identifier {
+            let _ = self.Set_state(Task_identifier, Task_state_type::Running);
+        }
+    }
+
+    /// Get the identifiers of all the tasks, in ascending order.
+    pub fn Get_task_identifiers(&self) -> Result_type<Vec<Task_identifier_type>> {
+        Ok(self.Tasks.read()?.keys().copied().collect())
+    }
+
+    /// Get information about a task at the current time.
+    pub fn Get_task_snapshot(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Task_snapshot_type> {
+        let Tasks = self.Tasks.read()?;
+
+        let Task = Tasks
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?;
+
+        Ok(Self::New_snapshot(Task_identifier, Task))
+    }
+
+    /// Get information about all the tasks at the current time, in ascending order of identifier.
+    pub fn Get_task_snapshots(&self) -> Result_type<Vec<Task_snapshot_type>> {
+        Ok(self
+            .Tasks
+            .read()?
+            .iter()
+            .map(|(Identifier, Task)| Self::New_snapshot(*Identifier, Task))
+            .collect())
+    }
+
+    fn New_snapshot(
+        Task_identifier: Task_identifier_type,
+        Task: &Task_internal_type,
+    ) -> Task_snapshot_type {
+        Task_snapshot_type::New(
+            Task_identifier,
+            Task.Thread.Get_name().unwrap_or_default().to_string(),
+            Task.Parent,
+            Task.Children.clone(),
+            Task.Owner,
+            Task.State,
+            Task.Start_time,
+            Task.Thread.Get_CPU_time(),
+            Task.Stack_size,
+        )
+    }
+
     pub fn Get_current_task_identifier(&self) -> Result_type<Task_identifier_type> {
+        if let Some(Task_identifier) = Delegated_task.get() {
+            return Ok(Task_identifier);
+        }
+
         let Tasks = self.Tasks.read()?;
 
         for (Task_identifier, Task) in Tasks.iter() {
@@ -270,34 +811,317 @@ impl Manager_type {
 
         Ok(())
     }
-}
 
-#[cfg(test)]
-mod Tests {
-    use super::*;
+    /// Get the resource limits of a task.
+    pub fn Get_limits(&self, Task_identifier: Task_identifier_type) -> Result_type<Limits_type> {
+        Ok(self
+            .Tasks
+            .read()?
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Limits)
+    }
 
-    #[test]
-    fn Test_task_manager() {
-        let Manager = Initialize().expect("Failed to initialize task manager");
+    /// Set the resource limits of a task.
+    ///
+    /// Only tasks owned by the root user can raise limits, other tasks can only lower the limits
+    /// of the tasks of their owner.
+    /// The new limits are inherited by the children created afterwards.
+    pub fn Set_limits(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Limits: Limits_type,
+    ) -> Result_type<()> {
+        let Current_task_owner = self.Get_owner(self.Get_current_task_identifier()?)?;
 
-        Test_get_task_name(Manager);
-        Test_new_task(Manager);
-        Test_delete_task(Manager);
-        Test_get_owner(Manager);
-        Test_get_current_task_identifier(Manager);
-        Test_multiple_tasks_with_same_owner(Manager);
-        Test_environment_variables(Manager);
-        Test_environment_variable_inheritance(Manager);
-        Test_join_handle(Manager);
-    }
+        let mut Tasks = self.Tasks.write()?; // Acquire lock
 
-    fn Test_get_task_name(Manager: &Manager_type) {
-        let Task_name = "Test Task";
-        let (Task_identifier, _) = Manager
-            .New_task(None, None, Task_name, None, || {})
-            .unwrap();
-        assert_eq!(
-            Get_instance()
+        let Task = Tasks
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?;
+
+        if !Users::Manager_type::Is_root(Current_task_owner)
+            && (Task.Owner != Current_task_owner || !Limits.Is_within(&Task.Limits))
+        {
+            return Err(Error_type::Permission_denied);
+        }
+
+        Task.Limits = Limits;
+
+        Ok(())
+    }
+
+    pub fn Get_priority(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Priority_type> {
+        Ok(self
+            .Tasks
+            .read()?
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Priority)
+    }
+
+    /// Change the scheduling priority of a running task.
+    ///
+    /// Only tasks owned by the root user can raise priorities, other tasks can only lower the priorities of the tasks
+    /// of their user.
+    pub fn Set_priority(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Priority: Priority_type,
+    ) -> Result_type<()> {
+        let Current_task_owner = self.Get_owner(self.Get_current_task_identifier()?)?;
+
+        let mut Tasks = self.Tasks.write()?; // Acquire lock
+
+        let Task = Tasks
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?;
+
+        if !Users::Manager_type::Is_root(Current_task_owner)
+            && (Task.Owner != Current_task_owner || Priority > Task.Priority)
+        {
+            return Err(Error_type::Permission_denied);
+        }
+
+        Task.Thread.Set_priority(Priority)?;
+
+        Task.Priority = Priority;
+
+        Ok(())
+    }
+
+    /// Get the cores on which a task can run, they are chosen when the task is created.
+    pub fn Get_affinity(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Affinity_type> {
+        Ok(self
+            .Tasks
+            .read()?
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Affinity)
+    }
+
+    /// Get the CPU time consumed by a task.
+    ///
+    /// Returns `None` if the platform doesn't support CPU time measurement.
+    pub fn Get_CPU_time(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<Option<Duration>> {
+        Ok(self
+            .Tasks
+            .read()?
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Thread
+            .Get_CPU_time())
+    }
+
+    /// Check that a task didn't consume more CPU time than allowed by its limits.
+    ///
+    /// This check is cooperative : it should be called at safe points (e.g. system calls).
+    pub fn Check_CPU_time_limit(&self, Task_identifier: Task_identifier_type) -> Result_type<()> {
+        let Tasks = self.Tasks.read()?;
+
+        let Task = Tasks
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?;
+
+        if let (Some(Maximum), Some(CPU_time)) =
+            (Task.Limits.Get_CPU_time(), Task.Thread.Get_CPU_time())
+        {
+            if CPU_time > Maximum {
+                return Err(Error_type::CPU_time_limit_exceeded);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Notify a task that an interrupt occurred (e.g. `Ctrl-C` on its terminal), see `Raise_signal`.
+    ///
+    /// The interrupt stays pending until the task takes it with `Take_interrupt` or a handler.
+    pub fn Send_interrupt(&self, Task_identifier: Task_identifier_type) -> Result_type<()> {
+        self.Raise_signal(Task_identifier, Signal_type::Interrupt)
+    }
+
+    /// Take the pending interrupt of a task, returns `true` if an unblocked interrupt was pending.
+    ///
+    /// This check is cooperative : it should be called at safe points (e.g. system calls).
+    pub fn Take_interrupt(&self, Task_identifier: Task_identifier_type) -> Result_type<bool> {
+        let mut Tasks = self.Tasks.write()?; // Acquire lock
+
+        let Task = Tasks
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?;
+
+        if !Task
+            .Get_deliverable_signals()
+            .Contains(Signal_type::Interrupt)
+        {
+            return Ok(false);
+        }
+
+        Task.Pending_signals = Task.Pending_signals.Remove(Signal_type::Interrupt);
+
+        Ok(true)
+    }
+
+    /// Create a stopped software timer owned by a task, use `Start_timer` to arm it.
+    ///
+    /// The callback runs on the timer service thread, on behalf of the task (see `Get_current_task_identifier`).
+    /// Callbacks should return quickly since they delay the other timers. The timers of a task are deleted with it.
+    pub fn New_timer(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Callback: impl FnMut() + Send + 'static,
+    ) -> Result_type<Timer_identifier_type> {
+        if !self.Tasks.read()?.contains_key(&Task_identifier) {
+            return Err(Error_type::Invalid_task_identifier);
+        }
+
+        self.Timers.New_timer(Task_identifier, Box::new(Callback))
+    }
+
+    /// Start (or restart) a timer owned by a task.
+    ///
+    /// The timer expires after `Delay`, then every `Period` if any (a one-shot timer otherwise).
+    /// Starting a running timer reschedules it.
+    pub fn Start_timer(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Timer_identifier: Timer_identifier_type,
+        Delay: Duration,
+        Period: Option<Duration>,
+    ) -> Result_type<()> {
+        self.Timers
+            .Start_timer(Task_identifier, Timer_identifier, Delay, Period)
+    }
+
+    /// Stop a timer owned by a task, it can be started again later.
+    pub fn Stop_timer(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Timer_identifier: Timer_identifier_type,
+    ) -> Result_type<()> {
+        self.Timers.Stop_timer(Task_identifier, Timer_identifier)
+    }
+
+    /// Check whether a timer owned by a task will expire (one-shot timers stop once expired).
+    pub fn Is_timer_active(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Timer_identifier: Timer_identifier_type,
+    ) -> Result_type<bool> {
+        self.Timers
+            .Is_timer_active(Task_identifier, Timer_identifier)
+    }
+
+    pub fn Delete_timer(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Timer_identifier: Timer_identifier_type,
+    ) -> Result_type<()> {
+        self.Timers.Delete_timer(Task_identifier, Timer_identifier)
+    }
+
+    /// Get the working directory of a task.
+    pub fn Get_working_directory(
+        &self,
+        Task_identifier: Task_identifier_type,
+    ) -> Result_type<String> {
+        let Tasks = self.Tasks.read()?; // Acquire lock
+
+        Ok(Tasks
+            .get(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Working_directory
+            .clone())
+    }
+
+    /// Set the working directory of a task.
+    ///
+    /// The path must be absolute. The existence of the directory is not checked here, use
+    /// the virtual file system to change the working directory of a task safely.
+    pub fn Set_working_directory(
+        &self,
+        Task_identifier: Task_identifier_type,
+        Path: &str,
+    ) -> Result_type<()> {
+        if !Path.starts_with('/') {
+            return Err(Error_type::Invalid_working_directory);
+        }
+
+        let mut Tasks = self.Tasks.write()?; // Acquire lock
+
+        Tasks
+            .get_mut(&Task_identifier)
+            .ok_or(Error_type::Invalid_task_identifier)?
+            .Working_directory = Path.to_string();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_task_manager() {
+        let Manager = Initialize().expect("Failed to initialize task manager");
+
+        Test_get_task_name(Manager);
+        Test_new_task(Manager);
+        Test_delete_task(Manager);
+        Test_get_owner(Manager);
+        Test_get_current_task_identifier(Manager);
+        Test_multiple_tasks_with_same_owner(Manager);
+        Test_environment_variables(Manager);
+        Test_environment_variable_inheritance(Manager);
+        Test_join_handle(Manager);
+        Test_working_directory(Manager);
+        Test_working_directory_inheritance(Manager);
+        Test_limits(Manager);
+        Test_CPU_time_limit(Manager);
+        Test_interrupt(Manager);
+        Test_timers(Manager);
+        Test_exit_status(Manager);
+        Test_termination(Manager);
+        Test_reparenting(Manager);
+        Test_signals(Manager);
+        Test_snapshots(Manager);
+        Test_scheduling(Manager);
+    }
+
+    #[test]
+    fn Test_run_on_behalf_of() {
+        let Task = Task_identifier_type::New(0xFFFF);
+
+        assert_eq!(Run_on_behalf_of(Task, || Delegated_task.get()), Some(Task));
+        assert_eq!(Delegated_task.get(), None);
+
+        // - The delegation ends even if the function panics
+        let Result = panic::catch_unwind(|| Run_on_behalf_of(Task, || panic!("Test panic")));
+
+        assert!(Result.is_err());
+        assert_eq!(Delegated_task.get(), None);
+    }
+
+    fn Test_get_task_name(Manager: &Manager_type) {
+        let Task_name = "Test Task";
+        let (Task_identifier, _) = Manager
+            .New_task(None, None, Task_name, None, None, None, || {
+                Task_type::Sleep(Duration::from_millis(100));
+            })
+            .unwrap();
+        assert_eq!(
+            Get_instance()
                 .unwrap()
                 .Get_task_name(Task_identifier)
                 .unwrap(),
@@ -308,14 +1132,18 @@ mod Tests {
     fn Test_new_task(Manager: &Manager_type) {
         let Task_name = "Child Task";
         let (Task_identifier, _) = Manager
-            .New_task(None, None, Task_name, None, || {})
+            .New_task(None, None, Task_name, None, None, None, || {
+                Task_type::Sleep(Duration::from_millis(100));
+            })
             .unwrap();
         assert!(Manager.Get_task_name(Task_identifier).is_ok());
     }
 
     fn Test_delete_task(Manager: &Manager_type) {
         let (Task_identifier, _) = Manager
-            .New_task(None, None, "Task to delete", None, || {})
+            .New_task(None, None, "Task to delete", None, None, None, || {
+                Task_type::Sleep(Duration::from_millis(100));
+            })
             .unwrap();
         assert!(Manager.Delete_task(Task_identifier).is_ok());
         assert!(Manager.Get_task_name(Task_identifier).is_err());
@@ -324,14 +1152,24 @@ mod Tests {
     fn Test_get_owner(Manager: &Manager_type) {
         let User_identifier = 123; // Assuming User_identifier_type is i32 for example
         let (Task_identifier, _) = Manager
-            .New_task(None, Some(User_identifier), "Task with owner", None, || {})
+            .New_task(
+                None,
+                Some(User_identifier),
+                "Task with owner",
+                None,
+                None,
+                None,
+                || {
+                    Task_type::Sleep(Duration::from_millis(100));
+                },
+            )
             .unwrap();
         assert_eq!(Manager.Get_owner(Task_identifier).unwrap(), User_identifier);
     }
 
     fn Test_get_current_task_identifier(Manager: &Manager_type) {
         let (Task_identifier, Join_handle) = Manager
-            .New_task(None, None, "Current Task", None, move || {
+            .New_task(None, None, "Current Task", None, None, None, move || {
                 let _ = Get_instance()
                     .unwrap()
                     .Get_current_task_identifier()
@@ -345,65 +1183,73 @@ mod Tests {
     fn Test_multiple_tasks_with_same_owner(Manager: &Manager_type) {
         let User_identifier = 123; // Assuming User_identifier_type is i32 for example
         let (Task_identifier_1, _) = Manager
-            .New_task(None, Some(User_identifier), "Task 1", None, move || {
-                let (Task_identifier_2, _) = Get_instance()
-                    .unwrap()
-                    .New_task(None, None, "Task 2", None, move || {
-                        assert_eq!(
-                            Get_instance()
-                                .unwrap()
-                                .Get_current_task()
-                                .unwrap()
-                                .Get_owner()
-                                .unwrap(),
-                            User_identifier
-                        );
-                        assert_eq!(
-                            Get_instance()
-                                .unwrap()
-                                .Get_current_task()
-                                .unwrap()
-                                .Get_name()
-                                .unwrap(),
-                            "Task 2"
-                        );
+            .New_task(
+                None,
+                Some(User_identifier),
+                "Task 1",
+                None,
+                None,
+                None,
+                move || {
+                    let (Task_identifier_2, _) = Get_instance()
+                        .unwrap()
+                        .New_task(None, None, "Task 2", None, None, None, move || {
+                            assert_eq!(
+                                Get_instance()
+                                    .unwrap()
+                                    .Get_current_task()
+                                    .unwrap()
+                                    .Get_owner()
+                                    .unwrap(),
+                                User_identifier
+                            );
+                            assert_eq!(
+                                Get_instance()
+                                    .unwrap()
+                                    .Get_current_task()
+                                    .unwrap()
+                                    .Get_name()
+                                    .unwrap(),
+                                "Task 2"
+                            );
 
-                        Task_type::Sleep(std::time::Duration::from_secs(1));
-                    })
-                    .unwrap();
+                            Task_type::Sleep(std::time::Duration::from_secs(1));
+                        })
+                        .unwrap();
 
-                assert_eq!(
-                    Get_instance()
-                        .unwrap()
-                        .Get_owner(Task_identifier_2)
-                        .unwrap(),
-                    User_identifier
-                );
+                    assert_eq!(
+                        Get_instance()
+                            .unwrap()
+                            .Get_owner(Task_identifier_2)
+                            .unwrap(),
+                        User_identifier
+                    );
 
-                let _ = Get_instance()
-                    .unwrap()
-                    .New_task(None, Some(6969), "Task 3", None, move || {
-                        assert_eq!(
-                            Get_instance()
-                                .unwrap()
-                                .Get_current_task()
-                                .unwrap()
-                                .Get_owner()
-                                .unwrap(),
-                            6969
-                        );
-                        assert_eq!(
-                            Get_instance()
-                                .unwrap()
-                                .Get_current_task()
-                                .unwrap()
-                                .Get_name()
-                                .unwrap(),
-                            "Task 3"
-                        );
-                    })
-                    .unwrap();
-            })
+                    let _ = Get_instance()
+                        .unwrap()
+                        .New_task(None, Some(6969), "Task 3", None, None, None, move || {
+                            assert_eq!(
+                                Get_instance()
+                                    .unwrap()
+                                    .Get_current_task()
+                                    .unwrap()
+                                    .Get_owner()
+                                    .unwrap(),
+                                6969
+                            );
+                            assert_eq!(
+                                Get_instance()
+                                    .unwrap()
+                                    .Get_current_task()
+                                    .unwrap()
+                                    .Get_name()
+                                    .unwrap(),
+                                "Task 3"
+                            );
+                        })
+                        .unwrap();
+                },
+            )
             .unwrap();
 
         assert_eq!(
@@ -449,7 +1295,7 @@ mod Tests {
             )
             .unwrap();
         let (_, _) = Manager
-            .New_task(None, None, "Child Task", None, move || {
+            .New_task(None, None, "Child Task", None, None, None, move || {
                 let Current_task = Get_instance().unwrap().Get_current_task().unwrap();
 
                 assert_eq!(
@@ -462,9 +1308,824 @@ mod Tests {
 
     fn Test_join_handle(Manager: &Manager_type) {
         let (_, Join_handle) = Manager
-            .New_task(None, None, "Task with join handle", None, || 42)
+            .New_task(None, None, "Task with join handle", None, None, None, || 42)
             .unwrap();
         let Result = Join_handle.Join();
         assert_eq!(Result.unwrap(), 42);
     }
+
+    fn Test_working_directory(Manager: &Manager_type) {
+        let (Task_identifier, _) = Manager
+            .New_task(
+                None,
+                None,
+                "Task with working directory",
+                None,
+                None,
+                None,
+                || {
+                    Task_type::Sleep(std::time::Duration::from_millis(100));
+                },
+            )
+            .unwrap();
+
+        Manager
+            .Set_working_directory(Task_identifier, "/Xila/Users")
+            .unwrap();
+        assert_eq!(
+            Manager.Get_working_directory(Task_identifier).unwrap(),
+            "/Xila/Users"
+        );
+
+        assert!(Manager
+            .Set_working_directory(Task_identifier, "Relative")
+            .is_err());
+        assert_eq!(
+            Manager.Get_working_directory(Task_identifier).unwrap(),
+            "/Xila/Users"
+        );
+    }
+
+    fn Test_working_directory_inheritance(Manager: &Manager_type) {
+        let Task_identifier = Manager
+            .Get_current_task_identifier()
+            .expect("Failed to get current task identifier");
+
+        Manager
+            .Set_working_directory(Task_identifier, "/Home")
+            .unwrap();
+
+        let (_, Join_handle) = Manager
+            .New_task(None, None, "Child Task", None, None, None, move || {
+                Get_instance()
+                    .unwrap()
+                    .Get_current_task()
+                    .unwrap()
+                    .Get_working_directory()
+                    .unwrap()
+            })
+            .unwrap();
+
+        assert_eq!(Join_handle.Join().unwrap(), "/Home");
+
+        Manager.Set_working_directory(Task_identifier, "/").unwrap();
+    }
+
+    fn Test_limits(Manager: &Manager_type) {
+        let Task_identifier = Manager
+            .Get_current_task_identifier()
+            .expect("Failed to get current task identifier");
+
+        assert_eq!(
+            Manager.Get_limits(Task_identifier).unwrap(),
+            Limits_type::New_unlimited()
+        );
+
+        let Limits = Limits_type::New_unlimited()
+            .Set_child_tasks(Some(1))
+            .Set_stack_size(Some(64 * 1024));
+
+        let (Child_task_identifier, Join_handle) = Manager
+            .New_task(None, None, "Limited task", None, None, None, move || {
+                let Manager = Get_instance().unwrap();
+
+                let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                Manager.Set_limits(Task_identifier, Limits).unwrap();
+
+                // - Stack size limit
+                assert!(matches!(
+                    Manager.New_task(None, None, "Too big", Some(128 * 1024), None, None, || {}),
+                    Err(Error_type::Stack_size_limit_exceeded)
+                ));
+
+                // - Child tasks limit
+                let (_, Join_handle) = Manager
+                    .New_task(None, None, "Child", None, None, None, || {
+                        Task_type::Sleep(std::time::Duration::from_millis(100));
+                    })
+                    .unwrap();
+
+                assert!(matches!(
+                    Manager.New_task(None, None, "Second child", None, None, None, || {}),
+                    Err(Error_type::Child_tasks_limit_exceeded)
+                ));
+
+                Join_handle.Join().unwrap();
+            })
+            .unwrap();
+
+        Join_handle.Join().unwrap();
+
+        // - A task of another user can't change the limits, even to lower them
+        let (_, Join_handle) = Manager
+            .New_task(
+                None,
+                Some(123),
+                "Other user task",
+                None,
+                None,
+                None,
+                move || {
+                    assert!(matches!(
+                        Get_instance().unwrap().Set_limits(Task_identifier, Limits),
+                        Err(Error_type::Permission_denied)
+                    ));
+                },
+            )
+            .unwrap();
+
+        Join_handle.Join().unwrap();
+
+        assert!(Manager.Get_limits(Child_task_identifier).is_err());
+        assert_eq!(
+            Manager.Get_limits(Task_identifier).unwrap(),
+            Limits_type::New_unlimited()
+        );
+    }
+
+    fn Test_CPU_time_limit(Manager: &Manager_type) {
+        let (_, Join_handle) = Manager
+            .New_task(
+                None,
+                None,
+                "CPU time limited task",
+                None,
+                None,
+                None,
+                || {
+                    let Manager = Get_instance().unwrap();
+
+                    let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                    assert!(Manager.Check_CPU_time_limit(Task_identifier).is_ok());
+
+                    if Manager.Get_CPU_time(Task_identifier).unwrap().is_none() {
+                        return;
+                    }
+
+                    Manager
+                        .Set_limits(
+                            Task_identifier,
+                            Limits_type::New_unlimited()
+                                .Set_CPU_time(Some(Duration::from_millis(1))),
+                        )
+                        .unwrap();
+
+                    let Start = std::time::Instant::now();
+
+                    while Start.elapsed() < Duration::from_millis(20) {
+                        std::hint::spin_loop();
+                    }
+
+                    assert!(matches!(
+                        Manager.Check_CPU_time_limit(Task_identifier),
+                        Err(Error_type::CPU_time_limit_exceeded)
+                    ));
+                },
+            )
+            .unwrap();
+
+        Join_handle.Join().unwrap();
+    }
+
+    fn Test_interrupt(Manager: &Manager_type) {
+        let (Task_identifier, Join_handle) = Manager
+            .New_task(None, None, "Interrupted task", None, None, None, || {
+                let Manager = Get_instance().unwrap();
+
+                let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                while !Manager.Take_interrupt(Task_identifier).unwrap() {
+                    Task_type::Sleep(Duration::from_millis(1));
+                }
+
+                Manager.Take_interrupt(Task_identifier).unwrap()
+            })
+            .unwrap();
+
+        Manager.Send_interrupt(Task_identifier).unwrap();
+
+        // - The interrupt is only delivered once
+        assert!(!Join_handle.Join().unwrap());
+
+        assert!(Manager
+            .Send_interrupt(Task_identifier_type::New(0xFFFF))
+            .is_err());
+    }
+
+    fn Test_timers(Manager: &Manager_type) {
+        use std::sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        };
+
+        let One_shot_count = Arc::new(AtomicU32::new(0));
+        let Periodic_count = Arc::new(AtomicU32::new(0));
+
+        let (Task_identifier, Join_handle) = Manager
+            .New_task(None, None, "Task with timers", None, None, None, {
+                let One_shot_count = One_shot_count.clone();
+                let Periodic_count = Periodic_count.clone();
+
+                move || {
+                    let Manager = Get_instance().unwrap();
+
+                    let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                    // - One-shot timer, the callback runs on behalf of the task
+                    let One_shot = Manager
+                        .New_timer(Task_identifier, move || {
+                            assert_eq!(
+                                Get_instance()
+                                    .unwrap()
+                                    .Get_current_task_identifier()
+                                    .unwrap(),
+                                Task_identifier
+                            );
+                            One_shot_count.fetch_add(1, Ordering::SeqCst);
+                        })
+                        .unwrap();
+
+                    Manager
+                        .Start_timer(Task_identifier, One_shot, Duration::from_millis(10), None)
+                        .unwrap();
+
+                    // - Periodic timer, rescheduled then stopped
+                    let Periodic = Manager
+                        .New_timer(Task_identifier, move || {
+                            Periodic_count.fetch_add(1, Ordering::SeqCst);
+                        })
+                        .unwrap();
+
+                    assert!(matches!(
+                        Manager.Start_timer(
+                            Task_identifier,
+                            Periodic,
+                            Duration::ZERO,
+                            Some(Duration::ZERO)
+                        ),
+                        Err(Error_type::Invalid_timer_period)
+                    ));
+
+                    Manager
+                        .Start_timer(Task_identifier, Periodic, Duration::from_secs(60), None)
+                        .unwrap();
+                    Manager
+                        .Start_timer(
+                            Task_identifier,
+                            Periodic,
+                            Duration::from_millis(5),
+                            Some(Duration::from_millis(5)),
+                        )
+                        .unwrap();
+
+                    Task_type::Sleep(Duration::from_millis(100));
+
+                    assert!(!Manager.Is_timer_active(Task_identifier, One_shot).unwrap());
+                    assert!(Manager.Is_timer_active(Task_identifier, Periodic).unwrap());
+
+                    Manager.Stop_timer(Task_identifier, One_shot).unwrap();
+                    Manager.Delete_timer(Task_identifier, One_shot).unwrap();
+                    assert!(matches!(
+                        Manager.Stop_timer(Task_identifier, One_shot),
+                        Err(Error_type::Invalid_timer_identifier)
+                    ));
+
+                    // - The periodic timer is left running, it is deleted with the task
+                    Periodic
+                }
+            })
+            .unwrap();
+
+        let Periodic = Join_handle.Join().unwrap();
+
+        assert_eq!(One_shot_count.load(Ordering::SeqCst), 1);
+        assert!(Periodic_count.load(Ordering::SeqCst) >= 2);
+
+        // - The timers of a task are deleted with it
+        let Count = Periodic_count.load(Ordering::SeqCst);
+
+        Task_type::Sleep(Duration::from_millis(50));
+
+        assert_eq!(Periodic_count.load(Ordering::SeqCst), Count);
+        assert!(matches!(
+            Manager.Is_timer_active(Task_identifier, Periodic),
+            Err(Error_type::Invalid_timer_identifier)
+        ));
+
+        // - Only the owner can manipulate a timer
+        let Current_task = Manager.Get_current_task_identifier().unwrap();
+
+        let Timer = Manager.New_timer(Current_task, || {}).unwrap();
+
+        assert!(matches!(
+            Manager.Delete_timer(Task_identifier_type::New(0xFFFF), Timer),
+            Err(Error_type::Invalid_timer_identifier)
+        ));
+
+        Manager.Delete_timer(Current_task, Timer).unwrap();
+    }
+
+    fn Test_exit_status(Manager: &Manager_type) {
+        let Current_task = Manager.Get_current_task_identifier().unwrap();
+
+        let (Returning, _) = Manager
+            .New_task(None, None, "Returning task", None, None, None, || {})
+            .unwrap();
+
+        let (Exiting, _) = Manager
+            .New_task(None, None, "Exiting task", None, None, None, || {
+                let Manager = Get_instance().unwrap();
+
+                Manager
+                    .Set_exit_code(Manager.Get_current_task_identifier().unwrap(), 3)
+                    .unwrap();
+            })
+            .unwrap();
+
+        let (Panicking, Join_handle) = Manager
+            .New_task(None, None, "Panicking task", None, None, None, || {
+                panic!("Expected panic")
+            })
+            .unwrap();
+
+        // - The join handle still reports the panic
+        assert!(Join_handle.Join().is_err());
+
+        assert_eq!(
+            Manager.Wait_child(Current_task, Some(Exiting)).unwrap(),
+            (Exiting, Exit_status_type::Exited(3))
+        );
+        assert_eq!(
+            Manager.Wait_child(Current_task, Some(Panicking)).unwrap(),
+            (Panicking, Exit_status_type::Panicked)
+        );
+        assert_eq!(
+            Manager.Wait_child(Current_task, Some(Returning)).unwrap(),
+            (Returning, Exit_status_type::Success)
+        );
+
+        // - The exit status is only returned once
+        assert!(matches!(
+            Manager.Wait_child(Current_task, Some(Returning)),
+            Err(Error_type::Invalid_task_identifier)
+        ));
+
+        // - The children of a task without children
+        let (_, Join_handle) = Manager
+            .New_task(None, None, "Childless task", None, None, None, || {
+                let Manager = Get_instance().unwrap();
+
+                Manager.Wait_child(Manager.Get_current_task_identifier().unwrap(), None)
+            })
+            .unwrap();
+
+        assert!(matches!(
+            Join_handle.Join().unwrap(),
+            Err(Error_type::No_child_task)
+        ));
+    }
+
+    fn Test_termination(Manager: &Manager_type) {
+        let Current_task = Manager.Get_current_task_identifier().unwrap();
+
+        let (Task_identifier, _) = Manager
+            .New_task(None, None, "Task to terminate", None, None, None, || {
+                let Manager = Get_instance().unwrap();
+
+                let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                while !Manager.Is_termination_requested(Task_identifier).unwrap() {
+                    Task_type::Sleep(Duration::from_millis(1));
+                }
+            })
+            .unwrap();
+
+        assert!(!Manager.Is_termination_requested(Task_identifier).unwrap());
+
+        Manager.Request_termination(Task_identifier).unwrap();
+
+        assert_eq!(
+            Manager
+                .Wait_child(Current_task, Some(Task_identifier))
+                .unwrap(),
+            (Task_identifier, Exit_status_type::Terminated)
+        );
+
+        // - Only the tasks of the same owner can be terminated by a regular user
+        let (_, Join_handle) = Manager
+            .New_task(
+                None,
+                Some(123),
+                "Unprivileged task",
+                None,
+                None,
+                None,
+                move || Get_instance().unwrap().Request_termination(Current_task),
+            )
+            .unwrap();
+
+        assert!(matches!(
+            Join_handle.Join().unwrap(),
+            Err(Error_type::Permission_denied)
+        ));
+        assert!(!Manager.Is_termination_requested(Current_task).unwrap());
+    }
+
+    fn Test_reparenting(Manager: &Manager_type) {
+        let Current_task = Manager.Get_current_task_identifier().unwrap();
+
+        let (Parent, Join_handle) = Manager
+            .New_task(None, None, "Parent task", None, None, None, || {
+                let Manager = Get_instance().unwrap();
+
+                // - The child outlives its parent
+                let (Child, _) = Manager
+                    .New_task(None, None, "Orphan task", None, None, None, || {
+                        let Manager = Get_instance().unwrap();
+
+                        let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                        while !Manager.Is_termination_requested(Task_identifier).unwrap() {
+                            Task_type::Sleep(Duration::from_millis(1));
+                        }
+
+                        Manager.Set_exit_code(Task_identifier, 7).unwrap();
+                    })
+                    .unwrap();
+
+                Child
+            })
+            .unwrap();
+
+        let Child = Join_handle.Join().unwrap();
+
+        assert_eq!(
+            Manager.Wait_child(Current_task, Some(Parent)).unwrap(),
+            (Parent, Exit_status_type::Success)
+        );
+
+        // - The root task adopted the orphan
+        assert_eq!(
+            Manager.Get_parent(Child).unwrap(),
+            Manager_type::Root_task_identifier
+        );
+
+        Manager.Request_termination(Child).unwrap();
+
+        assert_eq!(
+            Manager
+                .Wait_child(Manager_type::Root_task_identifier, Some(Child))
+                .unwrap(),
+            (Child, Exit_status_type::Exited(7))
+        );
+    }
+
+    fn Test_signals(Manager: &Manager_type) {
+        let Current_task = Manager.Get_current_task_identifier().unwrap();
+
+        let (Task_identifier, _) = Manager
+            .New_task(None, None, "Signaled task", None, None, None, || {
+                let Manager = Get_instance().unwrap();
+
+                let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                while !Manager.Is_termination_requested(Task_identifier).unwrap() {
+                    Task_type::Sleep(Duration::from_millis(1));
+                }
+            })
+            .unwrap();
+
+        Manager
+            .Set_signal_handled(Current_task, Signal_type::Child_exited, true)
+            .unwrap();
+
+        // - A blocked signal stays pending
+        Manager
+            .Set_signal_handled(Task_identifier, Signal_type::User_1, true)
+            .unwrap();
+        Manager
+            .Set_signal_mask(
+                Task_identifier,
+                Signal_set_type::Empty.Add(Signal_type::User_1),
+            )
+            .unwrap();
+
+        Manager
+            .Send_signal(Task_identifier, Signal_type::User_1)
+            .unwrap();
+        Manager
+            .Send_signal(Task_identifier, Signal_type::User_1)
+            .unwrap();
+
+        assert_eq!(Manager.Take_signal(Task_identifier).unwrap(), None);
+        assert!(Manager
+            .Get_pending_signals(Task_identifier)
+            .unwrap()
+            .Contains(Signal_type::User_1));
+
+        // - Once unblocked, a handled signal is delivered once and doesn't terminate the task
+        Manager
+            .Set_signal_mask(Task_identifier, Signal_set_type::Empty)
+            .unwrap();
+
+        assert!(!Manager.Is_termination_requested(Task_identifier).unwrap());
+        assert_eq!(
+            Manager.Take_signal(Task_identifier).unwrap(),
+            Some(Signal_type::User_1)
+        );
+        assert_eq!(Manager.Take_signal(Task_identifier).unwrap(), None);
+
+        // - The signals ignored by default are discarded
+        Manager
+            .Send_signal(Task_identifier, Signal_type::Child_exited)
+            .unwrap();
+
+        assert!(Manager
+            .Get_pending_signals(Task_identifier)
+            .unwrap()
+            .Is_empty());
+
+        // - The other signals terminate the task by default
+        Manager
+            .Send_signal(Task_identifier, Signal_type::Alarm)
+            .unwrap();
+
+        assert_eq!(
+            Manager
+                .Wait_child(Current_task, Some(Task_identifier))
+                .unwrap(),
+            (Task_identifier, Exit_status_type::Terminated)
+        );
+
+        // - The parent is notified of the exit of its child
+        assert_eq!(
+            Manager.Take_signal(Current_task).unwrap(),
+            Some(Signal_type::Child_exited)
+        );
+
+        Manager
+            .Set_signal_handled(Current_task, Signal_type::Child_exited, false)
+            .unwrap();
+
+        // - A handled signal interrupts the wait for a child
+        let (Waiting_task, Join_handle) = Manager
+            .New_task(None, None, "Waiting task", None, None, None, || {
+                let Manager = Get_instance().unwrap();
+
+                let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                Manager
+                    .Set_signal_handled(Task_identifier, Signal_type::User_1, true)
+                    .unwrap();
+
+                let (Child, _) = Manager
+                    .New_task(None, None, "Sleeping task", None, None, None, || {
+                        let Manager = Get_instance().unwrap();
+
+                        let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                        while !Manager.Is_termination_requested(Task_identifier).unwrap() {
+                            Task_type::Sleep(Duration::from_millis(1));
+                        }
+                    })
+                    .unwrap();
+
+                let Result = Manager.Wait_child(Task_identifier, Some(Child));
+
+                // - The signal stays pending until it is delivered
+                assert_eq!(
+                    Manager.Take_signal(Task_identifier).unwrap(),
+                    Some(Signal_type::User_1)
+                );
+
+                Manager.Request_termination(Child).unwrap();
+                Manager.Wait_child(Task_identifier, Some(Child)).unwrap();
+
+                Result
+            })
+            .unwrap();
+
+        while Manager.Get_task_snapshot(Waiting_task).unwrap().Get_state()
+            != Task_state_type::Blocked
+        {
+            Task_type::Sleep(Duration::from_millis(1));
+        }
+
+        Manager
+            .Send_signal(Waiting_task, Signal_type::User_1)
+            .unwrap();
+
+        assert!(matches!(
+            Join_handle.Join().unwrap(),
+            Err(Error_type::Interrupted)
+        ));
+
+        assert!(matches!(
+            Manager.Send_signal(Task_identifier_type::New(0xFFFF), Signal_type::User_2),
+            Err(Error_type::Invalid_task_identifier)
+        ));
+    }
+
+    fn Test_snapshots(Manager: &Manager_type) {
+        let Current_task = Manager.Get_current_task_identifier().unwrap();
+
+        let (Parent, Join_handle) = Manager
+            .New_task(
+                None,
+                Some(123),
+                "Listed task",
+                Some(64 * 1024),
+                None,
+                None,
+                || {
+                    let Manager = Get_instance().unwrap();
+
+                    let (Child, _) = Manager
+                        .New_task(None, None, "Sleeping task", None, None, None, || {
+                            let Manager = Get_instance().unwrap();
+
+                            let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                            while !Manager.Is_termination_requested(Task_identifier).unwrap() {
+                                Task_type::Sleep(Duration::from_millis(1));
+                            }
+                        })
+                        .unwrap();
+
+                    // - Blocked until the child is terminated
+                    Manager
+                        .Wait_child(Manager.Get_current_task_identifier().unwrap(), Some(Child))
+                        .unwrap();
+                },
+            )
+            .unwrap();
+
+        let Child = loop {
+            let Snapshot = Manager.Get_task_snapshot(Parent).unwrap();
+
+            match Snapshot.Get_children() {
+                [Child] if Snapshot.Get_state() == Task_state_type::Blocked => break *Child,
+                _ => Task_type::Sleep(Duration::from_millis(1)),
+            }
+        };
+
+        let Snapshot = Manager.Get_task_snapshot(Parent).unwrap();
+
+        assert_eq!(Snapshot.Get_identifier(), Parent);
+        assert_eq!(Snapshot.Get_name(), "Listed task");
+        assert_eq!(Snapshot.Get_parent(), Current_task);
+        assert_eq!(Snapshot.Get_owner(), 123);
+        assert_eq!(Snapshot.Get_stack_size(), Some(64 * 1024));
+        assert!(Snapshot.Get_start_time() <= Manager.Start.elapsed());
+
+        // - The child spends most of its time sleeping
+        while Manager.Get_task_snapshot(Child).unwrap().Get_state() != Task_state_type::Sleeping {
+            Task_type::Sleep(Duration::from_millis(1));
+        }
+
+        let Snapshot = Manager.Get_task_snapshot(Child).unwrap();
+
+        assert_eq!(Snapshot.Get_parent(), Parent);
+        assert_eq!(Snapshot.Get_stack_size(), None);
+        assert!(
+            Snapshot.Get_start_time()
+                >= Manager.Get_task_snapshot(Parent).unwrap().Get_start_time()
+        );
+
+        // - The listing contains all the tasks
+        let Identifiers = Manager.Get_task_identifiers().unwrap();
+
+        assert!(Identifiers.contains(&Current_task));
+        assert!(Identifiers.contains(&Parent));
+        assert!(Identifiers.contains(&Child));
+        assert_eq!(
+            Manager
+                .Get_task_snapshots()
+                .unwrap()
+                .iter()
+                .map(Task_snapshot_type::Get_identifier)
+                .collect::<Vec<_>>(),
+            Identifiers
+        );
+
+        Manager.Request_termination(Child).unwrap();
+
+        Join_handle.Join().unwrap();
+
+        Manager.Wait_child(Current_task, Some(Parent)).unwrap();
+
+        assert!(matches!(
+            Manager.Get_task_snapshot(Parent),
+            Err(Error_type::Invalid_task_identifier)
+        ));
+    }
+
+    fn Test_scheduling(Manager: &Manager_type) {
+        let Current_task = Manager.Get_current_task_identifier().unwrap();
+
+        assert_eq!(
+            Manager.Get_priority(Current_task).unwrap(),
+            Priority_type::Normal
+        );
+
+        let (Task_identifier, Join_handle) = Manager
+            .New_task(
+                None,
+                Some(123),
+                "Low priority task",
+                None,
+                Some(Priority_type::Low),
+                Some(Affinity_type::New_core(0).unwrap()),
+                move || {
+                    let Manager = Get_instance().unwrap();
+
+                    let Task_identifier = Manager.Get_current_task_identifier().unwrap();
+
+                    #[cfg(target_os = "linux")]
+                    unsafe {
+                        assert_eq!(
+                            libc::getpriority(libc::PRIO_PROCESS, libc::gettid() as libc::id_t),
+                            Priority_type::Low.Get_nice_value()
+                        );
+
+                        let mut Set: libc::cpu_set_t = std::mem::zeroed();
+
+                        assert_eq!(
+                            libc::sched_getaffinity(
+                                0,
+                                std::mem::size_of::<libc::cpu_set_t>(),
+                                &mut Set
+                            ),
+                            0
+                        );
+                        assert_eq!(libc::CPU_COUNT(&Set), 1);
+                        assert!(libc::CPU_ISSET(0, &Set));
+                    }
+
+                    // - The scheduling parameters are inherited
+                    let (Child, Join_handle) = Manager
+                        .New_task(None, None, "Child", None, None, None, || {})
+                        .unwrap();
+
+                    assert_eq!(Manager.Get_priority(Child).unwrap(), Priority_type::Low);
+                    assert_eq!(
+                        Manager.Get_affinity(Child).unwrap(),
+                        Affinity_type::New_core(0).unwrap()
+                    );
+
+                    Join_handle.Join().unwrap();
+
+                    // - A regular user can't raise priorities
+                    assert!(matches!(
+                        Manager.New_task(
+                            None,
+                            None,
+                            "High priority task",
+                            None,
+                            Some(Priority_type::High),
+                            None,
+                            || {}
+                        ),
+                        Err(Error_type::Permission_denied)
+                    ));
+                    assert!(matches!(
+                        Manager.Set_priority(Task_identifier, Priority_type::Normal),
+                        Err(Error_type::Permission_denied)
+                    ));
+
+                    // - Nor change the priority of the tasks of another user, even to lower it
+                    assert!(matches!(
+                        Manager.Set_priority(Current_task, Priority_type::Lowest),
+                        Err(Error_type::Permission_denied)
+                    ));
+
+                    Manager
+                        .Set_priority(Task_identifier, Priority_type::Lowest)
+                        .unwrap();
+
+                    #[cfg(target_os = "linux")]
+                    assert_eq!(
+                        unsafe {
+                            libc::getpriority(libc::PRIO_PROCESS, libc::gettid() as libc::id_t)
+                        },
+                        Priority_type::Lowest.Get_nice_value()
+                    );
+
+                    Manager.Get_priority(Task_identifier).unwrap()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            Manager.Get_affinity(Task_identifier).unwrap(),
+            Affinity_type::New_core(0).unwrap()
+        );
+        assert_eq!(Join_handle.Join().unwrap(), Priority_type::Lowest);
+
+        Manager
+            .Wait_child(Current_task, Some(Task_identifier))
+            .unwrap();
+    }
 }
@@ -0,0 +1,127 @@
+/// Scheduling priority of a task, the higher the more CPU time it gets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(u8)]
+pub enum Priority_type {
+    Lowest,
+    Low,
+    #[default]
+    Normal,
+    High,
+    Highest,
+}
+
+impl Priority_type {
+    /// Get the corresponding nice value on Linux (lower values are higher priorities).
+    pub const fn Get_nice_value(&self) -> i32 {
+        match self {
+            Self::Lowest => 19,
+            Self::Low => 10,
+            Self::Normal => 0,
+            Self::High => -5,
+            Self::Highest => -10,
+        }
+    }
+
+    /// Get the corresponding FreeRTOS priority on ESP-IDF (the default priority of the threads is `5`).
+    pub const fn Get_FreeRTOS_priority(&self) -> u32 {
+        match self {
+            Self::Lowest => 1,
+            Self::Low => 3,
+            Self::Normal => 5,
+            Self::High => 10,
+            Self::Highest => 20,
+        }
+    }
+}
+
+impl TryFrom<u8> for Priority_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        match Value {
+            0 => Ok(Self::Lowest),
+            1 => Ok(Self::Low),
+            2 => Ok(Self::Normal),
+            3 => Ok(Self::High),
+            4 => Ok(Self::Highest),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Set of the CPU cores on which a task can run.
+///
+/// On ESP-IDF, a task can only be pinned to a single core : the other sets let it run on any core.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Affinity_type(u32);
+
+impl Default for Affinity_type {
+    fn default() -> Self {
+        Self::Any
+    }
+}
+
+impl Affinity_type {
+    pub const Maximum_cores: usize = 32;
+
+    /// The task can run on any core allowed to the process.
+    pub const Any: Self = Self(u32::MAX);
+
+    /// Create an affinity from a bit mask indexed by the core numbers, returns `None` if the mask is empty.
+    pub const fn New(Mask: u32) -> Option<Self> {
+        if Mask == 0 {
+            return None;
+        }
+
+        Some(Self(Mask))
+    }
+
+    /// Create an affinity pinning the task to a single core.
+    pub const fn New_core(Core: usize) -> Option<Self> {
+        if Core >= Self::Maximum_cores {
+            return None;
+        }
+
+        Some(Self(1 << Core))
+    }
+
+    pub const fn Get_mask(&self) -> u32 {
+        self.0
+    }
+
+    pub const fn Contains(&self, Core: usize) -> bool {
+        Core < Self::Maximum_cores && self.0 & (1 << Core) != 0
+    }
+
+    /// Get the core to which the task is pinned, if the set contains a single core.
+    pub const fn Get_single_core(&self) -> Option<usize> {
+        if self.0.count_ones() == 1 {
+            Some(self.0.trailing_zeros() as usize)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_scheduling() {
+        assert!(Priority_type::Highest > Priority_type::Normal);
+        assert_eq!(Priority_type::try_from(1), Ok(Priority_type::Low));
+        assert_eq!(Priority_type::try_from(5), Err(()));
+        assert!(Priority_type::High.Get_nice_value() < Priority_type::Normal.Get_nice_value());
+
+        let Affinity = Affinity_type::New_core(1).unwrap();
+
+        assert!(Affinity.Contains(1));
+        assert!(!Affinity.Contains(0));
+        assert_eq!(Affinity.Get_single_core(), Some(1));
+        assert_eq!(Affinity_type::Any.Get_single_core(), None);
+        assert_eq!(Affinity_type::New(0), None);
+        assert_eq!(Affinity_type::New_core(32), None);
+    }
+}
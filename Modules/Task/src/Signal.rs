@@ -0,0 +1,137 @@
+/// Asynchronous notification sent to a task, the numbers are the POSIX ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Signal_type {
+    /// Interrupt from the keyboard (e.g. `Ctrl-C` on a terminal).
+    Interrupt = 2,
+    User_1 = 10,
+    User_2 = 12,
+    Alarm = 14,
+    /// Polite request to terminate.
+    Terminate = 15,
+    /// A child of the task exited.
+    Child_exited = 17,
+}
+
+/// What happens to a signal that the task doesn't handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal_action_type {
+    /// The signal is discarded.
+    Ignore,
+    /// The task is asked to terminate (see [crate::Manager_type::Is_termination_requested]).
+    Terminate,
+}
+
+impl Signal_type {
+    pub const All: [Self; 6] = [
+        Self::Interrupt,
+        Self::User_1,
+        Self::User_2,
+        Self::Alarm,
+        Self::Terminate,
+        Self::Child_exited,
+    ];
+
+    pub const fn Get_default_action(&self) -> Signal_action_type {
+        match self {
+            Self::Child_exited => Signal_action_type::Ignore,
+            _ => Signal_action_type::Terminate,
+        }
+    }
+}
+
+impl TryFrom<u8> for Signal_type {
+    type Error = ();
+
+    fn try_from(Value: u8) -> Result<Self, Self::Error> {
+        Self::All
+            .into_iter()
+            .find(|Signal| *Signal as u8 == Value)
+            .ok_or(())
+    }
+}
+
+/// Set of signals, used for the masks and the pending signals of the tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(transparent)]
+pub struct Signal_set_type(u32);
+
+impl Signal_set_type {
+    pub const Empty: Self = Self(0);
+
+    pub const fn New(Raw: u32) -> Self {
+        Self(Raw)
+    }
+
+    pub const fn Get_raw(&self) -> u32 {
+        self.0
+    }
+
+    pub const fn Contains(&self, Signal: Signal_type) -> bool {
+        self.0 & (1 << Signal as u8) != 0
+    }
+
+    pub const fn Is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn Add(self, Signal: Signal_type) -> Self {
+        Self(self.0 | (1 << Signal as u8))
+    }
+
+    pub const fn Remove(self, Signal: Signal_type) -> Self {
+        Self(self.0 & !(1 << Signal as u8))
+    }
+
+    pub const fn Set(self, Signal: Signal_type, Value: bool) -> Self {
+        if Value {
+            self.Add(Signal)
+        } else {
+            self.Remove(Signal)
+        }
+    }
+
+    /// Get the signals of the set, the lowest numbers first.
+    pub fn Get_signals(&self) -> impl Iterator<Item = Signal_type> + '_ {
+        Signal_type::All
+            .into_iter()
+            .filter(|Signal| self.Contains(*Signal))
+    }
+}
+
+impl FromIterator<Signal_type> for Signal_set_type {
+    fn from_iter<I: IntoIterator<Item = Signal_type>>(Iterator: I) -> Self {
+        Iterator
+            .into_iter()
+            .fold(Self::Empty, |Set, Signal| Set.Add(Signal))
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_signal_set() {
+        let Set: Signal_set_type = [Signal_type::Terminate, Signal_type::Interrupt]
+            .into_iter()
+            .collect();
+
+        assert!(Set.Contains(Signal_type::Interrupt));
+        assert!(!Set.Contains(Signal_type::Alarm));
+        assert_eq!(
+            Set.Get_signals().collect::<Vec<_>>(),
+            [Signal_type::Interrupt, Signal_type::Terminate]
+        );
+
+        let Set = Set
+            .Remove(Signal_type::Interrupt)
+            .Remove(Signal_type::Terminate);
+
+        assert!(Set.Is_empty());
+        assert_eq!(Signal_set_type::New(Set.Get_raw()), Signal_set_type::Empty);
+
+        assert_eq!(Signal_type::try_from(17), Ok(Signal_type::Child_exited));
+        assert_eq!(Signal_type::try_from(9), Err(()));
+    }
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use Users::User_identifier_type;
+
+use crate::Task_identifier_type;
+
+/// What a task is doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Task_state_type {
+    /// The task is running or ready to run.
+    Running,
+    /// The task sleeps for a given duration (see [crate::Task_type::Sleep]).
+    Sleeping,
+    /// The task waits for an event (e.g. the exit of a child).
+    Blocked,
+    /// The function of the task returned, the task is being deleted.
+    Exited,
+}
+
+/// Information about a task at a given time, see [crate::Manager_type::Get_task_snapshot].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Task_snapshot_type {
+    Identifier: Task_identifier_type,
+    Name: String,
+    Parent: Task_identifier_type,
+    Children: Vec<Task_identifier_type>,
+    Owner: User_identifier_type,
+    State: Task_state_type,
+    Start_time: Duration,
+    CPU_time: Option<Duration>,
+    Stack_size: Option<usize>,
+}
+
+impl Task_snapshot_type {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn New(
+        Identifier: Task_identifier_type,
+        Name: String,
+        Parent: Task_identifier_type,
+        Children: Vec<Task_identifier_type>,
+        Owner: User_identifier_type,
+        State: Task_state_type,
+        Start_time: Duration,
+        CPU_time: Option<Duration>,
+        Stack_size: Option<usize>,
+    ) -> Self {
+        Self {
+            Identifier,
+            Name,
+            Parent,
+            Children,
+            Owner,
+            State,
+            Start_time,
+            CPU_time,
+            Stack_size,
+        }
+    }
+
+    pub fn Get_identifier(&self) -> Task_identifier_type {
+        self.Identifier
+    }
+
+    pub fn Get_name(&self) -> &str {
+        &self.Name
+    }
+
+    pub fn Get_parent(&self) -> Task_identifier_type {
+        self.Parent
+    }
+
+    pub fn Get_children(&self) -> &[Task_identifier_type] {
+        &self.Children
+    }
+
+    pub fn Get_owner(&self) -> User_identifier_type {
+        self.Owner
+    }
+
+    pub fn Get_state(&self) -> Task_state_type {
+        self.State
+    }
+
+    /// Get the time at which the task was created, relative to the initialization of the task manager.
+    pub fn Get_start_time(&self) -> Duration {
+        self.Start_time
+    }
+
+    /// Get the CPU time consumed by the task, if the platform supports it.
+    pub fn Get_CPU_time(&self) -> Option<Duration> {
+        self.CPU_time
+    }
+
+    /// Get the stack size of the task, if it was set when the task was created.
+    pub fn Get_stack_size(&self) -> Option<usize> {
+        self.Stack_size
+    }
+}
@@ -2,7 +2,10 @@ use std::borrow::Cow;
 
 use Users::User_identifier_type;
 
-use crate::{Get_instance, Join_handle_type, Result_type, Thread_wrapper_type};
+use crate::{
+    Exit_status_type, Get_instance, Join_handle_type, Limits_type, Priority_type, Result_type,
+    Signal_set_type, Signal_type, Task_snapshot_type, Thread_wrapper_type,
+};
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -10,6 +13,10 @@ pub struct Task_identifier_type(u32);
 
 impl Task_identifier_type {
     pub const Maximum: u32 = u32::MAX;
+
+    pub const fn New(Identifier: u32) -> Self {
+        Self(Identifier)
+    }
 }
 
 impl From<u32> for Task_identifier_type {
@@ -48,8 +55,15 @@ impl Task_type {
         T: Send + 'static,
         F: FnOnce() -> T + Send + 'static,
     {
-        let (Task_identifier, Join_handle) =
-            Get_instance()?.New_task(Some(self.Identifier), Owner, Name, Stack_size, Function)?;
+        let (Task_identifier, Join_handle) = Get_instance()?.New_task(
+            Some(self.Identifier),
+            Owner,
+            Name,
+            Stack_size,
+            None,
+            None,
+            Function,
+        )?;
 
         Ok((Task_type::New(Task_identifier), Join_handle))
     }
@@ -67,7 +81,10 @@ impl Task_type {
     }
 
     pub fn Sleep(Duration: std::time::Duration) {
-        Thread_wrapper_type::Sleep(Duration)
+        match Get_instance() {
+            Ok(Manager) => Manager.Sleep(Duration),
+            Err(_) => Thread_wrapper_type::Sleep(Duration),
+        }
     }
 
     pub fn Get_environment_variable(&self, Name: &str) -> Result_type<Cow<'static, str>> {
@@ -81,4 +98,79 @@ impl Task_type {
     pub fn Remove_environment_variable(&self, Name: &str) -> Result_type<()> {
         Get_instance()?.Remove_environment_variable(self.Identifier, Name)
     }
+
+    pub fn Get_working_directory(&self) -> Result_type<String> {
+        Get_instance()?.Get_working_directory(self.Identifier)
+    }
+
+    pub fn Set_working_directory(&self, Path: &str) -> Result_type<()> {
+        Get_instance()?.Set_working_directory(self.Identifier, Path)
+    }
+
+    pub fn Get_limits(&self) -> Result_type<Limits_type> {
+        Get_instance()?.Get_limits(self.Identifier)
+    }
+
+    pub fn Set_limits(&self, Limits: Limits_type) -> Result_type<()> {
+        Get_instance()?.Set_limits(self.Identifier, Limits)
+    }
+
+    pub fn Get_snapshot(&self) -> Result_type<Task_snapshot_type> {
+        Get_instance()?.Get_task_snapshot(self.Identifier)
+    }
+
+    pub fn Get_priority(&self) -> Result_type<Priority_type> {
+        Get_instance()?.Get_priority(self.Identifier)
+    }
+
+    pub fn Set_priority(&self, Priority: Priority_type) -> Result_type<()> {
+        Get_instance()?.Set_priority(self.Identifier, Priority)
+    }
+
+    pub fn Take_interrupt(&self) -> Result_type<bool> {
+        Get_instance()?.Take_interrupt(self.Identifier)
+    }
+
+    pub fn Get_parent(&self) -> Result_type<Task_type> {
+        Ok(Task_type::New(Get_instance()?.Get_parent(self.Identifier)?))
+    }
+
+    /// Wait for a child task to exit (any child if `Child` is `None`), see [crate::Manager_type::Wait_child].
+    pub fn Wait_child(
+        &self,
+        Child: Option<&Task_type>,
+    ) -> Result_type<(Task_type, Exit_status_type)> {
+        let (Child, Exit_status) =
+            Get_instance()?.Wait_child(self.Identifier, Child.map(Task_type::Get_identifier))?;
+
+        Ok((Task_type::New(Child), Exit_status))
+    }
+
+    pub fn Set_exit_code(&self, Code: i32) -> Result_type<()> {
+        Get_instance()?.Set_exit_code(self.Identifier, Code)
+    }
+
+    pub fn Request_termination(&self) -> Result_type<()> {
+        Get_instance()?.Request_termination(self.Identifier)
+    }
+
+    pub fn Is_termination_requested(&self) -> Result_type<bool> {
+        Get_instance()?.Is_termination_requested(self.Identifier)
+    }
+
+    pub fn Send_signal(&self, Signal: Signal_type) -> Result_type<()> {
+        Get_instance()?.Send_signal(self.Identifier, Signal)
+    }
+
+    pub fn Take_signal(&self) -> Result_type<Option<Signal_type>> {
+        Get_instance()?.Take_signal(self.Identifier)
+    }
+
+    pub fn Get_signal_mask(&self) -> Result_type<Signal_set_type> {
+        Get_instance()?.Get_signal_mask(self.Identifier)
+    }
+
+    pub fn Set_signal_mask(&self, Mask: Signal_set_type) -> Result_type<()> {
+        Get_instance()?.Set_signal_mask(self.Identifier, Mask)
+    }
 }
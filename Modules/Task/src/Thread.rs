@@ -1,29 +1,63 @@
 use super::*;
 use std::{
     any::Any,
+    sync::mpsc,
     thread::{self, ThreadId},
+    time::Duration,
 };
 
-pub struct Join_handle_type<T>(thread::JoinHandle<T>);
+/// Identifier of a thread for the scheduler of the platform.
+#[cfg(target_os = "linux")]
+type Native_identifier_type = libc::pid_t;
+/// Identifier of a thread for the scheduler of the platform (the handle of the FreeRTOS task).
+#[cfg(target_os = "espidf")]
+type Native_identifier_type = usize;
+/// Identifier of a thread for the scheduler of the platform.
+#[cfg(not(any(target_os = "linux", target_os = "espidf")))]
+type Native_identifier_type = ();
+
+/// The function of the thread doesn't run if its scheduling parameters couldn't be applied.
+pub struct Join_handle_type<T>(thread::JoinHandle<Option<T>>, Native_identifier_type);
 
 impl<T> Join_handle_type<T> {
     pub fn Join(self) -> std::result::Result<T, Box<dyn Any + Send>> {
-        self.0.join()
+        self.0
+            .join()
+            .map(|Result| Result.expect("The function of a started thread always runs"))
     }
 
     pub(crate) fn Get_thread_wrapper(&self) -> Thread_wrapper_type {
-        Thread_wrapper_type(self.0.thread().clone())
+        Thread_wrapper_type {
+            Thread: self.0.thread().clone(),
+            Native_identifier: self.1,
+            #[cfg(target_os = "linux")]
+            CPU_clock: {
+                use std::os::unix::thread::JoinHandleExt;
+
+                Thread_wrapper_type::Get_CPU_clock(self.0.as_pthread_t())
+            },
+        }
     }
 }
 
 /// A wrapper around [std::thread::Thread].
-pub struct Thread_wrapper_type(thread::Thread);
+pub struct Thread_wrapper_type {
+    Thread: thread::Thread,
+    Native_identifier: Native_identifier_type,
+    /// Clock measuring the CPU time consumed by the thread.
+    #[cfg(target_os = "linux")]
+    CPU_clock: Option<libc::clockid_t>,
+}
 
 impl Thread_wrapper_type {
-    /// Creates a new thread with a given name, stack size and function.
+    /// Creates a new thread with a given name, stack size, scheduling parameters and function.
+    ///
+    /// The thread inherits the scheduling parameters of the current thread when they are `None`.
     pub fn New<F, T>(
         Name: &str,
         Stack_size: Option<usize>,
+        Priority: Option<Priority_type>,
+        Affinity: Option<Affinity_type>,
         Function: F,
     ) -> Result_type<Join_handle_type<T>>
     where
@@ -37,16 +71,212 @@ impl Thread_wrapper_type {
             None => Thread_builder,
         };
 
-        let Join_handle = Thread_builder
-            .spawn(Function)
+        let (Identifier_sender, Identifier_receiver) = mpsc::channel();
+        let (Start_sender, Start_receiver) = mpsc::channel();
+
+        // - On ESP-IDF, the core of a FreeRTOS task can only be chosen when it is created
+        #[cfg(target_os = "espidf")]
+        Self::Configure_next_threads(Priority, Affinity)?;
+
+        let Join_handle = Thread_builder.spawn(move || {
+            let _ = Identifier_sender.send(Self::Get_current_native_identifier());
+
+            // - Wait for the scheduling parameters to be applied
+            if Start_receiver.recv() != Ok(true) {
+                return None;
+            }
+
+            Some(Function())
+        });
+
+        #[cfg(target_os = "espidf")]
+        Self::Configure_next_threads(None, None)?;
+
+        let Join_handle = Join_handle.map_err(|_| Error_type::Failed_to_spawn_thread)?;
+
+        let Native_identifier = Identifier_receiver
+            .recv()
             .map_err(|_| Error_type::Failed_to_spawn_thread)?;
 
-        Ok(Join_handle_type(Join_handle))
+        let Result = Priority
+            .map_or(Ok(()), |Priority| {
+                Self::Set_native_priority(Native_identifier, Priority)
+            })
+            .and_then(|_| {
+                Affinity.map_or(Ok(()), |Affinity| {
+                    Self::Set_native_affinity(Native_identifier, Affinity)
+                })
+            });
+
+        let _ = Start_sender.send(Result.is_ok());
+
+        if let Err(Error) = Result {
+            let _ = Join_handle.join();
+
+            return Err(Error);
+        }
+
+        Ok(Join_handle_type(Join_handle, Native_identifier))
+    }
+
+    /// Set the scheduling priority of the thread.
+    pub fn Set_priority(&self, Priority: Priority_type) -> Result_type<()> {
+        Self::Set_native_priority(self.Native_identifier, Priority)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn Get_current_native_identifier() -> Native_identifier_type {
+        unsafe { libc::gettid() }
+    }
+
+    /// Set the nice value of a thread.
+    ///
+    /// An unprivileged process can only lower nice values down to the limit given by `RLIMIT_NICE` : the higher
+    /// priorities are clamped to the highest priority the process is allowed to use.
+    #[cfg(target_os = "linux")]
+    fn Set_native_priority(
+        Native_identifier: Native_identifier_type,
+        Priority: Priority_type,
+    ) -> Result_type<()> {
+        // - On Linux, the nice value is a property of each thread
+        let Set_nice_value = |Nice_value: i32| unsafe {
+            libc::setpriority(
+                libc::PRIO_PROCESS,
+                Native_identifier as libc::id_t,
+                Nice_value,
+            ) == 0
+        };
+
+        if Set_nice_value(Priority.Get_nice_value()) {
+            return Ok(());
+        }
+
+        if !matches!(
+            std::io::Error::last_os_error().raw_os_error(),
+            Some(libc::EACCES | libc::EPERM)
+        ) {
+            return Err(Error_type::Failed_to_set_priority);
+        }
+
+        let mut Limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+
+        if unsafe { libc::getrlimit(libc::RLIMIT_NICE, &mut Limit) } != 0 {
+            return Err(Error_type::Failed_to_set_priority);
+        }
+
+        let Current_nice_value =
+            unsafe { libc::getpriority(libc::PRIO_PROCESS, Native_identifier as libc::id_t) };
+
+        if !Set_nice_value(Clamp_nice_value(
+            Priority.Get_nice_value(),
+            Current_nice_value,
+            Limit.rlim_cur,
+        )) {
+            return Err(Error_type::Failed_to_set_priority);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn Set_native_affinity(
+        Native_identifier: Native_identifier_type,
+        Affinity: Affinity_type,
+    ) -> Result_type<()> {
+        // - The threads keep the CPU set of the process (e.g. from `taskset` or a cgroup)
+        if Affinity == Affinity_type::Any {
+            return Ok(());
+        }
+
+        let mut Set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+
+        for Core in (0..Affinity_type::Maximum_cores).filter(|Core| Affinity.Contains(*Core)) {
+            unsafe { libc::CPU_SET(Core, &mut Set) };
+        }
+
+        if unsafe {
+            libc::sched_setaffinity(
+                Native_identifier,
+                std::mem::size_of::<libc::cpu_set_t>(),
+                &Set,
+            )
+        } != 0
+        {
+            return Err(Error_type::Failed_to_set_affinity);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn Get_current_native_identifier() -> Native_identifier_type {
+        unsafe { esp_idf_sys::xTaskGetCurrentTaskHandle() as usize }
+    }
+
+    #[cfg(target_os = "espidf")]
+    fn Set_native_priority(
+        Native_identifier: Native_identifier_type,
+        Priority: Priority_type,
+    ) -> Result_type<()> {
+        unsafe {
+            esp_idf_sys::vTaskPrioritySet(
+                Native_identifier as esp_idf_sys::TaskHandle_t,
+                Priority.Get_FreeRTOS_priority(),
+            )
+        };
+
+        Ok(())
+    }
+
+    /// The core is chosen when the FreeRTOS task is created, see `Configure_next_threads`.
+    #[cfg(target_os = "espidf")]
+    fn Set_native_affinity(_: Native_identifier_type, _: Affinity_type) -> Result_type<()> {
+        Ok(())
+    }
+
+    /// Set the priority and the core of the threads created afterwards by the current thread.
+    #[cfg(target_os = "espidf")]
+    fn Configure_next_threads(
+        Priority: Option<Priority_type>,
+        Affinity: Option<Affinity_type>,
+    ) -> Result_type<()> {
+        let mut Configuration = unsafe { esp_idf_sys::esp_pthread_get_default_config() };
+
+        if let Some(Priority) = Priority {
+            Configuration.prio = Priority.Get_FreeRTOS_priority() as _;
+        }
+
+        if let Some(Core) = Affinity.and_then(|Affinity| Affinity.Get_single_core()) {
+            Configuration.pin_to_core = Core as _;
+        }
+
+        if unsafe { esp_idf_sys::esp_pthread_set_cfg(&Configuration) } != esp_idf_sys::ESP_OK {
+            return Err(Error_type::Failed_to_set_affinity);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "espidf")))]
+    fn Get_current_native_identifier() -> Native_identifier_type {}
+
+    /// The scheduling parameters are only recorded by the task manager on the other platforms.
+    #[cfg(not(any(target_os = "linux", target_os = "espidf")))]
+    fn Set_native_priority(_: Native_identifier_type, _: Priority_type) -> Result_type<()> {
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "espidf")))]
+    fn Set_native_affinity(_: Native_identifier_type, _: Affinity_type) -> Result_type<()> {
+        Ok(())
     }
 
     /// Gets the name of the thread.
     pub fn Get_name(&self) -> Option<&str> {
-        self.0.name()
+        self.Thread.name()
     }
 
     pub fn Sleep(Duration: std::time::Duration) {
@@ -54,10 +284,76 @@ impl Thread_wrapper_type {
     }
 
     pub fn Get_identifier(&self) -> ThreadId {
-        self.0.id()
+        self.Thread.id()
     }
 
     pub fn Get_current() -> Thread_wrapper_type {
-        Thread_wrapper_type(thread::current())
+        Thread_wrapper_type {
+            Thread: thread::current(),
+            Native_identifier: Self::Get_current_native_identifier(),
+            #[cfg(target_os = "linux")]
+            CPU_clock: Self::Get_CPU_clock(unsafe { libc::pthread_self() }),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn Get_CPU_clock(Thread: libc::pthread_t) -> Option<libc::clockid_t> {
+        let mut Clock: libc::clockid_t = 0;
+
+        if unsafe { libc::pthread_getcpuclockid(Thread, &mut Clock) } != 0 {
+            return None;
+        }
+
+        Some(Clock)
+    }
+
+    /// Gets the CPU time consumed by the thread, if the platform supports it.
+    #[cfg(target_os = "linux")]
+    pub fn Get_CPU_time(&self) -> Option<Duration> {
+        let mut Time = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+
+        if unsafe { libc::clock_gettime(self.CPU_clock?, &mut Time) } != 0 {
+            return None;
+        }
+
+        Some(Duration::new(Time.tv_sec as u64, Time.tv_nsec as u32))
+    }
+
+    /// Gets the CPU time consumed by the thread, if the platform supports it.
+    #[cfg(not(target_os = "linux"))]
+    pub fn Get_CPU_time(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Get the nice value closest to `Nice_value` that an unprivileged thread can use.
+///
+/// The nice value can always be raised, and lowered down to `20 - Nice_limit` (`Nice_limit` is the soft limit of
+/// `RLIMIT_NICE`) but not below the current nice value if the limit is above it.
+#[cfg(target_os = "linux")]
+fn Clamp_nice_value(Nice_value: i32, Current_nice_value: i32, Nice_limit: libc::rlim_t) -> i32 {
+    let Lowest_nice_value = 20 - Nice_limit.min(40) as i32;
+
+    Nice_value.max(Lowest_nice_value.min(Current_nice_value))
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_clamp_nice_value() {
+        // - Without `RLIMIT_NICE`, the nice value can't be lowered
+        assert_eq!(Clamp_nice_value(-5, 0, 0), 0);
+        assert_eq!(Clamp_nice_value(-10, 10, 0), 10);
+        assert_eq!(Clamp_nice_value(19, 0, 0), 19);
+
+        // - `RLIMIT_NICE` of 25 allows nice values down to -5
+        assert_eq!(Clamp_nice_value(-10, 0, 25), -5);
+        assert_eq!(Clamp_nice_value(-5, 0, 25), -5);
+        assert_eq!(Clamp_nice_value(-10, 0, libc::RLIM_INFINITY), -10);
     }
 }
@@ -0,0 +1,289 @@
+use std::{
+    collections::BTreeMap,
+    panic::AssertUnwindSafe,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::{Error_type, Result_type, Run_on_behalf_of, Task_identifier_type};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Timer_identifier_type(u32);
+
+impl Timer_identifier_type {
+    pub const Maximum: u32 = u32::MAX;
+
+    pub const fn New(Identifier: u32) -> Self {
+        Self(Identifier)
+    }
+}
+
+impl From<u32> for Timer_identifier_type {
+    fn from(Value: u32) -> Self {
+        Self(Value)
+    }
+}
+
+impl From<Timer_identifier_type> for u32 {
+    fn from(Value: Timer_identifier_type) -> Self {
+        Value.0
+    }
+}
+
+pub type Timer_callback_type = Box<dyn FnMut() + Send>;
+
+/// Internal representation of a timer.
+struct Timer_internal_type {
+    /// The task that owns the timer, on behalf of which the callback runs.
+    Owner: Task_identifier_type,
+    /// Next expiration of the timer, `None` if the timer is stopped.
+    Deadline: Option<Instant>,
+    /// Period of the timer, `None` for a one-shot timer.
+    Period: Option<Duration>,
+    Callback: Arc<Mutex<Timer_callback_type>>,
+}
+
+struct Timers_inner_type {
+    Timers: BTreeMap<Timer_identifier_type, Timer_internal_type>,
+    /// The task whose callback is running on the service thread, if any.
+    Running: Option<Task_identifier_type>,
+    /// Whether the service thread has been spawned.
+    Started: bool,
+}
+
+/// Software timers of the tasks, fired by a single service thread.
+///
+/// Since all the callbacks run on the same thread, the callbacks of a task never run concurrently.
+pub(crate) struct Timers_type {
+    Inner: Mutex<Timers_inner_type>,
+    /// Notified when the timers change or when a callback returns.
+    Condition: Condvar,
+}
+
+impl Timers_type {
+    pub(crate) fn New() -> Self {
+        Self {
+            Inner: Mutex::new(Timers_inner_type {
+                Timers: BTreeMap::new(),
+                Running: None,
+                Started: false,
+            }),
+            Condition: Condvar::new(),
+        }
+    }
+
+    /// Lock the timers, and spawn the service thread if it isn't running yet.
+    ///
+    /// The service thread serves the timers of the global manager.
+    fn Lock(&self) -> Result_type<MutexGuard<'_, Timers_inner_type>> {
+        let mut Inner = self.Inner.lock()?;
+
+        if !Inner.Started {
+            std::thread::Builder::new()
+                .name("Timer service".to_string())
+                .spawn(|| {
+                    if let Ok(Manager) = crate::Get_instance() {
+                        Manager.Timers.Run();
+                    }
+                })
+                .map_err(|_| Error_type::Failed_to_spawn_thread)?;
+
+            Inner.Started = true;
+        }
+
+        Ok(Inner)
+    }
+
+    fn Get_new_timer_identifier(Inner: &Timers_inner_type) -> Option<Timer_identifier_type> {
+        (0..Timer_identifier_type::Maximum)
+            .map(Timer_identifier_type::New)
+            .find(|Identifier| !Inner.Timers.contains_key(Identifier))
+    }
+
+    /// Get a timer, checking that it is owned by the task.
+    fn Get_timer(
+        Inner: &mut Timers_inner_type,
+        Task: Task_identifier_type,
+        Timer: Timer_identifier_type,
+    ) -> Result_type<&mut Timer_internal_type> {
+        match Inner.Timers.get_mut(&Timer) {
+            Some(Timer) if Timer.Owner == Task => Ok(Timer),
+            _ => Err(Error_type::Invalid_timer_identifier),
+        }
+    }
+
+    pub(crate) fn New_timer(
+        &self,
+        Task: Task_identifier_type,
+        Callback: Timer_callback_type,
+    ) -> Result_type<Timer_identifier_type> {
+        let mut Inner = self.Lock()?;
+
+        let Identifier =
+            Self::Get_new_timer_identifier(&Inner).ok_or(Error_type::Too_many_timers)?;
+
+        Inner.Timers.insert(
+            Identifier,
+            Timer_internal_type {
+                Owner: Task,
+                Deadline: None,
+                Period: None,
+                Callback: Arc::new(Mutex::new(Callback)),
+            },
+        );
+
+        Ok(Identifier)
+    }
+
+    pub(crate) fn Start_timer(
+        &self,
+        Task: Task_identifier_type,
+        Timer: Timer_identifier_type,
+        Delay: Duration,
+        Period: Option<Duration>,
+    ) -> Result_type<()> {
+        if Period == Some(Duration::ZERO) {
+            return Err(Error_type::Invalid_timer_period);
+        }
+
+        let mut Inner = self.Lock()?;
+
+        let Timer = Self::Get_timer(&mut Inner, Task, Timer)?;
+
+        Timer.Deadline = Some(Instant::now() + Delay);
+        Timer.Period = Period;
+
+        self.Condition.notify_all();
+
+        Ok(())
+    }
+
+    pub(crate) fn Stop_timer(
+        &self,
+        Task: Task_identifier_type,
+        Timer: Timer_identifier_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        Self::Get_timer(&mut Inner, Task, Timer)?.Deadline = None;
+
+        self.Condition.notify_all();
+
+        Ok(())
+    }
+
+    pub(crate) fn Is_timer_active(
+        &self,
+        Task: Task_identifier_type,
+        Timer: Timer_identifier_type,
+    ) -> Result_type<bool> {
+        let mut Inner = self.Inner.lock()?;
+
+        Ok(Self::Get_timer(&mut Inner, Task, Timer)?.Deadline.is_some())
+    }
+
+    pub(crate) fn Delete_timer(
+        &self,
+        Task: Task_identifier_type,
+        Timer: Timer_identifier_type,
+    ) -> Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        Self::Get_timer(&mut Inner, Task, Timer)?;
+
+        Inner.Timers.remove(&Timer);
+
+        self.Condition.notify_all();
+
+        Ok(())
+    }
+
+    /// Delete all the timers of a task, and wait for its running callback (if any) to return.
+    ///
+    /// Once this returns, no callback of the task will run anymore.
+    pub(crate) fn Delete_task_timers(&self, Task: Task_identifier_type) -> Result_type<()> {
+        let mut Inner = self.Inner.lock()?;
+
+        Inner.Timers.retain(|_, Timer| Timer.Owner != Task);
+
+        self.Condition.notify_all();
+
+        while Inner.Running == Some(Task) {
+            Inner = self.Condition.wait(Inner)?;
+        }
+
+        Ok(())
+    }
+
+    /// Service loop : wait for the next expiration and run the callbacks of the expired timers.
+    fn Run(&self) {
+        let mut Inner = self.Inner.lock().unwrap();
+
+        loop {
+            let Next = Inner
+                .Timers
+                .iter()
+                .filter_map(|(Identifier, Timer)| Some((Timer.Deadline?, *Identifier)))
+                .min();
+
+            let Identifier = match Next {
+                None => {
+                    Inner = self.Condition.wait(Inner).unwrap();
+                    continue;
+                }
+                Some((Deadline, Identifier)) => {
+                    let Now = Instant::now();
+
+                    if Deadline > Now {
+                        Inner = self
+                            .Condition
+                            .wait_timeout(Inner, Deadline - Now)
+                            .unwrap()
+                            .0;
+                        continue;
+                    }
+
+                    Identifier
+                }
+            };
+
+            let Timer = Inner.Timers.get_mut(&Identifier).unwrap();
+
+            // - Reload periodic timers, skipping the periods missed by a late callback
+            Timer.Deadline = match (Timer.Deadline, Timer.Period) {
+                (Some(Deadline), Some(Period)) => {
+                    let Now = Instant::now();
+                    let mut Deadline = Deadline + Period;
+
+                    while Deadline <= Now {
+                        Deadline += Period;
+                    }
+
+                    Some(Deadline)
+                }
+                _ => None,
+            };
+
+            let Owner = Timer.Owner;
+            let Callback = Timer.Callback.clone();
+
+            Inner.Running = Some(Owner);
+            drop(Inner);
+
+            // - A panicking callback must neither stop the service nor block the task deletion
+            let _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                Run_on_behalf_of(Owner, || {
+                    if let Ok(mut Callback) = Callback.lock() {
+                        Callback();
+                    }
+                })
+            }));
+
+            Inner = self.Inner.lock().unwrap();
+            Inner.Running = None;
+
+            self.Condition.notify_all();
+        }
+    }
+}
@@ -5,12 +5,30 @@
 mod Error;
 pub use Error::*;
 
+mod Exit_status;
+pub use Exit_status::*;
+
+mod Limits;
+pub use Limits::*;
+
 mod Manager;
 pub use Manager::*;
 
+mod Scheduling;
+pub use Scheduling::*;
+
+mod Signal;
+pub use Signal::*;
+
+mod Snapshot;
+pub use Snapshot::*;
+
 mod Task;
 pub use Task::*;
 
+mod Timer;
+pub use Timer::*;
+
 mod Thread;
 use Thread::*;
 
@@ -0,0 +1,15 @@
+use crate::{Result_type, Time_type};
+
+/// Clock keeping the wall clock time, such as a battery backed real time clock or the clock of the host.
+///
+/// The time manager reads it once at initialization and stores the time set afterwards, so that it persists across
+/// reboots.
+pub trait Real_time_clock_trait: Send + Sync {
+    fn Get_time(&self) -> Result_type<Time_type>;
+
+    /// Store the time in the clock.
+    ///
+    /// # Errors
+    /// Returns `Unsupported_operation` if the clock can't be set (the time is then only kept until the next reboot).
+    fn Set_time(&self, Time: Time_type) -> Result_type<()>;
+}
@@ -0,0 +1,294 @@
+use crate::{Error_type, Result_type, Time_type};
+
+const Seconds_per_day: i64 = 86_400;
+
+/// Get the number of days since the Unix epoch of a date of the proleptic Gregorian calendar.
+pub(crate) const fn Get_days_from_date(Year: i64, Month: u8, Day: u8) -> i64 {
+    let Year = if Month <= 2 { Year - 1 } else { Year };
+    let Era = Year.div_euclid(400);
+    let Year_of_era = Year - Era * 400;
+    let Month = Month as i64;
+    let Day_of_year =
+        (153 * (if Month > 2 { Month - 3 } else { Month + 9 }) + 2) / 5 + Day as i64 - 1;
+    let Day_of_era = Year_of_era * 365 + Year_of_era / 4 - Year_of_era / 100 + Day_of_year;
+
+    Era * 146_097 + Day_of_era - 719_468
+}
+
+/// Get the date (year, month, day) from a number of days since the Unix epoch.
+pub(crate) const fn Get_date_from_days(Days: i64) -> (i64, u8, u8) {
+    let Days = Days + 719_468;
+    let Era = Days.div_euclid(146_097);
+    let Day_of_era = Days - Era * 146_097;
+    let Year_of_era =
+        (Day_of_era - Day_of_era / 1460 + Day_of_era / 36_524 - Day_of_era / 146_096) / 365;
+    let Day_of_year = Day_of_era - (365 * Year_of_era + Year_of_era / 4 - Year_of_era / 100);
+    let Shifted_month = (5 * Day_of_year + 2) / 153;
+    let Day = (Day_of_year - (153 * Shifted_month + 2) / 5 + 1) as u8;
+    let Month = if Shifted_month < 10 {
+        Shifted_month + 3
+    } else {
+        Shifted_month - 9
+    } as u8;
+    let Year = Year_of_era + Era * 400 + if Month <= 2 { 1 } else { 0 };
+
+    (Year, Month, Day)
+}
+
+/// Get the day of the week (`0` for Sunday) from a number of days since the Unix epoch (a Thursday).
+pub(crate) const fn Get_weekday_from_days(Days: i64) -> u8 {
+    (Days + 4).rem_euclid(7) as u8
+}
+
+pub(crate) const fn Is_leap_year(Year: i64) -> bool {
+    Year % 4 == 0 && (Year % 100 != 0 || Year % 400 == 0)
+}
+
+pub(crate) const fn Get_days_in_month(Year: i64, Month: u8) -> u8 {
+    match Month {
+        2 if Is_leap_year(Year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Calendar date and time of day, in a given offset from UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Date_time_type {
+    Year: u16,
+    /// From `1` (January) to `12`.
+    Month: u8,
+    /// From `1` to `31`.
+    Day: u8,
+    Hour: u8,
+    Minute: u8,
+    Second: u8,
+    /// From `0` (Sunday) to `6`.
+    Weekday: u8,
+    Microsecond: u32,
+    /// Offset from UTC, in seconds (positive east of Greenwich).
+    Offset: i32,
+}
+
+impl Date_time_type {
+    /// Create a date time, with an offset from UTC in seconds.
+    ///
+    /// # Errors
+    /// Returns `Invalid_date_time` if the date doesn't exist or if it is before the Unix epoch.
+    pub fn New(
+        Year: u16,
+        Month: u8,
+        Day: u8,
+        Hour: u8,
+        Minute: u8,
+        Second: u8,
+        Offset: i32,
+    ) -> Result_type<Self> {
+        let Date_time = Self {
+            Year,
+            Month,
+            Day,
+            Hour,
+            Minute,
+            Second,
+            Weekday: Get_weekday_from_days(Get_days_from_date(Year as i64, Month, Day)),
+            Microsecond: 0,
+            Offset,
+        };
+
+        if !Date_time.Is_valid() {
+            return Err(Error_type::Invalid_date_time);
+        }
+
+        // - Check that the date time can be represented as a time
+        Date_time.Get_time()?;
+
+        Ok(Date_time)
+    }
+
+    /// Check that the date exists and that the time of day is in range (e.g. when coming from WASM).
+    pub fn Is_valid(&self) -> bool {
+        (1..=12).contains(&self.Month)
+            && self.Day >= 1
+            && self.Day <= Get_days_in_month(self.Year as i64, self.Month)
+            && self.Hour <= 23
+            && self.Minute <= 59
+            && self.Second <= 59
+            && self.Microsecond <= 999_999
+    }
+
+    /// Get the calendar date time of a time, with an offset from UTC in seconds.
+    pub fn From_time(Time: Time_type, Offset: i32) -> Self {
+        let Seconds = Time.Get_seconds() as i64 + Offset as i64;
+
+        let Days = Seconds.div_euclid(Seconds_per_day);
+        let Second_of_day = Seconds.rem_euclid(Seconds_per_day);
+
+        let (Year, Month, Day) = Get_date_from_days(Days);
+
+        Self {
+            Year: Year.clamp(0, u16::MAX as i64) as u16,
+            Month,
+            Day,
+            Hour: (Second_of_day / 3600) as u8,
+            Minute: (Second_of_day % 3600 / 60) as u8,
+            Second: (Second_of_day % 60) as u8,
+            Weekday: Get_weekday_from_days(Days),
+            Microsecond: (Time.Get_microseconds() % 1_000_000) as u32,
+            Offset,
+        }
+    }
+
+    /// Get the time corresponding to the date time.
+    ///
+    /// # Errors
+    /// Returns `Invalid_date_time` if the date time is before the Unix epoch.
+    pub fn Get_time(&self) -> Result_type<Time_type> {
+        let Seconds = Get_days_from_date(self.Year as i64, self.Month, self.Day) * Seconds_per_day
+            + self.Hour as i64 * 3600
+            + self.Minute as i64 * 60
+            + self.Second as i64
+            - self.Offset as i64;
+
+        if Seconds < 0 {
+            return Err(Error_type::Invalid_date_time);
+        }
+
+        Ok(Time_type::New(
+            Seconds as u64 * 1_000_000 + self.Microsecond as u64,
+        ))
+    }
+
+    pub fn Set_microsecond(mut self, Microsecond: u32) -> Self {
+        self.Microsecond = Microsecond.min(999_999);
+        self
+    }
+
+    pub const fn Get_year(&self) -> u16 {
+        self.Year
+    }
+
+    pub const fn Get_month(&self) -> u8 {
+        self.Month
+    }
+
+    pub const fn Get_day(&self) -> u8 {
+        self.Day
+    }
+
+    pub const fn Get_hour(&self) -> u8 {
+        self.Hour
+    }
+
+    pub const fn Get_minute(&self) -> u8 {
+        self.Minute
+    }
+
+    pub const fn Get_second(&self) -> u8 {
+        self.Second
+    }
+
+    pub const fn Get_weekday(&self) -> u8 {
+        self.Weekday
+    }
+
+    pub const fn Get_microsecond(&self) -> u32 {
+        self.Microsecond
+    }
+
+    pub const fn Get_offset(&self) -> i32 {
+        self.Offset
+    }
+}
+
+/// ISO 8601 representation (e.g. `2024-03-31T14:05:09+02:00`).
+impl std::fmt::Display for Date_time_type {
+    fn fmt(&self, Formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            Formatter,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.Year, self.Month, self.Day, self.Hour, self.Minute, self.Second
+        )?;
+
+        if self.Offset == 0 {
+            return write!(Formatter, "Z");
+        }
+
+        let Sign = if self.Offset < 0 { '-' } else { '+' };
+        let Offset = self.Offset.unsigned_abs();
+
+        write!(
+            Formatter,
+            "{}{:02}:{:02}",
+            Sign,
+            Offset / 3600,
+            Offset % 3600 / 60
+        )
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_days_conversion() {
+        assert_eq!(Get_days_from_date(1970, 1, 1), 0);
+        assert_eq!(Get_days_from_date(2000, 3, 1), 11_017);
+        assert_eq!(Get_date_from_days(11_016), (2000, 2, 29));
+        assert_eq!(Get_date_from_days(-1), (1969, 12, 31));
+
+        // - Round trip over several leap cycles
+        for Days in (-800_000..800_000).step_by(97) {
+            let (Year, Month, Day) = Get_date_from_days(Days);
+
+            assert_eq!(Get_days_from_date(Year, Month, Day), Days);
+        }
+
+        assert_eq!(Get_weekday_from_days(0), 4);
+        assert_eq!(Get_days_in_month(1900, 2), 28);
+        assert_eq!(Get_days_in_month(2000, 2), 29);
+    }
+
+    #[test]
+    fn Test_date_time() {
+        // - 2024-03-31T14:05:09.25+02:00
+        let Time = Time_type::New(1_711_886_709_250_000);
+
+        let Date_time = Date_time_type::From_time(Time, 2 * 3600);
+
+        assert_eq!(Date_time.Get_year(), 2024);
+        assert_eq!(Date_time.Get_month(), 3);
+        assert_eq!(Date_time.Get_day(), 31);
+        assert_eq!(Date_time.Get_hour(), 14);
+        assert_eq!(Date_time.Get_minute(), 5);
+        assert_eq!(Date_time.Get_second(), 9);
+        assert_eq!(Date_time.Get_weekday(), 0);
+        assert_eq!(Date_time.Get_microsecond(), 250_000);
+        assert_eq!(Date_time.to_string(), "2024-03-31T14:05:09+02:00");
+
+        assert_eq!(Date_time.Get_time(), Ok(Time));
+
+        assert_eq!(
+            Date_time_type::New(2024, 3, 31, 14, 5, 9, 2 * 3600)
+                .unwrap()
+                .Set_microsecond(250_000),
+            Date_time
+        );
+
+        assert_eq!(
+            Date_time_type::New(2023, 2, 29, 0, 0, 0, 0),
+            Err(Error_type::Invalid_date_time)
+        );
+        assert_eq!(
+            Date_time_type::New(1970, 1, 1, 0, 0, 0, 3600),
+            Err(Error_type::Invalid_date_time)
+        );
+        assert_eq!(
+            Date_time_type::From_time(Time_type::Unix_epoch, 0).to_string(),
+            "1970-01-01T00:00:00Z"
+        );
+    }
+}
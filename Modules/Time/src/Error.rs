@@ -0,0 +1,29 @@
+use std::{num::NonZeroU32, sync::PoisonError};
+
+pub type Result_type<T> = std::result::Result<T, Error_type>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub enum Error_type {
+    Poisoned_lock = 1,
+    Not_initialized,
+    Already_initialized,
+    Invalid_time_zone,
+    Invalid_date_time,
+    Unsupported_operation,
+    /// The real time clock failed to read or store the time.
+    Real_time_clock_error,
+    Permission_denied,
+}
+
+impl<T> From<PoisonError<T>> for Error_type {
+    fn from(_: PoisonError<T>) -> Self {
+        Error_type::Poisoned_lock
+    }
+}
+
+impl From<Error_type> for NonZeroU32 {
+    fn from(Error: Error_type) -> Self {
+        unsafe { NonZeroU32::new_unchecked(Error as u32) }
+    }
+}
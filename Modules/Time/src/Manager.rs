@@ -0,0 +1,217 @@
+use std::{
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Date_time_type, Error_type, Real_time_clock_trait, Result_type, Time_type, Time_zone_type,
+};
+
+static mut Manager_instance: Option<Manager_type> = None;
+
+/// Initialize the time manager, with the real time clock of the platform if any.
+pub fn Initialize(
+    Real_time_clock: Option<Box<dyn Real_time_clock_trait>>,
+) -> Result_type<&'static Manager_type> {
+    if Is_initialized() {
+        return Err(Error_type::Already_initialized);
+    }
+
+    unsafe {
+        Manager_instance = Some(Manager_type::New(Real_time_clock));
+    }
+
+    Get_instance()
+}
+
+pub fn Get_instance() -> Result_type<&'static Manager_type> {
+    unsafe { Manager_instance.as_ref().ok_or(Error_type::Not_initialized) }
+}
+
+pub fn Is_initialized() -> bool {
+    unsafe { Manager_instance.is_some() }
+}
+
+/// Wall clock time at a given instant of the monotonic clock.
+#[derive(Clone, Copy)]
+struct Wall_clock_type {
+    Instant: Instant,
+    Time: Time_type,
+}
+
+/// A manager for the system time.
+///
+/// The wall clock is derived from the monotonic clock, so that it never jumps unless it is set.
+pub struct Manager_type {
+    /// Origin of the monotonic clock.
+    Start: Instant,
+    Real_time_clock: Option<Box<dyn Real_time_clock_trait>>,
+    Wall_clock: RwLock<Wall_clock_type>,
+    Time_zone: RwLock<Time_zone_type>,
+}
+
+impl Manager_type {
+    fn New(Real_time_clock: Option<Box<dyn Real_time_clock_trait>>) -> Self {
+        // - Without a (working) real time clock, the wall clock starts at the Unix epoch until it is set
+        let Time = Real_time_clock
+            .as_ref()
+            .and_then(|Clock| Clock.Get_time().ok())
+            .unwrap_or(Time_type::Unix_epoch);
+
+        let Start = Instant::now();
+
+        Self {
+            Start,
+            Real_time_clock,
+            Wall_clock: RwLock::new(Wall_clock_type {
+                Instant: Start,
+                Time,
+            }),
+            Time_zone: RwLock::new(Time_zone_type::UTC),
+        }
+    }
+
+    /// Get the time elapsed since the initialization of the manager, which never goes backward.
+    pub fn Get_monotonic_time(&self) -> Duration {
+        self.Start.elapsed()
+    }
+
+    /// Get the current wall clock time.
+    pub fn Get_current_time(&self) -> Result_type<Time_type> {
+        let Wall_clock = *self.Wall_clock.read()?;
+
+        Ok(Wall_clock.Time + Wall_clock.Instant.elapsed())
+    }
+
+    /// Set the current wall clock time, and store it in the real time clock if possible.
+    ///
+    /// # Errors
+    /// Returns `Real_time_clock_error` if the real time clock failed to store the time, the time is set anyway.
+    pub fn Set_current_time(&self, Time: Time_type) -> Result_type<()> {
+        *self.Wall_clock.write()? = Wall_clock_type {
+            Instant: Instant::now(),
+            Time,
+        };
+
+        match self
+            .Real_time_clock
+            .as_ref()
+            .map(|Clock| Clock.Set_time(Time))
+        {
+            Some(Err(Error_type::Unsupported_operation)) | Some(Ok(())) | None => Ok(()),
+            Some(Err(_)) => Err(Error_type::Real_time_clock_error),
+        }
+    }
+
+    pub fn Get_time_zone(&self) -> Result_type<Time_zone_type> {
+        Ok(*self.Time_zone.read()?)
+    }
+
+    pub fn Set_time_zone(&self, Time_zone: Time_zone_type) -> Result_type<()> {
+        if !Time_zone.Is_valid() {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        *self.Time_zone.write()? = Time_zone;
+
+        Ok(())
+    }
+
+    /// Get the calendar date time of a time in the time zone of the system.
+    pub fn Get_local_date_time(&self, Time: Time_type) -> Result_type<Date_time_type> {
+        let Offset = self.Time_zone.read()?.Get_offset(Time);
+
+        Ok(Date_time_type::From_time(Time, Offset))
+    }
+
+    pub fn Get_current_date_time(&self) -> Result_type<Date_time_type> {
+        self.Get_local_date_time(self.Get_current_time()?)
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// Real time clock storing the time in memory, which survives the managers.
+    #[derive(Clone)]
+    struct Memory_clock_type(Arc<Mutex<Option<Time_type>>>);
+
+    impl Real_time_clock_trait for Memory_clock_type {
+        fn Get_time(&self) -> Result_type<Time_type> {
+            self.0.lock()?.ok_or(Error_type::Real_time_clock_error)
+        }
+
+        fn Set_time(&self, Time: Time_type) -> Result_type<()> {
+            *self.0.lock()? = Some(Time);
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn Test_wall_clock() {
+        let Clock = Memory_clock_type(Arc::new(Mutex::new(None)));
+
+        // - Unset clock
+        let Manager = Manager_type::New(Some(Box::new(Clock.clone())));
+
+        assert!(Manager.Get_current_time().unwrap() < Time_type::From_seconds(1));
+
+        let Time = Time_type::From_seconds(1_700_000_000);
+
+        Manager.Set_current_time(Time).unwrap();
+
+        let First = Manager.Get_current_time().unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        let Second = Manager.Get_current_time().unwrap();
+
+        assert!(
+            First >= Time && Second.Duration_since(First).unwrap() >= Duration::from_millis(20)
+        );
+
+        // - The time persists across "reboots"
+        let Manager = Manager_type::New(Some(Box::new(Clock)));
+
+        assert!(Manager.Get_current_time().unwrap() >= Time);
+
+        // - Without real time clock
+        let Manager = Manager_type::New(None);
+
+        assert_eq!(Manager.Set_current_time(Time), Ok(()));
+    }
+
+    #[test]
+    fn Test_monotonic_clock() {
+        let Manager = Manager_type::New(None);
+
+        let First = Manager.Get_monotonic_time();
+
+        // - Setting the wall clock backward doesn't affect the monotonic clock
+        Manager.Set_current_time(Time_type::Unix_epoch).unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert!(Manager.Get_monotonic_time() > First);
+    }
+
+    #[test]
+    fn Test_local_date_time() {
+        let Manager = Manager_type::New(None);
+
+        let Time = Time_type::From_seconds(1_719_835_200); // - 2024-07-01T12:00:00Z
+
+        assert_eq!(Manager.Get_local_date_time(Time).unwrap().Get_hour(), 12);
+
+        Manager
+            .Set_time_zone(Time_zone_type::From_POSIX_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap())
+            .unwrap();
+
+        let Date_time = Manager.Get_local_date_time(Time).unwrap();
+
+        assert_eq!(Date_time.Get_hour(), 14);
+        assert_eq!(Date_time.Get_offset(), 7200);
+        assert_eq!(Date_time.Get_time(), Ok(Time));
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    ops::{Add, Sub},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Point in time of the wall clock, in microseconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Time_type(u64);
+
+impl Time_type {
+    pub const Unix_epoch: Self = Self(0);
+
+    pub const fn New(Microseconds: u64) -> Self {
+        Self(Microseconds)
+    }
+
+    pub const fn From_seconds(Seconds: u64) -> Self {
+        Self(Seconds * 1_000_000)
+    }
+
+    pub const fn Get_microseconds(&self) -> u64 {
+        self.0
+    }
+
+    pub const fn Get_seconds(&self) -> u64 {
+        self.0 / 1_000_000
+    }
+
+    /// Get the duration elapsed since an earlier time, or `None` if it is later.
+    pub fn Duration_since(&self, Earlier: Time_type) -> Option<Duration> {
+        self.0.checked_sub(Earlier.0).map(Duration::from_micros)
+    }
+}
+
+impl Add<Duration> for Time_type {
+    type Output = Self;
+
+    fn add(self, Duration: Duration) -> Self::Output {
+        Self(self.0.saturating_add(Duration.as_micros() as u64))
+    }
+}
+
+impl Sub<Duration> for Time_type {
+    type Output = Self;
+
+    fn sub(self, Duration: Duration) -> Self::Output {
+        Self(self.0.saturating_sub(Duration.as_micros() as u64))
+    }
+}
+
+impl From<u64> for Time_type {
+    fn from(Microseconds: u64) -> Self {
+        Self(Microseconds)
+    }
+}
+
+impl From<Time_type> for u64 {
+    fn from(Time: Time_type) -> Self {
+        Time.0
+    }
+}
+
+/// Times before the Unix epoch are clamped to it.
+impl From<SystemTime> for Time_type {
+    fn from(Time: SystemTime) -> Self {
+        Self(
+            Time.duration_since(UNIX_EPOCH)
+                .map(|Duration| Duration.as_micros() as u64)
+                .unwrap_or(0),
+        )
+    }
+}
+
+impl From<Time_type> for SystemTime {
+    fn from(Time: Time_type) -> Self {
+        UNIX_EPOCH + Duration::from_micros(Time.0)
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+
+    #[test]
+    fn Test_time_arithmetic() {
+        let Time = Time_type::From_seconds(10);
+
+        assert_eq!(Time.Get_microseconds(), 10_000_000);
+        assert_eq!(
+            (Time + Duration::from_millis(1500)).Get_microseconds(),
+            11_500_000
+        );
+        assert_eq!(Time - Duration::from_secs(20), Time_type::Unix_epoch);
+
+        assert_eq!(
+            (Time + Duration::from_secs(5)).Duration_since(Time),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(Time.Duration_since(Time + Duration::from_secs(5)), None);
+
+        let System_time = SystemTime::from(Time);
+
+        assert_eq!(Time_type::from(System_time), Time);
+    }
+}
@@ -0,0 +1,560 @@
+use std::mem::{offset_of, size_of};
+
+use crate::{
+    Date_time::{Get_date_from_days, Get_days_from_date, Get_days_in_month, Get_weekday_from_days},
+    Error_type, Result_type, Time_type,
+};
+
+const Seconds_per_hour: i32 = 3600;
+
+/// Maximum time of a transition, in seconds after the local midnight (POSIX allows ±167 hours).
+const Maximum_transition_time: i32 = 167 * Seconds_per_hour;
+
+/// Maximum offset from UTC, in seconds.
+const Maximum_offset: i32 = 25 * Seconds_per_hour - 1;
+
+/// Rule of a transition from or to daylight saving time : a time of the `Week`th `Weekday` of `Month`
+/// (POSIX `Mm.w.d/time`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Transition_rule_type {
+    /// From `1` (January) to `12`.
+    Month: u8,
+    /// From `1` to `5`, `5` being the last week of the month.
+    Week: u8,
+    /// From `0` (Sunday) to `6`.
+    Weekday: u8,
+    /// Explicit padding, so that the rule can be copied as raw bytes.
+    _Reserved: u8,
+    /// Local time of the transition, in seconds after midnight.
+    Time: i32,
+}
+
+impl Transition_rule_type {
+    pub const fn New(Month: u8, Week: u8, Weekday: u8, Time: i32) -> Self {
+        Self {
+            Month,
+            Week,
+            Weekday,
+            _Reserved: 0,
+            Time,
+        }
+    }
+
+    pub const fn Get_month(&self) -> u8 {
+        self.Month
+    }
+
+    pub const fn Get_week(&self) -> u8 {
+        self.Week
+    }
+
+    pub const fn Get_weekday(&self) -> u8 {
+        self.Weekday
+    }
+
+    pub const fn Get_time(&self) -> i32 {
+        self.Time
+    }
+
+    pub const fn Is_valid(&self) -> bool {
+        self.Month >= 1
+            && self.Month <= 12
+            && self.Week >= 1
+            && self.Week <= 5
+            && self.Weekday <= 6
+            && self.Time.unsigned_abs() <= Maximum_transition_time as u32
+    }
+
+    /// Get the time of the transition during a year, in seconds since the Unix epoch, given the offset from UTC in
+    /// effect before the transition.
+    fn Get_seconds(&self, Year: i64, Offset: i32) -> i64 {
+        let First_day = Get_days_from_date(Year, self.Month, 1);
+        let First_weekday = Get_weekday_from_days(First_day);
+
+        let mut Day = 1 + (self.Weekday + 7 - First_weekday) % 7 + (self.Week - 1) * 7;
+
+        while Day > Get_days_in_month(Year, self.Month) {
+            Day -= 7;
+        }
+
+        (First_day + Day as i64 - 1) * 86_400 + self.Time as i64 - Offset as i64
+    }
+}
+
+/// Time zone, with an optional daylight saving time.
+///
+/// Daylight saving time is in effect from its start transition (included) to its end transition (excluded), both
+/// being in local time. There is no daylight saving time when both transitions are equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct Time_zone_type {
+    /// Offset from UTC of the standard time, in seconds (positive east of Greenwich).
+    Standard_offset: i32,
+    /// Offset from UTC of the daylight saving time, in seconds.
+    Daylight_offset: i32,
+    Daylight_start: Transition_rule_type,
+    Daylight_end: Transition_rule_type,
+}
+
+impl Default for Time_zone_type {
+    fn default() -> Self {
+        Self::UTC
+    }
+}
+
+impl Time_zone_type {
+    pub const UTC: Self = Self::New_fixed(0);
+
+    /// Create a time zone without daylight saving time, with an offset from UTC in seconds.
+    pub const fn New_fixed(Offset: i32) -> Self {
+        let Rule = Transition_rule_type::New(1, 1, 0, 0);
+
+        Self {
+            Standard_offset: Offset,
+            Daylight_offset: Offset,
+            Daylight_start: Rule,
+            Daylight_end: Rule,
+        }
+    }
+
+    /// Create a time zone with daylight saving time.
+    ///
+    /// # Errors
+    /// Returns `Invalid_time_zone` if an offset or a transition rule is out of range.
+    pub fn New(
+        Standard_offset: i32,
+        Daylight_offset: i32,
+        Daylight_start: Transition_rule_type,
+        Daylight_end: Transition_rule_type,
+    ) -> Result_type<Self> {
+        let Time_zone = Self {
+            Standard_offset,
+            Daylight_offset,
+            Daylight_start,
+            Daylight_end,
+        };
+
+        if !Time_zone.Is_valid() {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        Ok(Time_zone)
+    }
+
+    /// Parse a POSIX time zone string (e.g. `CET-1CEST,M3.5.0,M10.5.0/3`, as used by the `TZ` environment variable).
+    ///
+    /// Only the `Mm.w.d` form of the transition rules is supported. When the rules are omitted, the United States
+    /// rules are used.
+    ///
+    /// # Errors
+    /// Returns `Invalid_time_zone` if the string is malformed or unsupported.
+    pub fn From_POSIX_string(Value: &str) -> Result_type<Self> {
+        let mut Parser = Parser_type::New(Value);
+
+        Parser.Parse_name()?;
+
+        // - POSIX offsets are positive west of Greenwich
+        let Standard_offset = -Parser.Parse_time(Maximum_offset)?;
+
+        if Parser.Is_finished() {
+            return Ok(Self::New_fixed(Standard_offset));
+        }
+
+        Parser.Parse_name()?;
+
+        let Daylight_offset = match Parser.Peek() {
+            Some(b',') | None => Standard_offset + Seconds_per_hour,
+            Some(_) => -Parser.Parse_time(Maximum_offset)?,
+        };
+
+        let (Daylight_start, Daylight_end) = if Parser.Is_finished() {
+            (
+                Transition_rule_type::New(3, 2, 0, 2 * Seconds_per_hour),
+                Transition_rule_type::New(11, 1, 0, 2 * Seconds_per_hour),
+            )
+        } else {
+            Parser.Expect(b',')?;
+            let Start = Parser.Parse_rule()?;
+            Parser.Expect(b',')?;
+            let End = Parser.Parse_rule()?;
+
+            (Start, End)
+        };
+
+        if !Parser.Is_finished() {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        Self::New(
+            Standard_offset,
+            Daylight_offset,
+            Daylight_start,
+            Daylight_end,
+        )
+    }
+
+    pub const fn Get_standard_offset(&self) -> i32 {
+        self.Standard_offset
+    }
+
+    pub const fn Get_daylight_offset(&self) -> i32 {
+        self.Daylight_offset
+    }
+
+    pub const fn Get_daylight_start(&self) -> Transition_rule_type {
+        self.Daylight_start
+    }
+
+    pub const fn Get_daylight_end(&self) -> Transition_rule_type {
+        self.Daylight_end
+    }
+
+    pub fn Has_daylight_saving_time(&self) -> bool {
+        self.Daylight_start != self.Daylight_end
+    }
+
+    pub fn Is_valid(&self) -> bool {
+        self.Standard_offset.unsigned_abs() <= Maximum_offset as u32
+            && self.Daylight_offset.unsigned_abs() <= Maximum_offset as u32
+            && self.Daylight_start.Is_valid()
+            && self.Daylight_end.Is_valid()
+    }
+
+    pub fn Is_daylight_saving_time(&self, Time: Time_type) -> bool {
+        if !self.Has_daylight_saving_time() {
+            return false;
+        }
+
+        let Seconds = Time.Get_seconds() as i64;
+
+        let (Year, _, _) =
+            Get_date_from_days((Seconds + self.Standard_offset as i64).div_euclid(86_400));
+
+        let Start = self.Daylight_start.Get_seconds(Year, self.Standard_offset);
+        let End = self.Daylight_end.Get_seconds(Year, self.Daylight_offset);
+
+        if Start < End {
+            Start <= Seconds && Seconds < End
+        } else {
+            // - Southern hemisphere : the daylight saving time spans the new year
+            Seconds >= Start || Seconds < End
+        }
+    }
+
+    /// Get the offset from UTC in effect at a time, in seconds.
+    pub fn Get_offset(&self, Time: Time_type) -> i32 {
+        if self.Is_daylight_saving_time(Time) {
+            self.Daylight_offset
+        } else {
+            self.Standard_offset
+        }
+    }
+}
+
+/// Decode a time zone from raw bytes (e.g. a control argument coming from WASM), validating each field.
+impl TryFrom<&[u8]> for Time_zone_type {
+    type Error = ();
+
+    fn try_from(Value: &[u8]) -> Result<Self, Self::Error> {
+        if Value.len() != size_of::<Self>() {
+            return Err(());
+        }
+
+        let Get_i32 = |Offset: usize| -> Result<i32, ()> {
+            Ok(i32::from_ne_bytes(
+                Value[Offset..Offset + size_of::<i32>()]
+                    .try_into()
+                    .map_err(|_| ())?,
+            ))
+        };
+
+        let Get_rule = |Offset: usize| -> Result<Transition_rule_type, ()> {
+            Ok(Transition_rule_type::New(
+                Value[Offset + offset_of!(Transition_rule_type, Month)],
+                Value[Offset + offset_of!(Transition_rule_type, Week)],
+                Value[Offset + offset_of!(Transition_rule_type, Weekday)],
+                Get_i32(Offset + offset_of!(Transition_rule_type, Time))?,
+            ))
+        };
+
+        Self::New(
+            Get_i32(offset_of!(Self, Standard_offset))?,
+            Get_i32(offset_of!(Self, Daylight_offset))?,
+            Get_rule(offset_of!(Self, Daylight_start))?,
+            Get_rule(offset_of!(Self, Daylight_end))?,
+        )
+        .map_err(|_| ())
+    }
+}
+
+struct Parser_type<'a> {
+    Bytes: &'a [u8],
+    Position: usize,
+}
+
+impl<'a> Parser_type<'a> {
+    fn New(Value: &'a str) -> Self {
+        Self {
+            Bytes: Value.as_bytes(),
+            Position: 0,
+        }
+    }
+
+    fn Peek(&self) -> Option<u8> {
+        self.Bytes.get(self.Position).copied()
+    }
+
+    fn Is_finished(&self) -> bool {
+        self.Position == self.Bytes.len()
+    }
+
+    fn Expect(&mut self, Byte: u8) -> Result_type<()> {
+        if self.Peek() != Some(Byte) {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        self.Position += 1;
+
+        Ok(())
+    }
+
+    /// Parse an abbreviation, either alphabetic (`CET`) or quoted (`<+03>`), of at least 3 characters.
+    fn Parse_name(&mut self) -> Result_type<()> {
+        let Length = if self.Peek() == Some(b'<') {
+            self.Position += 1;
+
+            let Length = self.Bytes[self.Position..]
+                .iter()
+                .position(|Byte| *Byte == b'>')
+                .ok_or(Error_type::Invalid_time_zone)?;
+
+            self.Position += Length + 1;
+
+            Length
+        } else {
+            let Length = self.Bytes[self.Position..]
+                .iter()
+                .take_while(|Byte| Byte.is_ascii_alphabetic())
+                .count();
+
+            self.Position += Length;
+
+            Length
+        };
+
+        if Length < 3 {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        Ok(())
+    }
+
+    fn Parse_number(&mut self, Maximum: u32) -> Result_type<u32> {
+        let Digits = self.Bytes[self.Position..]
+            .iter()
+            .take_while(|Byte| Byte.is_ascii_digit())
+            .count();
+
+        if Digits == 0 || Digits > 3 {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        let Number = self.Bytes[self.Position..self.Position + Digits]
+            .iter()
+            .fold(0, |Number, Digit| Number * 10 + (Digit - b'0') as u32);
+
+        self.Position += Digits;
+
+        if Number > Maximum {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        Ok(Number)
+    }
+
+    /// Parse a signed time (`[+-]hh[:mm[:ss]]`) in seconds.
+    fn Parse_time(&mut self, Maximum: i32) -> Result_type<i32> {
+        let Sign = match self.Peek() {
+            Some(b'-') => {
+                self.Position += 1;
+                -1
+            }
+            Some(b'+') => {
+                self.Position += 1;
+                1
+            }
+            _ => 1,
+        };
+
+        let mut Time =
+            self.Parse_number(Maximum as u32 / Seconds_per_hour as u32)? as i32 * Seconds_per_hour;
+
+        for Factor in [60, 1] {
+            if self.Peek() != Some(b':') {
+                break;
+            }
+
+            self.Position += 1;
+            Time += self.Parse_number(59)? as i32 * Factor;
+        }
+
+        if Time > Maximum {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        Ok(Sign * Time)
+    }
+
+    /// Parse a transition rule (`Mm.w.d[/time]`), the time defaulting to 02:00:00.
+    fn Parse_rule(&mut self) -> Result_type<Transition_rule_type> {
+        self.Expect(b'M')?;
+        let Month = self.Parse_number(12)? as u8;
+        self.Expect(b'.')?;
+        let Week = self.Parse_number(5)? as u8;
+        self.Expect(b'.')?;
+        let Weekday = self.Parse_number(6)? as u8;
+
+        let Time = if self.Peek() == Some(b'/') {
+            self.Position += 1;
+            self.Parse_time(Maximum_transition_time)?
+        } else {
+            2 * Seconds_per_hour
+        };
+
+        let Rule = Transition_rule_type::New(Month, Week, Weekday, Time);
+
+        if !Rule.Is_valid() {
+            return Err(Error_type::Invalid_time_zone);
+        }
+
+        Ok(Rule)
+    }
+}
+
+#[cfg(test)]
+mod Tests {
+    use super::*;
+    use crate::Date_time_type;
+
+    fn Get_time(Year: u16, Month: u8, Day: u8, Hour: u8, Minute: u8) -> Time_type {
+        Date_time_type::New(Year, Month, Day, Hour, Minute, 0, 0)
+            .unwrap()
+            .Get_time()
+            .unwrap()
+    }
+
+    #[test]
+    fn Test_parse_fixed() {
+        assert_eq!(
+            Time_zone_type::From_POSIX_string("UTC0"),
+            Ok(Time_zone_type::UTC)
+        );
+        assert_eq!(
+            Time_zone_type::From_POSIX_string("<+0530>-5:30"),
+            Ok(Time_zone_type::New_fixed(5 * 3600 + 30 * 60))
+        );
+        assert_eq!(
+            Time_zone_type::From_POSIX_string("EST5"),
+            Ok(Time_zone_type::New_fixed(-5 * 3600))
+        );
+
+        for Invalid in [
+            "",
+            "UT0",
+            "CET",
+            "CET-1CEST,M3.5.0",
+            "CET-1CEST,J60,J300",
+            "CET-99",
+        ] {
+            assert_eq!(
+                Time_zone_type::From_POSIX_string(Invalid),
+                Err(Error_type::Invalid_time_zone),
+                "{Invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn Test_northern_daylight_saving_time() {
+        let Time_zone = Time_zone_type::From_POSIX_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+
+        assert_eq!(Time_zone.Get_standard_offset(), 3600);
+        assert_eq!(Time_zone.Get_daylight_offset(), 7200);
+        assert_eq!(
+            Time_zone.Get_daylight_end(),
+            Transition_rule_type::New(10, 5, 0, 3 * 3600)
+        );
+
+        // - 2024-03-31 02:00 CET (01:00 UTC) is the start
+        assert_eq!(Time_zone.Get_offset(Get_time(2024, 3, 31, 0, 59)), 3600);
+        assert_eq!(Time_zone.Get_offset(Get_time(2024, 3, 31, 1, 0)), 7200);
+        assert_eq!(Time_zone.Get_offset(Get_time(2024, 7, 14, 12, 0)), 7200);
+        // - 2024-10-27 03:00 CEST (01:00 UTC) is the end
+        assert_eq!(Time_zone.Get_offset(Get_time(2024, 10, 27, 0, 59)), 7200);
+        assert_eq!(Time_zone.Get_offset(Get_time(2024, 10, 27, 1, 0)), 3600);
+        assert_eq!(Time_zone.Get_offset(Get_time(2024, 12, 25, 12, 0)), 3600);
+    }
+
+    #[test]
+    fn Test_southern_daylight_saving_time() {
+        let Time_zone = Time_zone_type::From_POSIX_string("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+
+        assert!(Time_zone.Is_daylight_saving_time(Get_time(2024, 1, 15, 0, 0)));
+        assert!(!Time_zone.Is_daylight_saving_time(Get_time(2024, 7, 15, 0, 0)));
+        // - 2024-10-06 02:00 AEST (2024-10-05 16:00 UTC) is the start
+        assert!(!Time_zone.Is_daylight_saving_time(Get_time(2024, 10, 5, 15, 59)));
+        assert!(Time_zone.Is_daylight_saving_time(Get_time(2024, 10, 5, 16, 0)));
+    }
+
+    #[test]
+    fn Test_default_rules() {
+        let Time_zone = Time_zone_type::From_POSIX_string("EST5EDT").unwrap();
+
+        assert_eq!(Time_zone.Get_daylight_offset(), -4 * 3600);
+        // - 2024-03-10 02:00 EST (07:00 UTC) is the start
+        assert_eq!(
+            Time_zone.Get_offset(Get_time(2024, 3, 10, 6, 59)),
+            -5 * 3600
+        );
+        assert_eq!(Time_zone.Get_offset(Get_time(2024, 3, 10, 7, 0)), -4 * 3600);
+    }
+
+    #[test]
+    fn Test_time_zone_from_bytes() {
+        let Time_zone = Time_zone_type::From_POSIX_string("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+
+        let mut Bytes = [0_u8; size_of::<Time_zone_type>()];
+
+        let mut Set_i32 = |Offset: usize, Value: i32| {
+            Bytes[Offset..Offset + size_of::<i32>()].copy_from_slice(&Value.to_ne_bytes())
+        };
+
+        Set_i32(offset_of!(Time_zone_type, Standard_offset), 3600);
+        Set_i32(offset_of!(Time_zone_type, Daylight_offset), 7200);
+        Set_i32(
+            offset_of!(Time_zone_type, Daylight_start) + offset_of!(Transition_rule_type, Time),
+            2 * 3600,
+        );
+        Set_i32(
+            offset_of!(Time_zone_type, Daylight_end) + offset_of!(Transition_rule_type, Time),
+            3 * 3600,
+        );
+
+        for (Rule, Month) in [
+            (offset_of!(Time_zone_type, Daylight_start), 3),
+            (offset_of!(Time_zone_type, Daylight_end), 10),
+        ] {
+            Bytes[Rule + offset_of!(Transition_rule_type, Month)] = Month;
+            Bytes[Rule + offset_of!(Transition_rule_type, Week)] = 5;
+        }
+
+        assert_eq!(Time_zone_type::try_from(Bytes.as_slice()), Ok(Time_zone));
+
+        Bytes[offset_of!(Time_zone_type, Daylight_start)
+            + offset_of!(Transition_rule_type, Month)] = 13;
+
+        assert!(Time_zone_type::try_from(Bytes.as_slice()).is_err());
+        assert!(Time_zone_type::try_from(&Bytes[1..]).is_err());
+    }
+}
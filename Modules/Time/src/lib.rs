@@ -0,0 +1,21 @@
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+
+mod Clock;
+pub use Clock::*;
+
+mod Date_time;
+pub use Date_time::*;
+
+mod Error;
+pub use Error::*;
+
+mod Manager;
+pub use Manager::*;
+
+mod Time;
+pub use Time::*;
+
+mod Time_zone;
+pub use Time_zone::*;
@@ -5,9 +5,9 @@
 use wamr_rust_sdk::value::WasmValue;
 
 use Virtual_machine::{
-    Data_type, Environment_pointer_type, Environment_type, Function_descriptor_type,
-    Function_descriptors, Instantiate_test_environment, Registrable_trait, WASM_pointer,
-    WASM_usize,
+    Data_type, Environment_pointer_type, Environment_type, Error_type, Function_descriptor_type,
+    Function_descriptors, Instance_type, Instantiate_test_environment, Registrable_trait,
+    WASM_pointer, WASM_usize,
 };
 
 const Testing_slice: [i32; 10] = [9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
@@ -102,8 +102,19 @@ fn Integration_test() {
 
     let User_data = Data_type::New();
 
-    let (_Runtime, _Module, Instance) =
-        Instantiate_test_environment(Binary_buffer, Registrable {}, &User_data);
+    let (Runtime, Module, Instance) =
+        Instantiate_test_environment(Binary_buffer, Registrable {}, User_data);
+
+    // - The module needs more than a page of linear memory (at least its stack)
+    assert!(matches!(
+        Instance_type::New(
+            &Runtime,
+            &Module,
+            1024 * 4,
+            Data_type::New().Set_memory_limit(Some(64 * 1024))
+        ),
+        Err(Error_type::Memory_limit_exceeded)
+    ));
 
     let Environment =
         Environment_type::From_instance(&Instance).expect("Failed to get execution environment");
@@ -1,7 +1,36 @@
-pub struct Data_type();
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// User data attached to a virtual machine instance.
+pub struct Data_type {
+    /// Maximum size (in bytes) of the linear memory of the instance.
+    Memory_limit: Option<usize>,
+    /// Lock held while a callback of the guest runs, so that its callbacks never run concurrently.
+    Callback_lock: Mutex<()>,
+}
 
 impl Data_type {
     pub fn New() -> Self {
-        Self {}
+        Self {
+            Memory_limit: None,
+            Callback_lock: Mutex::new(()),
+        }
+    }
+
+    pub fn Set_memory_limit(mut self, Memory_limit: Option<usize>) -> Self {
+        self.Memory_limit = Memory_limit;
+        self
+    }
+
+    pub fn Get_memory_limit(&self) -> Option<usize> {
+        self.Memory_limit
+    }
+
+    /// Lock the callbacks of the instance until the guard is dropped.
+    ///
+    /// A panicking callback doesn't prevent the other callbacks from running.
+    pub fn Lock_callbacks(&self) -> MutexGuard<'_, ()> {
+        self.Callback_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
     }
 }
@@ -12,9 +12,9 @@ use wamr_rust_sdk::{
     sys::{
         wasm_exec_env_t, wasm_module_inst_t, wasm_runtime_addr_app_to_native,
         wasm_runtime_addr_native_to_app, wasm_runtime_call_indirect, wasm_runtime_create_exec_env,
-        wasm_runtime_get_exception, wasm_runtime_get_exec_env_singleton,
-        wasm_runtime_get_module_inst, wasm_runtime_get_user_data, wasm_runtime_module_free,
-        wasm_runtime_module_malloc, wasm_runtime_set_user_data, wasm_runtime_validate_app_addr,
+        wasm_runtime_get_custom_data, wasm_runtime_get_exception,
+        wasm_runtime_get_exec_env_singleton, wasm_runtime_get_module_inst,
+        wasm_runtime_module_free, wasm_runtime_module_malloc, wasm_runtime_validate_app_addr,
         wasm_runtime_validate_native_addr,
     },
     value::WasmValue,
@@ -41,7 +41,7 @@ impl<'a> Environment_type<'a> {
     }
 
     pub fn From_instance(Instance: &Instance_type) -> Result_type<Self> {
-        let Instance_pointer = Instance.Get_inner_reference().get_inner_instance();
+        let Instance_pointer = Instance.Get_inner_pointer();
 
         if Instance_pointer.is_null() {
             return Err(Error_type::Invalid_pointer);
@@ -52,30 +52,24 @@ impl<'a> Environment_type<'a> {
         ))
     }
 
-    /// # Safety
+    /// Get the user data of the instance.
     ///
-    /// This function is unsafe because it is not checked that the user data is valid pointer.
-    pub(crate) fn Set_user_data(&mut self, User_data: &Data_type) {
-        unsafe {
-            wasm_runtime_set_user_data(
-                self.0,
-                User_data as *const Data_type as *mut std::ffi::c_void,
-            );
-        }
+    /// The user data is owned by the instance (see [Instance_type::New]).
+    pub fn Get_user_data(&self) -> &Data_type {
+        self.Try_get_user_data()
+            .expect("Virtual machine user data is null")
     }
 
-    /// # Safety
-    ///
-    /// This function is unsafe because it is not checked that the user data is valid pointer.
-    #[allow(clippy::mut_from_ref)]
-    pub fn Get_user_data(&self) -> &Data_type {
+    /// Get the user data if it has been set.
+    fn Try_get_user_data(&self) -> Option<&Data_type> {
         unsafe {
-            let User_data = wasm_runtime_get_user_data(self.0);
+            let User_data = wasm_runtime_get_custom_data(self.Get_instance_pointer());
 
             if User_data.is_null() {
-                panic!("Virtual machine user data is null");
+                return None;
             }
-            &*(User_data as *const Data_type)
+
+            Some(&*(User_data as *const Data_type))
         }
     }
 
@@ -283,6 +277,19 @@ impl<'a> Environment_type<'a> {
         Ok(())
     }
 
+    /// Call a callback of the guest (e.g. a timer handler) by its function index.
+    ///
+    /// The callbacks of an instance are serialized : this waits for any other callback of the instance to return.
+    pub fn Call_callback(
+        &self,
+        Function_index: u32,
+        Parameters: &Vec<WasmValue>,
+    ) -> Result_type<()> {
+        let _Guard = self.Try_get_user_data().map(Data_type::Lock_callbacks);
+
+        self.Call_indirect_function(Function_index, Parameters)
+    }
+
     /// Create a new execution environment.
     /// This environment should be initialized with `Initialize_thread_environment` and deinitialized with `Deinitialize_thread_environment`.
     pub fn Create_environment(&self, Stack_size: usize) -> Result_type<Self> {
@@ -302,7 +309,6 @@ impl<'a> Environment_type<'a> {
         unsafe { wasm_runtime_get_module_inst(self.0) }
     }
 
-    #[allow(dead_code)]
     pub(crate) fn Get_inner_reference(&self) -> Environment_pointer_type {
         self.0
     }
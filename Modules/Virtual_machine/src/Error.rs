@@ -17,6 +17,7 @@ pub enum Error_type {
     Execution_error(String),
     Function_not_found,
     Allocation_failure,
+    Memory_limit_exceeded,
 }
 
 impl From<RuntimeError> for Error_type {
@@ -1,32 +1,119 @@
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 
-use wamr_rust_sdk::{function::Function, instance::Instance, value::WasmValue};
+use std::{
+    ffi::{c_char, CStr, CString},
+    os::raw::c_void,
+};
+
+use wamr_rust_sdk::{
+    sys::{
+        wasm_func_get_result_count, wasm_func_get_result_types, wasm_module_inst_t,
+        wasm_runtime_call_wasm, wasm_runtime_deinstantiate, wasm_runtime_get_exception,
+        wasm_runtime_instantiate_ex, wasm_runtime_lookup_function, wasm_runtime_set_custom_data,
+        wasm_valkind_t, InstantiationArgs,
+    },
+    value::WasmValue,
+};
 
 use crate::{
-    Data::Data_type, Environment_type, Module::Module_type, Result_type, Runtime::Runtime_type,
+    Data::Data_type, Environment_type, Error_type, Module::Module_type, Result_type,
+    Runtime::Runtime_type,
 };
 
-pub struct Instance_type(Instance);
+/// Size of a page of the linear memory.
+const Page_size: usize = 64 * 1024;
+
+/// Kinds of the values returned by the functions of the guest (see `wasm_valkind_t`).
+const Kind_I32: wasm_valkind_t = 0;
+const Kind_I64: wasm_valkind_t = 1;
+const Kind_F32: wasm_valkind_t = 2;
+const Kind_F64: wasm_valkind_t = 3;
+
+/// An instance of a module, which owns its user data.
+pub struct Instance_type {
+    Inner: wasm_module_inst_t,
+    /// Boxed so that the pointer given to the runtime stays valid while the instance lives.
+    _Data: Box<Data_type>,
+}
+
+unsafe impl Send for Instance_type {}
+
+unsafe impl Sync for Instance_type {}
 
 impl Instance_type {
+    /// Instantiate a module, the memory limit of `Data` caps the linear memory of the instance.
+    ///
+    /// # Errors
+    /// Returns `Memory_limit_exceeded` if the module needs more memory than the limit allows.
     pub fn New(
-        Runtime: &Runtime_type,
+        _Runtime: &Runtime_type,
         Module: &Module_type,
         Stack_size: usize,
-        Data: &Data_type,
+        Data: Data_type,
     ) -> Result_type<Self> {
-        let Instance = Instance_type(Instance::new(
-            Runtime.Get_inner_reference(),
-            Module.Get_inner_reference(),
-            Stack_size as u32,
-        )?);
+        // - `0` keeps the maximum declared by the module
+        let Maximum_pages = Data
+            .Get_memory_limit()
+            .map_or(0, |Memory_limit| (Memory_limit / Page_size).max(1) as u32);
+
+        let Inner = match Self::Instantiate(Module, Stack_size, Maximum_pages) {
+            Ok(Inner) => Inner,
+            Err(Error) => {
+                // - The limit is the cause if the module can be instantiated without it
+                if Maximum_pages != 0 {
+                    if let Ok(Inner) = Self::Instantiate(Module, Stack_size, 0) {
+                        unsafe { wasm_runtime_deinstantiate(Inner) };
+
+                        return Err(Error_type::Memory_limit_exceeded);
+                    }
+                }
+
+                return Err(Error);
+            }
+        };
 
-        let mut Execution_environment = Environment_type::From_instance(&Instance)?;
+        let Data = Box::new(Data);
 
-        Execution_environment.Set_user_data(Data);
+        // - The user data is attached once to the instance, all its environments share it
+        unsafe {
+            wasm_runtime_set_custom_data(Inner, &*Data as *const Data_type as *mut c_void);
+        }
 
-        Ok(Instance)
+        Ok(Self { Inner, _Data: Data })
+    }
+
+    fn Instantiate(
+        Module: &Module_type,
+        Stack_size: usize,
+        Maximum_pages: u32,
+    ) -> Result_type<wasm_module_inst_t> {
+        let Arguments = InstantiationArgs {
+            default_stack_size: Stack_size as u32,
+            host_managed_heap_size: 0,
+            max_memory_pages: Maximum_pages,
+        };
+
+        let mut Error_buffer = [0 as c_char; 128];
+
+        let Inner = unsafe {
+            wasm_runtime_instantiate_ex(
+                Module.Get_inner_pointer(),
+                &Arguments,
+                Error_buffer.as_mut_ptr(),
+                Error_buffer.len() as u32,
+            )
+        };
+
+        if Inner.is_null() {
+            let Message = unsafe { CStr::from_ptr(Error_buffer.as_ptr()) };
+
+            return Err(Error_type::Instantiation_failure(
+                Message.to_string_lossy().to_string(),
+            ));
+        }
+
+        Ok(Inner)
     }
 
     pub fn Call_export_function(
@@ -34,24 +121,75 @@ impl Instance_type {
         Name: &str,
         Parameters: &Vec<WasmValue>,
     ) -> Result_type<WasmValue> {
-        if Parameters.is_empty() {
-            Ok(
-                Function::find_export_func(self.Get_inner_reference(), Name)?
-                    .call(&self.0, &vec![WasmValue::I32(0)])?,
-            )
-        } else {
-            Ok(
-                Function::find_export_func(self.Get_inner_reference(), Name)?
-                    .call(&self.0, Parameters)?,
+        let Name = CString::new(Name).map_err(|_| Error_type::Function_not_found)?;
+
+        let Function = unsafe { wasm_runtime_lookup_function(self.Inner, Name.as_ptr()) };
+
+        if Function.is_null() {
+            return Err(Error_type::Function_not_found);
+        }
+
+        let mut Arguments = Vec::new();
+
+        for Parameter in Parameters {
+            Arguments.append(&mut Parameter.encode());
+        }
+
+        let Arguments_count = Arguments.len() as u32;
+
+        // - The results are written over the arguments, an `i64` or a `f64` takes two cells
+        Arguments.resize(Arguments.len().max(2), 0);
+
+        let Result_kind = unsafe {
+            let mut Result_kinds =
+                vec![0; wasm_func_get_result_count(Function, self.Inner) as usize];
+
+            wasm_func_get_result_types(Function, self.Inner, Result_kinds.as_mut_ptr());
+
+            Result_kinds.first().copied()
+        };
+
+        let Environment = Environment_type::From_instance(self)?;
+
+        if !unsafe {
+            wasm_runtime_call_wasm(
+                Environment.Get_inner_reference(),
+                Function,
+                Arguments_count,
+                Arguments.as_mut_ptr(),
             )
+        } {
+            let Exception_message =
+                unsafe { CStr::from_ptr(wasm_runtime_get_exception(self.Inner)) };
+
+            return Err(Error_type::Execution_error(
+                Exception_message.to_string_lossy().to_string(),
+            ));
         }
+
+        let Low = Arguments[0];
+        let Wide = (Arguments[1] as u64) << 32 | Low as u64;
+
+        Ok(match Result_kind {
+            Some(Kind_I32) => WasmValue::I32(Low as i32),
+            Some(Kind_I64) => WasmValue::I64(Wide as i64),
+            Some(Kind_F32) => WasmValue::F32(f32::from_bits(Low)),
+            Some(Kind_F64) => WasmValue::F64(f64::from_bits(Wide)),
+            _ => WasmValue::Void,
+        })
     }
 
     pub fn Call_main(&self, Parameters: &Vec<WasmValue>) -> Result_type<WasmValue> {
         self.Call_export_function("main", Parameters)
     }
 
-    pub(crate) fn Get_inner_reference(&self) -> &Instance {
-        &self.0
+    pub(crate) fn Get_inner_pointer(&self) -> wasm_module_inst_t {
+        self.Inner
+    }
+}
+
+impl Drop for Instance_type {
+    fn drop(&mut self) {
+        unsafe { wasm_runtime_deinstantiate(self.Inner) }
     }
 }
@@ -1,4 +1,4 @@
-use wamr_rust_sdk::{module::Module, RuntimeError};
+use wamr_rust_sdk::{module::Module, sys::wasm_module_t, RuntimeError};
 
 use crate::Runtime::Runtime_type;
 
@@ -20,4 +20,8 @@ impl Module_type {
     pub(crate) fn Get_inner_reference(&self) -> &Module {
         &self.0
     }
+
+    pub(crate) fn Get_inner_pointer(&self) -> wasm_module_t {
+        self.0.get_inner_module()
+    }
 }
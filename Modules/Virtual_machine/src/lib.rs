@@ -25,7 +25,7 @@ pub type WASM_usize = u32;
 pub fn Instantiate_test_environment(
     Binary_buffer: &[u8],
     Registrable: impl Registrable_trait,
-    User_data: &Data_type,
+    User_data: Data_type,
 ) -> (Runtime_type, Module_type, Instance_type) {
     let Runtime = Runtime_type::Builder()
         .Register(Registrable)